@@ -5,9 +5,12 @@ use hmac::{Hmac, Mac};
 use log::{debug, warn};
 use moka::sync::Cache;
 use quick_xml::de::from_str as from_xml_str;
+use quick_xml::escape::escape as xml_escape;
+use reqwest::header::HeaderValue;
 use reqwest::{Client, Method, Request, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::Value;
 use sha1::Sha1;
 use sha2::{Digest, Sha256};
 use std::sync::LazyLock;
@@ -15,26 +18,58 @@ use std::time::Duration;
 
 use super::auth::credentials::Credentials;
 use super::models::cce::{
-    CceClusterCertRequest, CceClusterListResponse, CceCreateClusterRequest,
-    CceCreateNodePoolRequest, CceNodePoolListResponse, CceUpdateClusterRequest,
-    CceUpdateClusterSpec,
+    CceAddonInstance, CceAddonInstanceListResponse, CceAddonRequest, CceAddonTemplateListResponse,
+    CceCluster, CceClusterCertRequest, CceClusterListResponse, CceClusterTask,
+    CceCreateClusterRequest, CceCreateNodePoolRequest, CceNode, CceNodeListResponse, CceNodePool,
+    CceNodePoolListResponse, CcePrecheckRequest, CceRemoveNodesRequest, CceResetNodesRequest,
+    CceUpdateClusterRequest, CceUpdateClusterSpec, CceUpgradeInfo, CceUpgradeRequest,
 };
 use super::models::ecs::{
-    CreateEcsRequest, DeleteEcsRequest, DeleteEcsServer, EcsListResponse, Flavor,
-    FlavorListResponse, StopEcsAction, StopEcsRequest, StopEcsServer,
+    AttachVolumeBody, AttachVolumeRequest, ChangeEcsOsBody, ChangeEcsOsRequest, CreateEcsRequest,
+    DeleteEcsRequest, DeleteEcsServer, EcsListResponse, EcsServer, EcsServerDetailResponse, Flavor,
+    FlavorListResponse, ReinstallEcsBody, ReinstallEcsRequest, ResizeEcsBody, ResizeEcsRequest,
+    StopEcsAction, StopEcsRequest, StopEcsServer,
 };
 use super::models::eip::{
-    CreatePublicIpBandwidth, CreatePublicIpBody, CreatePublicIpRequest, EipListResponse,
+    BandwidthInsertBody, BandwidthInsertRequest, BandwidthListResponse, BandwidthPublicIpRef,
+    BandwidthRemoveBody, BandwidthRemoveRequest, BandwidthSingleResponse, BandwidthUpdateBody,
+    BandwidthUpdateRequest, CreatePublicIpBandwidth, CreatePublicIpBody, CreatePublicIpRequest,
+    EipAssociateBody, EipAssociateRequest, EipBandwidth, EipListResponse, EipSingleResponse,
+    EipUpdateBody, EipUpdateRequest, PublicIp, SharedBandwidthCreateBody,
+    SharedBandwidthCreateRequest,
+};
+use super::models::eps::EnterpriseProjectListResponse;
+use super::models::evs::{
+    EvsCreateBody, EvsCreateRequest, EvsExtendBody, EvsExtendRequest, EvsListResponse, EvsSnapshot,
+    EvsSnapshotCreateBody, EvsSnapshotCreateRequest, EvsSnapshotListResponse,
+    EvsSnapshotRollbackBody, EvsSnapshotRollbackRequest, EvsSnapshotSingleResponse, EvsVolume,
+    EvsVolumeSingleResponse,
 };
-use super::models::evs::EvsListResponse;
 use super::models::iam::ProjectsResponse;
-use super::models::ims::{Image, ImageListResponse};
+use super::models::ims::{
+    Image, ImageCreateFromServerRequest, ImageExportRequest, ImageImportRequest, ImageListResponse,
+    ImageMemberStatusRequest, ImageMembersRequest, WholeImageCreateRequest,
+};
 use super::models::nat::{
-    NatGatewayCreateBody, NatGatewayCreateRequest, NatGatewayListResponse,
-    NatGatewaySingleResponse, SnatRuleCreateBody, SnatRuleCreateRequest, SnatRuleListResponse,
+    DnatRuleCreateBody, DnatRuleCreateRequest, DnatRuleListResponse, NatGatewayCreateBody,
+    NatGatewayCreateRequest, NatGatewayListResponse, NatGatewaySingleResponse,
+    NatGatewayUpdateBody, NatGatewayUpdateRequest, SnatRuleCreateBody, SnatRuleCreateRequest,
+    SnatRuleListResponse, SnatRuleUpdateBody, SnatRuleUpdateRequest,
 };
 use super::models::obs::{ObsBucket, ObsListBucketsResponse, ObsListObjectsResponse, ObsObject};
-use super::models::vpc::{Subnet, SubnetListResponse, Vpc, VpcListResponse};
+use super::models::quotas::{
+    CceQuotaResponse, EcsLimitsResponse, EvsQuotaSetResponse, VpcQuotaResponse,
+};
+use super::models::tags::{
+    CceClusterTagsRequest, ResourceInstancesFilterRequest, ResourceInstancesResponse, ResourceTag,
+    TagActionEntry, TagActionRequest, TagFilter, TaggedResource,
+};
+use super::models::vpc::{
+    SecurityGroup, SecurityGroupCreateBody, SecurityGroupCreateRequest, SecurityGroupListResponse,
+    SecurityGroupRuleCreateBody, SecurityGroupRuleCreateRequest, SecurityGroupRuleListResponse,
+    Subnet, SubnetCreateBody, SubnetCreateRequest, SubnetListResponse, Vpc, VpcCreateBody,
+    VpcCreateRequest, VpcListResponse, VpcSingleResponse,
+};
 
 type HmacSha256 = Hmac<Sha256>;
 type HmacSha1 = Hmac<Sha1>;
@@ -45,13 +80,16 @@ const HEADER_HOST: &str = "Host";
 const HEADER_DATE: &str = "X-Sdk-Date";
 const HEADER_AUTH: &str = "Authorization";
 const HEADER_CONTENT_TYPE: &str = "Content-Type";
+const HEADER_PROJECT_ID: &str = "X-Project-Id";
 const HEADER_DATE_RFC1123: &str = "Date";
 const HEADER_CONTENT_MD5: &str = "Content-MD5";
 const CONTENT_TYPE_JSON: &str = "application/json";
 const CONTENT_TYPE_XML: &str = "application/xml";
+const PUBLIC_IP_LOOKUP_URL: &str = "https://api.ipify.org";
 const OBS_AUTH_PREFIX: &str = "OBS";
 const OBS_HEADER_PREFIX: &str = "x-obs-";
 const IAM_PROJECTS_PATH: &str = "/v3/auth/projects";
+const EPS_HOST: &str = "eps.myhuaweicloud.com";
 const PROJECT_ID_CACHE_MAX_CAPACITY: u64 = 256;
 const PROJECT_ID_CACHE_TTL_SECS: u64 = 900;
 
@@ -120,6 +158,8 @@ pub struct ListParams {
     pub marker: Option<String>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// Enterprise project ID, or `all_granted_eps` for every project the caller can see.
+    pub enterprise_project_id: Option<String>,
 }
 
 fn push_query_param(params: &mut Vec<String>, key: &str, value: &str) {
@@ -134,6 +174,27 @@ fn push_query_param_u32(params: &mut Vec<String>, key: &str, value: Option<u32>)
     }
 }
 
+/// Detect the public IPv4 address this machine egresses from.
+pub async fn lookup_public_ipv4() -> Result<std::net::Ipv4Addr> {
+    let resp = SHARED_HTTP_CLIENT
+        .get(PUBLIC_IP_LOOKUP_URL)
+        .send()
+        .await
+        .context("Public IP lookup request failed")?;
+    let status = resp.status();
+    let text = resp
+        .text()
+        .await
+        .context("Failed to read public IP lookup response")?;
+    if !status.is_success() {
+        anyhow::bail!("Public IP lookup returned {}", status);
+    }
+
+    text.trim()
+        .parse()
+        .with_context(|| format!("Public IP lookup returned an invalid IPv4 address: {text}"))
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 struct ObsListBucketsXml {
@@ -157,6 +218,66 @@ struct ObsBucketXml {
     bucket_type: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ObsInitiateMultipartUploadXml {
+    upload_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ObsTaggingXml {
+    #[serde(default)]
+    tag_set: ObsTagSetXml,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ObsTagSetXml {
+    #[serde(rename = "Tag", default)]
+    tag: Vec<ObsTagXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ObsTagXml {
+    key: Option<String>,
+    value: Option<String>,
+}
+
+/// Services sharing the `tags/action` and `resource_instances/action` tag APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagService {
+    Ecs,
+    Evs,
+    Eip,
+    NatGateway,
+}
+
+impl TagService {
+    /// Host and collection path that the tag endpoints hang off.
+    fn endpoint(self, region: &str, project_id: &str) -> (String, String) {
+        match self {
+            TagService::Ecs => (
+                format!("ecs.{region}.myhuaweicloud.com"),
+                format!("/v1/{project_id}/cloudservers"),
+            ),
+            TagService::Evs => (
+                format!("evs.{region}.myhuaweicloud.com"),
+                format!("/v2/{project_id}/cloudvolumes"),
+            ),
+            TagService::Eip => (
+                format!("vpc.{region}.myhuaweicloud.com"),
+                format!("/v2.0/{project_id}/publicips"),
+            ),
+            TagService::NatGateway => (
+                format!("nat.{region}.myhuaweicloud.com"),
+                format!("/v2.0/{project_id}/nat_gateways"),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 struct ObsListObjectsXml {
@@ -188,11 +309,82 @@ impl HwcClient {
         }
     }
 
-    /// List VPCs for the given region.
-    pub async fn list_vpcs(&self, region: &str) -> Result<Vec<Vpc>> {
+    /// Read ECS instance, core and RAM limits for the project.
+    /// ECS Querying Tenant Quotas: GET /v1/{project_id}/cloudservers/limits
+    pub async fn get_ecs_limits(&self, region: &str) -> Result<EcsLimitsResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/limits");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to query ECS quotas")
+    }
+
+    /// Read EVS volume and capacity quotas with current usage.
+    /// EVS Querying Tenant Quotas: GET /v2/{project_id}/os-quota-sets/{target_project_id}?usage=True
+    pub async fn get_evs_quotas(&self, region: &str) -> Result<EvsQuotaSetResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/os-quota-sets/{project_id}?usage=True");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to query EVS quotas")
+    }
+
+    /// Read VPC network quotas (VPCs, subnets, security groups, EIPs, ...).
+    /// VPC Querying Quotas: GET /v1/{project_id}/quotas
+    pub async fn get_vpc_quotas(&self, region: &str) -> Result<VpcQuotaResponse> {
         let project_id = self.project_id(region).await?;
         let host = format!("vpc.{region}.myhuaweicloud.com");
-        let path = format!("/v1/{project_id}/vpcs");
+        let path = format!("/v1/{project_id}/quotas");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to query VPC quotas")
+    }
+
+    /// Read CCE resource quotas (clusters, nodes, ...).
+    /// CCE Querying Resource Quotas: GET /api/v3/projects/{project_id}/quotas
+    pub async fn get_cce_quotas(&self, region: &str) -> Result<CceQuotaResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/quotas");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to query CCE quotas")
+    }
+
+    /// List the enabled enterprise projects visible to the caller.
+    /// EPS Querying Enterprise Projects: GET /v1.0/enterprise-projects
+    pub async fn list_enterprise_projects(&self) -> Result<EnterpriseProjectListResponse> {
+        self.send_json(
+            Method::GET,
+            EPS_HOST,
+            "/v1.0/enterprise-projects?status=1&limit=1000",
+            None,
+        )
+        .await
+        .context("Failed to list enterprise projects")
+    }
+
+    /// List VPCs for the given region, optionally scoped to one enterprise project.
+    pub async fn list_vpcs(
+        &self,
+        region: &str,
+        enterprise_project_id: Option<&str>,
+    ) -> Result<Vec<Vpc>> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = match enterprise_project_id {
+            Some(project) => format!(
+                "/v1/{project_id}/vpcs?enterprise_project_id={}",
+                encode_rfc3986(project)
+            ),
+            None => format!("/v1/{project_id}/vpcs"),
+        };
 
         let body: VpcListResponse = self
             .send_json(Method::GET, &host, &path, None)
@@ -216,6 +408,80 @@ impl HwcClient {
         Ok(body.subnets)
     }
 
+    /// Query one VPC.
+    /// VPC Querying VPC Details: GET /v1/{project_id}/vpcs/{vpc_id}
+    pub async fn get_vpc(&self, region: &str, vpc_id: &str) -> Result<Vpc> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/vpcs/{vpc_id}");
+
+        let body: VpcSingleResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get VPC")?;
+
+        Ok(body.vpc)
+    }
+
+    /// Create one VPC.
+    /// VPC Creating a VPC: POST /v1/{project_id}/vpcs
+    pub async fn create_vpc(
+        &self,
+        region: &str,
+        body: &VpcCreateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/vpcs");
+        let payload = VpcCreateRequest { vpc: body.clone() };
+        let json = serde_json::to_string(&payload).context("Failed to serialize VPC payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete one VPC.
+    /// VPC Deleting a VPC: DELETE /v1/{project_id}/vpcs/{vpc_id}
+    pub async fn delete_vpc(&self, region: &str, vpc_id: &str) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/vpcs/{vpc_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Create one subnet.
+    /// VPC Creating a Subnet: POST /v1/{project_id}/subnets
+    pub async fn create_subnet(
+        &self,
+        region: &str,
+        body: &SubnetCreateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/subnets");
+        let payload = SubnetCreateRequest {
+            subnet: body.clone(),
+        };
+        let json = serde_json::to_string(&payload).context("Failed to serialize subnet payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete one subnet.
+    /// VPC Deleting a Subnet: DELETE /v1/{project_id}/vpcs/{vpc_id}/subnets/{subnet_id}
+    pub async fn delete_subnet(
+        &self,
+        region: &str,
+        vpc_id: &str,
+        subnet_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/vpcs/{vpc_id}/subnets/{subnet_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
     /// List images for the given region.
     /// IMS Querying Images: GET https://{Endpoint}/v2/cloudimages
     pub async fn list_images(
@@ -252,6 +518,132 @@ impl HwcClient {
         Ok(body.images)
     }
 
+    /// Capture the system disk of one ECS as a private image.
+    /// IMS Creating a System Disk Image: POST /v2/cloudimages/action
+    pub async fn create_image_from_server(
+        &self,
+        region: &str,
+        body: &ImageCreateFromServerRequest,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let json = serde_json::to_string(body).context("Failed to serialize image payload")?;
+
+        self.send_raw(Method::POST, &host, "/v2/cloudimages/action", Some(json))
+            .await
+    }
+
+    /// Capture one ECS with all of its disks as a whole image.
+    /// IMS Creating a Full-ECS Image: POST /v1/cloudimages/wholeimages/action
+    pub async fn create_whole_image_from_server(
+        &self,
+        region: &str,
+        body: &WholeImageCreateRequest,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize whole image payload")?;
+
+        self.send_raw(
+            Method::POST,
+            &host,
+            "/v1/cloudimages/wholeimages/action",
+            Some(json),
+        )
+        .await
+    }
+
+    /// Register a system image from an image file already stored in OBS.
+    /// IMS Registering an Image File as a Private Image: POST /v2/cloudimages/action
+    pub async fn import_image_from_obs(
+        &self,
+        region: &str,
+        body: &ImageImportRequest,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize image import payload")?;
+
+        self.send_raw(Method::POST, &host, "/v2/cloudimages/action", Some(json))
+            .await
+    }
+
+    /// Export one private image to an OBS bucket.
+    /// IMS Exporting an Image: POST /v1/cloudimages/{image_id}/file
+    pub async fn export_image_to_obs(
+        &self,
+        region: &str,
+        image_id: &str,
+        body: &ImageExportRequest,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let path = format!("/v1/cloudimages/{image_id}/file");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize image export payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Query one IMS job by ID and return status + raw body.
+    /// IMS Querying the Status of an Asynchronous Job: GET /v1/{project_id}/jobs/{job_id}
+    pub async fn get_ims_job(&self, region: &str, job_id: &str) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/jobs/{job_id}");
+
+        self.send_raw(Method::GET, &host, &path, None).await
+    }
+
+    /// Delete one private image.
+    /// IMS Deleting an Image (Native OpenStack API): DELETE /v2/images/{image_id}
+    pub async fn delete_image(&self, region: &str, image_id: &str) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let path = format!("/v2/images/{image_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Share images with other projects (`add`) or stop sharing them (`remove`).
+    /// IMS Adding Image Members in Batches: POST /v1/cloudimages/members
+    /// IMS Deleting Image Members in Batches: DELETE /v1/cloudimages/members
+    pub async fn update_image_members(
+        &self,
+        region: &str,
+        add: bool,
+        body: &ImageMembersRequest,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let method = if add { Method::POST } else { Method::DELETE };
+        let json =
+            serde_json::to_string(body).context("Failed to serialize image members payload")?;
+
+        self.send_raw(method, &host, "/v1/cloudimages/members", Some(json))
+            .await
+    }
+
+    /// Accept or reject images shared with the caller's project in `region`.
+    /// IMS Updating Image Sharing Member Statuses in Batches: PUT /v1/cloudimages/members
+    pub async fn respond_image_share(
+        &self,
+        region: &str,
+        image_ids: &[String],
+        accept: bool,
+        vault_id: Option<&str>,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ims.{region}.myhuaweicloud.com");
+        let payload = ImageMemberStatusRequest {
+            images: image_ids.to_vec(),
+            project_id,
+            status: if accept { "accepted" } else { "rejected" }.to_string(),
+            vault_id: vault_id.map(str::to_string),
+        };
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize image member status payload")?;
+
+        self.send_raw(Method::PUT, &host, "/v1/cloudimages/members", Some(json))
+            .await
+    }
+
     /// List flavors for the given region.
     /// ECS Querying Flavors: GET https://{Endpoint}/v1/{project_id}/cloudservers/flavors
     pub async fn list_flavors(&self, region: &str) -> Result<Vec<Flavor>> {
@@ -285,6 +677,9 @@ impl HwcClient {
             }
             push_query_param_u32(&mut query, "limit", params.limit);
             push_query_param_u32(&mut query, "offset", params.offset);
+            if let Some(project) = params.enterprise_project_id.as_deref() {
+                push_query_param(&mut query, "enterprise_project_id", project);
+            }
         }
 
         let query_string = if query.is_empty() {
@@ -322,6 +717,9 @@ impl HwcClient {
                 push_query_param(&mut query, "marker", marker);
             }
             push_query_param_u32(&mut query, "limit", params.limit);
+            if let Some(project) = params.enterprise_project_id.as_deref() {
+                push_query_param(&mut query, "enterprise_project_id", project);
+            }
         }
 
         let base_path = format!("/v1.1/{project_id}/cloudservers/detail");
@@ -353,6 +751,9 @@ impl HwcClient {
             }
             push_query_param_u32(&mut query, "limit", params.limit);
             push_query_param_u32(&mut query, "offset", params.offset);
+            if let Some(project) = params.enterprise_project_id.as_deref() {
+                push_query_param(&mut query, "enterprise_project_id", project);
+            }
         }
 
         let base_path = format!("/v2/{project_id}/cloudvolumes/detail");
@@ -367,422 +768,1527 @@ impl HwcClient {
             .context("Failed to list EVS disks")
     }
 
-    /// List CCE clusters for the given region.
-    /// CCE Querying Clusters: GET /api/v3/projects/{project_id}/clusters
-    pub async fn list_cce_clusters(&self, region: &str) -> Result<CceClusterListResponse> {
+    /// Query one EVS disk.
+    /// EVS Querying Details About an EVS Disk: GET /v2/{project_id}/cloudvolumes/{volume_id}
+    pub async fn get_evs_volume(&self, region: &str, volume_id: &str) -> Result<EvsVolume> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters?detail=true");
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudvolumes/{volume_id}");
 
-        self.send_json(Method::GET, &host, &path, None)
+        let body: EvsVolumeSingleResponse = self
+            .send_json(Method::GET, &host, &path, None)
             .await
-            .context("Failed to list CCE clusters")
+            .context("Failed to get EVS disk")?;
+
+        Ok(body.volume)
     }
 
-    /// Create a CCE cluster and return status + raw response body.
-    /// CCE Creating a Cluster: POST /api/v3/projects/{project_id}/clusters
-    pub async fn create_cce_cluster(
+    /// Create one pay-per-use EVS disk.
+    /// EVS Creating EVS Disks: POST /v2.1/{project_id}/cloudvolumes
+    pub async fn create_evs_volume(
         &self,
         region: &str,
-        body: &CceCreateClusterRequest,
+        body: &EvsCreateBody,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters");
-        let json = serde_json::to_string(body).context("Failed to serialize CCE payload")?;
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2.1/{project_id}/cloudvolumes");
+        let payload = EvsCreateRequest {
+            volume: body.clone(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize EVS create payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Delete one CCE cluster.
-    /// CCE Deleting a Cluster: DELETE /api/v3/projects/{project_id}/clusters/{cluster_id}
-    pub async fn delete_cce_cluster(
+    /// Expand one EVS disk to a larger size in GiB.
+    /// EVS Expanding EVS Disk Capacity: POST /v2.1/{project_id}/cloudvolumes/{volume_id}/action
+    pub async fn extend_evs_volume(
         &self,
         region: &str,
-        cluster_id: &str,
+        volume_id: &str,
+        new_size: u32,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}");
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2.1/{project_id}/cloudvolumes/{volume_id}/action");
+        let payload = EvsExtendRequest {
+            os_extend: EvsExtendBody { new_size },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize EVS extend payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete one EVS disk.
+    /// EVS Deleting an EVS Disk: DELETE /v2/{project_id}/cloudvolumes/{volume_id}
+    pub async fn delete_evs_volume(
+        &self,
+        region: &str,
+        volume_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudvolumes/{volume_id}");
 
         self.send_raw(Method::DELETE, &host, &path, None).await
     }
 
-    /// List node pools under one CCE cluster.
-    /// CCE Querying Node Pools: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools
-    pub async fn list_cce_node_pools(
+    /// List EVS snapshots, optionally only those of one disk.
+    /// EVS Querying EVS Snapshots: GET /v2/{project_id}/cloudsnapshots/detail
+    pub async fn list_evs_snapshots(
         &self,
         region: &str,
-        cluster_id: &str,
-    ) -> Result<CceNodePoolListResponse> {
+        volume_id: Option<&str>,
+    ) -> Result<EvsSnapshotListResponse> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools");
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let mut query: Vec<String> = Vec::new();
+        if let Some(volume_id) = volume_id {
+            push_query_param(&mut query, "volume_id", volume_id);
+        }
+
+        let base_path = format!("/v2/{project_id}/cloudsnapshots/detail");
+        let path = if query.is_empty() {
+            base_path
+        } else {
+            format!("{base_path}?{}", query.join("&"))
+        };
 
         self.send_json(Method::GET, &host, &path, None)
             .await
-            .context("Failed to list CCE node pools")
+            .context("Failed to list EVS snapshots")
     }
 
-    /// Create one node pool under one CCE cluster.
-    /// CCE Creating a Node Pool: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools
-    pub async fn create_cce_node_pool(
+    /// Create one snapshot of an EVS disk.
+    /// EVS Creating an EVS Snapshot: POST /v2/{project_id}/cloudsnapshots
+    pub async fn create_evs_snapshot(
         &self,
         region: &str,
-        cluster_id: &str,
-        body: &CceCreateNodePoolRequest,
-    ) -> Result<(StatusCode, String)> {
+        body: &EvsSnapshotCreateBody,
+    ) -> Result<EvsSnapshot> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools");
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudsnapshots");
+        let payload = EvsSnapshotCreateRequest {
+            snapshot: body.clone(),
+        };
         let json =
-            serde_json::to_string(body).context("Failed to serialize CCE node pool payload")?;
+            serde_json::to_string(&payload).context("Failed to serialize EVS snapshot payload")?;
 
-        self.send_raw(Method::POST, &host, &path, Some(json)).await
+        let body: EvsSnapshotSingleResponse = self
+            .send_json(Method::POST, &host, &path, Some(json))
+            .await
+            .context("Failed to create EVS snapshot")?;
+
+        Ok(body.snapshot)
+    }
+
+    /// Delete one EVS snapshot.
+    /// EVS Deleting an EVS Snapshot: DELETE /v2/{project_id}/cloudsnapshots/{snapshot_id}
+    pub async fn delete_evs_snapshot(
+        &self,
+        region: &str,
+        snapshot_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudsnapshots/{snapshot_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Roll one EVS disk back to a snapshot; the disk must be detached.
+    /// EVS Rolling Back a Snapshot to an EVS Disk: POST /v2/{project_id}/cloudsnapshots/{snapshot_id}/rollback
+    pub async fn rollback_evs_snapshot(
+        &self,
+        region: &str,
+        snapshot_id: &str,
+        volume_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("evs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudsnapshots/{snapshot_id}/rollback");
+        let payload = EvsSnapshotRollbackRequest {
+            rollback: EvsSnapshotRollbackBody {
+                volume_id: volume_id.to_string(),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize EVS rollback payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// List CCE clusters for the given region.
+    /// CCE Querying Clusters: GET /api/v3/projects/{project_id}/clusters
+    pub async fn list_cce_clusters(&self, region: &str) -> Result<CceClusterListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters?detail=true");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list CCE clusters")
+    }
+
+    /// Create a CCE cluster and return status + raw response body.
+    /// CCE Creating a Cluster: POST /api/v3/projects/{project_id}/clusters
+    pub async fn create_cce_cluster(
+        &self,
+        region: &str,
+        body: &CceCreateClusterRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters");
+        let json = serde_json::to_string(body).context("Failed to serialize CCE payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete one CCE cluster.
+    /// CCE Deleting a Cluster: DELETE /api/v3/projects/{project_id}/clusters/{cluster_id}
+    pub async fn delete_cce_cluster(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// List node pools under one CCE cluster.
+    /// CCE Querying Node Pools: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools
+    pub async fn list_cce_node_pools(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<CceNodePoolListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list CCE node pools")
+    }
+
+    /// Create one node pool under one CCE cluster.
+    /// CCE Creating a Node Pool: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools
+    pub async fn create_cce_node_pool(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        body: &CceCreateNodePoolRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize CCE node pool payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete one node pool under one CCE cluster.
+    /// CCE Deleting a Node Pool: DELETE /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{nodepool_id}
+    pub async fn delete_cce_node_pool(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        node_pool_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{node_pool_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// List nodes under one CCE cluster.
+    /// CCE Querying All Nodes: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/nodes
+    pub async fn list_cce_nodes(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<CceNodeListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodes");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list CCE nodes")
+    }
+
+    /// Fetch one node under one CCE cluster.
+    /// CCE Querying a Node: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/{node_id}
+    pub async fn get_cce_node(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        node_id: &str,
+    ) -> Result<CceNode> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/{node_id}");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get CCE node")
+    }
+
+    /// Delete one node (and its ECS) under one CCE cluster.
+    /// CCE Deleting a Node: DELETE /api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/{node_id}
+    pub async fn delete_cce_node(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        node_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/{node_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Reinstall the OS of nodes under one CCE cluster and rejoin them.
+    /// CCE Resetting a Node: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/reset
+    pub async fn reset_cce_nodes(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        body: &CceResetNodesRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/reset");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize CCE node reset payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Remove nodes from their cluster and node pool while keeping the ECS.
+    /// CCE Removing a Node: PUT /api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/operation/remove
+    pub async fn remove_cce_nodes(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        body: &CceRemoveNodesRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodes/operation/remove");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize CCE node removal payload")?;
+
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
+    }
+
+    /// Fetch one node pool under one CCE cluster.
+    /// CCE Querying a Node Pool: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{nodepool_id}
+    pub async fn get_cce_node_pool(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        node_pool_id: &str,
+    ) -> Result<CceNodePool> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{node_pool_id}");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get CCE node pool")
+    }
+
+    /// Update node count, autoscaling and node template fields of one node pool.
+    /// CCE Updating a Node Pool: PUT /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{nodepool_id}
+    pub async fn update_cce_node_pool(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        node_pool_id: &str,
+        body: &Value,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{node_pool_id}");
+        let json = serde_json::to_string(body)
+            .context("Failed to serialize CCE node pool update payload")?;
+
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
+    }
+
+    /// Fetch one CCE cluster.
+    /// CCE Querying a Cluster: GET /api/v3/projects/{project_id}/clusters/{cluster_id}
+    pub async fn get_cce_cluster(&self, region: &str, cluster_id: &str) -> Result<CceCluster> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get CCE cluster")
+    }
+
+    /// Query the versions one CCE cluster can be upgraded to.
+    /// CCE Obtaining Cluster Upgrade Information: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/upgradeinfo
+    pub async fn get_cce_upgrade_info(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<CceUpgradeInfo> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/upgradeinfo");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get CCE cluster upgrade info")
+    }
+
+    /// Start the pre-upgrade check of one CCE cluster.
+    /// CCE Performing a Pre-upgrade Check: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/operation/precheck
+    pub async fn create_cce_upgrade_precheck(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        body: &CcePrecheckRequest,
+    ) -> Result<CceClusterTask> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/operation/precheck");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize CCE precheck payload")?;
+
+        self.send_json(Method::POST, &host, &path, Some(json))
+            .await
+            .context("Failed to start CCE pre-upgrade check")
+    }
+
+    /// Query one pre-upgrade check task of one CCE cluster.
+    /// CCE Obtaining Details About a Pre-upgrade Check Task: GET /api/v3/projects/{project_id}/clusters/{cluster_id}/operation/precheck/tasks/{task_id}
+    pub async fn get_cce_upgrade_precheck(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        task_id: &str,
+    ) -> Result<CceClusterTask> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/api/v3/projects/{project_id}/clusters/{cluster_id}/operation/precheck/tasks/{task_id}"
+        );
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get CCE pre-upgrade check task")
+    }
+
+    /// Start upgrading one CCE cluster to a target version.
+    /// CCE Upgrading a Cluster: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/operation/upgrade
+    pub async fn upgrade_cce_cluster(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        body: &CceUpgradeRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/operation/upgrade");
+        let json =
+            serde_json::to_string(body).context("Failed to serialize CCE upgrade payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Hibernate one CCE cluster and return status + raw body.
+    /// CCE Hibernating a Cluster: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/operation/hibernate
+    pub async fn hibernate_cce_cluster(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path =
+            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/operation/hibernate");
+
+        self.send_raw(Method::POST, &host, &path, None).await
+    }
+
+    /// Wake one hibernated CCE cluster and return status + raw body.
+    /// CCE Waking Up a Cluster: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/operation/awake
+    pub async fn awake_cce_cluster(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/operation/awake");
+
+        self.send_raw(Method::POST, &host, &path, None).await
+    }
+
+    /// List add-on templates from the CCE catalog, optionally for one add-on name.
+    /// CCE Querying an Add-on Template List: GET /api/v3/addontemplates
+    pub async fn list_cce_addon_templates(
+        &self,
+        region: &str,
+        template_name: Option<&str>,
+    ) -> Result<CceAddonTemplateListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = match template_name
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(name) => format!(
+                "/api/v3/addontemplates?addon_template_name={}",
+                encode_rfc3986(name)
+            ),
+            None => "/api/v3/addontemplates".to_string(),
+        };
+
+        self.send_project_json(Method::GET, &host, &path, &project_id, None)
+            .await
+            .context("Failed to list CCE add-on templates")
+    }
+
+    /// List add-ons installed in one CCE cluster.
+    /// CCE Listing Add-on Instances: GET /api/v3/addons?cluster_id={cluster_id}
+    pub async fn list_cce_addons(
+        &self,
+        region: &str,
+        cluster_id: &str,
+    ) -> Result<CceAddonInstanceListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/addons?cluster_id={}", encode_rfc3986(cluster_id));
+
+        self.send_project_json(Method::GET, &host, &path, &project_id, None)
+            .await
+            .context("Failed to list CCE add-ons")
+    }
+
+    /// Get one add-on installed in a CCE cluster.
+    /// CCE Reading an Add-on Instance: GET /api/v3/addons/{id}?cluster_id={cluster_id}
+    pub async fn get_cce_addon(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        addon_id: &str,
+    ) -> Result<CceAddonInstance> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/api/v3/addons/{addon_id}?cluster_id={}",
+            encode_rfc3986(cluster_id)
+        );
+
+        self.send_project_json(Method::GET, &host, &path, &project_id, None)
+            .await
+            .context("Failed to get CCE add-on")
+    }
+
+    /// Install an add-on into a CCE cluster and return status + raw body.
+    /// CCE Creating an Add-on Instance: POST /api/v3/addons
+    pub async fn create_cce_addon(
+        &self,
+        region: &str,
+        body: &CceAddonRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let json = serde_json::to_string(body).context("Failed to serialize CCE add-on payload")?;
+
+        self.send_project_raw(
+            Method::POST,
+            &host,
+            "/api/v3/addons",
+            &project_id,
+            Some(json),
+        )
+        .await
+    }
+
+    /// Upgrade or reconfigure an installed CCE add-on and return status + raw body.
+    /// CCE Updating an Add-on Instance: PUT /api/v3/addons/{id}
+    pub async fn update_cce_addon(
+        &self,
+        region: &str,
+        addon_id: &str,
+        body: &CceAddonRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/addons/{addon_id}");
+        let json = serde_json::to_string(body).context("Failed to serialize CCE add-on payload")?;
+
+        self.send_project_raw(Method::PUT, &host, &path, &project_id, Some(json))
+            .await
+    }
+
+    /// Uninstall an add-on from a CCE cluster and return status + raw body.
+    /// CCE Deleting an Add-on Instance: DELETE /api/v3/addons/{id}?cluster_id={cluster_id}
+    pub async fn delete_cce_addon(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        addon_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/api/v3/addons/{addon_id}?cluster_id={}",
+            encode_rfc3986(cluster_id)
+        );
+
+        self.send_project_raw(Method::DELETE, &host, &path, &project_id, None)
+            .await
+    }
+
+    /// Query one CCE job by ID and return status + raw body.
+    /// CCE Querying Task Status: GET /api/v3/projects/{project_id}/jobs/{job_id}
+    pub async fn get_cce_job(&self, region: &str, job_id: &str) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/jobs/{job_id}");
+
+        self.send_raw(Method::GET, &host, &path, None).await
+    }
+
+    /// List NAT gateways in one region (optionally filtered by VPC/subnet/enterprise project).
+    /// NAT Querying Public NAT Gateways: GET /v2/{project_id}/nat_gateways
+    pub async fn list_nat_gateways(
+        &self,
+        region: &str,
+        vpc_id: Option<&str>,
+        subnet_id: Option<&str>,
+        enterprise_project_id: Option<&str>,
+    ) -> Result<NatGatewayListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let mut query: Vec<String> = Vec::new();
+        if let Some(value) = vpc_id.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(format!("router_id={}", encode_rfc3986(value)));
+        }
+        if let Some(value) = subnet_id.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(format!("internal_network_id={}", encode_rfc3986(value)));
+        }
+        if let Some(value) = enterprise_project_id {
+            query.push(format!("enterprise_project_id={}", encode_rfc3986(value)));
+        }
+
+        let base_path = format!("/v2/{project_id}/nat_gateways");
+        let path = if query.is_empty() {
+            base_path
+        } else {
+            format!("{base_path}?{}", query.join("&"))
+        };
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list NAT gateways")
+    }
+
+    /// Create one NAT gateway.
+    /// NAT Creating a Public NAT Gateway: POST /v2/{project_id}/nat_gateways
+    pub async fn create_nat_gateway(
+        &self,
+        region: &str,
+        body: &NatGatewayCreateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/nat_gateways");
+        let payload = NatGatewayCreateRequest {
+            nat_gateway: body.clone(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize NAT gateway payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Query one NAT gateway.
+    /// NAT Querying Public NAT Gateway Details: GET /v2/{project_id}/nat_gateways/{nat_gateway_id}
+    pub async fn get_nat_gateway(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+    ) -> Result<NatGatewaySingleResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}");
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get NAT gateway")
+    }
+
+    /// Create one SNAT rule.
+    /// NAT Creating an SNAT Rule: POST /v2/{project_id}/snat_rules
+    pub async fn create_snat_rule(
+        &self,
+        region: &str,
+        body: &SnatRuleCreateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/snat_rules");
+        let payload = SnatRuleCreateRequest {
+            snat_rule: body.clone(),
+        };
+        let json = serde_json::to_string(&payload).context("Failed to serialize SNAT payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Replace the EIPs or description of one SNAT rule.
+    /// NAT Updating an SNAT Rule: PUT /v2/{project_id}/snat_rules/{snat_rule_id}
+    pub async fn update_snat_rule(
+        &self,
+        region: &str,
+        snat_rule_id: &str,
+        body: &SnatRuleUpdateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/snat_rules/{snat_rule_id}");
+        let payload = SnatRuleUpdateRequest {
+            snat_rule: body.clone(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize SNAT update payload")?;
+
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
+    }
+
+    /// List SNAT rules for one NAT gateway.
+    /// NAT Querying SNAT Rules: GET /v2/{project_id}/snat_rules?nat_gateway_id={nat_gateway_id}
+    pub async fn list_snat_rules(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+    ) -> Result<SnatRuleListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/v2/{project_id}/snat_rules?nat_gateway_id={}",
+            encode_rfc3986(nat_gateway_id)
+        );
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list SNAT rules")
+    }
+
+    /// Delete one SNAT rule.
+    /// NAT Deleting an SNAT Rule:
+    /// - Preferred: DELETE /v2/{project_id}/nat_gateways/{nat_gateway_id}/snat_rules/{snat_rule_id}
+    /// - Fallback:  DELETE /v2/{project_id}/snat_rules/{snat_rule_id}
+    pub async fn delete_snat_rule(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+        snat_rule_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let scoped_path =
+            format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}/snat_rules/{snat_rule_id}");
+        let (scoped_status, scoped_body) = self
+            .send_raw(Method::DELETE, &host, &scoped_path, None)
+            .await?;
+        if scoped_status != StatusCode::NOT_FOUND && scoped_status != StatusCode::METHOD_NOT_ALLOWED
+        {
+            return Ok((scoped_status, scoped_body));
+        }
+
+        let legacy_path = format!("/v2/{project_id}/snat_rules/{snat_rule_id}");
+        self.send_raw(Method::DELETE, &host, &legacy_path, None)
+            .await
+    }
+
+    /// Create one DNAT rule.
+    /// NAT Creating a DNAT Rule: POST /v2/{project_id}/dnat_rules
+    pub async fn create_dnat_rule(
+        &self,
+        region: &str,
+        body: &DnatRuleCreateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/dnat_rules");
+        let payload = DnatRuleCreateRequest {
+            dnat_rule: body.clone(),
+        };
+        let json = serde_json::to_string(&payload).context("Failed to serialize DNAT payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// List DNAT rules for one NAT gateway.
+    /// NAT Querying DNAT Rules: GET /v2/{project_id}/dnat_rules?nat_gateway_id={nat_gateway_id}
+    pub async fn list_dnat_rules(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+    ) -> Result<DnatRuleListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/v2/{project_id}/dnat_rules?nat_gateway_id={}",
+            encode_rfc3986(nat_gateway_id)
+        );
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list DNAT rules")
+    }
+
+    /// Delete one DNAT rule.
+    /// NAT Deleting a DNAT Rule:
+    /// - Preferred: DELETE /v2/{project_id}/nat_gateways/{nat_gateway_id}/dnat_rules/{dnat_rule_id}
+    /// - Fallback:  DELETE /v2/{project_id}/dnat_rules/{dnat_rule_id}
+    pub async fn delete_dnat_rule(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+        dnat_rule_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let scoped_path =
+            format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}/dnat_rules/{dnat_rule_id}");
+        let (scoped_status, scoped_body) = self
+            .send_raw(Method::DELETE, &host, &scoped_path, None)
+            .await?;
+        if scoped_status != StatusCode::NOT_FOUND && scoped_status != StatusCode::METHOD_NOT_ALLOWED
+        {
+            return Ok((scoped_status, scoped_body));
+        }
+
+        let legacy_path = format!("/v2/{project_id}/dnat_rules/{dnat_rule_id}");
+        self.send_raw(Method::DELETE, &host, &legacy_path, None)
+            .await
+    }
+
+    /// Change the name, description or spec of one NAT gateway.
+    /// NAT Updating a Public NAT Gateway: PUT /v2/{project_id}/nat_gateways/{nat_gateway_id}
+    pub async fn update_nat_gateway(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+        body: &NatGatewayUpdateBody,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}");
+        let payload = NatGatewayUpdateRequest {
+            nat_gateway: body.clone(),
+        };
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize NAT gateway update payload")?;
+
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
+    }
+
+    /// Delete one NAT gateway.
+    /// NAT Deleting a Public NAT Gateway: DELETE /v2/{project_id}/nat_gateways/{nat_gateway_id}
+    pub async fn delete_nat_gateway(
+        &self,
+        region: &str,
+        nat_gateway_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("nat.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Create an ECS instance and return the status + raw response body.
+    pub async fn create_ecs(
+        &self,
+        region: &str,
+        body: &CreateEcsRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers");
+        let json = serde_json::to_string(body).context("Failed to serialize ECS payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete an ECS instance.
+    /// ECS Deleting ECSs in Batches: POST /v1/{project_id}/cloudservers/delete
+    pub async fn delete_ecs(
+        &self,
+        region: &str,
+        server_id: &str,
+        delete_publicip: bool,
+        delete_volume: bool,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/delete");
+        let payload = DeleteEcsRequest {
+            servers: vec![DeleteEcsServer {
+                id: server_id.to_string(),
+            }],
+            delete_publicip: Some(delete_publicip),
+            delete_volume: Some(delete_volume),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize ECS delete payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Delete an Elastic IP.
+    /// EIP Deleting an Elastic IP: DELETE /v1/{project_id}/publicips/{publicip_id}
+    pub async fn delete_eip(&self, region: &str, eip_id: &str) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+
+        // Try v3 first; fall back to v1 where needed.
+        let path_v3 = format!("/v3/{project_id}/eip/publicips/{eip_id}");
+        let (status_v3, body_v3) = self.send_raw(Method::DELETE, &host, &path_v3, None).await?;
+        if status_v3 != StatusCode::NOT_FOUND && status_v3 != StatusCode::METHOD_NOT_ALLOWED {
+            return Ok((status_v3, body_v3));
+        }
+
+        let path_v1 = format!("/v1/{project_id}/publicips/{eip_id}");
+        self.send_raw(Method::DELETE, &host, &path_v1, None).await
+    }
+
+    /// Create an Elastic IP.
+    /// EIP Applying for an EIP: POST /v1/{project_id}/publicips
+    pub async fn create_eip(
+        &self,
+        region: &str,
+        bandwidth_size: u32,
+        bandwidth_name: Option<&str>,
+        enterprise_project_id: Option<&str>,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/publicips");
+        let generated_name = format!("cce-nat-eip-{}", Utc::now().format("%Y%m%d%H%M%S"));
+        let payload = CreatePublicIpRequest {
+            publicip: CreatePublicIpBody {
+                ip_type: "5_bgp".to_string(),
+                alias: None,
+            },
+            bandwidth: CreatePublicIpBandwidth {
+                id: None,
+                name: Some(
+                    bandwidth_name
+                        .map(str::trim)
+                        .filter(|value| !value.is_empty())
+                        .unwrap_or(generated_name.as_str())
+                        .to_string(),
+                ),
+                size: Some(bandwidth_size),
+                share_type: "PER".to_string(),
+                charge_mode: Some("traffic".to_string()),
+            },
+            enterprise_project_id: enterprise_project_id.map(str::to_string),
+        };
+        let json = serde_json::to_string(&payload).context("Failed to serialize EIP payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Allocate one EIP with caller-provided type and bandwidth settings.
+    /// EIP Assigning an EIP: POST /v1/{project_id}/publicips
+    pub async fn allocate_eip(
+        &self,
+        region: &str,
+        body: &CreatePublicIpRequest,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/publicips");
+        let json = serde_json::to_string(body).context("Failed to serialize EIP payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Query one EIP.
+    /// EIP Querying an EIP: GET /v3/{project_id}/eip/publicips/{publicip_id}
+    pub async fn get_eip(&self, region: &str, eip_id: &str) -> Result<PublicIp> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/eip/publicips/{eip_id}");
+
+        let body: EipSingleResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get EIP")?;
+
+        Ok(body.publicip)
+    }
+
+    /// Bind one EIP to a port or another supported instance.
+    /// EIP Binding an EIP: POST /v3/{project_id}/eip/publicips/{publicip_id}/associate-instance
+    pub async fn associate_eip(
+        &self,
+        region: &str,
+        eip_id: &str,
+        instance_type: &str,
+        instance_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/eip/publicips/{eip_id}/associate-instance");
+        let payload = EipAssociateRequest {
+            publicip: EipAssociateBody {
+                associate_instance_type: instance_type.to_string(),
+                associate_instance_id: instance_id.to_string(),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize EIP bind payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Unbind one EIP from whatever instance it is attached to.
+    /// EIP Unbinding an EIP: POST /v3/{project_id}/eip/publicips/{publicip_id}/disassociate-instance
+    pub async fn disassociate_eip(
+        &self,
+        region: &str,
+        eip_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/eip/publicips/{eip_id}/disassociate-instance");
+
+        self.send_raw(Method::POST, &host, &path, None).await
+    }
+
+    /// Update alias and/or description of one EIP.
+    /// EIP Updating an EIP: PUT /v3/{project_id}/eip/publicips/{publicip_id}
+    pub async fn update_eip(
+        &self,
+        region: &str,
+        eip_id: &str,
+        alias: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/eip/publicips/{eip_id}");
+        let payload = EipUpdateRequest {
+            publicip: EipUpdateBody {
+                alias: alias.map(str::to_string),
+                description: description.map(str::to_string),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize EIP update payload")?;
+
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
     }
 
-    /// Delete one node pool under one CCE cluster.
-    /// CCE Deleting a Node Pool: DELETE /api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{nodepool_id}
-    pub async fn delete_cce_node_pool(
+    /// Query one bandwidth, including the EIPs that use it.
+    /// VPC Querying a Bandwidth: GET /v1/{project_id}/bandwidths/{bandwidth_id}
+    pub async fn get_bandwidth(&self, region: &str, bandwidth_id: &str) -> Result<EipBandwidth> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/bandwidths/{bandwidth_id}");
+
+        let body: BandwidthSingleResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get bandwidth")?;
+
+        Ok(body.bandwidth)
+    }
+
+    /// List shared (WHOLE) bandwidths.
+    /// VPC Querying Bandwidths: GET /v1/{project_id}/bandwidths?share_type=WHOLE
+    pub async fn list_shared_bandwidths(&self, region: &str) -> Result<Vec<EipBandwidth>> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/bandwidths?share_type=WHOLE");
+
+        let body: BandwidthListResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list shared bandwidths")?;
+
+        Ok(body.bandwidths)
+    }
+
+    /// Change the size, charge mode or name of one bandwidth.
+    /// VPC Updating a Bandwidth: PUT /v1/{project_id}/bandwidths/{bandwidth_id}
+    pub async fn update_bandwidth(
         &self,
         region: &str,
-        cluster_id: &str,
-        node_pool_id: &str,
+        bandwidth_id: &str,
+        body: &BandwidthUpdateBody,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path =
-            format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/nodepools/{node_pool_id}");
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/bandwidths/{bandwidth_id}");
+        let payload = BandwidthUpdateRequest {
+            bandwidth: body.clone(),
+        };
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize bandwidth update payload")?;
 
-        self.send_raw(Method::DELETE, &host, &path, None).await
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
     }
 
-    /// Query one CCE job by ID and return status + raw body.
-    /// CCE Querying Task Status: GET /api/v3/projects/{project_id}/jobs/{job_id}
-    pub async fn get_cce_job(&self, region: &str, job_id: &str) -> Result<(StatusCode, String)> {
+    /// Create one shared bandwidth.
+    /// VPC Assigning a Shared Bandwidth: POST /v2.0/{project_id}/bandwidths
+    pub async fn create_shared_bandwidth(
+        &self,
+        region: &str,
+        name: &str,
+        size: u32,
+        charge_mode: Option<&str>,
+    ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/jobs/{job_id}");
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v2.0/{project_id}/bandwidths");
+        let payload = SharedBandwidthCreateRequest {
+            bandwidth: SharedBandwidthCreateBody {
+                name: name.to_string(),
+                size,
+                charge_mode: charge_mode.map(str::to_string),
+            },
+        };
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize shared bandwidth payload")?;
 
-        self.send_raw(Method::GET, &host, &path, None).await
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// List NAT gateways in one region (optionally filtered by VPC/subnet).
-    /// NAT Querying Public NAT Gateways: GET /v2/{project_id}/nat_gateways
-    pub async fn list_nat_gateways(
+    /// Delete one shared bandwidth.
+    /// VPC Deleting a Shared Bandwidth: DELETE /v2.0/{project_id}/bandwidths/{bandwidth_id}
+    pub async fn delete_shared_bandwidth(
         &self,
         region: &str,
-        vpc_id: Option<&str>,
-        subnet_id: Option<&str>,
-    ) -> Result<NatGatewayListResponse> {
+        bandwidth_id: &str,
+    ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let mut query: Vec<String> = Vec::new();
-        if let Some(value) = vpc_id.map(str::trim).filter(|value| !value.is_empty()) {
-            query.push(format!("router_id={}", encode_rfc3986(value)));
-        }
-        if let Some(value) = subnet_id.map(str::trim).filter(|value| !value.is_empty()) {
-            query.push(format!("internal_network_id={}", encode_rfc3986(value)));
-        }
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v2.0/{project_id}/bandwidths/{bandwidth_id}");
 
-        let base_path = format!("/v2/{project_id}/nat_gateways");
-        let path = if query.is_empty() {
-            base_path
-        } else {
-            format!("{base_path}?{}", query.join("&"))
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Move EIPs into one shared bandwidth.
+    /// VPC Adding EIPs to a Shared Bandwidth: POST /v2.0/{project_id}/bandwidths/{bandwidth_id}/insert
+    pub async fn insert_bandwidth_eips(
+        &self,
+        region: &str,
+        bandwidth_id: &str,
+        eip_ids: &[String],
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v2.0/{project_id}/bandwidths/{bandwidth_id}/insert");
+        let payload = BandwidthInsertRequest {
+            bandwidth: BandwidthInsertBody {
+                publicip_info: eip_ids
+                    .iter()
+                    .map(|id| BandwidthPublicIpRef {
+                        publicip_id: id.clone(),
+                    })
+                    .collect(),
+            },
         };
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize bandwidth insert payload")?;
 
-        self.send_json(Method::GET, &host, &path, None)
-            .await
-            .context("Failed to list NAT gateways")
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Create one NAT gateway.
-    /// NAT Creating a Public NAT Gateway: POST /v2/{project_id}/nat_gateways
-    pub async fn create_nat_gateway(
+    /// Move EIPs out of one shared bandwidth onto new dedicated bandwidths.
+    /// VPC Removing EIPs from a Shared Bandwidth: POST /v2.0/{project_id}/bandwidths/{bandwidth_id}/remove
+    pub async fn remove_bandwidth_eips(
         &self,
         region: &str,
-        name: &str,
-        description: Option<&str>,
-        spec: &str,
-        vpc_id: &str,
-        subnet_id: &str,
+        bandwidth_id: &str,
+        eip_ids: &[String],
+        charge_mode: &str,
+        size: u32,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let path = format!("/v2/{project_id}/nat_gateways");
-        let payload = NatGatewayCreateRequest {
-            nat_gateway: NatGatewayCreateBody {
-                name: name.to_string(),
-                description: description.map(str::to_string),
-                spec: spec.to_string(),
-                router_id: vpc_id.to_string(),
-                internal_network_id: subnet_id.to_string(),
-                enterprise_project_id: None,
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v2.0/{project_id}/bandwidths/{bandwidth_id}/remove");
+        let payload = BandwidthRemoveRequest {
+            bandwidth: BandwidthRemoveBody {
+                charge_mode: charge_mode.to_string(),
+                size,
+                publicip_info: eip_ids
+                    .iter()
+                    .map(|id| BandwidthPublicIpRef {
+                        publicip_id: id.clone(),
+                    })
+                    .collect(),
             },
         };
-        let json =
-            serde_json::to_string(&payload).context("Failed to serialize NAT gateway payload")?;
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize bandwidth remove payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Query one NAT gateway.
-    /// NAT Querying Public NAT Gateway Details: GET /v2/{project_id}/nat_gateways/{nat_gateway_id}
-    pub async fn get_nat_gateway(
+    /// Update one CCE cluster with an external API EIP.
+    /// CCE Updating a Cluster: PUT /api/v3/projects/{project_id}/clusters/{cluster_id}
+    pub async fn update_cce_cluster_external_ip(
         &self,
         region: &str,
-        nat_gateway_id: &str,
-    ) -> Result<NatGatewaySingleResponse> {
+        cluster_id: &str,
+        external_ip: &str,
+    ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let path = format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}");
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}");
+        let payload = CceUpdateClusterRequest {
+            spec: CceUpdateClusterSpec {
+                cluster_external_ip: external_ip.to_string(),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize CCE access payload")?;
 
-        self.send_json(Method::GET, &host, &path, None)
-            .await
-            .context("Failed to get NAT gateway")
+        self.send_raw(Method::PUT, &host, &path, Some(json)).await
     }
 
-    /// Create one SNAT rule.
-    /// NAT Creating an SNAT Rule: POST /v2/{project_id}/snat_rules
-    pub async fn create_snat_rule(
+    /// Request cluster cert/config payload for kubeconfig usage.
+    /// CCE Creating a User Certificate: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/clustercert
+    pub async fn get_cce_cluster_kubeconfig(
         &self,
         region: &str,
-        nat_gateway_id: &str,
-        subnet_id: &str,
-        floating_ip_id: &str,
+        cluster_id: &str,
+        context: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let path = format!("/v2/{project_id}/snat_rules");
-        let payload = SnatRuleCreateRequest {
-            snat_rule: SnatRuleCreateBody {
-                nat_gateway_id: nat_gateway_id.to_string(),
-                network_id: subnet_id.to_string(),
-                floating_ip_id: floating_ip_id.to_string(),
-            },
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/clustercert");
+        let payload = CceClusterCertRequest {
+            context: context
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string),
         };
-        let json = serde_json::to_string(&payload).context("Failed to serialize SNAT payload")?;
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize CCE clustercert payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// List SNAT rules for one NAT gateway.
-    /// NAT Querying SNAT Rules: GET /v2/{project_id}/snat_rules?nat_gateway_id={nat_gateway_id}
-    pub async fn list_snat_rules(
+    /// Stop ECS instances.
+    /// ECS Batch Stop: POST /v1/{project_id}/cloudservers/action with os-stop body.
+    pub async fn stop_ecs(
         &self,
         region: &str,
-        nat_gateway_id: &str,
-    ) -> Result<SnatRuleListResponse> {
+        server_id: &str,
+        stop_type: &str,
+    ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/action");
+        let payload = StopEcsRequest {
+            os_stop: StopEcsAction {
+                servers: vec![StopEcsServer {
+                    id: server_id.to_string(),
+                }],
+                stop_type: stop_type.to_string(),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize ECS stop payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Query one ECS instance.
+    /// ECS Querying Details About an ECS: GET /v1/{project_id}/cloudservers/{server_id}
+    pub async fn get_ecs(&self, region: &str, server_id: &str) -> Result<EcsServer> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/{server_id}");
+
+        let body: EcsServerDetailResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to get ECS details")?;
+
+        Ok(body.server)
+    }
+
+    /// List flavors one ECS instance can be resized to.
+    /// ECS Querying Target Flavors: GET /v1/{project_id}/cloudservers/resize_flavors?instance_uuid={server_id}
+    pub async fn list_resize_flavors(&self, region: &str, server_id: &str) -> Result<Vec<Flavor>> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
         let path = format!(
-            "/v2/{project_id}/snat_rules?nat_gateway_id={}",
-            encode_rfc3986(nat_gateway_id)
+            "/v1/{project_id}/cloudservers/resize_flavors?instance_uuid={}",
+            encode_rfc3986(server_id)
         );
 
-        self.send_json(Method::GET, &host, &path, None)
+        let body: FlavorListResponse = self
+            .send_json(Method::GET, &host, &path, None)
             .await
-            .context("Failed to list SNAT rules")
+            .context("Failed to list ECS resize flavors")?;
+
+        Ok(body.flavors)
     }
 
-    /// Delete one SNAT rule.
-    /// NAT Deleting an SNAT Rule:
-    /// - Preferred: DELETE /v2/{project_id}/nat_gateways/{nat_gateway_id}/snat_rules/{snat_rule_id}
-    /// - Fallback:  DELETE /v2/{project_id}/snat_rules/{snat_rule_id}
-    pub async fn delete_snat_rule(
+    /// Change the flavor of one ECS instance.
+    /// ECS Modifying ECS Specifications: POST /v1.1/{project_id}/cloudservers/{server_id}/resize
+    pub async fn resize_ecs(
         &self,
         region: &str,
-        nat_gateway_id: &str,
-        snat_rule_id: &str,
+        server_id: &str,
+        flavor_id: &str,
+        mode: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let scoped_path =
-            format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}/snat_rules/{snat_rule_id}");
-        let (scoped_status, scoped_body) = self
-            .send_raw(Method::DELETE, &host, &scoped_path, None)
-            .await?;
-        if scoped_status != StatusCode::NOT_FOUND && scoped_status != StatusCode::METHOD_NOT_ALLOWED
-        {
-            return Ok((scoped_status, scoped_body));
-        }
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1.1/{project_id}/cloudservers/{server_id}/resize");
+        let payload = ResizeEcsRequest {
+            resize: ResizeEcsBody {
+                flavor_ref: flavor_id.to_string(),
+                mode: mode.map(str::to_string),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize ECS resize payload")?;
 
-        let legacy_path = format!("/v2/{project_id}/snat_rules/{snat_rule_id}");
-        self.send_raw(Method::DELETE, &host, &legacy_path, None)
-            .await
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Delete one NAT gateway.
-    /// NAT Deleting a Public NAT Gateway: DELETE /v2/{project_id}/nat_gateways/{nat_gateway_id}
-    pub async fn delete_nat_gateway(
+    /// Reinstall the current OS image of one ECS instance.
+    /// ECS Reinstalling an ECS OS: POST /v2/{project_id}/cloudservers/{server_id}/reinstallos
+    pub async fn reinstall_ecs(
         &self,
         region: &str,
-        nat_gateway_id: &str,
+        server_id: &str,
+        admin_password: Option<&str>,
+        key_name: Option<&str>,
+        mode: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("nat.{region}.myhuaweicloud.com");
-        let path = format!("/v2/{project_id}/nat_gateways/{nat_gateway_id}");
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v2/{project_id}/cloudservers/{server_id}/reinstallos");
+        let payload = ReinstallEcsRequest {
+            os_reinstall: ReinstallEcsBody {
+                adminpass: admin_password.map(str::to_string),
+                keyname: key_name.map(str::to_string),
+                mode: mode.map(str::to_string),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize ECS reinstall payload")?;
 
-        self.send_raw(Method::DELETE, &host, &path, None).await
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Create an ECS instance and return the status + raw response body.
-    pub async fn create_ecs(
+    /// Switch one ECS instance to a different OS image.
+    /// ECS Changing an ECS OS: POST /v2/{project_id}/cloudservers/{server_id}/changeos
+    pub async fn change_ecs_os(
         &self,
         region: &str,
-        body: &CreateEcsRequest,
+        server_id: &str,
+        image_id: &str,
+        admin_password: Option<&str>,
+        key_name: Option<&str>,
+        mode: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
         let host = format!("ecs.{region}.myhuaweicloud.com");
-        let path = format!("/v1/{project_id}/cloudservers");
-        let json = serde_json::to_string(body).context("Failed to serialize ECS payload")?;
+        let path = format!("/v2/{project_id}/cloudservers/{server_id}/changeos");
+        let payload = ChangeEcsOsRequest {
+            os_change: ChangeEcsOsBody {
+                imageid: image_id.to_string(),
+                adminpass: admin_password.map(str::to_string),
+                keyname: key_name.map(str::to_string),
+                mode: mode.map(str::to_string),
+            },
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize ECS change OS payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Delete an ECS instance.
-    /// ECS Deleting ECSs in Batches: POST /v1/{project_id}/cloudservers/delete
-    pub async fn delete_ecs(
+    /// Attach one EVS disk to an ECS instance.
+    /// ECS Attaching a Disk to an ECS: POST /v1/{project_id}/cloudservers/{server_id}/attachvolume
+    pub async fn attach_ecs_volume(
         &self,
         region: &str,
         server_id: &str,
-        delete_publicip: bool,
-        delete_volume: bool,
+        volume_id: &str,
+        device: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("ecs.{region}.myhuaweicloud.com");
-        let path = format!("/v1/{project_id}/cloudservers/delete");
-        let payload = DeleteEcsRequest {
-            servers: vec![DeleteEcsServer {
-                id: server_id.to_string(),
-            }],
-            delete_publicip: Some(delete_publicip),
-            delete_volume: Some(delete_volume),
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/{server_id}/attachvolume");
+        let payload = AttachVolumeRequest {
+            volume_attachment: AttachVolumeBody {
+                volume_id: volume_id.to_string(),
+                device: device.map(str::to_string),
+            },
         };
         let json =
-            serde_json::to_string(&payload).context("Failed to serialize ECS delete payload")?;
+            serde_json::to_string(&payload).context("Failed to serialize attach volume payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Delete an Elastic IP.
-    /// EIP Deleting an Elastic IP: DELETE /v1/{project_id}/publicips/{publicip_id}
-    pub async fn delete_eip(&self, region: &str, eip_id: &str) -> Result<(StatusCode, String)> {
+    /// Detach one EVS disk from an ECS instance.
+    /// ECS Detaching a Disk from an ECS: DELETE /v1/{project_id}/cloudservers/{server_id}/detachvolume/{volume_id}
+    pub async fn detach_ecs_volume(
+        &self,
+        region: &str,
+        server_id: &str,
+        volume_id: &str,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/cloudservers/{server_id}/detachvolume/{volume_id}");
+
+        self.send_raw(Method::DELETE, &host, &path, None).await
+    }
+
+    /// Query one ECS job by ID and return status + raw body.
+    /// ECS Querying Task Execution Status: GET /v1/{project_id}/jobs/{job_id}
+    pub async fn get_ecs_job(&self, region: &str, job_id: &str) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("ecs.{region}.myhuaweicloud.com");
+        let path = format!("/v1/{project_id}/jobs/{job_id}");
+
+        self.send_raw(Method::GET, &host, &path, None).await
+    }
+
+    /// List security groups for the given region.
+    /// VPC Querying Security Groups: GET /v3/{project_id}/vpc/security-groups
+    pub async fn list_security_groups(&self, region: &str) -> Result<Vec<SecurityGroup>> {
         let project_id = self.project_id(region).await?;
         let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/vpc/security-groups?limit=2000");
 
-        // Try v3 first; fall back to v1 where needed.
-        let path_v3 = format!("/v3/{project_id}/eip/publicips/{eip_id}");
-        let (status_v3, body_v3) = self.send_raw(Method::DELETE, &host, &path_v3, None).await?;
-        if status_v3 != StatusCode::NOT_FOUND && status_v3 != StatusCode::METHOD_NOT_ALLOWED {
-            return Ok((status_v3, body_v3));
-        }
+        let body: SecurityGroupListResponse = self
+            .send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list security groups")?;
 
-        let path_v1 = format!("/v1/{project_id}/publicips/{eip_id}");
-        self.send_raw(Method::DELETE, &host, &path_v1, None).await
+        Ok(body.security_groups)
     }
 
-    /// Create an Elastic IP.
-    /// EIP Applying for an EIP: POST /v1/{project_id}/publicips
-    pub async fn create_eip(
+    /// Create one security group.
+    /// VPC Creating a Security Group: POST /v3/{project_id}/vpc/security-groups
+    pub async fn create_security_group(
         &self,
         region: &str,
-        bandwidth_size: u32,
-        bandwidth_name: Option<&str>,
+        name: &str,
+        description: Option<&str>,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
         let host = format!("vpc.{region}.myhuaweicloud.com");
-        let path = format!("/v1/{project_id}/publicips");
-        let generated_name = format!("cce-nat-eip-{}", Utc::now().format("%Y%m%d%H%M%S"));
-        let payload = CreatePublicIpRequest {
-            publicip: CreatePublicIpBody {
-                ip_type: "5_bgp".to_string(),
-            },
-            bandwidth: CreatePublicIpBandwidth {
-                name: bandwidth_name
-                    .map(str::trim)
-                    .filter(|value| !value.is_empty())
-                    .unwrap_or(generated_name.as_str())
-                    .to_string(),
-                size: bandwidth_size,
-                share_type: "PER".to_string(),
-                charge_mode: "traffic".to_string(),
+        let path = format!("/v3/{project_id}/vpc/security-groups");
+        let payload = SecurityGroupCreateRequest {
+            security_group: SecurityGroupCreateBody {
+                name: name.to_string(),
+                description: description.map(str::to_string),
             },
         };
-        let json = serde_json::to_string(&payload).context("Failed to serialize EIP payload")?;
+        let json = serde_json::to_string(&payload)
+            .context("Failed to serialize security group payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Update one CCE cluster with an external API EIP.
-    /// CCE Updating a Cluster: PUT /api/v3/projects/{project_id}/clusters/{cluster_id}
-    pub async fn update_cce_cluster_external_ip(
+    /// Delete one security group.
+    /// VPC Deleting a Security Group: DELETE /v3/{project_id}/vpc/security-groups/{security_group_id}
+    pub async fn delete_security_group(
         &self,
         region: &str,
-        cluster_id: &str,
-        external_ip: &str,
+        security_group_id: &str,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}");
-        let payload = CceUpdateClusterRequest {
-            spec: CceUpdateClusterSpec {
-                cluster_external_ip: external_ip.to_string(),
-            },
-        };
-        let json =
-            serde_json::to_string(&payload).context("Failed to serialize CCE access payload")?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/vpc/security-groups/{security_group_id}");
 
-        self.send_raw(Method::PUT, &host, &path, Some(json)).await
+        self.send_raw(Method::DELETE, &host, &path, None).await
     }
 
-    /// Request cluster cert/config payload for kubeconfig usage.
-    /// CCE Creating a User Certificate: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/clustercert
-    pub async fn get_cce_cluster_kubeconfig(
+    /// List rules for one security group.
+    /// VPC Querying Security Group Rules: GET /v3/{project_id}/vpc/security-group-rules?security_group_id={security_group_id}
+    pub async fn list_security_group_rules(
         &self,
         region: &str,
-        cluster_id: &str,
-        context: Option<&str>,
+        security_group_id: &str,
+    ) -> Result<SecurityGroupRuleListResponse> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!(
+            "/v3/{project_id}/vpc/security-group-rules?security_group_id={}&limit=2000",
+            encode_rfc3986(security_group_id)
+        );
+
+        self.send_json(Method::GET, &host, &path, None)
+            .await
+            .context("Failed to list security group rules")
+    }
+
+    /// Create one security group rule.
+    /// VPC Creating a Security Group Rule: POST /v3/{project_id}/vpc/security-group-rules
+    pub async fn create_security_group_rule(
+        &self,
+        region: &str,
+        rule: &SecurityGroupRuleCreateBody,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("cce.{region}.myhuaweicloud.com");
-        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/clustercert");
-        let payload = CceClusterCertRequest {
-            context: context
-                .map(str::trim)
-                .filter(|value| !value.is_empty())
-                .map(str::to_string),
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/vpc/security-group-rules");
+        let payload = SecurityGroupRuleCreateRequest {
+            security_group_rule: rule.clone(),
         };
         let json = serde_json::to_string(&payload)
-            .context("Failed to serialize CCE clustercert payload")?;
+            .context("Failed to serialize security group rule payload")?;
 
         self.send_raw(Method::POST, &host, &path, Some(json)).await
     }
 
-    /// Stop ECS instances.
-    /// ECS Batch Stop: POST /v1/{project_id}/cloudservers/action with os-stop body.
-    pub async fn stop_ecs(
+    /// Delete one security group rule.
+    /// VPC Deleting a Security Group Rule: DELETE /v3/{project_id}/vpc/security-group-rules/{security_group_rule_id}
+    pub async fn delete_security_group_rule(
         &self,
         region: &str,
-        server_id: &str,
-        stop_type: &str,
+        security_group_rule_id: &str,
     ) -> Result<(StatusCode, String)> {
         let project_id = self.project_id(region).await?;
-        let host = format!("ecs.{region}.myhuaweicloud.com");
-        let path = format!("/v1/{project_id}/cloudservers/action");
-        let payload = StopEcsRequest {
-            os_stop: StopEcsAction {
-                servers: vec![StopEcsServer {
-                    id: server_id.to_string(),
-                }],
-                stop_type: stop_type.to_string(),
-            },
-        };
-        let json =
-            serde_json::to_string(&payload).context("Failed to serialize ECS stop payload")?;
+        let host = format!("vpc.{region}.myhuaweicloud.com");
+        let path = format!("/v3/{project_id}/vpc/security-group-rules/{security_group_rule_id}");
 
-        self.send_raw(Method::POST, &host, &path, Some(json)).await
+        self.send_raw(Method::DELETE, &host, &path, None).await
     }
 
     /// List OBS buckets in the provided region.
@@ -837,99 +2343,239 @@ impl HwcClient {
         .await
     }
 
-    /// Delete an OBS bucket.
-    pub async fn delete_obs_bucket(
+    /// Delete an OBS bucket.
+    pub async fn delete_obs_bucket(
+        &self,
+        region: &str,
+        bucket_name: &str,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let canonical_resource = format!("/{bucket_name}/");
+        self.send_obs_raw(
+            Method::DELETE,
+            &host,
+            "/",
+            &canonical_resource,
+            None,
+            None,
+            &[],
+        )
+        .await
+    }
+
+    /// List objects from one OBS bucket.
+    pub async fn list_obs_objects(
+        &self,
+        region: &str,
+        bucket_name: &str,
+        prefix: Option<&str>,
+        marker: Option<&str>,
+        max_keys: Option<u32>,
+    ) -> Result<ObsListObjectsResponse> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let mut query: Vec<String> = Vec::new();
+
+        if let Some(value) = prefix.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(format!("prefix={}", encode_rfc3986(value)));
+        }
+        if let Some(value) = marker.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(format!("marker={}", encode_rfc3986(value)));
+        }
+        if let Some(value) = max_keys.filter(|value| *value > 0) {
+            query.push(format!("max-keys={value}"));
+        }
+
+        let path = if query.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/?{}", query.join("&"))
+        };
+        let canonical_resource = format!("/{bucket_name}/");
+        let (status, body) = self
+            .send_obs_raw(
+                Method::GET,
+                &host,
+                &path,
+                &canonical_resource,
+                None,
+                None,
+                &[],
+            )
+            .await
+            .context("Failed to list OBS objects")?;
+
+        if !status.is_success() {
+            anyhow::bail!("OBS list objects returned {}: {}", status, body);
+        }
+
+        parse_obs_list_objects_response(bucket_name, &body)
+    }
+
+    /// Upload one object to OBS.
+    pub async fn put_obs_object(
+        &self,
+        region: &str,
+        bucket_name: &str,
+        object_key: &str,
+        content: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let encoded_key = encode_obs_object_key(object_key);
+        let path = format!("/{encoded_key}");
+        let canonical_resource = format!("/{bucket_name}/{encoded_key}");
+        let mime = content_type
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or("application/octet-stream");
+
+        self.send_obs_raw(
+            Method::PUT,
+            &host,
+            &path,
+            &canonical_resource,
+            Some(content),
+            Some(mime),
+            &[],
+        )
+        .await
+    }
+
+    /// Start a multipart upload and return its upload ID.
+    /// OBS Initiating a Multipart Upload: POST /{object_key}?uploads
+    pub async fn initiate_obs_multipart_upload(
+        &self,
+        region: &str,
+        bucket_name: &str,
+        object_key: &str,
+        content_type: &str,
+    ) -> Result<String> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let encoded_key = encode_obs_object_key(object_key);
+        let path = format!("/{encoded_key}?uploads");
+        let canonical_resource = format!("/{bucket_name}/{encoded_key}?uploads");
+        let (status, body) = self
+            .send_obs_raw(
+                Method::POST,
+                &host,
+                &path,
+                &canonical_resource,
+                None,
+                Some(content_type),
+                &[],
+            )
+            .await?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "OBS initiate multipart upload returned {}: {}",
+                status,
+                body
+            );
+        }
+
+        let parsed: ObsInitiateMultipartUploadXml = from_xml_str(&body)
+            .context("Failed to parse OBS initiate multipart upload XML response")?;
+        parsed
+            .upload_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .context("OBS initiate multipart upload response has no UploadId")
+    }
+
+    /// Upload one part of a multipart upload and return its ETag.
+    /// OBS Uploading Parts: PUT /{object_key}?partNumber={n}&uploadId={upload_id}
+    pub async fn upload_obs_part(
+        &self,
+        region: &str,
+        bucket_name: &str,
+        object_key: &str,
+        upload_id: &str,
+        part_number: u32,
+        content: Vec<u8>,
+    ) -> Result<String> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let encoded_key = encode_obs_object_key(object_key);
+        let query = format!("partNumber={part_number}&uploadId={upload_id}");
+        let path = format!("/{encoded_key}?{query}");
+        let canonical_resource = format!("/{bucket_name}/{encoded_key}?{query}");
+        let req = self.build_obs_request(
+            Method::PUT,
+            &host,
+            &path,
+            &canonical_resource,
+            Some(content),
+            None,
+            &[],
+        )?;
+        let resp = self.http.execute(req).await.context("Request failed")?;
+        let status = resp.status();
+        let etag = resp
+            .headers()
+            .get("ETag")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let body = resp.text().await.context("Failed to read response")?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "OBS upload part {} returned {}: {}",
+                part_number,
+                status,
+                body
+            );
+        }
+
+        etag.context("OBS upload part response has no ETag header")
+    }
+
+    /// Merge uploaded parts, given as `(part number, ETag)`, into the final object.
+    /// OBS Merging Parts: POST /{object_key}?uploadId={upload_id}
+    pub async fn complete_obs_multipart_upload(
         &self,
         region: &str,
         bucket_name: &str,
+        object_key: &str,
+        upload_id: &str,
+        parts: &[(u32, String)],
     ) -> Result<(StatusCode, String)> {
         let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
-        let canonical_resource = format!("/{bucket_name}/");
+        let encoded_key = encode_obs_object_key(object_key);
+        let path = format!("/{encoded_key}?uploadId={upload_id}");
+        let canonical_resource = format!("/{bucket_name}/{encoded_key}?uploadId={upload_id}");
+        let xml = build_obs_complete_multipart_xml(parts).into_bytes();
+
         self.send_obs_raw(
-            Method::DELETE,
+            Method::POST,
             &host,
-            "/",
+            &path,
             &canonical_resource,
-            None,
-            None,
+            Some(xml),
+            Some(CONTENT_TYPE_XML),
             &[],
         )
         .await
     }
 
-    /// List objects from one OBS bucket.
-    pub async fn list_obs_objects(
-        &self,
-        region: &str,
-        bucket_name: &str,
-        prefix: Option<&str>,
-        marker: Option<&str>,
-        max_keys: Option<u32>,
-    ) -> Result<ObsListObjectsResponse> {
-        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
-        let mut query: Vec<String> = Vec::new();
-
-        if let Some(value) = prefix.map(str::trim).filter(|value| !value.is_empty()) {
-            query.push(format!("prefix={}", encode_rfc3986(value)));
-        }
-        if let Some(value) = marker.map(str::trim).filter(|value| !value.is_empty()) {
-            query.push(format!("marker={}", encode_rfc3986(value)));
-        }
-        if let Some(value) = max_keys.filter(|value| *value > 0) {
-            query.push(format!("max-keys={value}"));
-        }
-
-        let path = if query.is_empty() {
-            "/".to_string()
-        } else {
-            format!("/?{}", query.join("&"))
-        };
-        let canonical_resource = format!("/{bucket_name}/");
-        let (status, body) = self
-            .send_obs_raw(
-                Method::GET,
-                &host,
-                &path,
-                &canonical_resource,
-                None,
-                None,
-                &[],
-            )
-            .await
-            .context("Failed to list OBS objects")?;
-
-        if !status.is_success() {
-            anyhow::bail!("OBS list objects returned {}: {}", status, body);
-        }
-
-        parse_obs_list_objects_response(bucket_name, &body)
-    }
-
-    /// Upload one object to OBS.
-    pub async fn put_obs_object(
+    /// Abort a multipart upload and drop its uploaded parts.
+    /// OBS Canceling a Multipart Upload Task: DELETE /{object_key}?uploadId={upload_id}
+    pub async fn abort_obs_multipart_upload(
         &self,
         region: &str,
         bucket_name: &str,
         object_key: &str,
-        content: Vec<u8>,
-        content_type: Option<&str>,
+        upload_id: &str,
     ) -> Result<(StatusCode, String)> {
         let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
         let encoded_key = encode_obs_object_key(object_key);
-        let path = format!("/{encoded_key}");
-        let canonical_resource = format!("/{bucket_name}/{encoded_key}");
-        let mime = content_type
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .unwrap_or("application/octet-stream");
+        let path = format!("/{encoded_key}?uploadId={upload_id}");
+        let canonical_resource = format!("/{bucket_name}/{encoded_key}?uploadId={upload_id}");
 
         self.send_obs_raw(
-            Method::PUT,
+            Method::DELETE,
             &host,
             &path,
             &canonical_resource,
-            Some(content),
-            Some(mime),
+            None,
+            None,
             &[],
         )
         .await
@@ -1011,7 +2657,186 @@ impl HwcClient {
     }
 
     /// Resolve project ID for the provided region.
-    async fn project_id(&self, region: &str) -> Result<String> {
+    /// Add ("create") or remove ("delete") tags on one ECS, EVS, EIP or NAT resource.
+    /// Batch Tag Actions: POST {collection}/{resource_id}/tags/action
+    pub async fn update_resource_tags(
+        &self,
+        region: &str,
+        service: TagService,
+        resource_id: &str,
+        action: &str,
+        tags: Vec<TagActionEntry>,
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let (host, collection) = service.endpoint(region, &project_id);
+        let path = format!("{collection}/{resource_id}/tags/action");
+        let payload = TagActionRequest {
+            action: action.to_string(),
+            tags,
+        };
+        let json = serde_json::to_string(&payload).context("Failed to serialize tag payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// List ECS, EVS, EIP or NAT resources carrying every filtered tag.
+    /// Querying Resources by Tag: POST {collection}/resource_instances/action
+    pub async fn filter_resources_by_tags(
+        &self,
+        region: &str,
+        service: TagService,
+        filters: &[TagFilter],
+    ) -> Result<Vec<TaggedResource>> {
+        let project_id = self.project_id(region).await?;
+        let (host, collection) = service.endpoint(region, &project_id);
+        let path = format!("{collection}/resource_instances/action");
+        let payload = ResourceInstancesFilterRequest {
+            action: "filter".to_string(),
+            tags: filters.to_vec(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize tag filter payload")?;
+
+        let body: ResourceInstancesResponse = self
+            .send_json(Method::POST, &host, &path, Some(json))
+            .await
+            .context("Failed to filter resources by tag")?;
+        Ok(body.resources)
+    }
+
+    /// Add or overwrite tags on one CCE cluster.
+    /// CCE Batch Adding Cluster Tags: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/tags/create
+    pub async fn create_cce_cluster_tags(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        tags: &[ResourceTag],
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/tags/create");
+        let payload = CceClusterTagsRequest {
+            tags: tags
+                .iter()
+                .map(|tag| TagActionEntry {
+                    key: tag.key.clone(),
+                    value: Some(tag.value.clone()),
+                })
+                .collect(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize CCE tag payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Remove tags from one CCE cluster by key.
+    /// CCE Batch Deleting Cluster Tags: POST /api/v3/projects/{project_id}/clusters/{cluster_id}/tags/delete
+    pub async fn delete_cce_cluster_tags(
+        &self,
+        region: &str,
+        cluster_id: &str,
+        keys: &[String],
+    ) -> Result<(StatusCode, String)> {
+        let project_id = self.project_id(region).await?;
+        let host = format!("cce.{region}.myhuaweicloud.com");
+        let path = format!("/api/v3/projects/{project_id}/clusters/{cluster_id}/tags/delete");
+        let payload = CceClusterTagsRequest {
+            tags: keys
+                .iter()
+                .map(|key| TagActionEntry {
+                    key: key.clone(),
+                    value: None,
+                })
+                .collect(),
+        };
+        let json =
+            serde_json::to_string(&payload).context("Failed to serialize CCE tag payload")?;
+
+        self.send_raw(Method::POST, &host, &path, Some(json)).await
+    }
+
+    /// Read the tags of one OBS bucket; a bucket without tags yields an empty list.
+    /// OBS Obtaining Bucket Tags: GET /?tagging
+    pub async fn get_obs_bucket_tags(
+        &self,
+        region: &str,
+        bucket_name: &str,
+    ) -> Result<Vec<ResourceTag>> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let canonical_resource = format!("/{bucket_name}/?tagging");
+        let (status, body) = self
+            .send_obs_raw(
+                Method::GET,
+                &host,
+                "/?tagging",
+                &canonical_resource,
+                None,
+                None,
+                &[],
+            )
+            .await
+            .context("Failed to get OBS bucket tags")?;
+
+        if status == StatusCode::NOT_FOUND && body.contains("NoSuchTagSet") {
+            return Ok(Vec::new());
+        }
+        if !status.is_success() {
+            anyhow::bail!("OBS get bucket tags returned {}: {}", status, body);
+        }
+
+        parse_obs_tagging_response(&body)
+    }
+
+    /// Replace the full tag set of one OBS bucket.
+    /// OBS Configuring Bucket Tags: PUT /?tagging
+    pub async fn put_obs_bucket_tags(
+        &self,
+        region: &str,
+        bucket_name: &str,
+        tags: &[ResourceTag],
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let canonical_resource = format!("/{bucket_name}/?tagging");
+        let xml = build_obs_tagging_xml(tags).into_bytes();
+        let content_md5 = BASE64_STANDARD.encode(md5_digest(&xml));
+
+        self.send_obs_raw(
+            Method::PUT,
+            &host,
+            "/?tagging",
+            &canonical_resource,
+            Some(xml),
+            Some(CONTENT_TYPE_XML),
+            &[(HEADER_CONTENT_MD5.to_string(), content_md5)],
+        )
+        .await
+    }
+
+    /// Remove every tag from one OBS bucket.
+    /// OBS Deleting Bucket Tags: DELETE /?tagging
+    pub async fn delete_obs_bucket_tags(
+        &self,
+        region: &str,
+        bucket_name: &str,
+    ) -> Result<(StatusCode, String)> {
+        let host = format!("{bucket_name}.obs.{region}.myhuaweicloud.com");
+        let canonical_resource = format!("/{bucket_name}/?tagging");
+
+        self.send_obs_raw(
+            Method::DELETE,
+            &host,
+            "/?tagging",
+            &canonical_resource,
+            None,
+            None,
+            &[],
+        )
+        .await
+    }
+
+    /// IAM project ID of a region, cached per access key.
+    pub async fn project_id(&self, region: &str) -> Result<String> {
         let cache_key = format!("{}::{region}", self.credentials.access_key);
         if let Some(project_id) = PROJECT_ID_CACHE.get(&cache_key) {
             return Ok(project_id);
@@ -1060,6 +2885,28 @@ impl HwcClient {
         body: Option<String>,
     ) -> Result<T> {
         let req = self.build_request(method, host, path, body)?;
+        self.execute_json(req, host, path).await
+    }
+
+    /// `send_json` for project-scoped APIs whose path carries no project ID.
+    async fn send_project_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        host: &str,
+        path: &str,
+        project_id: &str,
+        body: Option<String>,
+    ) -> Result<T> {
+        let req = self.build_project_request(method, host, path, project_id, body)?;
+        self.execute_json(req, host, path).await
+    }
+
+    async fn execute_json<T: DeserializeOwned>(
+        &self,
+        req: Request,
+        host: &str,
+        path: &str,
+    ) -> Result<T> {
         let resp = self.http.execute(req).await.context("Request failed")?;
         let status = resp.status();
         let bytes = resp.bytes().await.context("Failed to read response")?;
@@ -1084,6 +2931,28 @@ impl HwcClient {
         body: Option<String>,
     ) -> Result<(StatusCode, String)> {
         let req = self.build_request(method, host, path, body)?;
+        self.execute_raw(req, host, path).await
+    }
+
+    /// `send_raw` for project-scoped APIs whose path carries no project ID.
+    async fn send_project_raw(
+        &self,
+        method: Method,
+        host: &str,
+        path: &str,
+        project_id: &str,
+        body: Option<String>,
+    ) -> Result<(StatusCode, String)> {
+        let req = self.build_project_request(method, host, path, project_id, body)?;
+        self.execute_raw(req, host, path).await
+    }
+
+    async fn execute_raw(
+        &self,
+        req: Request,
+        host: &str,
+        path: &str,
+    ) -> Result<(StatusCode, String)> {
         let resp = self.http.execute(req).await.context("Request failed")?;
         let status = resp.status();
         let text = resp.text().await.context("Failed to read response")?;
@@ -1195,6 +3064,21 @@ impl HwcClient {
         Ok(req.build()?)
     }
 
+    /// Build a signed request that names its project in `X-Project-Id`.
+    fn build_project_request(
+        &self,
+        method: Method,
+        host: &str,
+        path: &str,
+        project_id: &str,
+        body: Option<String>,
+    ) -> Result<Request> {
+        let mut req = self.build_request(method, host, path, body)?;
+        req.headers_mut()
+            .insert(HEADER_PROJECT_ID, HeaderValue::from_str(project_id)?);
+        Ok(req)
+    }
+
     /// Build a signed OBS request using Huawei OBS `Authorization: OBS AK:Signature`.
     fn build_obs_request(
         &self,
@@ -1438,6 +3322,114 @@ fn parse_obs_list_objects_response(bucket_name: &str, xml: &str) -> Result<ObsLi
     })
 }
 
+fn build_obs_tagging_xml(tags: &[ResourceTag]) -> String {
+    let tag_set = tags
+        .iter()
+        .map(|tag| {
+            format!(
+                "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+                xml_escape(&tag.key),
+                xml_escape(&tag.value)
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><Tagging><TagSet>{tag_set}</TagSet></Tagging>"#
+    )
+}
+
+fn build_obs_complete_multipart_xml(parts: &[(u32, String)]) -> String {
+    let parts = parts
+        .iter()
+        .map(|(number, etag)| {
+            format!(
+                "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                number,
+                xml_escape(etag)
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><CompleteMultipartUpload>{parts}</CompleteMultipartUpload>"#
+    )
+}
+
+fn parse_obs_tagging_response(xml: &str) -> Result<Vec<ResourceTag>> {
+    let parsed: ObsTaggingXml =
+        from_xml_str(xml).context("Failed to parse OBS bucket tagging XML response")?;
+    Ok(parsed
+        .tag_set
+        .tag
+        .into_iter()
+        .filter_map(|tag| {
+            let key = tag.key.unwrap_or_default().trim().to_string();
+            if key.is_empty() {
+                return None;
+            }
+            Some(ResourceTag {
+                key,
+                value: tag.value.unwrap_or_default(),
+            })
+        })
+        .collect())
+}
+
+/// MD5 (RFC 1321), only needed for the Content-MD5 header OBS requires on tagging writes.
+fn md5_digest(input: &[u8]) -> [u8; 16] {
+    const SHIFTS: [u32; 64] = [
+        7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5,
+        9, 14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10,
+        15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+    ];
+    let constants: Vec<u32> = (0..64)
+        .map(|index: u32| ((f64::from(index) + 1.0).sin().abs() * 4_294_967_296.0) as u32)
+        .collect();
+
+    let mut message = input.to_vec();
+    let bit_len = (input.len() as u64).wrapping_mul(8);
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_le_bytes());
+
+    let mut state: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+    for chunk in message.chunks_exact(64) {
+        let words: Vec<u32> = chunk
+            .chunks_exact(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        let [mut a, mut b, mut c, mut d] = state;
+        for (index, (&shift, &constant)) in SHIFTS.iter().zip(&constants).enumerate() {
+            let (mixed, word) = match index / 16 {
+                0 => ((b & c) | (!b & d), index),
+                1 => ((d & b) | (!d & c), (5 * index + 1) % 16),
+                2 => (b ^ c ^ d, (3 * index + 5) % 16),
+                _ => (c ^ (b | !d), (7 * index) % 16),
+            };
+            let rotated = a
+                .wrapping_add(mixed)
+                .wrapping_add(constant)
+                .wrapping_add(words[word])
+                .rotate_left(shift);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+    }
+
+    let mut digest = [0u8; 16];
+    for (slot, word) in digest.chunks_exact_mut(4).zip(state) {
+        slot.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
 fn canonicalize_obs_headers(headers: &[(String, String)]) -> String {
     let mut canonical = headers
         .iter()
@@ -1472,8 +3464,10 @@ fn encode_obs_object_key(key: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        canonicalize_obs_headers, canonicalize_path, canonicalize_query, encode_obs_object_key,
+        build_obs_complete_multipart_xml, build_obs_tagging_xml, canonicalize_obs_headers,
+        canonicalize_path, canonicalize_query, encode_obs_object_key, from_xml_str, md5_digest,
         parse_obs_list_buckets_response, parse_obs_list_objects_response,
+        parse_obs_tagging_response, ObsInitiateMultipartUploadXml, ResourceTag,
     };
 
     #[test]
@@ -1597,4 +3591,51 @@ mod tests {
         assert!(parsed.is_truncated);
         assert_eq!(parsed.next_marker.as_deref(), Some("token-2"));
     }
+
+    #[test]
+    fn md5_digest_matches_rfc_1321_vectors() {
+        assert_eq!(
+            hex::encode(md5_digest(b"")),
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+        assert_eq!(
+            hex::encode(md5_digest(b"message digest")),
+            "f96b697d7cb7938d525a2f31aaf161d0"
+        );
+        assert_eq!(
+            hex::encode(md5_digest(
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            )),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn obs_tagging_xml_round_trips_and_escapes() {
+        let tags = vec![ResourceTag {
+            key: "team".to_string(),
+            value: "R&D <core>".to_string(),
+        }];
+        let xml = build_obs_tagging_xml(&tags);
+        assert!(xml.contains("<Value>R&amp;D &lt;core&gt;</Value>"));
+        let parsed = parse_obs_tagging_response(&xml).expect("parse tagging");
+        assert_eq!(parsed, tags);
+    }
+
+    #[test]
+    fn obs_multipart_xml_lists_parts_and_parses_upload_id() {
+        let xml = build_obs_complete_multipart_xml(&[
+            (1, "\"etag-1\"".to_string()),
+            (2, "\"etag-2\"".to_string()),
+        ]);
+        assert!(xml.contains(
+            "<Part><PartNumber>1</PartNumber><ETag>&quot;etag-1&quot;</ETag></Part><Part><PartNumber>2</PartNumber>"
+        ));
+
+        let parsed: ObsInitiateMultipartUploadXml = from_xml_str(
+            "<InitiateMultipartUploadResult><Bucket>images</Bucket><Key>a.qcow2</Key><UploadId>0000abcd</UploadId></InitiateMultipartUploadResult>",
+        )
+        .expect("parse initiate response");
+        assert_eq!(parsed.upload_id.as_deref(), Some("0000abcd"));
+    }
 }
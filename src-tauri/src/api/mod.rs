@@ -4,4 +4,4 @@ pub mod client;
 pub mod models;
 
 pub use auth::credentials::{Credentials, CredentialsSource};
-pub use client::{HwcClient, ImageListFilters, ListParams};
+pub use client::{lookup_public_ipv4, HwcClient, ImageListFilters, ListParams, TagService};
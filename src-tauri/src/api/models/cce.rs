@@ -1,5 +1,7 @@
+use super::tags::ResourceTag;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CceCreateClusterRequest {
@@ -17,22 +19,79 @@ pub struct CceClusterCreateMetadata {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CceClusterCreateSpec {
+    /// CCE, or Turbo for ENI container networking.
+    pub category: String,
     #[serde(rename = "type")]
     pub cluster_type: String,
     pub flavor: String,
     pub version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(rename = "ipv6enable", skip_serializing_if = "std::ops::Not::not")]
+    pub ipv6_enable: bool,
     #[serde(rename = "hostNetwork")]
     pub host_network: CceHostNetwork,
     #[serde(rename = "containerNetwork")]
     pub container_network: CceContainerNetwork,
+    #[serde(rename = "eniNetwork", skip_serializing_if = "Option::is_none")]
+    pub eni_network: Option<CceEniNetwork>,
     #[serde(rename = "kubernetesSvcIpRange")]
     pub kubernetes_svc_ip_range: String,
+    #[serde(rename = "kubeProxyMode", skip_serializing_if = "Option::is_none")]
+    pub kube_proxy_mode: Option<String>,
+    /// 0 for pay-per-use, 1 for yearly/monthly.
+    #[serde(rename = "billingMode")]
+    pub billing_mode: u8,
+    /// One entry per control-plane node; empty lets CCE place them.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub masters: Vec<CceMasterSpec>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authentication: Option<CceAuthentication>,
     #[serde(rename = "clusterTags", skip_serializing_if = "Vec::is_empty", default)]
-    pub cluster_tags: Vec<CceClusterTag>,
+    pub cluster_tags: Vec<ResourceTag>,
+    #[serde(rename = "extendParam", skip_serializing_if = "Option::is_none")]
+    pub extend_param: Option<CceClusterExtendParam>,
+}
+
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct CceClusterExtendParam {
+    #[serde(
+        rename = "enterpriseProjectId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enterprise_project_id: Option<String>,
+    /// `multi_az` when the control plane spans several zones.
+    #[serde(rename = "clusterAZ", skip_serializing_if = "Option::is_none")]
+    pub cluster_az: Option<String>,
+    /// Existing EIP address bound to the API server at creation.
+    #[serde(rename = "clusterExternalIP", skip_serializing_if = "Option::is_none")]
+    pub cluster_external_ip: Option<String>,
+    #[serde(rename = "periodType", skip_serializing_if = "Option::is_none")]
+    pub period_type: Option<String>,
+    #[serde(rename = "periodNum", skip_serializing_if = "Option::is_none")]
+    pub period_num: Option<u32>,
+    #[serde(rename = "isAutoRenew", skip_serializing_if = "Option::is_none")]
+    pub is_auto_renew: Option<String>,
+    #[serde(rename = "isAutoPay", skip_serializing_if = "Option::is_none")]
+    pub is_auto_pay: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceEniNetwork {
+    pub subnets: Vec<CceEniSubnet>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceEniSubnet {
+    /// IPv4 (neutron) subnet ID, not the VPC subnet ID used by `hostNetwork`.
+    #[serde(rename = "subnetID")]
+    pub subnet_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceMasterSpec {
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -44,7 +103,9 @@ pub struct CceHostNetwork {
 #[derive(Debug, Clone, Serialize)]
 pub struct CceContainerNetwork {
     pub mode: String,
-    pub cidr: String,
+    /// Pod CIDR; ENI clusters take pod IPs from `eniNetwork` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -52,11 +113,9 @@ pub struct CceAuthentication {
     pub mode: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct CceClusterTag {
-    pub key: String,
-    pub value: String,
-}
+/// Response fields not modelled explicitly; flattened back on serialization so the
+/// frontend still receives the full CCE payload.
+pub type CceExtraFields = Map<String, Value>;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CceCluster {
@@ -64,11 +123,97 @@ pub struct CceCluster {
     #[serde(rename = "apiVersion")]
     pub api_version: Option<String>,
     #[serde(default)]
-    pub metadata: Value,
+    pub metadata: CceClusterMetadata,
     #[serde(default)]
-    pub spec: Value,
+    pub spec: CceClusterSpec,
     #[serde(default)]
-    pub status: Value,
+    pub status: CceClusterStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterMetadata {
+    pub uid: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "creationTimestamp")]
+    pub creation_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterSpec {
+    #[serde(rename = "type")]
+    pub cluster_type: Option<String>,
+    pub flavor: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "hostNetwork")]
+    pub host_network: Option<CceClusterHostNetwork>,
+    #[serde(rename = "containerNetwork")]
+    pub container_network: Option<CceClusterContainerNetwork>,
+    #[serde(rename = "clusterTags")]
+    pub cluster_tags: Option<Vec<ResourceTag>>,
+    #[serde(rename = "extendParam")]
+    pub extend_param: Option<CceClusterSpecExtendParam>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterHostNetwork {
+    pub vpc: Option<String>,
+    /// Neutron network ID of the subnet hosting the control plane.
+    pub subnet: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterContainerNetwork {
+    pub mode: Option<String>,
+    pub cidr: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterSpecExtendParam {
+    #[serde(rename = "enterpriseProjectId")]
+    pub enterprise_project_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterStatus {
+    pub phase: Option<String>,
+    #[serde(rename = "jobID")]
+    pub job_id: Option<String>,
+    pub endpoints: Option<Vec<CceClusterEndpoint>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterEndpoint {
+    pub url: Option<String>,
+    #[serde(rename = "type")]
+    pub endpoint_type: Option<String>,
+}
+
+impl CceCluster {
+    pub fn tags(&self) -> &[ResourceTag] {
+        self.spec.cluster_tags.as_deref().unwrap_or_default()
+    }
+
+    /// Enterprise project of the cluster; CCE omits it for the default project "0".
+    pub fn enterprise_project_id(&self) -> &str {
+        self.spec
+            .extend_param
+            .as_ref()
+            .and_then(|param| param.enterprise_project_id.as_deref())
+            .unwrap_or("0")
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -86,11 +231,113 @@ pub struct CceNodePool {
     #[serde(rename = "apiVersion")]
     pub api_version: Option<String>,
     #[serde(default)]
-    pub metadata: Value,
+    pub metadata: CceNodePoolMetadata,
     #[serde(default)]
-    pub spec: Value,
+    pub spec: CceNodePoolSpec,
     #[serde(default)]
-    pub status: Value,
+    pub status: CceNodePoolStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodePoolMetadata {
+    pub uid: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "creationTimestamp")]
+    pub creation_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodePoolSpec {
+    #[serde(rename = "type")]
+    pub node_pool_type: Option<String>,
+    #[serde(rename = "initialNodeCount")]
+    pub initial_node_count: Option<u32>,
+    pub autoscaling: Option<CceNodePoolAutoscaling>,
+    #[serde(rename = "nodeTemplate")]
+    pub node_template: Option<CceNodePoolTemplate>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// Echoed back in node pool updates, so unset fields are omitted rather than nulled.
+pub struct CceNodePoolAutoscaling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable: Option<bool>,
+    #[serde(rename = "minNodeCount", skip_serializing_if = "Option::is_none")]
+    pub min_node_count: Option<u32>,
+    #[serde(rename = "maxNodeCount", skip_serializing_if = "Option::is_none")]
+    pub max_node_count: Option<u32>,
+    #[serde(
+        rename = "scaleDownCooldownTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scale_down_cooldown_time: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodePoolTemplate {
+    pub flavor: Option<String>,
+    pub az: Option<String>,
+    pub os: Option<String>,
+    #[serde(rename = "k8sTags")]
+    pub k8s_tags: Option<BTreeMap<String, String>>,
+    pub taints: Option<Vec<CceTaint>>,
+    #[serde(rename = "userTags")]
+    pub user_tags: Option<Vec<ResourceTag>>,
+    #[serde(rename = "rootVolume")]
+    pub root_volume: Option<CceNodePoolTemplateVolume>,
+    #[serde(rename = "dataVolumes")]
+    pub data_volumes: Option<Vec<CceNodePoolTemplateVolume>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodePoolTemplateVolume {
+    pub volumetype: Option<String>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CceTaint {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub effect: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodePoolStatus {
+    pub phase: Option<String>,
+    #[serde(rename = "currentNode")]
+    pub current_node: Option<u32>,
+    #[serde(rename = "creatingNode")]
+    pub creating_node: Option<u32>,
+    #[serde(rename = "deletingNode")]
+    pub deleting_node: Option<u32>,
+    #[serde(rename = "jobId")]
+    pub job_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+impl CceNodePool {
+    pub fn user_tags(&self) -> &[ResourceTag] {
+        self.spec
+            .node_template
+            .as_ref()
+            .and_then(|template| template.user_tags.as_deref())
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -102,6 +349,467 @@ pub struct CceNodePoolListResponse {
     pub items: Vec<CceNodePool>,
 }
 
+/// Annotation CCE sets on every node with the owning node pool ID.
+pub const CCE_NODE_POOL_ID_ANNOTATION: &str = "kubernetes.io/node-pool.id";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNode {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub metadata: CceNodeMetadata,
+    #[serde(default)]
+    pub spec: CceNodeSpec,
+    #[serde(default)]
+    pub status: CceNodeStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodeMetadata {
+    pub name: Option<String>,
+    pub uid: Option<String>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    #[serde(rename = "creationTimestamp")]
+    pub creation_timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodeSpec {
+    pub flavor: Option<String>,
+    pub az: Option<String>,
+    pub os: Option<String>,
+    #[serde(rename = "billingMode")]
+    pub billing_mode: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodeStatus {
+    pub phase: Option<String>,
+    #[serde(rename = "serverId")]
+    pub server_id: Option<String>,
+    #[serde(rename = "privateIP")]
+    pub private_ip: Option<String>,
+    #[serde(rename = "publicIP")]
+    pub public_ip: Option<String>,
+    #[serde(rename = "jobID")]
+    pub job_id: Option<String>,
+}
+
+impl CceNode {
+    /// ID of the custom node pool owning this node. Nodes in the default pool
+    /// carry an `az#flavor#os` annotation instead and yield `None`.
+    pub fn node_pool_id(&self) -> Option<&str> {
+        self.metadata
+            .annotations
+            .get(CCE_NODE_POOL_ID_ANNOTATION)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty() && !value.contains('#'))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceNodeListResponse {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub items: Vec<CceNode>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceResetNodesRequest {
+    pub kind: String,
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    #[serde(rename = "nodeList")]
+    pub node_list: Vec<CceResetNodeItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceResetNodeItem {
+    #[serde(rename = "nodeID")]
+    pub node_id: String,
+    pub spec: CceResetNodeSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceResetNodeSpec {
+    pub os: String,
+    pub login: CceNodePoolLogin,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceRemoveNodesRequest {
+    pub kind: String,
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub spec: CceRemoveNodesSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceRemoveNodesSpec {
+    pub login: CceNodePoolLogin,
+    pub nodes: Vec<CceNodeRef>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceNodeRef {
+    pub uid: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceUpgradeInfo {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub spec: CceUpgradeInfoSpec,
+    #[serde(default)]
+    pub status: CceTaskStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceUpgradeInfoSpec {
+    #[serde(rename = "versionInfo")]
+    pub version_info: Option<CceUpgradeVersionInfo>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceUpgradeVersionInfo {
+    pub release: Option<String>,
+    pub patch: Option<String>,
+    #[serde(rename = "suggestPatch")]
+    pub suggest_patch: Option<String>,
+    #[serde(rename = "targetVersions")]
+    pub target_versions: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+impl CceUpgradeInfo {
+    pub fn target_versions(&self) -> &[String] {
+        self.spec
+            .version_info
+            .as_ref()
+            .and_then(|info| info.target_versions.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// Cluster-scoped CCE task (upgrade pre-check or upgrade) as returned by the operation APIs.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceClusterTask {
+    #[serde(default)]
+    pub metadata: CceTaskMetadata,
+    #[serde(default)]
+    pub spec: CceExtraFields,
+    #[serde(default)]
+    pub status: CceTaskStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceTaskMetadata {
+    pub uid: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceTaskStatus {
+    pub phase: Option<String>,
+    pub progress: Option<String>,
+    pub message: Option<String>,
+    #[serde(rename = "jobID")]
+    pub job_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+/// CCE job returned by the job API, with one level of `subJobs` per step.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceJob {
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub metadata: CceTaskMetadata,
+    #[serde(default)]
+    pub spec: CceJobSpec,
+    #[serde(default)]
+    pub status: CceJobStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceJobSpec {
+    #[serde(rename = "type")]
+    pub job_type: Option<String>,
+    #[serde(rename = "subJobs")]
+    pub sub_jobs: Option<Vec<CceJob>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceJobStatus {
+    pub phase: Option<String>,
+    pub reason: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CcePrecheckRequest {
+    pub kind: String,
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub spec: CcePrecheckSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CcePrecheckSpec {
+    #[serde(rename = "clusterUpgradeAction")]
+    pub cluster_upgrade_action: CceUpgradeAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceUpgradeRequest {
+    pub metadata: CceUpgradeRequestMetadata,
+    pub spec: CceUpgradeSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceUpgradeRequestMetadata {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceUpgradeSpec {
+    #[serde(rename = "clusterUpgradeAction")]
+    pub cluster_upgrade_action: CceUpgradeAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceUpgradeAction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(rename = "targetVersion")]
+    pub target_version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<CceUpgradeStrategy>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceUpgradeStrategy {
+    #[serde(rename = "type")]
+    pub strategy_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonMetadata {
+    pub uid: Option<String>,
+    pub name: Option<String>,
+    pub alias: Option<String>,
+    #[serde(rename = "creationTimestamp")]
+    pub creation_timestamp: Option<String>,
+    #[serde(rename = "updateTimestamp")]
+    pub update_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+/// Add-on from the CCE catalog; each version carries the default values to install with.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplate {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub metadata: CceAddonMetadata,
+    #[serde(default)]
+    pub spec: CceAddonTemplateSpec,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplateSpec {
+    #[serde(rename = "type")]
+    pub template_type: Option<String>,
+    pub require: Option<bool>,
+    pub labels: Option<Vec<String>>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub versions: Vec<CceAddonTemplateVersion>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplateVersion {
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub input: CceAddonTemplateInput,
+    pub stable: Option<bool>,
+    #[serde(rename = "supportVersions")]
+    pub support_versions: Option<Vec<CceAddonSupportVersion>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonSupportVersion {
+    #[serde(rename = "clusterType")]
+    pub cluster_type: Option<String>,
+    /// Version patterns such as `v1.2[5-9].*`.
+    #[serde(rename = "clusterVersion")]
+    pub cluster_version: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+/// Default values of one template version. `null` defaults have to be supplied on install.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplateInput {
+    #[serde(default)]
+    pub basic: CceExtraFields,
+    #[serde(default)]
+    pub parameters: CceAddonTemplateParameters,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplateParameters {
+    #[serde(default)]
+    pub custom: CceExtraFields,
+    /// Resource flavors (`flavor1`, `flavor2`, ...) alongside any other template parameters.
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+impl CceAddonTemplate {
+    pub fn version(&self, version: &str) -> Option<&CceAddonTemplateVersion> {
+        self.spec
+            .versions
+            .iter()
+            .find(|candidate| candidate.version == version)
+    }
+}
+
+impl CceAddonTemplateParameters {
+    pub fn flavors(&self) -> Vec<(&str, &Value)> {
+        self.extra
+            .iter()
+            .filter(|(key, value)| key.starts_with("flavor") && value.is_object())
+            .map(|(key, value)| (key.as_str(), value))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonTemplateListResponse {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub items: Vec<CceAddonTemplate>,
+}
+
+/// Values sent with an add-on install or upgrade and returned on the installed instance.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonValues {
+    #[serde(default)]
+    pub basic: CceExtraFields,
+    #[serde(default)]
+    pub custom: CceExtraFields,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flavor: Option<Value>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+/// Add-on installed in one cluster.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonInstance {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub metadata: CceAddonMetadata,
+    #[serde(default)]
+    pub spec: CceAddonInstanceSpec,
+    #[serde(default)]
+    pub status: CceAddonInstanceStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonInstanceSpec {
+    #[serde(rename = "clusterID")]
+    pub cluster_id: Option<String>,
+    pub version: Option<String>,
+    #[serde(rename = "addonTemplateName")]
+    pub addon_template_name: Option<String>,
+    #[serde(rename = "addonTemplateType")]
+    pub addon_template_type: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub values: CceAddonValues,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonInstanceStatus {
+    pub status: Option<String>,
+    #[serde(rename = "Reason")]
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    #[serde(rename = "currentVersion")]
+    pub current_version: Option<CceAddonTemplateVersion>,
+    #[serde(rename = "isRollbackable")]
+    pub is_rollbackable: Option<bool>,
+    #[serde(flatten)]
+    pub extra: CceExtraFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceAddonInstanceListResponse {
+    pub kind: Option<String>,
+    #[serde(rename = "apiVersion")]
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub items: Vec<CceAddonInstance>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceAddonRequest {
+    pub kind: String,
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub metadata: CceAddonRequestMetadata,
+    pub spec: CceAddonRequestSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceAddonRequestMetadata {
+    pub annotations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CceAddonRequestSpec {
+    #[serde(rename = "clusterID")]
+    pub cluster_id: String,
+    pub version: String,
+    #[serde(rename = "addonTemplateName")]
+    pub addon_template_name: String,
+    pub values: CceAddonValues,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CceUpdateClusterRequest {
     pub spec: CceUpdateClusterSpec,
@@ -159,6 +867,9 @@ pub struct CceNodePoolTemplateSpec {
     pub node_nic_spec: Option<CceNodePoolNicSpec>,
     #[serde(rename = "billingMode")]
     pub billing_mode: u8,
+    /// Tags applied to the ECS nodes created by the pool.
+    #[serde(rename = "userTags", skip_serializing_if = "Vec::is_empty", default)]
+    pub user_tags: Vec<ResourceTag>,
     #[serde(rename = "extendParam", skip_serializing_if = "Option::is_none")]
     pub extend_param: Option<CceNodePoolExtendParam>,
 }
@@ -204,12 +915,19 @@ pub struct CceNodePoolExtendParam {
 #[cfg(test)]
 mod tests {
     use super::{
-        CceAuthentication, CceClusterCertRequest, CceClusterCreateMetadata, CceClusterCreateSpec,
-        CceClusterTag, CceContainerNetwork, CceCreateClusterRequest, CceCreateNodePoolRequest,
-        CceHostNetwork, CceNodePoolCreateMetadata, CceNodePoolCreateSpec, CceNodePoolExtendParam,
-        CceNodePoolListResponse, CceNodePoolLogin, CceNodePoolNicSpec, CceNodePoolPrimaryNic,
-        CceNodePoolTemplateSpec, CceNodePoolVolume, CceNodePoolVolumeExtendParam,
-        CceUpdateClusterRequest, CceUpdateClusterSpec,
+        CceAddonInstanceListResponse, CceAddonRequest, CceAddonRequestMetadata,
+        CceAddonRequestSpec, CceAddonTemplateListResponse, CceAddonValues, CceAuthentication,
+        CceCluster, CceClusterCertRequest, CceClusterCreateMetadata, CceClusterCreateSpec,
+        CceClusterExtendParam, CceContainerNetwork, CceCreateClusterRequest,
+        CceCreateNodePoolRequest, CceEniNetwork, CceEniSubnet, CceHostNetwork, CceJob,
+        CceMasterSpec, CceNodeListResponse, CceNodePool, CceNodePoolCreateMetadata,
+        CceNodePoolCreateSpec, CceNodePoolExtendParam, CceNodePoolListResponse, CceNodePoolLogin,
+        CceNodePoolNicSpec, CceNodePoolPrimaryNic, CceNodePoolTemplateSpec, CceNodePoolVolume,
+        CceNodePoolVolumeExtendParam, CceNodeRef, CcePrecheckRequest, CcePrecheckSpec,
+        CceRemoveNodesRequest, CceRemoveNodesSpec, CceResetNodeItem, CceResetNodeSpec,
+        CceResetNodesRequest, CceTaint, CceUpdateClusterRequest, CceUpdateClusterSpec,
+        CceUpgradeAction, CceUpgradeInfo, CceUpgradeRequest, CceUpgradeRequestMetadata,
+        CceUpgradeSpec, CceUpgradeStrategy, ResourceTag,
     };
 
     #[test]
@@ -221,23 +939,30 @@ mod tests {
                 name: "dev-cluster".to_string(),
             },
             spec: CceClusterCreateSpec {
+                category: "CCE".to_string(),
                 cluster_type: "VirtualMachine".to_string(),
                 flavor: "cce.s2.small".to_string(),
                 version: "v1.29".to_string(),
                 description: Some("integration cluster".to_string()),
+                ipv6_enable: false,
                 host_network: CceHostNetwork {
                     vpc: "vpc-id".to_string(),
                     subnet: "subnet-id".to_string(),
                 },
                 container_network: CceContainerNetwork {
                     mode: "overlay_l2".to_string(),
-                    cidr: "172.16.0.0/16".to_string(),
+                    cidr: Some("172.16.0.0/16".to_string()),
                 },
+                eni_network: None,
                 kubernetes_svc_ip_range: "10.247.0.0/16".to_string(),
+                kube_proxy_mode: None,
+                billing_mode: 0,
+                masters: Vec::new(),
                 authentication: Some(CceAuthentication {
                     mode: "rbac".to_string(),
                 }),
                 cluster_tags: Vec::new(),
+                extend_param: None,
             },
         };
 
@@ -275,8 +1000,9 @@ mod tests {
         assert_eq!(body.items.len(), 1);
         assert_eq!(body.kind.as_deref(), Some("ClusterList"));
         assert_eq!(body.items[0].kind.as_deref(), Some("Cluster"));
-        assert_eq!(body.items[0].metadata["name"], "cluster-name");
-        assert_eq!(body.items[0].status["phase"], "Available");
+        assert_eq!(body.items[0].metadata.name.as_deref(), Some("cluster-name"));
+        assert_eq!(body.items[0].metadata.extra["id"], "cluster-id");
+        assert_eq!(body.items[0].status.phase.as_deref(), Some("Available"));
     }
 
     #[test]
@@ -288,21 +1014,28 @@ mod tests {
                 name: "minimal-cluster".to_string(),
             },
             spec: CceClusterCreateSpec {
+                category: "CCE".to_string(),
                 cluster_type: "VirtualMachine".to_string(),
                 flavor: "cce.s2.small".to_string(),
                 version: "v1.30".to_string(),
                 description: None,
+                ipv6_enable: false,
                 host_network: CceHostNetwork {
                     vpc: "vpc-id".to_string(),
                     subnet: "subnet-id".to_string(),
                 },
                 container_network: CceContainerNetwork {
                     mode: "overlay_l2".to_string(),
-                    cidr: "172.16.0.0/16".to_string(),
+                    cidr: Some("172.16.0.0/16".to_string()),
                 },
+                eni_network: None,
                 kubernetes_svc_ip_range: "10.247.0.0/16".to_string(),
+                kube_proxy_mode: None,
+                billing_mode: 0,
+                masters: Vec::new(),
                 authentication: None,
                 cluster_tags: Vec::new(),
+                extend_param: None,
             },
         };
 
@@ -311,6 +1044,11 @@ mod tests {
         assert!(value["spec"].get("description").is_none());
         assert!(value["spec"].get("authentication").is_none());
         assert!(value["spec"].get("clusterTags").is_none());
+        assert!(value["spec"].get("ipv6enable").is_none());
+        assert!(value["spec"].get("eniNetwork").is_none());
+        assert!(value["spec"].get("kubeProxyMode").is_none());
+        assert!(value["spec"].get("masters").is_none());
+        assert_eq!(value["spec"]["billingMode"], 0);
     }
 
     #[test]
@@ -322,32 +1060,114 @@ mod tests {
                 name: "tagged-cluster".to_string(),
             },
             spec: CceClusterCreateSpec {
+                category: "CCE".to_string(),
                 cluster_type: "VirtualMachine".to_string(),
                 flavor: "cce.s2.medium".to_string(),
                 version: "v1.29".to_string(),
                 description: None,
+                ipv6_enable: false,
                 host_network: CceHostNetwork {
                     vpc: "vpc-id".to_string(),
                     subnet: "subnet-id".to_string(),
                 },
                 container_network: CceContainerNetwork {
                     mode: "overlay_l2".to_string(),
-                    cidr: "172.16.0.0/16".to_string(),
+                    cidr: Some("172.16.0.0/16".to_string()),
                 },
+                eni_network: None,
                 kubernetes_svc_ip_range: "10.247.0.0/16".to_string(),
+                kube_proxy_mode: None,
+                billing_mode: 0,
+                masters: Vec::new(),
                 authentication: Some(CceAuthentication {
                     mode: "rbac".to_string(),
                 }),
-                cluster_tags: vec![CceClusterTag {
+                cluster_tags: vec![ResourceTag {
                     key: "env".to_string(),
                     value: "prod".to_string(),
                 }],
+                extend_param: Some(CceClusterExtendParam {
+                    enterprise_project_id: Some("0".to_string()),
+                    ..CceClusterExtendParam::default()
+                }),
             },
         };
 
         let value = serde_json::to_value(payload).expect("serialize cce create request with tags");
         assert_eq!(value["spec"]["clusterTags"][0]["key"], "env");
         assert_eq!(value["spec"]["clusterTags"][0]["value"], "prod");
+        assert_eq!(value["spec"]["extendParam"]["enterpriseProjectId"], "0");
+        assert!(value["spec"]["extendParam"].get("clusterAZ").is_none());
+    }
+
+    #[test]
+    fn cce_create_request_serializes_turbo_and_multi_az_fields() {
+        let payload = CceCreateClusterRequest {
+            kind: "Cluster".to_string(),
+            api_version: "v3".to_string(),
+            metadata: CceClusterCreateMetadata {
+                name: "turbo-cluster".to_string(),
+            },
+            spec: CceClusterCreateSpec {
+                category: "Turbo".to_string(),
+                cluster_type: "VirtualMachine".to_string(),
+                flavor: "cce.s2.small".to_string(),
+                version: "v1.30".to_string(),
+                description: None,
+                ipv6_enable: true,
+                host_network: CceHostNetwork {
+                    vpc: "vpc-id".to_string(),
+                    subnet: "subnet-id".to_string(),
+                },
+                container_network: CceContainerNetwork {
+                    mode: "eni".to_string(),
+                    cidr: None,
+                },
+                eni_network: Some(CceEniNetwork {
+                    subnets: vec![CceEniSubnet {
+                        subnet_id: "neutron-subnet-id".to_string(),
+                    }],
+                }),
+                kubernetes_svc_ip_range: "10.247.0.0/16".to_string(),
+                kube_proxy_mode: Some("ipvs".to_string()),
+                billing_mode: 1,
+                masters: ["az1", "az2", "az3"]
+                    .into_iter()
+                    .map(|az| CceMasterSpec {
+                        availability_zone: az.to_string(),
+                    })
+                    .collect(),
+                authentication: None,
+                cluster_tags: Vec::new(),
+                extend_param: Some(CceClusterExtendParam {
+                    cluster_az: Some("multi_az".to_string()),
+                    cluster_external_ip: Some("203.0.113.10".to_string()),
+                    period_type: Some("month".to_string()),
+                    period_num: Some(3),
+                    is_auto_renew: Some("false".to_string()),
+                    is_auto_pay: Some("true".to_string()),
+                    ..CceClusterExtendParam::default()
+                }),
+            },
+        };
+
+        let value = serde_json::to_value(payload).expect("serialize turbo cluster request");
+        let spec = &value["spec"];
+        assert_eq!(spec["category"], "Turbo");
+        assert_eq!(spec["ipv6enable"], true);
+        assert!(spec["containerNetwork"].get("cidr").is_none());
+        assert_eq!(
+            spec["eniNetwork"]["subnets"][0]["subnetID"],
+            "neutron-subnet-id"
+        );
+        assert_eq!(spec["kubeProxyMode"], "ipvs");
+        assert_eq!(spec["billingMode"], 1);
+        assert_eq!(spec["masters"][2]["availabilityZone"], "az3");
+        assert_eq!(spec["extendParam"]["clusterAZ"], "multi_az");
+        assert_eq!(spec["extendParam"]["clusterExternalIP"], "203.0.113.10");
+        assert_eq!(spec["extendParam"]["periodNum"], 3);
+        assert_eq!(spec["extendParam"]["isAutoPay"], "true");
+        assert!(spec["extendParam"].get("enterpriseProjectId").is_none());
     }
 
     #[test]
@@ -370,8 +1190,8 @@ mod tests {
             serde_json::from_str(raw).expect("deserialize cce node pool list response");
         assert_eq!(body.items.len(), 1);
         assert_eq!(body.kind.as_deref(), Some("NodePoolList"));
-        assert_eq!(body.items[0].metadata["id"], "pool-1");
-        assert_eq!(body.items[0].status["phase"], "Running");
+        assert_eq!(body.items[0].metadata.extra["id"], "pool-1");
+        assert_eq!(body.items[0].status.phase.as_deref(), Some("Running"));
     }
 
     #[test]
@@ -429,6 +1249,10 @@ mod tests {
                         },
                     }),
                     billing_mode: 0,
+                    user_tags: vec![ResourceTag {
+                        key: "team".to_string(),
+                        value: "core".to_string(),
+                    }],
                     extend_param: Some(CceNodePoolExtendParam {
                         max_pods: Some(110),
                     }),
@@ -444,6 +1268,7 @@ mod tests {
         assert_eq!(value["spec"]["initialNodeCount"], 2);
         assert_eq!(value["spec"]["nodeTemplate"]["flavor"], "c6.2xlarge.2");
         assert_eq!(value["spec"]["nodeTemplate"]["az"], "sa-brazil-1a");
+        assert_eq!(value["spec"]["nodeTemplate"]["userTags"][0]["key"], "team");
         assert_eq!(
             value["spec"]["nodeTemplate"]["login"]["sshKey"],
             "my-keypair"
@@ -488,6 +1313,7 @@ mod tests {
                     data_volumes: Vec::new(),
                     node_nic_spec: None,
                     billing_mode: 0,
+                    user_tags: Vec::new(),
                     extend_param: None,
                 },
             },
@@ -500,4 +1326,490 @@ mod tests {
         assert!(value["spec"]["nodeTemplate"].get("nodeNicSpec").is_none());
         assert!(value["spec"]["nodeTemplate"].get("extendParam").is_none());
     }
+
+    #[test]
+    fn cce_node_list_deserializes_typed_fields() {
+        let raw = r#"{
+            "kind": "List",
+            "apiVersion": "v3",
+            "items": [
+                {
+                    "kind": "Node",
+                    "apiVersion": "v3",
+                    "metadata": {
+                        "name": "pool-a-node-1",
+                        "uid": "node-1",
+                        "labels": { "cce.cloud.com/cce-nodepool": "pool-a" },
+                        "annotations": { "kubernetes.io/node-pool.id": "pool-uid-1" },
+                        "creationTimestamp": "2026-01-02 03:04:05.678 +0000 UTC"
+                    },
+                    "spec": { "flavor": "c6.large.2", "az": "sa-brazil-1a", "os": "EulerOS 2.9" },
+                    "status": {
+                        "phase": "Active",
+                        "serverId": "server-1",
+                        "privateIP": "192.168.0.12",
+                        "jobID": "job-1"
+                    }
+                },
+                {
+                    "metadata": {
+                        "name": "default-node",
+                        "annotations": { "kubernetes.io/node-pool.id": "sa-brazil-1a#c6.large.2#EulerOS 2.9" }
+                    },
+                    "status": { "phase": "Installing", "privateIP": "" }
+                }
+            ]
+        }"#;
+
+        let response: CceNodeListResponse =
+            serde_json::from_str(raw).expect("deserialize cce node list");
+        assert_eq!(response.items.len(), 2);
+
+        let node = &response.items[0];
+        assert_eq!(node.metadata.name.as_deref(), Some("pool-a-node-1"));
+        assert_eq!(node.spec.flavor.as_deref(), Some("c6.large.2"));
+        assert_eq!(node.spec.az.as_deref(), Some("sa-brazil-1a"));
+        assert_eq!(node.status.phase.as_deref(), Some("Active"));
+        assert_eq!(node.status.server_id.as_deref(), Some("server-1"));
+        assert_eq!(node.node_pool_id(), Some("pool-uid-1"));
+        assert_eq!(node.status.private_ip.as_deref(), Some("192.168.0.12"));
+
+        let default_node = &response.items[1];
+        assert_eq!(default_node.node_pool_id(), None);
+        assert_eq!(default_node.status.private_ip.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn cce_node_action_requests_serialize_expected_fields() {
+        let reset = CceResetNodesRequest {
+            kind: "List".to_string(),
+            api_version: "v3".to_string(),
+            node_list: vec![CceResetNodeItem {
+                node_id: "node-1".to_string(),
+                spec: CceResetNodeSpec {
+                    os: "EulerOS 2.9".to_string(),
+                    login: CceNodePoolLogin {
+                        ssh_key: "my-key".to_string(),
+                    },
+                },
+            }],
+        };
+        let value = serde_json::to_value(reset).expect("serialize cce reset nodes request");
+        assert_eq!(value["nodeList"][0]["nodeID"], "node-1");
+        assert_eq!(value["nodeList"][0]["spec"]["os"], "EulerOS 2.9");
+        assert_eq!(value["nodeList"][0]["spec"]["login"]["sshKey"], "my-key");
+
+        let remove = CceRemoveNodesRequest {
+            kind: "RemoveNodesTask".to_string(),
+            api_version: "v3".to_string(),
+            spec: CceRemoveNodesSpec {
+                login: CceNodePoolLogin {
+                    ssh_key: "my-key".to_string(),
+                },
+                nodes: vec![CceNodeRef {
+                    uid: "node-1".to_string(),
+                }],
+            },
+        };
+        let value = serde_json::to_value(remove).expect("serialize cce remove nodes request");
+        assert_eq!(value["kind"], "RemoveNodesTask");
+        assert_eq!(value["apiVersion"], "v3");
+        assert_eq!(value["spec"]["nodes"][0]["uid"], "node-1");
+        assert_eq!(value["spec"]["login"]["sshKey"], "my-key");
+    }
+
+    #[test]
+    fn cce_cluster_deserializes_typed_fields_from_response_fixture() {
+        let raw = r#"{
+          "kind":"Cluster",
+          "apiVersion":"v3",
+          "metadata":{
+            "name":"prod-cluster",
+            "uid":"4d1ecb2c-229a-11e8-9c75-0255ac100ceb",
+            "creationTimestamp":"2026-03-01 10:20:30.123456 +0000 UTC",
+            "updateTimestamp":"2026-03-02 08:00:00.654321 +0000 UTC",
+            "labels":{"FeatureGates":"elbv3,"}
+          },
+          "spec":{
+            "category":"CCE",
+            "type":"VirtualMachine",
+            "flavor":"cce.s2.small",
+            "version":"v1.29",
+            "platformVersion":"cce.10.0",
+            "description":"",
+            "hostNetwork":{
+              "vpc":"vpc-1",
+              "subnet":"network-1",
+              "SecurityGroup":"sg-1"
+            },
+            "containerNetwork":{"mode":"vpc-router","cidr":"172.16.0.0/16","cidrs":[{"cidr":"172.16.0.0/16"}]},
+            "kubernetesSvcIpRange":"10.247.0.0/16",
+            "clusterTags":[{"key":"env","value":"prod"}],
+            "billingMode":0,
+            "extendParam":{"enterpriseProjectId":"ep-1","alpha.cce/fixPoolMask":"25"}
+          },
+          "status":{
+            "phase":"Available",
+            "jobID":"job-1",
+            "endpoints":[
+              {"url":"https://192.168.0.10:5443","type":"Internal"},
+              {"url":"https://203.0.113.5:5443","type":"External"}
+            ]
+          }
+        }"#;
+
+        let cluster: CceCluster = serde_json::from_str(raw).expect("deserialize cce cluster");
+        assert_eq!(
+            cluster.metadata.uid.as_deref(),
+            Some("4d1ecb2c-229a-11e8-9c75-0255ac100ceb")
+        );
+        assert_eq!(cluster.metadata.name.as_deref(), Some("prod-cluster"));
+        assert_eq!(
+            cluster.metadata.creation_timestamp.as_deref(),
+            Some("2026-03-01 10:20:30.123456 +0000 UTC")
+        );
+        assert_eq!(cluster.spec.flavor.as_deref(), Some("cce.s2.small"));
+        assert_eq!(cluster.spec.version.as_deref(), Some("v1.29"));
+        let host_network = cluster.spec.host_network.as_ref().expect("host network");
+        assert_eq!(host_network.vpc.as_deref(), Some("vpc-1"));
+        assert_eq!(host_network.subnet.as_deref(), Some("network-1"));
+        assert_eq!(host_network.extra["SecurityGroup"], "sg-1");
+        let container_network = cluster
+            .spec
+            .container_network
+            .as_ref()
+            .expect("container network");
+        assert_eq!(container_network.mode.as_deref(), Some("vpc-router"));
+        assert_eq!(container_network.cidr.as_deref(), Some("172.16.0.0/16"));
+        assert_eq!(cluster.tags()[0].key, "env");
+        assert_eq!(cluster.enterprise_project_id(), "ep-1");
+        assert_eq!(cluster.status.phase.as_deref(), Some("Available"));
+        let endpoints = cluster.status.endpoints.as_ref().expect("endpoints");
+        assert_eq!(endpoints[1].endpoint_type.as_deref(), Some("External"));
+        assert_eq!(cluster.spec.extra["platformVersion"], "cce.10.0");
+
+        let value = serde_json::to_value(&cluster).expect("serialize cce cluster");
+        assert_eq!(value["spec"]["kubernetesSvcIpRange"], "10.247.0.0/16");
+        assert_eq!(value["spec"]["hostNetwork"]["SecurityGroup"], "sg-1");
+        assert_eq!(value["spec"]["extendParam"]["alpha.cce/fixPoolMask"], "25");
+        assert_eq!(value["metadata"]["labels"]["FeatureGates"], "elbv3,");
+    }
+
+    #[test]
+    fn cce_cluster_defaults_enterprise_project_and_tags() {
+        let cluster: CceCluster =
+            serde_json::from_str(r#"{"metadata":{"uid":"c-1"},"spec":{},"status":{}}"#)
+                .expect("deserialize bare cce cluster");
+        assert_eq!(cluster.enterprise_project_id(), "0");
+        assert!(cluster.tags().is_empty());
+        assert!(cluster.spec.host_network.is_none());
+    }
+
+    #[test]
+    fn cce_node_pool_deserializes_typed_fields_from_response_fixture() {
+        let raw = r#"{
+          "kind":"NodePool",
+          "apiVersion":"v3",
+          "metadata":{
+            "name":"pool-a",
+            "uid":"pool-uid-1",
+            "creationTimestamp":"2026-03-01 11:00:00.000 +0000 UTC"
+          },
+          "spec":{
+            "type":"vm",
+            "initialNodeCount":3,
+            "autoscaling":{
+              "enable":true,
+              "minNodeCount":1,
+              "maxNodeCount":10,
+              "scaleDownCooldownTime":15,
+              "priority":2,
+              "scaleDownUnneededTime":10
+            },
+            "nodeManagement":{"serverGroupReference":""},
+            "nodeTemplate":{
+              "flavor":"c6.large.2",
+              "az":"sa-brazil-1a",
+              "os":"EulerOS 2.9",
+              "login":{"sshKey":"my-key"},
+              "rootVolume":{"volumetype":"SAS","size":40},
+              "k8sTags":{"cce.cloud.com/cce-nodepool":"pool-a","team":"core"},
+              "taints":[{"key":"dedicated","value":"gpu","effect":"NoSchedule"}],
+              "userTags":[{"key":"env","value":"dev"}],
+              "billingMode":0
+            }
+          },
+          "status":{
+            "currentNode":3,
+            "creatingNode":1,
+            "deletingNode":0,
+            "phase":"",
+            "jobId":"job-2"
+          }
+        }"#;
+
+        let pool: CceNodePool = serde_json::from_str(raw).expect("deserialize cce node pool");
+        assert_eq!(pool.metadata.uid.as_deref(), Some("pool-uid-1"));
+        assert_eq!(pool.spec.initial_node_count, Some(3));
+        let autoscaling = pool.spec.autoscaling.as_ref().expect("autoscaling");
+        assert_eq!(autoscaling.enable, Some(true));
+        assert_eq!(autoscaling.max_node_count, Some(10));
+        assert_eq!(autoscaling.priority, Some(2));
+        let template = pool.spec.node_template.as_ref().expect("node template");
+        assert_eq!(template.flavor.as_deref(), Some("c6.large.2"));
+        assert_eq!(template.az.as_deref(), Some("sa-brazil-1a"));
+        assert_eq!(
+            template.k8s_tags.as_ref().and_then(|tags| tags.get("team")),
+            Some(&"core".to_string())
+        );
+        assert_eq!(
+            template.taints.as_deref(),
+            Some(
+                &[CceTaint {
+                    key: "dedicated".to_string(),
+                    value: Some("gpu".to_string()),
+                    effect: "NoSchedule".to_string(),
+                }][..]
+            )
+        );
+        assert_eq!(pool.user_tags()[0].value, "dev");
+        assert_eq!(template.extra["login"]["sshKey"], "my-key");
+        assert_eq!(pool.status.current_node, Some(3));
+        assert_eq!(pool.status.creating_node, Some(1));
+        assert_eq!(pool.status.job_id.as_deref(), Some("job-2"));
+
+        let value = serde_json::to_value(&pool).expect("serialize cce node pool");
+        assert_eq!(value["spec"]["autoscaling"]["scaleDownUnneededTime"], 10);
+        assert_eq!(value["spec"]["nodeManagement"]["serverGroupReference"], "");
+        assert_eq!(value["spec"]["nodeTemplate"]["rootVolume"]["size"], 40);
+    }
+
+    #[test]
+    fn cce_upgrade_info_deserializes_target_versions() {
+        let raw = r#"{
+          "kind":"UpgradeInfo",
+          "apiVersion":"v3",
+          "metadata":{},
+          "spec":{
+            "lastUpgradeInfo":{"phase":"Success","progress":"100"},
+            "versionInfo":{
+              "release":"v1.27",
+              "patch":"r10",
+              "suggestPatch":"r12",
+              "targetVersions":["v1.28.5-r0","v1.29.1-r0"]
+            },
+            "isPackageUpgradeAllowed":false
+          },
+          "status":{"phase":"Success","progress":"100"}
+        }"#;
+
+        let info: CceUpgradeInfo = serde_json::from_str(raw).expect("deserialize upgrade info");
+        assert_eq!(info.target_versions(), ["v1.28.5-r0", "v1.29.1-r0"]);
+        let version_info = info.spec.version_info.as_ref().expect("version info");
+        assert_eq!(version_info.release.as_deref(), Some("v1.27"));
+        assert_eq!(version_info.suggest_patch.as_deref(), Some("r12"));
+        assert_eq!(info.spec.extra["isPackageUpgradeAllowed"], false);
+        assert!(CceUpgradeInfo::default().target_versions().is_empty());
+    }
+
+    #[test]
+    fn cce_job_deserializes_sub_jobs() {
+        let raw = r#"{
+          "kind":"Job",
+          "metadata":{"uid":"job-1","creationTimestamp":"2026-03-01 10:00:00 +0000 UTC"},
+          "spec":{
+            "type":"UpgradeCluster",
+            "clusterUID":"cluster-1",
+            "subJobs":[
+              {"kind":"Job","metadata":{"uid":"sub-1"},"spec":{"type":"UpgradeMaster"},"status":{"phase":"Success"}},
+              {"kind":"Job","metadata":{"uid":"sub-2"},"spec":{"type":"UpgradeNodes"},"status":{"phase":"Running"}}
+            ]
+          },
+          "status":{"phase":"Running","reason":""}
+        }"#;
+
+        let job: CceJob = serde_json::from_str(raw).expect("deserialize cce job");
+        assert_eq!(job.metadata.uid.as_deref(), Some("job-1"));
+        assert_eq!(job.spec.job_type.as_deref(), Some("UpgradeCluster"));
+        assert_eq!(job.status.phase.as_deref(), Some("Running"));
+        let sub_jobs = job.spec.sub_jobs.as_ref().expect("sub jobs");
+        assert_eq!(sub_jobs.len(), 2);
+        assert_eq!(sub_jobs[1].spec.job_type.as_deref(), Some("UpgradeNodes"));
+        assert_eq!(job.spec.extra["clusterUID"], "cluster-1");
+    }
+
+    #[test]
+    fn cce_upgrade_requests_serialize_expected_fields() {
+        let precheck = CcePrecheckRequest {
+            kind: "PreCheckTask".to_string(),
+            api_version: "v3".to_string(),
+            spec: CcePrecheckSpec {
+                cluster_upgrade_action: CceUpgradeAction {
+                    version: None,
+                    target_version: "v1.29.1-r0".to_string(),
+                    strategy: None,
+                },
+            },
+        };
+        let value = serde_json::to_value(precheck).expect("serialize precheck request");
+        assert_eq!(
+            value["spec"]["clusterUpgradeAction"]["targetVersion"],
+            "v1.29.1-r0"
+        );
+        assert!(value["spec"]["clusterUpgradeAction"]
+            .get("version")
+            .is_none());
+        assert!(value["spec"]["clusterUpgradeAction"]
+            .get("strategy")
+            .is_none());
+
+        let upgrade = CceUpgradeRequest {
+            metadata: CceUpgradeRequestMetadata {
+                api_version: "v3".to_string(),
+                kind: "UpgradeTask".to_string(),
+            },
+            spec: CceUpgradeSpec {
+                cluster_upgrade_action: CceUpgradeAction {
+                    version: Some("v1.27".to_string()),
+                    target_version: "v1.29.1-r0".to_string(),
+                    strategy: Some(CceUpgradeStrategy {
+                        strategy_type: "inPlaceRollingUpdate".to_string(),
+                    }),
+                },
+            },
+        };
+        let value = serde_json::to_value(upgrade).expect("serialize upgrade request");
+        assert_eq!(value["metadata"]["kind"], "UpgradeTask");
+        assert_eq!(value["spec"]["clusterUpgradeAction"]["version"], "v1.27");
+        assert_eq!(
+            value["spec"]["clusterUpgradeAction"]["strategy"]["type"],
+            "inPlaceRollingUpdate"
+        );
+    }
+
+    #[test]
+    fn cce_addon_template_list_deserializes_versions_and_flavors() {
+        let raw = r#"{
+          "kind":"Addon",
+          "apiVersion":"v3",
+          "items":[{
+            "kind":"Addon",
+            "apiVersion":"v3",
+            "metadata":{"uid":"autoscaler","name":"autoscaler","alias":"CCE Cluster Autoscaler"},
+            "spec":{
+              "type":"helm",
+              "require":false,
+              "labels":["ScaleAutomatically"],
+              "description":"Scales nodes automatically",
+              "logoURL":"https://example.invalid/logo.png",
+              "versions":[{
+                "version":"1.29.17",
+                "stable":true,
+                "supportVersions":[{"clusterType":"VirtualMachine","clusterVersion":["v1.29.*"]}],
+                "input":{
+                  "basic":{"swr_addr":"swr.example","swr_user":"hwofficial"},
+                  "parameters":{
+                    "custom":{"cluster_id":"","coresTotal":32000,"expander":"priority"},
+                    "flavor1":{"name":1,"replicas":1},
+                    "flavor2":{"name":2,"replicas":2}
+                  }
+                }
+              }]
+            }
+          }]
+        }"#;
+
+        let list: CceAddonTemplateListResponse =
+            serde_json::from_str(raw).expect("deserialize addon templates");
+        let template = &list.items[0];
+        assert_eq!(template.metadata.name.as_deref(), Some("autoscaler"));
+        assert_eq!(template.spec.template_type.as_deref(), Some("helm"));
+        assert_eq!(
+            template.spec.extra["logoURL"],
+            "https://example.invalid/logo.png"
+        );
+        assert!(template.version("1.0.0").is_none());
+        let version = template.version("1.29.17").expect("template version");
+        assert_eq!(version.stable, Some(true));
+        assert_eq!(version.input.basic["swr_user"], "hwofficial");
+        assert_eq!(version.input.parameters.custom["coresTotal"], 32000);
+        let flavors = version.input.parameters.flavors();
+        assert_eq!(
+            flavors.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["flavor1", "flavor2"]
+        );
+        let support = version.support_versions.as_ref().expect("support versions");
+        assert_eq!(support[0].cluster_type.as_deref(), Some("VirtualMachine"));
+    }
+
+    #[test]
+    fn cce_addon_instances_round_trip_values() {
+        let raw = r#"{
+          "kind":"List",
+          "apiVersion":"v3",
+          "items":[{
+            "kind":"Addon",
+            "apiVersion":"v3",
+            "metadata":{"uid":"addon-1","name":"coredns","creationTimestamp":"2026-03-01T10:00:00Z"},
+            "spec":{
+              "clusterID":"cluster-1",
+              "version":"1.30.5",
+              "addonTemplateName":"coredns",
+              "addonTemplateType":"helm",
+              "values":{
+                "basic":{"swr_addr":"swr.example"},
+                "custom":{"stub_domains":{}},
+                "flavor":{"name":2,"replicas":2}
+              }
+            },
+            "status":{"status":"running","Reason":"","message":"","isRollbackable":true}
+          }]
+        }"#;
+
+        let list: CceAddonInstanceListResponse =
+            serde_json::from_str(raw).expect("deserialize addon instances");
+        let addon = &list.items[0];
+        assert_eq!(addon.metadata.uid.as_deref(), Some("addon-1"));
+        assert_eq!(addon.spec.cluster_id.as_deref(), Some("cluster-1"));
+        assert_eq!(addon.spec.addon_template_name.as_deref(), Some("coredns"));
+        assert_eq!(addon.status.status.as_deref(), Some("running"));
+        assert_eq!(addon.status.is_rollbackable, Some(true));
+        assert_eq!(
+            addon
+                .spec
+                .values
+                .flavor
+                .as_ref()
+                .map(|flavor| &flavor["replicas"]),
+            Some(&serde_json::json!(2))
+        );
+
+        let request = CceAddonRequest {
+            kind: "Addon".to_string(),
+            api_version: "v3".to_string(),
+            metadata: CceAddonRequestMetadata {
+                annotations: [("addon.install/type".to_string(), "install".to_string())]
+                    .into_iter()
+                    .collect(),
+            },
+            spec: CceAddonRequestSpec {
+                cluster_id: "cluster-1".to_string(),
+                version: "1.30.5".to_string(),
+                addon_template_name: "coredns".to_string(),
+                values: CceAddonValues {
+                    flavor: None,
+                    ..addon.spec.values.clone()
+                },
+            },
+        };
+        let value = serde_json::to_value(request).expect("serialize addon request");
+        assert_eq!(
+            value["metadata"]["annotations"]["addon.install/type"],
+            "install"
+        );
+        assert_eq!(value["spec"]["clusterID"], "cluster-1");
+        assert_eq!(value["spec"]["addonTemplateName"], "coredns");
+        assert_eq!(value["spec"]["values"]["basic"]["swr_addr"], "swr.example");
+        assert!(value["spec"]["values"].get("flavor").is_none());
+    }
 }
@@ -1,3 +1,4 @@
+use super::tags::ResourceTag;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
@@ -50,6 +51,20 @@ pub struct Server {
 
     #[serde(rename = "adminPass", skip_serializing_if = "Option::is_none")]
     pub admin_pass: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub security_groups: Vec<SecurityGroupRef>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub server_tags: Vec<ResourceTag>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extendparam: Option<ServerExtendParam>,
+}
+
+#[derive(Serialize)]
+pub struct ServerExtendParam {
+    pub enterprise_project_id: String,
 }
 
 #[derive(Serialize)]
@@ -57,6 +72,11 @@ pub struct Nic {
     pub subnet_id: String,
 }
 
+#[derive(Serialize)]
+pub struct SecurityGroupRef {
+    pub id: String,
+}
+
 #[derive(Serialize)]
 pub struct RootVolume {
     pub volumetype: String,
@@ -133,6 +153,66 @@ pub struct StopEcsServer {
     pub id: String,
 }
 
+#[derive(Serialize)]
+pub struct ResizeEcsRequest {
+    pub resize: ResizeEcsBody,
+}
+
+#[derive(Serialize)]
+pub struct ResizeEcsBody {
+    #[serde(rename = "flavorRef")]
+    pub flavor_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ReinstallEcsRequest {
+    #[serde(rename = "os-reinstall")]
+    pub os_reinstall: ReinstallEcsBody,
+}
+
+#[derive(Serialize)]
+pub struct ReinstallEcsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adminpass: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ChangeEcsOsRequest {
+    #[serde(rename = "os-change")]
+    pub os_change: ChangeEcsOsBody,
+}
+
+#[derive(Serialize)]
+pub struct ChangeEcsOsBody {
+    pub imageid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adminpass: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct AttachVolumeRequest {
+    #[serde(rename = "volumeAttachment")]
+    pub volume_attachment: AttachVolumeBody,
+}
+
+#[derive(Serialize)]
+pub struct AttachVolumeBody {
+    #[serde(rename = "volumeId")]
+    pub volume_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Flavor {
     pub id: String,
@@ -143,7 +223,8 @@ pub struct Flavor {
     pub ram: Option<u32>,
     #[serde(default, deserialize_with = "deserialize_u32_opt")]
     pub disk: Option<u32>,
-    #[serde(default)]
+    // The resize flavor listing returns the same specs under `extra_specs`.
+    #[serde(default, alias = "extra_specs")]
     pub os_extra_specs: HashMap<String, String>,
 }
 
@@ -180,6 +261,64 @@ pub struct EcsServer {
     pub updated: Option<String>,
     pub spod_id: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub security_groups: Vec<EcsSecurityGroup>,
+    /// NIC addresses keyed by VPC ID.
+    #[serde(default)]
+    pub addresses: HashMap<String, Vec<EcsAddress>>,
+    #[serde(default)]
+    pub metadata: EcsServerMetadata,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EcsServerMetadata {
+    /// VPC of the primary NIC.
+    pub vpc_id: Option<String>,
+}
+
+impl EcsServer {
+    /// Port ID of the first fixed IPv4 address in the primary NIC's VPC, taken from
+    /// `metadata.vpc_id`. `None` when a multi-VPC server does not name that VPC.
+    pub fn primary_port_id(&self) -> Option<&str> {
+        let vpc_id = match self.metadata.vpc_id.as_deref().map(str::trim) {
+            Some(vpc_id) if !vpc_id.is_empty() => vpc_id,
+            _ if self.addresses.len() == 1 => self.addresses.keys().next()?.as_str(),
+            _ => return None,
+        };
+        self.addresses
+            .get(vpc_id)?
+            .iter()
+            .filter(|address| address.ip_type.as_deref() != Some("floating"))
+            .filter(|address| address.version.unwrap_or(4) == 4)
+            .filter_map(|address| address.port_id.as_deref())
+            .map(str::trim)
+            .find(|port_id| !port_id.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsAddress {
+    pub addr: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_u32_opt")]
+    pub version: Option<u32>,
+    /// "fixed" for private NIC addresses, "floating" for EIPs.
+    #[serde(rename = "OS-EXT-IPS:type")]
+    pub ip_type: Option<String>,
+    #[serde(rename = "OS-EXT-IPS:port_id")]
+    pub port_id: Option<String>,
+    #[serde(rename = "OS-EXT-IPS-MAC:mac_addr")]
+    pub mac_addr: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsSecurityGroup {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsServerDetailResponse {
+    pub server: EcsServer,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -218,6 +357,9 @@ mod tests {
             data_volumes: Vec::new(),
             publicip: None,
             admin_pass: None,
+            security_groups: Vec::new(),
+            server_tags: Vec::new(),
+            extendparam: None,
         };
         let without_json =
             serde_json::to_string(&without_password).expect("serialize server without password");
@@ -287,6 +429,9 @@ mod tests {
             }],
             publicip: None,
             admin_pass: None,
+            security_groups: Vec::new(),
+            server_tags: Vec::new(),
+            extendparam: None,
         };
 
         let value = serde_json::to_value(payload).expect("serialize server payload");
@@ -314,9 +459,197 @@ mod tests {
             data_volumes: Vec::new(),
             publicip: None,
             admin_pass: None,
+            security_groups: Vec::new(),
+            server_tags: Vec::new(),
+            extendparam: None,
         };
 
         let value = serde_json::to_value(payload).expect("serialize server payload");
         assert!(value.get("data_volumes").is_none());
     }
+
+    #[test]
+    fn resize_request_serializes_expected_fields() {
+        let payload = ResizeEcsRequest {
+            resize: ResizeEcsBody {
+                flavor_ref: "c7.large.2".to_string(),
+                mode: Some("withStopServer".to_string()),
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize resize ecs payload");
+        assert_eq!(value["resize"]["flavorRef"], "c7.large.2");
+        assert_eq!(value["resize"]["mode"], "withStopServer");
+    }
+
+    #[test]
+    fn change_os_request_serializes_key_without_password() {
+        let payload = ChangeEcsOsRequest {
+            os_change: ChangeEcsOsBody {
+                imageid: "image-id".to_string(),
+                adminpass: None,
+                keyname: Some("my-keypair".to_string()),
+                mode: None,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize change os payload");
+        assert_eq!(value["os-change"]["imageid"], "image-id");
+        assert_eq!(value["os-change"]["keyname"], "my-keypair");
+        assert!(value["os-change"].get("adminpass").is_none());
+        assert!(value["os-change"].get("mode").is_none());
+    }
+
+    #[test]
+    fn reinstall_request_serializes_expected_fields() {
+        let payload = ReinstallEcsRequest {
+            os_reinstall: ReinstallEcsBody {
+                adminpass: Some("Passw0rd!".to_string()),
+                keyname: None,
+                mode: Some("withStopServer".to_string()),
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize reinstall payload");
+        assert_eq!(value["os-reinstall"]["adminpass"], "Passw0rd!");
+        assert_eq!(value["os-reinstall"]["mode"], "withStopServer");
+        assert!(value["os-reinstall"].get("keyname").is_none());
+    }
+
+    #[test]
+    fn flavor_reads_resize_listing_extra_specs() {
+        let raw = r#"{
+          "id": "c7.large.2",
+          "name": "c7.large.2",
+          "vcpus": "2",
+          "ram": 4096,
+          "extra_specs": {"ecs:instance_architecture": "x86"}
+        }"#;
+        let flavor: Flavor = serde_json::from_str(raw).expect("deserialize resize flavor");
+        assert_eq!(flavor.vcpus, Some(2));
+        assert_eq!(
+            flavor
+                .os_extra_specs
+                .get("ecs:instance_architecture")
+                .map(String::as_str),
+            Some("x86")
+        );
+    }
+
+    #[test]
+    fn server_serializes_security_groups_when_present() {
+        let payload = Server {
+            name: "example".to_string(),
+            image_ref: "img".to_string(),
+            flavor_ref: "flavor".to_string(),
+            vpcid: "vpc".to_string(),
+            nics: vec![Nic {
+                subnet_id: "subnet".to_string(),
+            }],
+            root_volume: RootVolume {
+                volumetype: "GPSSD".to_string(),
+                size: 40,
+            },
+            data_volumes: Vec::new(),
+            publicip: None,
+            admin_pass: None,
+            security_groups: vec![SecurityGroupRef {
+                id: "sg-1".to_string(),
+            }],
+            server_tags: vec![ResourceTag {
+                key: "team".to_string(),
+                value: "core".to_string(),
+            }],
+            extendparam: Some(ServerExtendParam {
+                enterprise_project_id: "0".to_string(),
+            }),
+        };
+
+        let value = serde_json::to_value(payload).expect("serialize server payload");
+        assert_eq!(value["security_groups"][0]["id"], "sg-1");
+        assert_eq!(value["server_tags"][0]["key"], "team");
+        assert_eq!(value["server_tags"][0]["value"], "core");
+        assert_eq!(value["extendparam"]["enterprise_project_id"], "0");
+    }
+
+    #[test]
+    fn ecs_server_deserializes_security_groups() {
+        let raw = r#"{"id":"server-1","security_groups":[{"id":"sg-1","name":"default"}]}"#;
+        let server: EcsServer = serde_json::from_str(raw).expect("deserialize ecs server");
+        assert_eq!(server.security_groups.len(), 1);
+        assert_eq!(server.security_groups[0].id.as_deref(), Some("sg-1"));
+    }
+
+    #[test]
+    fn ecs_server_deserializes_nic_addresses() {
+        let raw = r#"{
+          "id":"server-1",
+          "addresses":{
+            "vpc-1":[
+              {
+                "addr":"192.168.0.10",
+                "version":"4",
+                "OS-EXT-IPS:type":"fixed",
+                "OS-EXT-IPS:port_id":"port-1",
+                "OS-EXT-IPS-MAC:mac_addr":"fa:16:3e:00:00:01"
+              },
+              {"addr":"203.0.113.7","version":4,"OS-EXT-IPS:type":"floating"}
+            ]
+          }
+        }"#;
+        let server: EcsServer = serde_json::from_str(raw).expect("deserialize ecs server");
+        let nics = &server.addresses["vpc-1"];
+        assert_eq!(nics.len(), 2);
+        assert_eq!(nics[0].port_id.as_deref(), Some("port-1"));
+        assert_eq!(nics[0].version, Some(4));
+        assert_eq!(nics[1].ip_type.as_deref(), Some("floating"));
+    }
+
+    #[test]
+    fn primary_port_id_skips_floating_and_ipv6_addresses() {
+        let raw = r#"{
+          "id":"server-1",
+          "addresses":{
+            "vpc-1":[
+              {"addr":"203.0.113.7","version":4,"OS-EXT-IPS:type":"floating","OS-EXT-IPS:port_id":"port-fip"},
+              {"addr":"2001:db8::10","version":6,"OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-v6"},
+              {"addr":"192.168.0.10","version":4,"OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-1"},
+              {"addr":"192.168.0.11","version":4,"OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-2"}
+            ]
+          }
+        }"#;
+        let server: EcsServer = serde_json::from_str(raw).expect("deserialize ecs server");
+        assert_eq!(server.primary_port_id(), Some("port-1"));
+
+        let no_nics: EcsServer =
+            serde_json::from_str(r#"{"id":"server-2"}"#).expect("deserialize ecs server");
+        assert_eq!(no_nics.primary_port_id(), None);
+    }
+
+    #[test]
+    fn primary_port_id_follows_the_primary_vpc_on_multi_vpc_servers() {
+        let raw = r#"{
+          "id":"server-1",
+          "metadata":{"vpc_id":"vpc-b","image_name":"Ubuntu"},
+          "addresses":{
+            "vpc-a":[{"addr":"10.0.0.5","version":4,"OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-secondary"}],
+            "vpc-b":[{"addr":"192.168.0.5","version":4,"OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-primary"}]
+          }
+        }"#;
+        let mut server: EcsServer = serde_json::from_str(raw).expect("deserialize ecs server");
+        assert_eq!(server.primary_port_id(), Some("port-primary"));
+
+        server.metadata.vpc_id = None;
+        assert_eq!(server.primary_port_id(), None);
+    }
+
+    #[test]
+    fn attach_volume_request_serializes_expected_fields() {
+        let payload = AttachVolumeRequest {
+            volume_attachment: AttachVolumeBody {
+                volume_id: "vol-1".to_string(),
+                device: None,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize attach volume payload");
+        assert_eq!(value["volumeAttachment"]["volumeId"], "vol-1");
+        assert!(value["volumeAttachment"].get("device").is_none());
+    }
 }
@@ -23,7 +23,7 @@ pub struct EipVnic {
     pub port_vif_details: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct EipBandwidth {
     pub id: Option<String>,
     pub size: Option<u32>,
@@ -31,6 +31,23 @@ pub struct EipBandwidth {
     pub charge_mode: Option<String>,
     pub name: Option<String>,
     pub billing_info: Option<String>,
+    #[serde(default)]
+    pub bandwidth_type: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub enterprise_project_id: Option<String>,
+    /// EIPs using this bandwidth; shared (WHOLE) bandwidths can list several.
+    #[serde(default)]
+    pub publicip_info: Vec<BandwidthPublicIpInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BandwidthPublicIpInfo {
+    pub publicip_id: Option<String>,
+    pub publicip_address: Option<String>,
+    pub publicip_type: Option<String>,
+    pub ip_version: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,45 +93,240 @@ pub struct EipListResponse {
 pub struct CreatePublicIpRequest {
     pub publicip: CreatePublicIpBody,
     pub bandwidth: CreatePublicIpBandwidth,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CreatePublicIpBody {
     #[serde(rename = "type")]
     pub ip_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
 }
 
+/// Dedicated (PER) bandwidths need name/size/charge_mode; shared (WHOLE) ones only need `id`.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreatePublicIpBandwidth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    pub share_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EipSingleResponse {
+    pub publicip: PublicIp,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EipAssociateRequest {
+    pub publicip: EipAssociateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EipAssociateBody {
+    pub associate_instance_type: String,
+    pub associate_instance_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BandwidthSingleResponse {
+    pub bandwidth: EipBandwidth,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BandwidthListResponse {
+    #[serde(default)]
+    pub bandwidths: Vec<EipBandwidth>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthUpdateRequest {
+    pub bandwidth: BandwidthUpdateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthUpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedBandwidthCreateRequest {
+    pub bandwidth: SharedBandwidthCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedBandwidthCreateBody {
     pub name: String,
     pub size: u32,
-    pub share_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthPublicIpRef {
+    pub publicip_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthInsertRequest {
+    pub bandwidth: BandwidthInsertBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthInsertBody {
+    pub publicip_info: Vec<BandwidthPublicIpRef>,
+}
+
+/// EIPs removed from a shared bandwidth get a new dedicated bandwidth with these settings.
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthRemoveRequest {
+    pub bandwidth: BandwidthRemoveBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BandwidthRemoveBody {
     pub charge_mode: String,
+    pub size: u32,
+    pub publicip_info: Vec<BandwidthPublicIpRef>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EipUpdateRequest {
+    pub publicip: EipUpdateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EipUpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CreatePublicIpBandwidth, CreatePublicIpBody, CreatePublicIpRequest};
+    use super::{
+        BandwidthPublicIpRef, BandwidthRemoveBody, BandwidthRemoveRequest, BandwidthSingleResponse,
+        CreatePublicIpBandwidth, CreatePublicIpBody, CreatePublicIpRequest, EipAssociateBody,
+        EipAssociateRequest, EipSingleResponse,
+    };
 
     #[test]
     fn create_public_ip_request_serializes_expected_fields() {
         let payload = CreatePublicIpRequest {
             publicip: CreatePublicIpBody {
                 ip_type: "5_bgp".to_string(),
+                alias: None,
             },
             bandwidth: CreatePublicIpBandwidth {
-                name: "cce-nat-eip".to_string(),
-                size: 100,
+                id: None,
+                name: Some("cce-nat-eip".to_string()),
+                size: Some(100),
                 share_type: "PER".to_string(),
-                charge_mode: "traffic".to_string(),
+                charge_mode: Some("traffic".to_string()),
             },
+            enterprise_project_id: None,
         };
 
         let value = serde_json::to_value(payload).expect("serialize create public ip payload");
+        assert!(value.get("enterprise_project_id").is_none());
         assert_eq!(value["publicip"]["type"], "5_bgp");
         assert_eq!(value["bandwidth"]["name"], "cce-nat-eip");
         assert_eq!(value["bandwidth"]["size"], 100);
         assert_eq!(value["bandwidth"]["share_type"], "PER");
         assert_eq!(value["bandwidth"]["charge_mode"], "traffic");
     }
+
+    #[test]
+    fn create_public_ip_request_for_shared_bandwidth_only_sends_id() {
+        let payload = CreatePublicIpRequest {
+            publicip: CreatePublicIpBody {
+                ip_type: "5_bgp".to_string(),
+                alias: Some("web".to_string()),
+            },
+            bandwidth: CreatePublicIpBandwidth {
+                id: Some("bw-1".to_string()),
+                name: None,
+                size: None,
+                share_type: "WHOLE".to_string(),
+                charge_mode: None,
+            },
+            enterprise_project_id: Some("0".to_string()),
+        };
+
+        let value = serde_json::to_value(payload).expect("serialize create public ip payload");
+        assert_eq!(value["enterprise_project_id"], "0");
+        assert_eq!(value["publicip"]["alias"], "web");
+        assert_eq!(value["bandwidth"]["id"], "bw-1");
+        assert_eq!(value["bandwidth"]["share_type"], "WHOLE");
+        assert!(value["bandwidth"].get("size").is_none());
+        assert!(value["bandwidth"].get("name").is_none());
+    }
+
+    #[test]
+    fn eip_associate_request_serializes_port_binding() {
+        let payload = EipAssociateRequest {
+            publicip: EipAssociateBody {
+                associate_instance_type: "PORT".to_string(),
+                associate_instance_id: "port-1".to_string(),
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize associate payload");
+        assert_eq!(value["publicip"]["associate_instance_type"], "PORT");
+        assert_eq!(value["publicip"]["associate_instance_id"], "port-1");
+    }
+
+    #[test]
+    fn eip_single_response_reads_vnic_port() {
+        let raw = r#"{"publicip":{"id":"eip-1","status":"ACTIVE","vnic":{"port_id":"port-1","instance_id":"ecs-1"}}}"#;
+        let body: EipSingleResponse = serde_json::from_str(raw).expect("deserialize eip");
+        let vnic = body.publicip.vnic.expect("vnic");
+        assert_eq!(vnic.port_id.as_deref(), Some("port-1"));
+        assert_eq!(vnic.instance_id.as_deref(), Some("ecs-1"));
+    }
+
+    #[test]
+    fn bandwidth_single_response_reads_shared_members() {
+        let raw = r#"{"bandwidth":{"id":"bw-1","name":"pool","size":50,"share_type":"WHOLE","charge_mode":"bandwidth","bandwidth_type":"share","publicip_info":[{"publicip_id":"eip-1","publicip_address":"203.0.113.7","publicip_type":"5_bgp","ip_version":4}]}}"#;
+        let body: BandwidthSingleResponse =
+            serde_json::from_str(raw).expect("deserialize bandwidth");
+        assert_eq!(body.bandwidth.share_type.as_deref(), Some("WHOLE"));
+        assert_eq!(body.bandwidth.publicip_info.len(), 1);
+        assert_eq!(
+            body.bandwidth.publicip_info[0].publicip_id.as_deref(),
+            Some("eip-1")
+        );
+    }
+
+    #[test]
+    fn bandwidth_remove_request_serializes_fallback_bandwidth() {
+        let payload = BandwidthRemoveRequest {
+            bandwidth: BandwidthRemoveBody {
+                charge_mode: "traffic".to_string(),
+                size: 10,
+                publicip_info: vec![BandwidthPublicIpRef {
+                    publicip_id: "eip-1".to_string(),
+                }],
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize remove payload");
+        assert_eq!(value["bandwidth"]["charge_mode"], "traffic");
+        assert_eq!(value["bandwidth"]["size"], 10);
+        assert_eq!(
+            value["bandwidth"]["publicip_info"][0]["publicip_id"],
+            "eip-1"
+        );
+    }
 }
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Enterprise project as returned by EPS; `id` "0" is the account's default project.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EnterpriseProject {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// 1 = enabled, 2 = disabled.
+    pub status: Option<u32>,
+    #[serde(rename = "type")]
+    pub project_type: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EnterpriseProjectListResponse {
+    #[serde(default)]
+    pub enterprise_projects: Vec<EnterpriseProject>,
+    pub total_count: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnterpriseProjectListResponse;
+
+    #[test]
+    fn enterprise_project_list_parses() {
+        let parsed: EnterpriseProjectListResponse = serde_json::from_str(
+            r#"{"enterprise_projects":[{"id":"0","name":"default","status":1,"type":"prod",
+                "created_at":"2024-01-01T00:00:00Z"}],"total_count":1}"#,
+        )
+        .expect("parse enterprise projects");
+        assert_eq!(parsed.total_count, Some(1));
+        assert_eq!(parsed.enterprise_projects[0].id.as_deref(), Some("0"));
+        assert_eq!(
+            parsed.enterprise_projects[0].project_type.as_deref(),
+            Some("prod")
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 
 fn deserialize_u32_opt<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
 where
@@ -38,7 +39,7 @@ where
     Ok(parsed)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct EvsAttachment {
     pub id: Option<String>,
     pub server_id: Option<String>,
@@ -46,7 +47,7 @@ pub struct EvsAttachment {
     pub attached_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct EvsVolume {
     pub id: Option<String>,
     pub name: Option<String>,
@@ -73,9 +74,108 @@ pub struct EvsListResponse {
     pub count: Option<u32>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvsVolumeSingleResponse {
+    pub volume: EvsVolume,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsCreateRequest {
+    pub volume: EvsCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsCreateBody {
+    pub availability_zone: String,
+    pub volume_type: String,
+    pub size: u32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiattach: Option<bool>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
+}
+
+/// Pay-per-use creates answer with a job plus the IDs of the new disks.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EvsCreateResponse {
+    pub job_id: Option<String>,
+    #[serde(default)]
+    pub volume_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsExtendRequest {
+    #[serde(rename = "os-extend")]
+    pub os_extend: EvsExtendBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsExtendBody {
+    pub new_size: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EvsSnapshot {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_u32_opt")]
+    pub size: Option<u32>,
+    pub volume_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvsSnapshotListResponse {
+    #[serde(default)]
+    pub snapshots: Vec<EvsSnapshot>,
+    #[serde(default, deserialize_with = "deserialize_u32_opt")]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvsSnapshotSingleResponse {
+    pub snapshot: EvsSnapshot,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsSnapshotCreateRequest {
+    pub snapshot: EvsSnapshotCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsSnapshotCreateBody {
+    pub volume_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Required to snapshot a disk that is attached (in-use).
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsSnapshotRollbackRequest {
+    pub rollback: EvsSnapshotRollbackBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvsSnapshotRollbackBody {
+    pub volume_id: String,
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{EvsListResponse, EvsVolume};
+    use super::{
+        EvsCreateBody, EvsCreateRequest, EvsCreateResponse, EvsExtendBody, EvsExtendRequest,
+        EvsListResponse, EvsSnapshotCreateBody, EvsSnapshotCreateRequest, EvsSnapshotListResponse,
+        EvsVolume,
+    };
+    use std::collections::BTreeMap;
 
     #[test]
     fn evs_volume_deserializes_mixed_types() {
@@ -131,4 +231,66 @@ mod tests {
         assert_eq!(volume.bootable, None);
         assert_eq!(volume.multiattach, None);
     }
+
+    #[test]
+    fn evs_create_request_skips_unset_multiattach() {
+        let payload = EvsCreateRequest {
+            volume: EvsCreateBody {
+                availability_zone: "sa-brazil-1a".to_string(),
+                volume_type: "GPSSD".to_string(),
+                size: 40,
+                name: "data-1".to_string(),
+                multiattach: None,
+                tags: BTreeMap::new(),
+                enterprise_project_id: None,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize evs create payload");
+        assert_eq!(value["volume"]["volume_type"], "GPSSD");
+        assert_eq!(value["volume"]["size"], 40);
+        assert!(value["volume"].get("multiattach").is_none());
+        assert!(value["volume"].get("tags").is_none());
+    }
+
+    #[test]
+    fn evs_create_response_reads_volume_ids() {
+        let raw = r#"{"job_id":"job-1","order_id":null,"volume_ids":["vol-1"]}"#;
+        let body: EvsCreateResponse = serde_json::from_str(raw).expect("deserialize create");
+        assert_eq!(body.job_id.as_deref(), Some("job-1"));
+        assert_eq!(body.volume_ids, vec!["vol-1".to_string()]);
+    }
+
+    #[test]
+    fn evs_extend_request_uses_os_extend_key() {
+        let payload = EvsExtendRequest {
+            os_extend: EvsExtendBody { new_size: 200 },
+        };
+        let value = serde_json::to_value(payload).expect("serialize extend payload");
+        assert_eq!(value["os-extend"]["new_size"], 200);
+    }
+
+    #[test]
+    fn evs_snapshot_list_response_deserializes_string_sizes() {
+        let raw = r#"{"snapshots":[{"id":"snap-1","status":"available","size":"40","volume_id":"vol-1"}],"count":1}"#;
+        let body: EvsSnapshotListResponse =
+            serde_json::from_str(raw).expect("deserialize snapshot list");
+        assert_eq!(body.count, Some(1));
+        assert_eq!(body.snapshots[0].size, Some(40));
+        assert_eq!(body.snapshots[0].volume_id.as_deref(), Some("vol-1"));
+    }
+
+    #[test]
+    fn evs_snapshot_create_request_sets_force() {
+        let payload = EvsSnapshotCreateRequest {
+            snapshot: EvsSnapshotCreateBody {
+                volume_id: "vol-1".to_string(),
+                name: "pre-update".to_string(),
+                description: None,
+                force: true,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize snapshot payload");
+        assert_eq!(value["snapshot"]["force"], true);
+        assert!(value["snapshot"].get("description").is_none());
+    }
 }
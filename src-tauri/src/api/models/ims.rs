@@ -24,6 +24,34 @@ pub struct Image {
     #[serde(default)]
     pub tags: Vec<String>,
     pub protected: Option<bool>,
+    /// "gold" (public), "private", "shared" or "market".
+    #[serde(rename = "__imagetype")]
+    pub image_type: Option<String>,
+    /// Backing store of the image, e.g. "uds", "obs" or "cbr" for whole images.
+    #[serde(rename = "__image_source_type")]
+    pub image_source_type: Option<String>,
+    /// Project that owns the image; differs from the caller for shared images.
+    pub owner: Option<String>,
+    // IMS reports the `__support_*` flags as "true"/"false" strings, or a GPU
+    // model name for the GPU type flags.
+    #[serde(rename = "__support_kvm")]
+    pub support_kvm: Option<String>,
+    #[serde(rename = "__support_xen")]
+    pub support_xen: Option<String>,
+    #[serde(rename = "__support_largememory")]
+    pub support_largememory: Option<String>,
+    #[serde(rename = "__support_diskintensive")]
+    pub support_diskintensive: Option<String>,
+    #[serde(rename = "__support_highperformance")]
+    pub support_highperformance: Option<String>,
+    #[serde(rename = "__support_kvm_gpu_type")]
+    pub support_kvm_gpu_type: Option<String>,
+    #[serde(rename = "__support_kvm_infiniband")]
+    pub support_kvm_infiniband: Option<String>,
+    #[serde(rename = "__support_arm")]
+    pub support_arm: Option<String>,
+    #[serde(rename = "__support_amd")]
+    pub support_amd: Option<String>,
 }
 
 // Define a response struct if needed
@@ -31,3 +59,144 @@ pub struct Image {
 pub struct ImageListResponse {
     pub images: Vec<Image>,
 }
+
+/// System-disk image of one ECS.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageCreateFromServerRequest {
+    pub name: String,
+    pub instance_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
+}
+
+/// Whole-ECS image (system and data disks), backed by a CBR vault.
+#[derive(Debug, Clone, Serialize)]
+pub struct WholeImageCreateRequest {
+    pub name: String,
+    pub instance_id: String,
+    pub vault_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
+}
+
+/// System image registered from an image file stored in OBS.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageImportRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `<bucket>:<object key>`.
+    pub image_url: String,
+    pub os_version: String,
+    /// GiB.
+    pub min_disk: u32,
+    /// "ECS" for system disk images.
+    #[serde(rename = "type")]
+    pub image_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageExportRequest {
+    /// `<bucket>:<object key>`.
+    pub bucket_url: String,
+    pub file_format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_quick_export: Option<bool>,
+}
+
+/// Add or remove share members on a batch of images.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageMembersRequest {
+    pub images: Vec<String>,
+    pub projects: Vec<String>,
+}
+
+/// Accept or reject images shared with `project_id`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageMemberStatusRequest {
+    pub images: Vec<String>,
+    pub project_id: String,
+    /// "accepted" or "rejected".
+    pub status: String,
+    /// Required when accepting a shared whole image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vault_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Image, ImageExportRequest, ImageImportRequest, ImageMemberStatusRequest,
+        WholeImageCreateRequest,
+    };
+
+    #[test]
+    fn image_deserializes_ims_extension_fields() {
+        let raw = r#"{"id":"img-1","name":"golden","status":"active","__imagetype":"private",
+            "__image_source_type":"cbr","__whole_image":true,"__support_kvm":"true",
+            "__support_kvm_gpu_type":"V100","owner":"proj-1"}"#;
+        let image: Image = serde_json::from_str(raw).expect("deserialize image");
+        assert_eq!(image.image_type.as_deref(), Some("private"));
+        assert_eq!(image.image_source_type.as_deref(), Some("cbr"));
+        assert_eq!(image.whole_image, Some(true));
+        assert_eq!(image.support_kvm.as_deref(), Some("true"));
+        assert_eq!(image.support_kvm_gpu_type.as_deref(), Some("V100"));
+        assert!(image.support_arm.is_none());
+    }
+
+    #[test]
+    fn image_requests_skip_optional_fields() {
+        let whole = WholeImageCreateRequest {
+            name: "golden-full".to_string(),
+            instance_id: "server-1".to_string(),
+            vault_id: "vault-1".to_string(),
+            description: None,
+            enterprise_project_id: None,
+        };
+        let value = serde_json::to_value(whole).expect("serialize whole image request");
+        assert_eq!(value["vault_id"], "vault-1");
+        assert!(value.get("description").is_none());
+
+        let accept = ImageMemberStatusRequest {
+            images: vec!["img-1".to_string()],
+            project_id: "proj-2".to_string(),
+            status: "accepted".to_string(),
+            vault_id: None,
+        };
+        let value = serde_json::to_value(accept).expect("serialize member status request");
+        assert_eq!(value["status"], "accepted");
+        assert!(value.get("vault_id").is_none());
+    }
+
+    #[test]
+    fn image_import_and_export_requests_use_bucket_urls() {
+        let import = ImageImportRequest {
+            name: "golden".to_string(),
+            description: None,
+            image_url: "images:golden.qcow2".to_string(),
+            os_version: "Ubuntu 22.04 server 64bit".to_string(),
+            min_disk: 40,
+            image_type: "ECS".to_string(),
+            enterprise_project_id: None,
+        };
+        let value = serde_json::to_value(import).expect("serialize import request");
+        assert_eq!(value["image_url"], "images:golden.qcow2");
+        assert_eq!(value["type"], "ECS");
+        assert!(value.get("enterprise_project_id").is_none());
+
+        let export = ImageExportRequest {
+            bucket_url: "images:golden.vhd".to_string(),
+            file_format: "vhd".to_string(),
+            is_quick_export: None,
+        };
+        let value = serde_json::to_value(export).expect("serialize export request");
+        assert_eq!(value["file_format"], "vhd");
+        assert!(value.get("is_quick_export").is_none());
+    }
+}
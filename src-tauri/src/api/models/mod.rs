@@ -1,9 +1,12 @@
 pub mod cce;
 pub mod ecs;
 pub mod eip;
+pub mod eps;
 pub mod evs;
 pub mod iam;
 pub mod ims;
 pub mod nat;
 pub mod obs;
+pub mod quotas;
+pub mod tags;
 pub mod vpc;
@@ -30,8 +30,14 @@ pub struct SnatRule {
     pub id: Option<String>,
     pub nat_gateway_id: Option<String>,
     pub network_id: Option<String>,
+    pub cidr: Option<String>,
+    pub source_type: Option<u32>,
+    /// Comma-separated when several EIPs back the rule.
     pub floating_ip_id: Option<String>,
+    pub floating_ip_address: Option<String>,
+    pub description: Option<String>,
     pub status: Option<String>,
+    pub created_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -40,6 +46,28 @@ pub struct SnatRuleListResponse {
     pub snat_rules: Vec<SnatRule>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DnatRule {
+    pub id: Option<String>,
+    pub nat_gateway_id: Option<String>,
+    pub port_id: Option<String>,
+    pub private_ip: Option<String>,
+    pub protocol: Option<String>,
+    pub internal_service_port: Option<u32>,
+    pub external_service_port: Option<u32>,
+    pub floating_ip_id: Option<String>,
+    pub floating_ip_address: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DnatRuleListResponse {
+    #[serde(default)]
+    pub dnat_rules: Vec<DnatRule>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct NatGatewayCreateRequest {
     pub nat_gateway: NatGatewayCreateBody,
@@ -57,23 +85,87 @@ pub struct NatGatewayCreateBody {
     pub enterprise_project_id: Option<String>,
 }
 
+/// Only the fields that are set are changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct NatGatewayUpdateRequest {
+    pub nat_gateway: NatGatewayUpdateBody,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NatGatewayUpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct SnatRuleCreateRequest {
     pub snat_rule: SnatRuleCreateBody,
 }
 
+/// Exactly one of `network_id` (a subnet) or `cidr` is set.
 #[derive(Debug, Clone, Serialize)]
 pub struct SnatRuleCreateBody {
     pub nat_gateway_id: String,
-    pub network_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr: Option<String>,
+    /// 0 for VPC sources, 1 for Direct Connect; required with `cidr`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<u32>,
+    /// One EIP ID, or several joined with commas.
+    pub floating_ip_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SnatRuleUpdateRequest {
+    pub snat_rule: SnatRuleUpdateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SnatRuleUpdateBody {
+    pub nat_gateway_id: String,
+    /// Replaces the rule's EIPs; addresses joined with commas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DnatRuleCreateRequest {
+    pub dnat_rule: DnatRuleCreateBody,
+}
+
+/// Exactly one of `port_id` (an ECS NIC) or `private_ip` is set.
+#[derive(Debug, Clone, Serialize)]
+pub struct DnatRuleCreateBody {
+    pub nat_gateway_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_ip: Option<String>,
+    /// "tcp", "udp" or "any"; "any" forwards every port and needs both ports set to 0.
+    pub protocol: String,
+    pub internal_service_port: u32,
+    pub external_service_port: u32,
     pub floating_ip_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        NatGatewayCreateBody, NatGatewayCreateRequest, NatGatewayListResponse, SnatRuleCreateBody,
-        SnatRuleCreateRequest, SnatRuleListResponse,
+        DnatRuleCreateBody, DnatRuleCreateRequest, DnatRuleListResponse, NatGatewayCreateBody,
+        NatGatewayCreateRequest, NatGatewayListResponse, NatGatewayUpdateBody,
+        NatGatewayUpdateRequest, SnatRuleCreateBody, SnatRuleCreateRequest, SnatRuleListResponse,
     };
 
     #[test]
@@ -129,15 +221,35 @@ mod tests {
         let payload = SnatRuleCreateRequest {
             snat_rule: SnatRuleCreateBody {
                 nat_gateway_id: "nat-1".to_string(),
-                network_id: "subnet-1".to_string(),
-                floating_ip_id: "eip-1".to_string(),
+                network_id: Some("subnet-1".to_string()),
+                cidr: None,
+                source_type: None,
+                floating_ip_id: "eip-1,eip-2".to_string(),
+                description: None,
             },
         };
 
         let value = serde_json::to_value(payload).expect("serialize snat create payload");
         assert_eq!(value["snat_rule"]["nat_gateway_id"], "nat-1");
         assert_eq!(value["snat_rule"]["network_id"], "subnet-1");
-        assert_eq!(value["snat_rule"]["floating_ip_id"], "eip-1");
+        assert_eq!(value["snat_rule"]["floating_ip_id"], "eip-1,eip-2");
+        assert!(value["snat_rule"].get("cidr").is_none());
+        assert!(value["snat_rule"].get("source_type").is_none());
+    }
+
+    #[test]
+    fn nat_gateway_update_request_only_sends_changed_fields() {
+        let payload = NatGatewayUpdateRequest {
+            nat_gateway: NatGatewayUpdateBody {
+                spec: Some("3".to_string()),
+                ..Default::default()
+            },
+        };
+
+        let value = serde_json::to_value(payload).expect("serialize nat gateway update payload");
+        assert_eq!(value["nat_gateway"]["spec"], "3");
+        assert!(value["nat_gateway"].get("name").is_none());
+        assert!(value["nat_gateway"].get("description").is_none());
     }
 
     #[test]
@@ -167,4 +279,56 @@ mod tests {
             Some("eip-1")
         );
     }
+
+    #[test]
+    fn dnat_rule_create_request_skips_unset_target() {
+        let payload = DnatRuleCreateRequest {
+            dnat_rule: DnatRuleCreateBody {
+                nat_gateway_id: "nat-1".to_string(),
+                port_id: None,
+                private_ip: Some("192.168.0.10".to_string()),
+                protocol: "tcp".to_string(),
+                internal_service_port: 22,
+                external_service_port: 2222,
+                floating_ip_id: "eip-1".to_string(),
+                description: None,
+            },
+        };
+
+        let value = serde_json::to_value(payload).expect("serialize dnat create payload");
+        assert_eq!(value["dnat_rule"]["private_ip"], "192.168.0.10");
+        assert_eq!(value["dnat_rule"]["external_service_port"], 2222);
+        assert!(value["dnat_rule"].get("port_id").is_none());
+        assert!(value["dnat_rule"].get("description").is_none());
+    }
+
+    #[test]
+    fn dnat_rule_list_response_deserializes_items() {
+        let raw = r#"{
+          "dnat_rules":[
+            {
+              "id":"dnat-1",
+              "nat_gateway_id":"nat-1",
+              "port_id":"port-1",
+              "private_ip":"",
+              "protocol":"tcp",
+              "internal_service_port":22,
+              "external_service_port":2222,
+              "floating_ip_id":"eip-1",
+              "floating_ip_address":"1.2.3.4",
+              "status":"ACTIVE"
+            }
+          ]
+        }"#;
+
+        let response: DnatRuleListResponse =
+            serde_json::from_str(raw).expect("deserialize dnat rule list response");
+        assert_eq!(response.dnat_rules.len(), 1);
+        assert_eq!(response.dnat_rules[0].port_id.as_deref(), Some("port-1"));
+        assert_eq!(response.dnat_rules[0].external_service_port, Some(2222));
+        assert_eq!(
+            response.dnat_rules[0].floating_ip_id.as_deref(),
+            Some("eip-1")
+        );
+    }
 }
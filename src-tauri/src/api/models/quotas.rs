@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// ECS tenant limits; a negative `max*` value means unlimited.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EcsLimitsResponse {
+    #[serde(default)]
+    pub absolute: EcsAbsoluteLimits,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EcsAbsoluteLimits {
+    pub max_total_instances: Option<i64>,
+    pub total_instances_used: Option<i64>,
+    pub max_total_cores: Option<i64>,
+    pub total_cores_used: Option<i64>,
+    /// MiB.
+    #[serde(rename = "maxTotalRAMSize")]
+    pub max_total_ram_size: Option<i64>,
+    /// MiB.
+    #[serde(rename = "totalRAMUsed")]
+    pub total_ram_used: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EvsQuotaSetResponse {
+    #[serde(default)]
+    pub quota_set: EvsQuotaSet,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EvsQuotaSet {
+    pub volumes: Option<EvsQuotaUsage>,
+    pub gigabytes: Option<EvsQuotaUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EvsQuotaUsage {
+    #[serde(default)]
+    pub in_use: i64,
+    #[serde(default)]
+    pub limit: i64,
+    #[serde(default)]
+    pub reserved: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VpcQuotaResponse {
+    #[serde(default)]
+    pub quotas: VpcQuotaResources,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VpcQuotaResources {
+    #[serde(default)]
+    pub resources: Vec<VpcQuotaResource>,
+}
+
+/// `type` is e.g. "vpc", "subnet", "securityGroup" or "publicIp".
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VpcQuotaResource {
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    #[serde(default)]
+    pub used: i64,
+    #[serde(default)]
+    pub quota: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CceQuotaResponse {
+    #[serde(default)]
+    pub quotas: Vec<CceQuotaResource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CceQuotaResource {
+    pub quota_key: String,
+    #[serde(default)]
+    pub quota_limit: i64,
+    #[serde(default)]
+    pub used: i64,
+    pub unit: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CceQuotaResponse, EcsLimitsResponse, EvsQuotaSetResponse, VpcQuotaResponse};
+
+    #[test]
+    fn quota_responses_parse_service_shapes() {
+        let ecs: EcsLimitsResponse = serde_json::from_str(
+            r#"{"absolute":{"maxTotalInstances":20,"totalInstancesUsed":3,"maxTotalCores":80,
+                "totalCoresUsed":12,"maxTotalRAMSize":163840,"totalRAMUsed":24576}}"#,
+        )
+        .expect("parse ecs limits");
+        assert_eq!(ecs.absolute.max_total_ram_size, Some(163840));
+        assert_eq!(ecs.absolute.total_cores_used, Some(12));
+
+        let evs: EvsQuotaSetResponse = serde_json::from_str(
+            r#"{"quota_set":{"id":"p","volumes":{"in_use":4,"limit":-1,"reserved":0},
+                "gigabytes":{"in_use":200,"limit":10000,"reserved":0}}}"#,
+        )
+        .expect("parse evs quota");
+        assert_eq!(evs.quota_set.volumes.expect("volumes").limit, -1);
+
+        let vpc: VpcQuotaResponse = serde_json::from_str(
+            r#"{"quotas":{"resources":[{"type":"publicIp","used":2,"quota":10,"min":0}]}}"#,
+        )
+        .expect("parse vpc quota");
+        assert_eq!(vpc.quotas.resources[0].resource_type, "publicIp");
+
+        let cce: CceQuotaResponse = serde_json::from_str(
+            r#"{"quotas":[{"quotaKey":"cluster","quotaLimit":50,"used":1,"unit":"count"}]}"#,
+        )
+        .expect("parse cce quota");
+        assert_eq!(cce.quotas[0].quota_key, "cluster");
+    }
+}
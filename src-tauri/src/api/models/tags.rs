@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// Key/value tag shared by the ECS, EVS, EIP, NAT, CCE and OBS tag APIs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ResourceTag {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+/// Body of the `tags/action` endpoints (ECS, EVS, EIP, NAT).
+#[derive(Debug, Clone, Serialize)]
+pub struct TagActionRequest {
+    /// "create" or "delete".
+    pub action: String,
+    pub tags: Vec<TagActionEntry>,
+}
+
+/// Deletes only need the key; the value is omitted.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagActionEntry {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Body of the CCE batch `tags/create` and `tags/delete` endpoints.
+#[derive(Debug, Clone, Serialize)]
+pub struct CceClusterTagsRequest {
+    pub tags: Vec<TagActionEntry>,
+}
+
+/// Body of the `resource_instances/action` endpoints used to filter by tag.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceInstancesFilterRequest {
+    pub action: String,
+    pub tags: Vec<TagFilter>,
+}
+
+/// Matches resources carrying `key`; an empty `values` matches any value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TagFilter {
+    pub key: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TaggedResource {
+    pub resource_id: Option<String>,
+    pub resource_name: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<ResourceTag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ResourceInstancesResponse {
+    #[serde(default)]
+    pub resources: Vec<TaggedResource>,
+    pub total_count: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ResourceInstancesFilterRequest, ResourceInstancesResponse, TagActionEntry,
+        TagActionRequest, TagFilter,
+    };
+
+    #[test]
+    fn tag_action_request_omits_values_on_delete() {
+        let payload = TagActionRequest {
+            action: "delete".to_string(),
+            tags: vec![TagActionEntry {
+                key: "env".to_string(),
+                value: None,
+            }],
+        };
+        let value = serde_json::to_value(payload).expect("serialize tag action");
+        assert_eq!(value["action"], "delete");
+        assert_eq!(value["tags"][0]["key"], "env");
+        assert!(value["tags"][0].get("value").is_none());
+    }
+
+    #[test]
+    fn resource_instances_filter_serializes_and_parses() {
+        let payload = ResourceInstancesFilterRequest {
+            action: "filter".to_string(),
+            tags: vec![TagFilter {
+                key: "team".to_string(),
+                values: vec!["core".to_string()],
+            }],
+        };
+        let value = serde_json::to_value(payload).expect("serialize filter");
+        assert_eq!(value["tags"][0]["values"][0], "core");
+
+        let parsed: ResourceInstancesResponse = serde_json::from_str(
+            r#"{"resources":[{"resource_id":"vol-1","resource_name":"data",
+                "tags":[{"key":"team","value":"core"}]}],"total_count":1}"#,
+        )
+        .expect("parse resources");
+        assert_eq!(parsed.resources[0].resource_id.as_deref(), Some("vol-1"));
+        assert_eq!(parsed.resources[0].tags[0].value, "core");
+    }
+}
@@ -1,9 +1,21 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Vpc {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub cidr: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub enterprise_project_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -11,13 +23,53 @@ pub struct VpcListResponse {
     pub vpcs: Vec<Vpc>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize)]
+pub struct VpcSingleResponse {
+    pub vpc: Vpc,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VpcCreateRequest {
+    pub vpc: VpcCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VpcCreateBody {
+    pub name: String,
+    pub cidr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Subnet {
     pub id: String,
     pub name: String,
     pub cidr: String,
     #[serde(default)]
     pub availability_zone: Option<String>,
+    #[serde(default)]
+    pub vpc_id: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub gateway_ip: Option<String>,
+    #[serde(default)]
+    pub dhcp_enable: Option<bool>,
+    #[serde(default)]
+    pub primary_dns: Option<String>,
+    #[serde(default)]
+    pub secondary_dns: Option<String>,
+    #[serde(default, rename = "dnsList")]
+    pub dns_list: Vec<String>,
+    #[serde(default)]
+    pub neutron_network_id: Option<String>,
+    #[serde(default)]
+    pub neutron_subnet_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,11 +77,113 @@ pub struct SubnetListResponse {
     pub subnets: Vec<Subnet>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SubnetCreateRequest {
+    pub subnet: SubnetCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubnetCreateBody {
+    pub name: String,
+    pub cidr: String,
+    pub gateway_ip: String,
+    pub vpc_id: String,
+    pub dhcp_enable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_dns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_dns: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SecurityGroupRule {
+    pub id: Option<String>,
+    pub security_group_id: Option<String>,
+    pub description: Option<String>,
+    pub direction: Option<String>,
+    pub ethertype: Option<String>,
+    pub protocol: Option<String>,
+    pub multiport: Option<String>,
+    pub remote_ip_prefix: Option<String>,
+    pub remote_group_id: Option<String>,
+    pub action: Option<String>,
+    pub priority: Option<u32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SecurityGroup {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub project_id: Option<String>,
+    pub enterprise_project_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub security_group_rules: Vec<SecurityGroupRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SecurityGroupListResponse {
+    #[serde(default)]
+    pub security_groups: Vec<SecurityGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SecurityGroupRuleListResponse {
+    #[serde(default)]
+    pub security_group_rules: Vec<SecurityGroupRule>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityGroupCreateRequest {
+    pub security_group: SecurityGroupCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityGroupCreateBody {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityGroupRuleCreateRequest {
+    pub security_group_rule: SecurityGroupRuleCreateBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityGroupRuleCreateBody {
+    pub security_group_id: String,
+    pub direction: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ethertype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiport: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+}
+
 // Display formatting removed: it was only needed for CLI-based selection.
 
 #[cfg(test)]
 mod tests {
-    use super::Subnet;
+    use super::{
+        SecurityGroupListResponse, SecurityGroupRuleCreateBody, SecurityGroupRuleCreateRequest,
+        Subnet, SubnetCreateBody, SubnetCreateRequest, VpcListResponse,
+    };
 
     #[test]
     fn subnet_deserializes_with_availability_zone() {
@@ -46,4 +200,125 @@ mod tests {
         assert_eq!(subnet.id, "subnet-2");
         assert!(subnet.availability_zone.is_none());
     }
+
+    #[test]
+    fn subnet_deserializes_full_response_fields() {
+        let raw = r#"{
+          "id":"subnet-3",
+          "name":"apps",
+          "description":"",
+          "cidr":"192.168.10.0/24",
+          "dnsList":["100.125.1.250","100.125.129.250"],
+          "status":"ACTIVE",
+          "vpc_id":"vpc-1",
+          "gateway_ip":"192.168.10.1",
+          "dhcp_enable":true,
+          "primary_dns":"100.125.1.250",
+          "secondary_dns":"100.125.129.250",
+          "availability_zone":"sa-brazil-1a",
+          "neutron_network_id":"subnet-3",
+          "neutron_subnet_id":"neutron-subnet-3"
+        }"#;
+        let subnet: Subnet = serde_json::from_str(raw).expect("deserialize subnet");
+        assert_eq!(subnet.vpc_id.as_deref(), Some("vpc-1"));
+        assert_eq!(subnet.gateway_ip.as_deref(), Some("192.168.10.1"));
+        assert_eq!(subnet.dns_list.len(), 2);
+        assert_eq!(subnet.dhcp_enable, Some(true));
+        assert_eq!(
+            subnet.neutron_subnet_id.as_deref(),
+            Some("neutron-subnet-3")
+        );
+    }
+
+    #[test]
+    fn vpc_deserializes_status_and_enterprise_project() {
+        let raw = r#"{"vpcs":[{"id":"vpc-1","name":"prod","cidr":"192.168.0.0/16","status":"OK","enterprise_project_id":"0","routes":[]}]}"#;
+        let body: VpcListResponse = serde_json::from_str(raw).expect("deserialize vpc list");
+        assert_eq!(body.vpcs[0].status.as_deref(), Some("OK"));
+        assert_eq!(body.vpcs[0].enterprise_project_id.as_deref(), Some("0"));
+    }
+
+    #[test]
+    fn security_group_list_response_deserializes_rules() {
+        let raw = r#"{
+          "security_groups":[
+            {
+              "id":"sg-1",
+              "name":"default",
+              "security_group_rules":[
+                {
+                  "id":"rule-1",
+                  "direction":"ingress",
+                  "protocol":"tcp",
+                  "multiport":"22",
+                  "remote_ip_prefix":"0.0.0.0/0",
+                  "action":"allow",
+                  "priority":1
+                }
+              ]
+            }
+          ]
+        }"#;
+        let body: SecurityGroupListResponse =
+            serde_json::from_str(raw).expect("deserialize security group list");
+        assert_eq!(body.security_groups.len(), 1);
+        let rule = &body.security_groups[0].security_group_rules[0];
+        assert_eq!(rule.multiport.as_deref(), Some("22"));
+        assert_eq!(rule.priority, Some(1));
+    }
+
+    #[test]
+    fn security_group_rule_create_request_omits_unset_fields() {
+        let payload = SecurityGroupRuleCreateRequest {
+            security_group_rule: SecurityGroupRuleCreateBody {
+                security_group_id: "sg-1".to_string(),
+                direction: "ingress".to_string(),
+                description: None,
+                ethertype: Some("IPv4".to_string()),
+                protocol: Some("tcp".to_string()),
+                multiport: Some("22".to_string()),
+                remote_ip_prefix: Some("203.0.113.7/32".to_string()),
+                action: None,
+                priority: None,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize security group rule");
+        assert_eq!(value["security_group_rule"]["direction"], "ingress");
+        assert_eq!(value["security_group_rule"]["multiport"], "22");
+        assert_eq!(
+            value["security_group_rule"]["remote_ip_prefix"],
+            "203.0.113.7/32"
+        );
+        assert!(value["security_group_rule"].get("action").is_none());
+        assert!(value["security_group_rule"].get("priority").is_none());
+    }
+
+    #[test]
+    fn vpc_list_response_reads_optional_cidr() {
+        let raw = r#"{"vpcs":[{"id":"vpc-1","name":"prod","cidr":"192.168.0.0/16"},{"id":"vpc-2","name":"bare"}]}"#;
+        let body: VpcListResponse = serde_json::from_str(raw).expect("deserialize vpc list");
+        assert_eq!(body.vpcs[0].cidr.as_deref(), Some("192.168.0.0/16"));
+        assert_eq!(body.vpcs[1].cidr, None);
+    }
+
+    #[test]
+    fn subnet_create_request_omits_unset_dns() {
+        let payload = SubnetCreateRequest {
+            subnet: SubnetCreateBody {
+                name: "subnet-a".to_string(),
+                cidr: "192.168.1.0/24".to_string(),
+                gateway_ip: "192.168.1.1".to_string(),
+                vpc_id: "vpc-1".to_string(),
+                dhcp_enable: true,
+                availability_zone: None,
+                primary_dns: None,
+                secondary_dns: None,
+            },
+        };
+        let value = serde_json::to_value(payload).expect("serialize subnet payload");
+        assert_eq!(value["subnet"]["gateway_ip"], "192.168.1.1");
+        assert_eq!(value["subnet"]["dhcp_enable"], true);
+        assert!(value["subnet"].get("primary_dns").is_none());
+        assert!(value["subnet"].get("availability_zone").is_none());
+    }
 }
@@ -0,0 +1,28 @@
+use directories::ProjectDirs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Which per-user app directory a file lives in.
+#[derive(Debug, Clone, Copy)]
+pub enum AppDir {
+    Config,
+    Data,
+}
+
+/// The HC Forge project directories, resolved once per process.
+fn project_dirs() -> Option<&'static ProjectDirs> {
+    static DIRS: OnceLock<Option<ProjectDirs>> = OnceLock::new();
+    DIRS.get_or_init(|| ProjectDirs::from("com", "tideman", "hc-forge"))
+        .as_ref()
+}
+
+/// `relative` under the app config or data directory, or `None` when the
+/// platform has no home directory to resolve them from.
+pub fn app_path(dir: AppDir, relative: impl AsRef<Path>) -> Option<PathBuf> {
+    let dirs = project_dirs()?;
+    let base = match dir {
+        AppDir::Config => dirs.config_dir(),
+        AppDir::Data => dirs.data_dir(),
+    };
+    Some(base.join(relative))
+}
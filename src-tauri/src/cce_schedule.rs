@@ -0,0 +1,166 @@
+use crate::api::auth::credentials::Credentials;
+use crate::app_dirs::AppDir;
+use crate::json_store::JsonFileStore;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+static CCE_HIBERNATE_SCHEDULES: JsonFileStore<CceHibernateSchedule> = JsonFileStore::new(
+    AppDir::Config,
+    "cce-hibernate-schedules.json",
+    "CCE hibernate schedules",
+);
+
+const HIBERNATE_TIME_FORMAT: &str = "%H:%M";
+
+/// Daily hibernation of one cluster at a local wall-clock time. Waking is on demand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CceHibernateSchedule {
+    pub region: String,
+    pub cluster_id: String,
+    pub cluster_name: Option<String>,
+    /// Local time as `HH:MM`.
+    pub hibernate_at: String,
+    pub enabled: bool,
+    /// Local date (`YYYY-MM-DD`) of the last slot that ran or was skipped, so each day fires once.
+    pub last_run_on: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl CceHibernateSchedule {
+    /// A new schedule whose slot for `now` is already consumed when it has passed,
+    /// so saving at 23:00 a 22:00 schedule does not hibernate the cluster right away.
+    pub fn new(
+        region: &str,
+        cluster_id: &str,
+        cluster_name: Option<String>,
+        hibernate_at: NaiveTime,
+        now: NaiveDateTime,
+    ) -> Self {
+        let stamp = Utc::now().to_rfc3339();
+        Self {
+            region: region.to_string(),
+            cluster_id: cluster_id.to_string(),
+            cluster_name,
+            hibernate_at: hibernate_at.format(HIBERNATE_TIME_FORMAT).to_string(),
+            enabled: true,
+            last_run_on: (now.time() >= hibernate_at).then(|| now.date().to_string()),
+            last_error: None,
+            created_at: stamp.clone(),
+            updated_at: stamp,
+        }
+    }
+
+    pub fn matches(&self, region: &str, cluster_id: &str) -> bool {
+        self.region == region && self.cluster_id == cluster_id
+    }
+
+    pub fn is_due(&self, now: NaiveDateTime) -> bool {
+        let Ok(at) = parse_hibernate_time(&self.hibernate_at) else {
+            return false;
+        };
+        self.enabled
+            && now.time() >= at
+            && self.last_run_on.as_deref() != Some(now.date().to_string().as_str())
+    }
+
+    pub fn mark_run(&mut self, today: NaiveDate, error: Option<String>) {
+        self.last_run_on = Some(today.to_string());
+        self.last_error = error;
+        self.updated_at = Utc::now().to_rfc3339();
+    }
+}
+
+pub fn parse_hibernate_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value.trim(), HIBERNATE_TIME_FORMAT)
+        .map_err(|_| format!("Invalid hibernate time '{}'. Use HH:MM.", value.trim()))
+}
+
+/// Session credentials for the scheduler. They are kept in memory only, so
+/// schedules fire while HC Forge is open and the user has signed in once.
+#[derive(Default)]
+pub struct CceScheduleState {
+    credentials: Mutex<Option<Credentials>>,
+    started: AtomicBool,
+}
+
+impl CceScheduleState {
+    pub fn set_credentials(&self, credentials: Credentials) {
+        if let Ok(mut slot) = self.credentials.lock() {
+            *slot = Some(credentials);
+        }
+    }
+
+    pub fn credentials(&self) -> Option<Credentials> {
+        self.credentials.lock().ok().and_then(|slot| slot.clone())
+    }
+
+    /// True only for the first caller, which owns the background loop.
+    pub fn claim_loop(&self) -> bool {
+        !self.started.swap(true, Ordering::SeqCst)
+    }
+}
+
+pub fn load_cce_hibernate_schedules() -> Result<Vec<CceHibernateSchedule>, String> {
+    CCE_HIBERNATE_SCHEDULES.load()
+}
+
+/// Change the stored schedules under the store lock and return them afterwards.
+pub fn update_cce_hibernate_schedules(
+    change: impl FnOnce(&mut Vec<CceHibernateSchedule>),
+) -> Result<Vec<CceHibernateSchedule>, String> {
+    CCE_HIBERNATE_SCHEDULES.update(|schedules| {
+        change(schedules);
+        schedules.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hibernate_time, CceHibernateSchedule};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .expect("valid timestamp")
+    }
+
+    #[test]
+    fn parse_hibernate_time_accepts_hh_mm_only() {
+        assert_eq!(
+            parse_hibernate_time(" 22:30 ").map(|time| time.to_string()),
+            Ok("22:30:00".to_string())
+        );
+        assert!(parse_hibernate_time("25:00").is_err());
+        assert!(parse_hibernate_time("10pm").is_err());
+    }
+
+    #[test]
+    fn hibernate_schedule_fires_once_per_day_after_its_time() {
+        let time = parse_hibernate_time("22:00").expect("time");
+        let mut schedule = CceHibernateSchedule::new("r", "c", None, time, at(1, 9, 0));
+        assert_eq!(schedule.hibernate_at, "22:00");
+        assert!(!schedule.is_due(at(1, 21, 59)));
+        assert!(schedule.is_due(at(1, 22, 0)));
+
+        schedule.mark_run(at(1, 22, 0).date(), None);
+        assert!(!schedule.is_due(at(1, 23, 30)));
+        assert!(!schedule.is_due(at(2, 8, 0)));
+        assert!(schedule.is_due(at(2, 22, 5)));
+
+        schedule.enabled = false;
+        assert!(!schedule.is_due(at(2, 22, 5)));
+    }
+
+    #[test]
+    fn hibernate_schedule_saved_after_its_time_starts_tomorrow() {
+        let time = parse_hibernate_time("22:00").expect("time");
+        let schedule = CceHibernateSchedule::new("r", "c", None, time, at(1, 23, 0));
+        assert!(!schedule.is_due(at(1, 23, 1)));
+        assert!(schedule.is_due(at(2, 22, 0)));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::app_dirs::{app_path, AppDir};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// A JSON array of records in one app file. The path is resolved on first use and
+/// every read-modify-write runs under one lock, so concurrent commands and the
+/// background scheduler cannot overwrite each other's changes.
+pub struct JsonFileStore<T> {
+    dir: AppDir,
+    file_name: &'static str,
+    /// Plural noun for error messages, e.g. "NAT bootstrap runs".
+    label: &'static str,
+    path: OnceLock<Option<PathBuf>>,
+    lock: Mutex<()>,
+    records: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned> JsonFileStore<T> {
+    pub const fn new(dir: AppDir, file_name: &'static str, label: &'static str) -> Self {
+        Self {
+            dir,
+            file_name,
+            label,
+            path: OnceLock::new(),
+            lock: Mutex::new(()),
+            records: PhantomData,
+        }
+    }
+
+    fn path(&self) -> Option<&Path> {
+        self.path
+            .get_or_init(|| app_path(self.dir, self.file_name))
+            .as_deref()
+    }
+
+    /// Stored records, or none when nothing has been saved yet.
+    pub fn load(&self) -> Result<Vec<T>, String> {
+        let _guard = self.lock()?;
+        self.read()
+    }
+
+    /// Load, change and save the records without another caller in between.
+    pub fn update<R>(&self, change: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, String> {
+        let _guard = self.lock()?;
+        let mut records = self.read()?;
+        let result = change(&mut records);
+        self.write(&records)?;
+        Ok(result)
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, ()>, String> {
+        self.lock
+            .lock()
+            .map_err(|_| format!("The {} store lock was poisoned.", self.label))
+    }
+
+    fn read(&self) -> Result<Vec<T>, String> {
+        let Some(path) = self.path().filter(|path| path.is_file()) else {
+            return Ok(Vec::new());
+        };
+        let raw = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        serde_json::from_str(&raw)
+            .map_err(|err| format!("Invalid {} at {}: {}", self.label, path.display(), err))
+    }
+
+    fn write(&self, records: &[T]) -> Result<(), String> {
+        let path = self
+            .path()
+            .ok_or_else(|| "Could not resolve the app directory.".to_string())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+        }
+        let raw = serde_json::to_string_pretty(records).map_err(|err| err.to_string())?;
+        std::fs::write(path, raw)
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonFileStore;
+    use crate::app_dirs::AppDir;
+
+    #[test]
+    fn update_round_trips_through_the_file() {
+        let store: JsonFileStore<String> = JsonFileStore::new(AppDir::Data, "unused.json", "test");
+        let path =
+            std::env::temp_dir().join(format!("hc-forge-json-store-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        store.path.set(Some(path.clone())).expect("path");
+
+        assert_eq!(store.load(), Ok(Vec::new()));
+        let count = store
+            .update(|records| {
+                records.push("a".to_string());
+                records.push("b".to_string());
+                records.len()
+            })
+            .expect("update");
+        assert_eq!(count, 2);
+        store
+            .update(|records| records.retain(|record| record != "a"))
+            .expect("update");
+        assert_eq!(store.load(), Ok(vec!["b".to_string()]));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,42 +1,111 @@
 mod api;
+mod app_dirs;
+mod cce_schedule;
+mod json_store;
+mod nat_bootstrap;
+mod orphans;
+mod pricing;
+mod quotas;
+mod tags;
+mod topology;
 mod validators;
 
-use crate::api::models::cce::{CceClusterListResponse, CceNodePoolListResponse};
-use crate::api::models::eip::EipListResponse;
-use crate::api::models::evs::EvsListResponse;
+use crate::api::models::cce::{
+    CceAddonInstance, CceAddonRequest, CceAddonRequestMetadata, CceAddonRequestSpec,
+    CceAddonTemplate, CceAddonTemplateVersion, CceAddonValues, CceCluster, CceClusterListResponse,
+    CceClusterTask, CceJob, CceNode, CceNodeListResponse, CceNodePool, CceNodePoolListResponse,
+    CcePrecheckRequest, CcePrecheckSpec, CceTaint, CceUpgradeAction, CceUpgradeInfo,
+    CceUpgradeRequest, CceUpgradeRequestMetadata, CceUpgradeSpec, CceUpgradeStrategy,
+};
+use crate::api::models::eip::{
+    BandwidthUpdateBody, CreatePublicIpBandwidth, CreatePublicIpBody, CreatePublicIpRequest,
+    EipBandwidth, EipListResponse,
+};
+use crate::api::models::eps::EnterpriseProjectListResponse;
+use crate::api::models::evs::{
+    EvsAttachment, EvsCreateBody, EvsCreateResponse, EvsListResponse, EvsSnapshot,
+    EvsSnapshotCreateBody, EvsSnapshotListResponse, EvsVolume,
+};
 use crate::api::models::ims::Image;
-use crate::api::models::nat::NatGatewayListResponse;
+use crate::api::models::nat::{
+    DnatRuleCreateBody, DnatRuleListResponse, NatGatewayCreateBody, NatGatewayListResponse,
+    NatGatewayUpdateBody, SnatRuleCreateBody, SnatRuleListResponse, SnatRuleUpdateBody,
+};
 use crate::api::models::obs::{ObsListBucketsResponse, ObsListObjectsResponse};
+use crate::api::models::tags::{ResourceTag, TagActionEntry, TagFilter};
+use crate::cce_schedule::{
+    load_cce_hibernate_schedules, parse_hibernate_time, update_cce_hibernate_schedules,
+    CceHibernateSchedule, CceScheduleState,
+};
+use crate::nat_bootstrap::{
+    find_adoptable_eip, find_adoptable_nat_gateway, find_adoptable_snat_rule,
+    find_nat_bootstrap_run, find_nat_bootstrap_run_for_gateway, load_nat_bootstrap_runs,
+    remove_nat_bootstrap_run, store_nat_bootstrap_run, NatBootstrapRun, NatBootstrapStatus,
+    NatBootstrapStep,
+};
+use crate::orphans::{
+    build_orphan_report, is_detached_volume, is_unbound_eip, nat_rule_count, OrphanCleanupOutcome,
+    OrphanInputs, OrphanKind, OrphanReport,
+};
+use crate::pricing::{PriceEstimate, PriceTableInfo};
+use crate::quotas::{
+    cce_node_quota_demands, cce_quota_usages, ecs_quota_demands, ecs_quota_usages,
+    evs_quota_usages, quota_check_error, quota_services, quota_shortfalls, vpc_quota_usages,
+    QuotaReport, QuotaResource, QuotaService, QuotaUsage,
+};
+use crate::tags::{
+    load_tag_policy, matches_tag_filters, merge_tags, normalize_tag_filters, normalize_tag_keys,
+    normalize_tags, save_tag_policy, TagInput, TagPolicy, TagPolicyCheck, TaggableResource,
+};
+use crate::topology::{build_network_topology, NetworkTopology, TopologyInputs};
 use crate::validators::{
-    control_char_from_input, normalize_obs_bucket_name, normalize_obs_object_key,
-    normalize_ssh_session_id,
+    control_char_from_input, ims_import_file_format, ipv4_cidr_contains, ipv4_cidr_overlaps,
+    is_evs_system_device, normalize_enterprise_project_id, normalize_evs_device_name,
+    normalize_evs_volume_type, normalize_ims_export_format, normalize_ims_image_name,
+    normalize_ipv4_network_cidr, normalize_obs_bucket_name, normalize_obs_object_key,
+    normalize_project_id, normalize_remote_ip_prefix, normalize_security_group_action,
+    normalize_security_group_direction, normalize_security_group_ports,
+    normalize_security_group_protocol, normalize_ssh_session_id, parse_ipv4_cidr,
+    ALL_ENTERPRISE_PROJECTS,
 };
 use api::models::cce::{
-    CceAuthentication, CceClusterCreateMetadata, CceClusterCreateSpec, CceClusterTag,
-    CceContainerNetwork, CceCreateClusterRequest, CceCreateNodePoolRequest, CceHostNetwork,
-    CceNodePoolCreateMetadata, CceNodePoolCreateSpec, CceNodePoolExtendParam, CceNodePoolLogin,
-    CceNodePoolNicSpec, CceNodePoolPrimaryNic, CceNodePoolTemplateSpec, CceNodePoolVolume,
-    CceNodePoolVolumeExtendParam,
+    CceAuthentication, CceClusterCreateMetadata, CceClusterCreateSpec, CceClusterExtendParam,
+    CceContainerNetwork, CceCreateClusterRequest, CceCreateNodePoolRequest, CceEniNetwork,
+    CceEniSubnet, CceHostNetwork, CceMasterSpec, CceNodePoolCreateMetadata, CceNodePoolCreateSpec,
+    CceNodePoolExtendParam, CceNodePoolLogin, CceNodePoolNicSpec, CceNodePoolPrimaryNic,
+    CceNodePoolTemplateSpec, CceNodePoolVolume, CceNodePoolVolumeExtendParam, CceNodeRef,
+    CceRemoveNodesRequest, CceRemoveNodesSpec, CceResetNodeItem, CceResetNodeSpec,
+    CceResetNodesRequest,
 };
 use api::models::ecs::{
     Bandwidth, CreateEcsRequest, DataVolume, EcsListResponse, Eip, Flavor, Nic, PublicIp,
-    RootVolume, Server,
+    RootVolume, SecurityGroupRef, Server, ServerExtendParam,
+};
+use api::models::ims::{
+    ImageCreateFromServerRequest, ImageExportRequest, ImageImportRequest, ImageMembersRequest,
+    WholeImageCreateRequest,
+};
+use api::models::vpc::{
+    SecurityGroup, SecurityGroupRule, SecurityGroupRuleCreateBody, SecurityGroupRuleListResponse,
+    Subnet, SubnetCreateBody, Vpc, VpcCreateBody,
+};
+use api::{
+    lookup_public_ipv4, Credentials, CredentialsSource, HwcClient, ImageListFilters, ListParams,
+    TagService,
 };
-use api::models::vpc::{Subnet, Vpc};
-use api::{Credentials, CredentialsSource, HwcClient, ImageListFilters, ListParams};
 use base64::Engine;
-use chrono::Utc;
+use chrono::{Local, NaiveDate, Utc};
 use futures::stream::{self, StreamExt};
 use log::{error, info, warn};
 use rand::{distr::Alphanumeric, Rng};
 use russh::{client, ChannelMsg, Disconnect};
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use std::collections::{HashMap, HashSet};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 use tokio::task::JoinHandle;
 
 const RANDOM_NAME_PLACEHOLDER: &str = "ecs-<RANDOM-VALUE>";
@@ -46,9 +115,29 @@ const DEFAULT_BANDWIDTH_CHARGE_MODE: &str = "traffic";
 const DEFAULT_BANDWIDTH_SIZE: u32 = 100;
 const MIN_BANDWIDTH_SIZE: u32 = 1;
 const MAX_BANDWIDTH_SIZE: u32 = 300;
+const MAX_BANDWIDTH_SIZE_BANDWIDTH_MODE: u32 = 2000;
+const MIN_SHARED_BANDWIDTH_SIZE: u32 = 5;
+const EVS_VOLUME_SIZE_MIN: u32 = 10;
+const EVS_VOLUME_SIZE_MAX: u32 = 32_768;
+const EVS_STATUS_POLL_ATTEMPTS: u32 = 40;
+const EVS_STATUS_POLL_INTERVAL_SECS: u64 = 3;
+// Pre-checks settle within minutes; control plane plus node upgrades can take over an hour.
+const CCE_UPGRADE_PRECHECK_POLL_ATTEMPTS: u32 = 60;
+const CCE_UPGRADE_JOB_POLL_ATTEMPTS: u32 = 540;
+const CCE_JOB_POLL_INTERVAL_SECS: u64 = 10;
+// Hibernating or waking a cluster settles within a few minutes.
+const CCE_CLUSTER_PHASE_POLL_ATTEMPTS: u32 = 90;
+const CCE_HIBERNATE_SCHEDULER_TICK_SECS: u64 = 60;
+const EVS_LIST_LIMIT: u32 = 1000;
+const EVS_SNAPSHOT_CONCURRENCY: usize = 4;
+const EVS_SNAPSHOT_NAME_MAX: usize = 64;
 const OBS_BUCKET_NAME_MIN: usize = 3;
 const OBS_BUCKET_NAME_MAX: usize = 63;
-const OBS_PUT_OBJECT_MAX_BYTES: usize = 5 * 1024 * 1024 * 1024;
+const OBS_MULTIPART_PART_SIZE: u64 = 16 * 1024 * 1024;
+const OBS_MULTIPART_MAX_PARTS: u64 = 10_000;
+const OBS_DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+const IMS_IMPORT_MIN_DISK_MIN: u32 = 1;
+const IMS_IMPORT_MIN_DISK_MAX: u32 = 1024;
 const OBS_LIST_MAX_KEYS: u32 = 1000;
 const OBS_BUCKET_TOTALS_MAX_PAGES: usize = 10_000;
 const CCE_NODE_POOL_INITIAL_COUNT_DEFAULT: u32 = 0;
@@ -61,9 +150,30 @@ const CCE_NODE_POOL_DATA_VOLUME_SIZE_MIN: u32 = 100;
 const CCE_NODE_POOL_DATA_VOLUME_SIZE_MAX: u32 = 32_768;
 const CCE_NODE_POOL_MAX_PODS_MIN: u32 = 16;
 const CCE_NODE_POOL_MAX_PODS_MAX: u32 = 256;
+const CCE_TAINT_EFFECTS: [&str; 3] = ["NoSchedule", "PreferNoSchedule", "NoExecute"];
+const CCE_CONTAINER_NETWORK_MODES: [&str; 4] =
+    ["overlay_l2", "underlay_ipvlan", "vpc-router", "eni"];
+const CCE_KUBE_PROXY_MODES: [&str; 2] = ["iptables", "ipvs"];
+// Yearly/monthly orders accept 1-9 months or 1-3 years.
+const CCE_PREPAID_MAX_MONTHS: u32 = 9;
+const CCE_PREPAID_MAX_YEARS: u32 = 3;
 const NAT_DELETE_CONCURRENCY: usize = 4;
+const NAT_SNAT_EIP_MAX: usize = 20;
 const NAT_EIP_DELETE_MAX_ATTEMPTS: u8 = 6;
 const NAT_EIP_DELETE_RETRY_DELAY_MS: u64 = 900;
+const SECURITY_GROUP_RULE_MAX_PORTS: usize = 20;
+const SECURITY_GROUP_DEFAULT_ACCESS_PORT: u16 = 22;
+const CCE_DEFAULT_CONTAINER_CIDR: &str = "172.16.0.0/16";
+const CCE_DEFAULT_SERVICE_CIDR: &str = "10.247.0.0/16";
+const VPC_PRIVATE_CIDRS: [&str; 3] = ["10.0.0.0/8", "172.16.0.0/12", "192.168.0.0/16"];
+const VPC_CIDR_MAX_PREFIX: u8 = 24;
+const SUBNET_CIDR_MAX_PREFIX: u8 = 29;
+const TOPOLOGY_LIST_LIMIT: u32 = 1000;
+const TOPOLOGY_SUBNET_CONCURRENCY: usize = 4;
+const ORPHAN_LIST_LIMIT: u32 = 1000;
+const ORPHAN_LOOKUP_CONCURRENCY: usize = 4;
+const OBS_TAG_LOOKUP_CONCURRENCY: usize = 4;
+const QUOTA_LOOKUP_CONCURRENCY: usize = 4;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -81,1986 +191,11227 @@ struct EcsCreateParams {
     eip_bandwidth_size: Option<u32>,
     admin_password: Option<String>,
     data_volumes: Option<Vec<EcsDataVolumeInput>>,
+    security_group_ids: Option<Vec<String>>,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct EcsDataVolumeInput {
+struct EvsCreateParams {
+    region: String,
+    name: Option<String>,
+    /// SATA, SAS, SSD, GPSSD, ESSD, GPSSD2 or ESSD2.
     volume_type: String,
     size: u32,
-    count: Option<u32>,
+    availability_zone: String,
     multiattach: Option<bool>,
-    hw_passthrough: Option<bool>,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct EcsDeleteParams {
+struct EvsAttachParams {
     region: String,
+    volume_id: String,
     server_id: String,
-    eip_id: Option<String>,
-    delete_volume: Option<bool>,
+    /// e.g. "/dev/vdb"; the cloud picks the next free slot when omitted.
+    device: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct EcsStopParams {
+struct EvsDetachParams {
     region: String,
-    server_id: String,
-    stop_type: Option<String>,
+    volume_id: String,
+    /// Required only when a shared disk is attached to several servers.
+    server_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct EipDeleteParams {
+struct EvsExpandParams {
     region: String,
-    eip_id: String,
+    volume_id: String,
+    new_size: u32,
 }
 
-/// AK/SK credentials input from the UI.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CredentialsInput {
-    access_key: String,
-    secret_key: String,
+struct EvsDeleteParams {
+    region: String,
+    volume_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ImageFilters {
-    visibility: Option<String>,
-    image_type: Option<String>,
-    flavor_id: Option<String>,
+struct OrphanCleanupItem {
+    kind: OrphanKind,
+    id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ListParamsInput {
-    marker: Option<String>,
-    limit: Option<u32>,
-    offset: Option<u32>,
+struct OrphanCleanupParams {
+    region: String,
+    items: Vec<OrphanCleanupItem>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsCreateBucketParams {
+struct ResourceTagParams {
     region: String,
-    bucket_name: String,
-    default_storage_class: Option<String>,
-    acl: Option<String>,
+    resource_type: TaggableResource,
+    resource_id: String,
+    tags: Vec<TagInput>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsDeleteBucketParams {
+struct ResourceUntagParams {
     region: String,
-    bucket_name: String,
+    resource_type: TaggableResource,
+    resource_id: String,
+    keys: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsListObjectsParams {
-    region: String,
-    bucket_name: String,
-    prefix: Option<String>,
-    marker: Option<String>,
-    max_keys: Option<u32>,
+struct PriceTableImportParams {
+    /// Local path of the TOML price table to import.
+    path: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsBucketTotalsParams {
+struct EvsSnapshotCreateParams {
     region: String,
-    bucket_name: String,
+    volume_id: String,
+    name: Option<String>,
+    description: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsPutObjectParams {
+struct EvsSnapshotDeleteParams {
     region: String,
-    bucket_name: String,
-    object_key: String,
-    content_base64: String,
-    content_type: Option<String>,
+    snapshot_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsDeleteObjectParams {
+struct EvsSnapshotRollbackParams {
     region: String,
-    bucket_name: String,
-    object_key: String,
+    snapshot_id: String,
+    /// Narrows the snapshot lookup; the snapshot's own disk is always the target.
+    volume_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ObsGetObjectParams {
+struct EcsSnapshotVolumesParams {
     region: String,
-    bucket_name: String,
-    object_key: String,
+    server_id: String,
+    /// Snapshot name prefix; defaults to "hc-forge-pre-task".
+    name_prefix: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceCreateClusterParams {
-    region: String,
-    name: String,
-    flavor: String,
-    version: String,
-    vpc_id: String,
-    subnet_id: String,
-    description: Option<String>,
-    cluster_type: Option<String>,
-    container_network_mode: Option<String>,
-    container_network_cidr: Option<String>,
-    kubernetes_svc_ip_range: Option<String>,
-    authentication_mode: Option<String>,
-    cluster_tag_env: Option<String>,
+struct EcsDataVolumeInput {
+    volume_type: String,
+    size: u32,
+    count: Option<u32>,
+    multiattach: Option<bool>,
+    hw_passthrough: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceDeleteClusterParams {
+struct EcsDeleteParams {
     region: String,
-    cluster_id: String,
+    server_id: String,
+    eip_id: Option<String>,
+    delete_volume: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceListNodePoolsParams {
+struct EcsStopParams {
     region: String,
-    cluster_id: String,
+    server_id: String,
+    stop_type: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceCreateNodePoolParams {
+struct EcsResizeParams {
     region: String,
-    cluster_id: String,
-    name: String,
-    flavor: String,
-    availability_zone: String,
-    subnet_id: Option<String>,
-    os: Option<String>,
-    ssh_key: Option<String>,
-    initial_node_count: Option<u32>,
-    root_volume_type: Option<String>,
-    root_volume_size: Option<u32>,
-    data_volume_type: Option<String>,
-    data_volume_size: Option<u32>,
-    max_pods: Option<u32>,
+    server_id: String,
+    flavor_id: String,
+    stop_server: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceDeleteNodePoolParams {
+struct EcsReinstallParams {
     region: String,
-    cluster_id: String,
-    node_pool_id: String,
+    server_id: String,
+    admin_password: Option<String>,
+    key_name: Option<String>,
+    stop_server: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceGetJobParams {
+struct EcsChangeOsParams {
     region: String,
-    job_id: String,
+    server_id: String,
+    image_id: String,
+    admin_password: Option<String>,
+    key_name: Option<String>,
+    stop_server: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceListNatGatewaysParams {
+struct EcsGetJobParams {
     region: String,
-    vpc_id: String,
-    subnet_id: String,
+    job_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceCreateNatGatewayParams {
+struct VpcCreateParams {
     region: String,
     name: String,
-    vpc_id: String,
-    subnet_id: String,
+    cidr: String,
     description: Option<String>,
-    spec: Option<String>,
+    /// Skip the check against the default CCE container/service CIDRs.
+    allow_cce_cidr_overlap: Option<bool>,
+    enterprise_project_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceDeleteNatGatewayParams {
+struct VpcDeleteParams {
     region: String,
-    nat_gateway_id: String,
+    vpc_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceBindClusterApiEipParams {
+struct SubnetCreateParams {
     region: String,
-    cluster_id: String,
-    eip_address: String,
+    vpc_id: String,
+    name: String,
+    cidr: String,
+    /// Defaults to the first usable address of the subnet.
+    gateway_ip: Option<String>,
+    availability_zone: Option<String>,
+    primary_dns: Option<String>,
+    secondary_dns: Option<String>,
+    allow_cce_cidr_overlap: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceCreateBindClusterApiEipParams {
+struct SubnetDeleteParams {
     region: String,
-    cluster_id: String,
+    vpc_id: String,
+    subnet_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct CceDownloadKubeconfigParams {
+struct SecurityGroupCreateParams {
     region: String,
-    cluster_id: String,
-    context: Option<String>,
+    name: String,
+    description: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct CreateEcsResult {
-    status: String,
-    status_code: u16,
-    body: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SecurityGroupDeleteParams {
+    region: String,
+    security_group_id: String,
 }
 
-#[derive(Debug, Serialize)]
-struct DeleteOperationResult {
-    status: String,
-    status_code: Option<u16>,
-    body: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SecurityGroupRuleListParams {
+    region: String,
+    security_group_id: String,
 }
 
-#[derive(Debug, Serialize)]
-struct DeleteEcsResult {
-    ecs: DeleteOperationResult,
-    eip: Option<DeleteOperationResult>,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SecurityGroupRuleAddParams {
+    region: String,
+    security_group_id: String,
+    /// "ingress" (default) or "egress".
+    direction: Option<String>,
+    /// "tcp", "udp", "icmp" or "any" (default).
+    protocol: Option<String>,
+    /// Comma-separated ports or ranges, e.g. "22,8000-8080". Only valid for tcp/udp.
+    ports: Option<String>,
+    /// IPv4 CIDR or bare address; omitted means any source/destination.
+    remote_ip_prefix: Option<String>,
+    action: Option<String>,
+    priority: Option<u32>,
+    description: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct StopEcsResult {
-    ecs: DeleteOperationResult,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SecurityGroupRuleDeleteParams {
+    region: String,
+    rule_id: String,
 }
 
-#[derive(Debug, Serialize)]
-struct ObsOperationResult {
-    status: String,
-    status_code: u16,
-    body: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SecurityGroupAllowCurrentIpParams {
+    region: String,
+    /// Server whose first security group receives the rule when no group is given.
+    server_id: Option<String>,
+    security_group_id: Option<String>,
+    /// TCP ports to open; defaults to SSH only.
+    ports: Option<Vec<u16>>,
+    /// Overrides public IP detection.
+    source_ip: Option<String>,
+    /// Look the public IP up through api.ipify.org when no source IP is given. Off unless
+    /// the user opted in, since it sends a request to a third-party service.
+    detect_public_ip: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
-struct ObsGetObjectResult {
-    status: String,
-    status_code: u16,
-    content_base64: Option<String>,
-    content_type: Option<String>,
-    body: Option<String>,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EipDeleteParams {
+    region: String,
+    eip_id: String,
 }
 
-#[derive(Debug, Serialize)]
-struct ObsBucketTotalsResult {
-    total_size_bytes: u64,
-    total_object_count: u64,
-    pages_scanned: u32,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EipAllocateParams {
+    region: String,
+    /// EIP line type, e.g. "5_bgp" (default) or "5_sbgp".
+    eip_type: Option<String>,
+    /// "PER" (default, dedicated) or "WHOLE" (join an existing shared bandwidth).
+    share_type: Option<String>,
+    /// "traffic" (default) or "bandwidth"; only used for dedicated bandwidth.
+    charge_mode: Option<String>,
+    bandwidth_size: Option<u32>,
+    bandwidth_name: Option<String>,
+    /// Required when share_type is "WHOLE".
+    shared_bandwidth_id: Option<String>,
+    alias: Option<String>,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct CceOperationResult {
-    status: String,
-    status_code: u16,
-    body: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EipBindParams {
+    region: String,
+    eip_id: String,
+    /// Target port; when omitted the primary NIC port of `server_id` is used.
+    port_id: Option<String>,
+    server_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct CceKubeconfigResult {
-    status: String,
-    status_code: u16,
-    body: String,
-    kubeconfig: Option<String>,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EipUnbindParams {
+    region: String,
+    eip_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshConnectParams {
-    session_id: String,
-    host: String,
-    port: Option<u16>,
-    username: Option<String>,
-    password: String,
+struct EipUpdateParams {
+    region: String,
+    eip_id: String,
+    alias: Option<String>,
+    description: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshExecParams {
-    session_id: String,
-    command: String,
+struct EipBandwidthResizeParams {
+    region: String,
+    /// EIP whose bandwidth is changed; ignored when `bandwidth_id` is set.
+    eip_id: Option<String>,
+    bandwidth_id: Option<String>,
+    size: Option<u32>,
+    /// "traffic" or "bandwidth"; shared bandwidths cannot switch.
+    charge_mode: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshDisconnectParams {
-    session_id: String,
+struct SharedBandwidthCreateParams {
+    region: String,
+    name: String,
+    size: u32,
+    /// "bandwidth" (default) or "traffic".
+    charge_mode: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshResizeParams {
-    session_id: String,
-    cols: u32,
-    rows: u32,
-    pixel_width: Option<u32>,
-    pixel_height: Option<u32>,
+struct SharedBandwidthDeleteParams {
+    region: String,
+    bandwidth_id: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshSendControlParams {
-    session_id: String,
-    control: String,
+struct SharedBandwidthAddEipsParams {
+    region: String,
+    bandwidth_id: String,
+    eip_ids: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshExecOneShotParams {
-    session_id: String,
-    host: String,
-    port: Option<u16>,
-    username: Option<String>,
-    password: String,
-    command: String,
+struct SharedBandwidthRemoveEipsParams {
+    region: String,
+    bandwidth_id: String,
+    eip_ids: Vec<String>,
+    /// Dedicated bandwidth given to each removed EIP; defaults to traffic / 100 Mbit/s.
+    charge_mode: Option<String>,
+    size: Option<u32>,
 }
 
-#[derive(Debug, Serialize)]
+/// AK/SK credentials input from the UI.
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshConnectResult {
-    session_id: String,
-    host: String,
-    port: u16,
-    username: String,
-    connected_at: String,
+struct CredentialsInput {
+    access_key: String,
+    secret_key: String,
+    /// Profile default for creates and list filters that leave the enterprise project empty.
+    enterprise_project_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshExecResult {
-    session_id: String,
-    command: String,
-    stdout: String,
-    stderr: String,
-    exit_status: Option<u32>,
+struct ImageCreateParams {
+    region: String,
+    server_id: String,
+    name: String,
+    description: Option<String>,
+    /// Capture every attached disk instead of only the system disk.
+    whole_image: Option<bool>,
+    /// CBR vault that stores whole-image backups; required with `whole_image`.
+    vault_id: Option<String>,
+    enterprise_project_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshDisconnectResult {
-    session_id: String,
-    disconnected: bool,
+struct ImageImportParams {
+    region: String,
+    bucket_name: String,
+    /// Image file object; its extension (qcow2, vhd, ...) sets the import format.
+    object_key: String,
+    name: String,
+    /// IMS OS version label, e.g. "Ubuntu 22.04 server 64bit".
+    os_version: String,
+    /// Minimum system disk size in GiB.
+    min_disk: u32,
+    description: Option<String>,
+    enterprise_project_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshResizeResult {
-    session_id: String,
-    cols: u32,
-    rows: u32,
+struct ImageFileImportParams {
+    /// Local image file uploaded to `bucketName` before registration.
+    file_path: String,
+    /// Leave `objectKey` empty to use the file name.
+    #[serde(flatten)]
+    import: ImageImportParams,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshSendControlResult {
-    session_id: String,
-    control: String,
-    sent: bool,
+struct ImageExportParams {
+    region: String,
+    image_id: String,
+    bucket_name: String,
+    object_key: String,
+    file_format: String,
+    /// Skip the conversion pass; only supported for some image sizes and formats.
+    quick_export: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshExecOneShotResult {
-    session_id: String,
-    host: String,
-    port: u16,
-    username: String,
-    command: String,
-    stdout: String,
-    stderr: String,
-    exit_status: Option<u32>,
+struct ImageJobParams {
+    region: String,
+    job_id: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SshStreamEvent {
-    session_id: String,
-    kind: String,
-    text: String,
-    at: String,
+struct ImageDeleteParams {
+    region: String,
+    image_id: String,
 }
 
-#[derive(Default)]
-struct SshClientHandler;
-
-impl client::Handler for SshClientHandler {
-    type Error = russh::Error;
-
-    async fn check_server_key(
-        &mut self,
-        _server_public_key: &russh::keys::ssh_key::PublicKey,
-    ) -> Result<bool, Self::Error> {
-        Ok(true)
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageShareParams {
+    region: String,
+    image_ids: Vec<String>,
+    project_ids: Vec<String>,
 }
 
-struct SshSessionEntry {
-    handle: client::Handle<SshClientHandler>,
-    shell_writer: russh::ChannelWriteHalf<client::Msg>,
-    shell_reader_task: JoinHandle<()>,
-    host: String,
-    port: u16,
-    username: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageShareResponseParams {
+    region: String,
+    image_ids: Vec<String>,
+    accept: bool,
+    /// Needed to accept a shared whole image.
+    vault_id: Option<String>,
 }
 
-#[derive(Default)]
-struct SshSessionStore {
-    sessions: Mutex<HashMap<String, SshSessionEntry>>,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageFilters {
+    visibility: Option<String>,
+    image_type: Option<String>,
+    flavor_id: Option<String>,
 }
 
-// Generate a ECS name when the placeholder is used.
-fn normalize_server_name(input: &str) -> String {
-    if input.trim().is_empty() || input == RANDOM_NAME_PLACEHOLDER {
-        let ts = Utc::now().format("%Y%m%d-%H%M%S");
-        let rand: String = rand::rng()
-            .sample_iter(&Alphanumeric)
-            .take(6)
-            .map(char::from)
-            .collect();
-
-        return format!("ecs-{}-{}", ts, rand);
-    }
-
-    input.to_string()
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListParamsInput {
+    marker: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    /// Only return resources carrying every one of these tags. The filter runs on
+    /// the single page the service returns, so it cannot be combined with paging.
+    tags: Option<Vec<TagInput>>,
+    /// Enterprise project ID, or `all_granted_eps` for every granted project.
+    enterprise_project_id: Option<String>,
 }
 
-// Require explicit credentials from the UI for every API call.
-fn resolve_credentials(
-    input: Option<CredentialsInput>,
-) -> Result<(Credentials, CredentialsSource), String> {
-    let input = input.ok_or_else(|| "Access Key and Secret Key are required.".to_string())?;
-    let access_key = input.access_key.trim();
-    let secret_key = input.secret_key.trim();
-
-    if access_key.is_empty() || secret_key.is_empty() {
-        return Err("Provide both Access Key and Secret Key.".to_string());
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsCreateBucketParams {
+    region: String,
+    bucket_name: String,
+    default_storage_class: Option<String>,
+    acl: Option<String>,
+    tags: Option<Vec<TagInput>>,
+}
 
-    Ok((
-        Credentials::new(access_key.to_string(), secret_key.to_string()),
-        CredentialsSource::Explicit,
-    ))
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsDeleteBucketParams {
+    region: String,
+    bucket_name: String,
 }
 
-fn credentials_source_label(source: &CredentialsSource) -> String {
-    match source {
-        CredentialsSource::Explicit => "explicit".to_string(),
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsListObjectsParams {
+    region: String,
+    bucket_name: String,
+    prefix: Option<String>,
+    marker: Option<String>,
+    max_keys: Option<u32>,
 }
 
-fn operation_result(status: reqwest::StatusCode, body: String) -> DeleteOperationResult {
-    DeleteOperationResult {
-        status: status.to_string(),
-        status_code: Some(status.as_u16()),
-        body,
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsBucketTotalsParams {
+    region: String,
+    bucket_name: String,
 }
 
-fn operation_error_result(status: &str, body: String) -> DeleteOperationResult {
-    DeleteOperationResult {
-        status: status.to_string(),
-        status_code: None,
-        body,
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsPutObjectParams {
+    region: String,
+    bucket_name: String,
+    object_key: String,
+    content_base64: String,
+    content_type: Option<String>,
 }
 
-fn obs_operation_result(status: reqwest::StatusCode, body: String) -> ObsOperationResult {
-    ObsOperationResult {
-        status: status.to_string(),
-        status_code: status.as_u16(),
-        body,
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsDeleteObjectParams {
+    region: String,
+    bucket_name: String,
+    object_key: String,
 }
 
-fn cce_operation_result(status: reqwest::StatusCode, body: String) -> CceOperationResult {
-    CceOperationResult {
-        status: status.to_string(),
-        status_code: status.as_u16(),
-        body,
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObsGetObjectParams {
+    region: String,
+    bucket_name: String,
+    object_key: String,
 }
 
-fn sanitize_cce_node_pool_initial_count(input: Option<u32>) -> u32 {
-    input
-        .unwrap_or(CCE_NODE_POOL_INITIAL_COUNT_DEFAULT)
-        .max(CCE_NODE_POOL_INITIAL_COUNT_MIN)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateClusterParams {
+    region: String,
+    name: String,
+    flavor: String,
+    version: String,
+    vpc_id: String,
+    subnet_id: String,
+    description: Option<String>,
+    cluster_type: Option<String>,
+    /// overlay_l2, underlay_ipvlan, vpc-router or eni; eni creates a CCE Turbo cluster.
+    container_network_mode: Option<String>,
+    /// Pod CIDR; eni clusters take pod IPs from their ENI subnets.
+    container_network_cidr: Option<String>,
+    /// VPC subnets ENI pods take their IPs from; defaults to the cluster subnet.
+    eni_subnet_ids: Option<Vec<String>>,
+    kubernetes_svc_ip_range: Option<String>,
+    ipv6_enable: Option<bool>,
+    /// iptables or ipvs; CCE picks its default when unset.
+    kube_proxy_mode: Option<String>,
+    /// Control-plane AZs: one for all masters, or one per master.
+    master_azs: Option<Vec<String>>,
+    /// Existing EIP address to expose the API server on.
+    eip_address: Option<String>,
+    authentication_mode: Option<String>,
+    /// payPerUse (default) or yearlyMonthly.
+    billing_mode: Option<String>,
+    /// month or year, for yearlyMonthly clusters.
+    period_type: Option<String>,
+    period_num: Option<u32>,
+    auto_renew: Option<bool>,
+    auto_pay: Option<bool>,
+    /// Legacy shorthand for an `env` tag; ignored when `tags` already has `env`.
+    cluster_tag_env: Option<String>,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
 }
 
-fn sanitize_cce_node_pool_root_volume_size(input: Option<u32>) -> u32 {
-    input
-        .unwrap_or(CCE_NODE_POOL_ROOT_VOLUME_SIZE_DEFAULT)
-        .clamp(
-            CCE_NODE_POOL_ROOT_VOLUME_SIZE_MIN,
-            CCE_NODE_POOL_ROOT_VOLUME_SIZE_MAX,
-        )
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDeleteClusterParams {
+    region: String,
+    cluster_id: String,
 }
 
-fn sanitize_cce_node_pool_data_volume_size(input: Option<u32>) -> u32 {
-    input
-        .unwrap_or(CCE_NODE_POOL_DATA_VOLUME_SIZE_DEFAULT)
-        .clamp(
-            CCE_NODE_POOL_DATA_VOLUME_SIZE_MIN,
-            CCE_NODE_POOL_DATA_VOLUME_SIZE_MAX,
-        )
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListNodePoolsParams {
+    region: String,
+    cluster_id: String,
+    tags: Option<Vec<TagInput>>,
 }
 
-fn sanitize_cce_node_pool_max_pods(input: Option<u32>) -> Option<u32> {
-    input.map(|value| value.clamp(CCE_NODE_POOL_MAX_PODS_MIN, CCE_NODE_POOL_MAX_PODS_MAX))
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateNodePoolParams {
+    region: String,
+    cluster_id: String,
+    name: String,
+    flavor: String,
+    availability_zone: String,
+    subnet_id: Option<String>,
+    os: Option<String>,
+    ssh_key: Option<String>,
+    initial_node_count: Option<u32>,
+    root_volume_type: Option<String>,
+    root_volume_size: Option<u32>,
+    data_volume_type: Option<String>,
+    data_volume_size: Option<u32>,
+    max_pods: Option<u32>,
+    /// Applied to the ECS nodes the pool creates.
+    tags: Option<Vec<TagInput>>,
 }
 
-fn parse_json_or_string(raw: &str) -> Value {
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        Value::String(String::new())
-    } else {
-        serde_json::from_str(trimmed).unwrap_or_else(|_| Value::String(trimmed.to_string()))
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDeleteNodePoolParams {
+    region: String,
+    cluster_id: String,
+    node_pool_id: String,
 }
 
-fn value_contains_case_insensitive(value: Option<&Value>, needle: &str) -> bool {
-    value
-        .and_then(Value::as_str)
-        .map(str::to_ascii_lowercase)
-        .is_some_and(|haystack| haystack.contains(&needle.to_ascii_lowercase()))
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceScaleNodePoolParams {
+    region: String,
+    cluster_id: String,
+    node_pool_id: String,
+    node_count: u32,
 }
 
-fn is_api_method_not_found_response(body: &Value) -> bool {
-    let error_code = body
-        .get("error_code")
-        .or_else(|| body.get("code"))
-        .and_then(Value::as_str)
-        .unwrap_or("")
-        .trim()
-        .to_ascii_uppercase();
-    if error_code == "APIGW.0101" {
-        return true;
-    }
-    value_contains_case_insensitive(body.get("error_msg"), "method")
-        && value_contains_case_insensitive(body.get("error_msg"), "not found")
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceUpdateNodePoolParams {
+    region: String,
+    cluster_id: String,
+    node_pool_id: String,
+    #[serde(flatten)]
+    changes: CceNodePoolChanges,
 }
 
-fn should_retry_nat_eip_delete(status: reqwest::StatusCode, body: &Value) -> bool {
-    if status != reqwest::StatusCode::CONFLICT {
-        return false;
-    }
-    let error_code = body
-        .get("error_code")
-        .or_else(|| body.get("code"))
-        .and_then(Value::as_str)
-        .unwrap_or("")
-        .trim()
-        .to_ascii_uppercase();
-    if error_code == "EIP.7922" {
-        return true;
-    }
-    value_contains_case_insensitive(body.get("error_msg"), "associated instance")
-        || value_contains_case_insensitive(body.get("message"), "associated instance")
+/// Requested node pool changes; `None` keeps the pool's current value.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceNodePoolChanges {
+    node_count: Option<u32>,
+    autoscaling_enabled: Option<bool>,
+    min_node_count: Option<u32>,
+    max_node_count: Option<u32>,
+    scale_down_cooldown_time: Option<u32>,
+    priority: Option<i32>,
+    /// Kubernetes labels applied to the pool's nodes (`k8sTags`).
+    labels: Option<BTreeMap<String, String>>,
+    taints: Option<Vec<CceTaint>>,
+    /// Resource tags applied to the pool's ECS nodes (`userTags`).
+    tags: Option<Vec<TagInput>>,
 }
 
-fn is_success_or_not_found(status: reqwest::StatusCode) -> bool {
-    status.is_success() || status == reqwest::StatusCode::NOT_FOUND
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListNodesParams {
+    region: String,
+    cluster_id: String,
+    /// Keeps only nodes owned by this node pool.
+    node_pool_id: Option<String>,
 }
 
-fn extract_nat_gateway_id(raw_body: &str) -> Option<String> {
-    let payload: Value = serde_json::from_str(raw_body).ok()?;
-    payload
-        .get("nat_gateway")
-        .and_then(|item| item.get("id"))
-        .and_then(Value::as_str)
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceNodeParams {
+    region: String,
+    cluster_id: String,
+    node_id: String,
 }
 
-fn extract_eip_id_and_address(raw_body: &str) -> (Option<String>, Option<String>) {
-    let payload: Value = match serde_json::from_str(raw_body) {
-        Ok(value) => value,
-        Err(_) => return (None, None),
-    };
-    let publicip = payload.get("publicip");
-    let id = publicip
-        .and_then(|item| item.get("id"))
-        .and_then(Value::as_str)
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string);
-    let address = publicip
-        .and_then(|item| {
-            item.get("public_ip_address")
-                .or_else(|| item.get("public_ip"))
-        })
-        .and_then(Value::as_str)
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string);
-    (id, address)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceResetNodesParams {
+    region: String,
+    cluster_id: String,
+    node_ids: Vec<String>,
+    os: String,
+    ssh_key: String,
 }
 
-fn extract_cluster_kubeconfig(raw_body: &str) -> Option<String> {
-    let trimmed = raw_body.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-    let payload: Value = match serde_json::from_str(trimmed) {
-        Ok(value) => value,
-        Err(_) => return Some(trimmed.to_string()),
-    };
-    if let Some(value) = payload
-        .get("kubeconfig")
-        .or_else(|| payload.get("kube_config"))
-        .or_else(|| payload.get("kubeConfig"))
-        .or_else(|| payload.get("config"))
-        .and_then(Value::as_str)
-    {
-        let text = value.trim();
-        if !text.is_empty() {
-            return Some(text.to_string());
-        }
-    }
-    if let Some(certs) = payload.get("certs") {
-        if let Some(value) = certs
-            .get("kubeconfig")
-            .or_else(|| certs.get("kube_config"))
-            .or_else(|| certs.get("kubeConfig"))
-            .or_else(|| certs.get("config"))
-            .and_then(Value::as_str)
-        {
-            let text = value.trim();
-            if !text.is_empty() {
-                return Some(text.to_string());
-            }
-        }
-    }
-    if payload.get("clusters").is_some()
-        && payload.get("contexts").is_some()
-        && payload.get("users").is_some()
-    {
-        return serde_json::to_string_pretty(&payload).ok();
-    }
-    None
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceRemoveNodesParams {
+    region: String,
+    cluster_id: String,
+    node_ids: Vec<String>,
+    /// Key pair applied when CCE reinstalls the OS of the removed ECS.
+    ssh_key: String,
 }
 
-/// List VPCs for the given region so the UI can populate a dropdown.
-#[tauri::command]
-async fn list_vpcs(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceGetJobParams {
     region: String,
-    credentials: Option<CredentialsInput>,
-) -> Result<Vec<Vpc>, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    job_id: String,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!("Listing VPCs: source={} region={}", source_label, region);
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceClusterUpgradeInfoParams {
+    region: String,
+    cluster_id: String,
+}
 
-    let client = HwcClient::new(credentials);
-    client.list_vpcs(&region).await.map_err(|err| {
-        error!("Failed to list VPCs: region={} error={}", region, err);
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceClusterUpgradeParams {
+    region: String,
+    cluster_id: String,
+    target_version: String,
 }
 
-/// List subnets for the selected VPC so the UI can populate a dropdown.
-#[tauri::command]
-async fn list_subnets(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceFollowUpgradeParams {
     region: String,
-    vpc_id: String,
-    credentials: Option<CredentialsInput>,
-) -> Result<Vec<Subnet>, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    cluster_id: String,
+    job_id: String,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Listing subnets: source={} region={} vpc_id={}",
-        source_label, region, vpc_id
-    );
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceClusterPowerParams {
+    region: String,
+    cluster_id: String,
+}
 
-    let client = HwcClient::new(credentials);
-    client.list_subnets(&region, &vpc_id).await.map_err(|err| {
-        error!(
-            "Failed to list subnets: region={} vpc_id={} error={}",
-            region, vpc_id, err
-        );
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceSetHibernateScheduleParams {
+    region: String,
+    cluster_id: String,
+    cluster_name: Option<String>,
+    /// Local time as `HH:MM`.
+    hibernate_at: String,
+    enabled: Option<bool>,
 }
 
-/// List images for the given region so the UI can populate a dropdown.
-#[tauri::command]
-async fn list_images(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceHibernateScheduleKeyParams {
     region: String,
-    filters: Option<ImageFilters>,
-    credentials: Option<CredentialsInput>,
-) -> Result<Vec<Image>, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    cluster_id: String,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!("Listing images: source={} region={}", source_label, region);
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListAddonTemplatesParams {
+    region: String,
+    template_name: Option<String>,
+}
 
-    let client = HwcClient::new(credentials);
-    let filters = filters.map(|input| ImageListFilters {
-        visibility: input.visibility,
-        image_type: input.image_type,
-        flavor_id: input.flavor_id,
-    });
-    let images = client.list_images(&region, filters).await.map_err(|err| {
-        error!("Failed to list images: region={} error={}", region, err);
-        err.to_string()
-    })?;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListAddonsParams {
+    region: String,
+    cluster_id: String,
+}
 
-    info!("Found {} images in region {}", images.len(), region);
+/// Values layered over the template defaults on install or upgrade.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceAddonValueOverrides {
+    basic: Option<Map<String, Value>>,
+    custom: Option<Map<String, Value>>,
+    /// Template flavor key such as `flavor2`.
+    flavor: Option<String>,
+}
 
-    Ok(images)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceInstallAddonParams {
+    region: String,
+    cluster_id: String,
+    template_name: String,
+    version: String,
+    #[serde(flatten)]
+    values: CceAddonValueOverrides,
 }
 
-/// List flavors for the given region so the UI can populate a dropdown.
-#[tauri::command]
-async fn list_flavors(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceUpgradeAddonParams {
     region: String,
-    credentials: Option<CredentialsInput>,
-) -> Result<Vec<Flavor>, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    cluster_id: String,
+    addon_id: String,
+    version: String,
+    #[serde(flatten)]
+    values: CceAddonValueOverrides,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!("Listing flavors: source={} region={}", source_label, region);
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceAddonParams {
+    region: String,
+    cluster_id: String,
+    addon_id: String,
+}
 
-    let client = HwcClient::new(credentials);
-    client.list_flavors(&region).await.map_err(|err| {
-        error!("Failed to list flavors: region={} error={}", region, err);
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListNatGatewaysParams {
+    region: String,
+    vpc_id: String,
+    subnet_id: String,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
 }
 
-/// List elastic IPs for the given region.
-#[tauri::command]
-async fn list_eips(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateNatGatewayParams {
     region: String,
-    params: Option<ListParamsInput>,
-    credentials: Option<CredentialsInput>,
-) -> Result<EipListResponse, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    name: String,
+    vpc_id: String,
+    subnet_id: String,
+    description: Option<String>,
+    spec: Option<String>,
+    /// Existing EIPs to bind to the SNAT rule instead of allocating a new one.
+    eip_ids: Option<Vec<String>>,
+    /// Applied to both the NAT gateway and the EIP the bootstrap creates.
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!("Listing EIPs: source={} region={}", source_label, region);
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceUpdateNatGatewayParams {
+    region: String,
+    nat_gateway_id: String,
+    name: Option<String>,
+    description: Option<String>,
+    spec: Option<String>,
+}
 
-    let client = HwcClient::new(credentials);
-    let params = params.map(|input| ListParams {
-        marker: input.marker,
-        limit: input.limit,
-        offset: input.offset,
-    });
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListSnatRulesParams {
+    region: String,
+    nat_gateway_id: String,
+}
 
-    client.list_eips(&region, params).await.map_err(|err| {
-        error!("Failed to list EIPs: region={} error={:#}", region, err);
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateSnatRuleParams {
+    region: String,
+    nat_gateway_id: String,
+    /// Source subnet; set this or `cidr`, not both.
+    subnet_id: Option<String>,
+    cidr: Option<String>,
+    /// Existing EIPs bound to the rule; several share the source's outbound traffic.
+    eip_ids: Vec<String>,
+    description: Option<String>,
 }
 
-/// List ECS instances for the given region.
-#[tauri::command]
-async fn list_ecses(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceUpdateSnatRuleParams {
     region: String,
-    params: Option<ListParamsInput>,
-    credentials: Option<CredentialsInput>,
-) -> Result<EcsListResponse, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    nat_gateway_id: String,
+    snat_rule_id: String,
+    /// Replaces every EIP bound to the rule.
+    eip_addresses: Option<Vec<String>>,
+    description: Option<String>,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Listing ECS instances: source={} region={}",
-        source_label, region
-    );
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDeleteSnatRuleParams {
+    region: String,
+    nat_gateway_id: String,
+    snat_rule_id: String,
+}
 
-    let client = HwcClient::new(credentials);
-    let params = params.map(|input| ListParams {
-        marker: input.marker,
-        limit: input.limit,
-        offset: input.offset,
-    });
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDeleteNatGatewayParams {
+    region: String,
+    nat_gateway_id: String,
+    /// Also release EIPs bound to DNAT rules; they are kept by default.
+    release_dnat_eips: Option<bool>,
+}
 
-    client.list_ecses(&region, params).await.map_err(|err| {
-        error!(
-            "Failed to list ECS instances: region={} error={}",
-            region, err
-        );
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceListDnatRulesParams {
+    region: String,
+    nat_gateway_id: String,
 }
 
-/// List EVS disks for the given region.
-#[tauri::command]
-async fn list_evss(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateDnatRuleParams {
     region: String,
-    params: Option<ListParamsInput>,
-    credentials: Option<CredentialsInput>,
-) -> Result<EvsListResponse, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    nat_gateway_id: String,
+    /// tcp, udp or any (all ports; both ports must then be 0 or omitted).
+    protocol: String,
+    internal_port: Option<u32>,
+    external_port: Option<u32>,
+    /// ECS NIC port to forward to; set this or `private_ip`, not both.
+    port_id: Option<String>,
+    private_ip: Option<String>,
+    eip_id: String,
+    description: Option<String>,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Listing EVS disks: source={} region={}",
-        source_label, region
-    );
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDeleteDnatRuleParams {
+    region: String,
+    nat_gateway_id: String,
+    dnat_rule_id: String,
+}
 
-    let client = HwcClient::new(credentials);
-    let params = params.map(|input| ListParams {
-        marker: input.marker,
-        limit: input.limit,
-        offset: input.offset,
-    });
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceBindClusterApiEipParams {
+    region: String,
+    cluster_id: String,
+    eip_address: String,
+}
 
-    client.list_evss(&region, params).await.map_err(|err| {
-        error!("Failed to list EVS disks: region={} error={}", region, err);
-        err.to_string()
-    })
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceCreateBindClusterApiEipParams {
+    region: String,
+    cluster_id: String,
 }
 
-/// List CCE clusters for the selected region.
-#[tauri::command]
-async fn list_cce_clusters(
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CceDownloadKubeconfigParams {
     region: String,
-    credentials: Option<CredentialsInput>,
-) -> Result<CceClusterListResponse, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+    cluster_id: String,
+    context: Option<String>,
+}
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Listing CCE clusters: source={} region={}",
-        source_label, region
-    );
+#[derive(Debug, Serialize)]
+struct CreateEcsResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
 
-    let client = HwcClient::new(credentials);
-    client.list_cce_clusters(&region).await.map_err(|err| {
-        error!(
-            "Failed to list CCE clusters: region={} error={}",
-            region, err
-        );
-        err.to_string()
-    })
+#[derive(Debug, Serialize)]
+struct DeleteOperationResult {
+    status: String,
+    status_code: Option<u16>,
+    body: String,
 }
 
-/// Create one CCE cluster.
-#[tauri::command]
-async fn create_cce_cluster(
-    params: CceCreateClusterParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+#[derive(Debug, Serialize)]
+struct DeleteEcsResult {
+    ecs: DeleteOperationResult,
+    eip: Option<DeleteOperationResult>,
+}
 
-    let cluster_name = params.name.trim();
-    if cluster_name.is_empty() {
-        return Err("CCE cluster name is required.".to_string());
-    }
-    let flavor = params.flavor.trim();
+#[derive(Debug, Serialize)]
+struct StopEcsResult {
+    ecs: DeleteOperationResult,
+}
+
+#[derive(Debug, Serialize)]
+struct EcsJobResult {
+    status: String,
+    status_code: u16,
+    body: String,
+    job_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImsJobResult {
+    status: String,
+    status_code: u16,
+    body: String,
+    job_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ObsOperationResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ObsGetObjectResult {
+    status: String,
+    status_code: u16,
+    content_base64: Option<String>,
+    content_type: Option<String>,
+    body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ObsBucketTotalsResult {
+    total_size_bytes: u64,
+    total_object_count: u64,
+    pages_scanned: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct CceOperationResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct VpcOperationResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EvsOperationResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct TagOperationResult {
+    status: String,
+    status_code: u16,
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CceUpgradeStartResult {
+    status: String,
+    status_code: u16,
+    body: String,
+    job_id: Option<String>,
+}
+
+/// Step-by-step progress of a cluster upgrade, emitted as `cce-upgrade-progress`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CceUpgradeProgressEvent {
+    cluster_id: String,
+    /// precheck or upgrade.
+    stage: String,
+    /// Sub-job type (e.g. UpgradeMaster) or the stage itself for overall transitions.
+    step: String,
+    phase: String,
+    message: Option<String>,
+    at: String,
+}
+
+/// Outcome of a scheduled hibernation, emitted as `cce-hibernate-schedule`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CceHibernateScheduleEvent {
+    region: String,
+    cluster_id: String,
+    phase: Option<String>,
+    error: Option<String>,
+    at: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CceKubeconfigResult {
+    status: String,
+    status_code: u16,
+    body: String,
+    kubeconfig: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshConnectParams {
+    session_id: String,
+    host: String,
+    port: Option<u16>,
+    username: Option<String>,
+    password: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshExecParams {
+    session_id: String,
+    command: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshDisconnectParams {
+    session_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshResizeParams {
+    session_id: String,
+    cols: u32,
+    rows: u32,
+    pixel_width: Option<u32>,
+    pixel_height: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshSendControlParams {
+    session_id: String,
+    control: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshExecOneShotParams {
+    session_id: String,
+    host: String,
+    port: Option<u16>,
+    username: Option<String>,
+    password: String,
+    command: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshConnectResult {
+    session_id: String,
+    host: String,
+    port: u16,
+    username: String,
+    connected_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshExecResult {
+    session_id: String,
+    command: String,
+    stdout: String,
+    stderr: String,
+    exit_status: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshDisconnectResult {
+    session_id: String,
+    disconnected: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshResizeResult {
+    session_id: String,
+    cols: u32,
+    rows: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshSendControlResult {
+    session_id: String,
+    control: String,
+    sent: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshExecOneShotResult {
+    session_id: String,
+    host: String,
+    port: u16,
+    username: String,
+    command: String,
+    stdout: String,
+    stderr: String,
+    exit_status: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SshStreamEvent {
+    session_id: String,
+    kind: String,
+    text: String,
+    at: String,
+}
+
+/// Progress of `import_image_from_file`, emitted as `image-import-progress`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageImportProgressEvent {
+    bucket_name: String,
+    object_key: String,
+    /// uploading, uploaded, registering, submitted or failed.
+    stage: String,
+    uploaded_bytes: u64,
+    total_bytes: u64,
+    at: String,
+}
+
+#[derive(Default)]
+struct SshClientHandler;
+
+impl client::Handler for SshClientHandler {
+    type Error = russh::Error;
+
+    async fn check_server_key(
+        &mut self,
+        _server_public_key: &russh::keys::ssh_key::PublicKey,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+struct SshSessionEntry {
+    handle: client::Handle<SshClientHandler>,
+    shell_writer: russh::ChannelWriteHalf<client::Msg>,
+    shell_reader_task: JoinHandle<()>,
+    host: String,
+    port: u16,
+    username: String,
+}
+
+#[derive(Default)]
+struct SshSessionStore {
+    sessions: Mutex<HashMap<String, SshSessionEntry>>,
+}
+
+// Generate a ECS name when the placeholder is used.
+fn normalize_server_name(input: &str) -> String {
+    if input.trim().is_empty() || input == RANDOM_NAME_PLACEHOLDER {
+        let ts = Utc::now().format("%Y%m%d-%H%M%S");
+        let rand: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(6)
+            .map(char::from)
+            .collect();
+
+        return format!("ecs-{}-{}", ts, rand);
+    }
+
+    input.to_string()
+}
+
+// Require explicit credentials from the UI for every API call.
+fn resolve_credentials(
+    input: Option<CredentialsInput>,
+) -> Result<(Credentials, CredentialsSource), String> {
+    let input = input.ok_or_else(|| "Access Key and Secret Key are required.".to_string())?;
+    let access_key = input.access_key.trim();
+    let secret_key = input.secret_key.trim();
+
+    if access_key.is_empty() || secret_key.is_empty() {
+        return Err("Provide both Access Key and Secret Key.".to_string());
+    }
+
+    Ok((
+        Credentials::new(access_key.to_string(), secret_key.to_string()),
+        CredentialsSource::Explicit,
+    ))
+}
+
+/// Pick the explicit enterprise project, falling back to the credentials profile default.
+/// `allow_all` marks list commands: they accept `all_granted_eps` and skip an invalid
+/// saved default with a warning, while creates still reject it.
+fn resolve_enterprise_project(
+    explicit: Option<&str>,
+    credentials: Option<&CredentialsInput>,
+    allow_all: bool,
+) -> Result<Option<String>, String> {
+    if let Some(project) = normalize_enterprise_project_id(explicit, allow_all)? {
+        return Ok(Some(project));
+    }
+    let saved = normalize_enterprise_project_id(
+        credentials.and_then(|input| input.enterprise_project_id.as_deref()),
+        false,
+    );
+    match saved {
+        Err(err) if allow_all => {
+            warn!("Ignoring saved default enterprise project: {}", err);
+            Ok(None)
+        }
+        saved => saved,
+    }
+}
+
+fn credentials_source_label(source: &CredentialsSource) -> String {
+    match source {
+        CredentialsSource::Explicit => "explicit".to_string(),
+    }
+}
+
+fn operation_result(status: reqwest::StatusCode, body: String) -> DeleteOperationResult {
+    DeleteOperationResult {
+        status: status.to_string(),
+        status_code: Some(status.as_u16()),
+        body,
+    }
+}
+
+fn operation_error_result(status: &str, body: String) -> DeleteOperationResult {
+    DeleteOperationResult {
+        status: status.to_string(),
+        status_code: None,
+        body,
+    }
+}
+
+fn ecs_job_result(status: reqwest::StatusCode, body: String) -> EcsJobResult {
+    let job_id = if status.is_success() {
+        extract_job_id(&body)
+    } else {
+        None
+    };
+    EcsJobResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+        job_id,
+    }
+}
+
+fn ims_job_result(status: reqwest::StatusCode, body: String) -> ImsJobResult {
+    let job_id = if status.is_success() {
+        extract_job_id(&body)
+    } else {
+        None
+    };
+    ImsJobResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+        job_id,
+    }
+}
+
+fn obs_operation_result(status: reqwest::StatusCode, body: String) -> ObsOperationResult {
+    ObsOperationResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    }
+}
+
+fn cce_operation_result(status: reqwest::StatusCode, body: String) -> CceOperationResult {
+    CceOperationResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    }
+}
+
+fn evs_operation_result(status: reqwest::StatusCode, body: String) -> EvsOperationResult {
+    EvsOperationResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    }
+}
+
+fn tag_operation_result(status: reqwest::StatusCode, body: String) -> TagOperationResult {
+    TagOperationResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    }
+}
+
+fn vpc_operation_result(status: reqwest::StatusCode, body: String) -> VpcOperationResult {
+    VpcOperationResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    }
+}
+
+fn sanitize_cce_node_pool_initial_count(input: Option<u32>) -> u32 {
+    input
+        .unwrap_or(CCE_NODE_POOL_INITIAL_COUNT_DEFAULT)
+        .max(CCE_NODE_POOL_INITIAL_COUNT_MIN)
+}
+
+fn sanitize_cce_node_pool_root_volume_size(input: Option<u32>) -> u32 {
+    input
+        .unwrap_or(CCE_NODE_POOL_ROOT_VOLUME_SIZE_DEFAULT)
+        .clamp(
+            CCE_NODE_POOL_ROOT_VOLUME_SIZE_MIN,
+            CCE_NODE_POOL_ROOT_VOLUME_SIZE_MAX,
+        )
+}
+
+fn sanitize_cce_node_pool_data_volume_size(input: Option<u32>) -> u32 {
+    input
+        .unwrap_or(CCE_NODE_POOL_DATA_VOLUME_SIZE_DEFAULT)
+        .clamp(
+            CCE_NODE_POOL_DATA_VOLUME_SIZE_MIN,
+            CCE_NODE_POOL_DATA_VOLUME_SIZE_MAX,
+        )
+}
+
+fn sanitize_cce_node_pool_max_pods(input: Option<u32>) -> Option<u32> {
+    input.map(|value| value.clamp(CCE_NODE_POOL_MAX_PODS_MIN, CCE_NODE_POOL_MAX_PODS_MAX))
+}
+
+fn parse_json_or_string(raw: &str) -> Value {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        Value::String(String::new())
+    } else {
+        serde_json::from_str(trimmed).unwrap_or_else(|_| Value::String(trimmed.to_string()))
+    }
+}
+
+fn value_contains_case_insensitive(value: Option<&Value>, needle: &str) -> bool {
+    value
+        .and_then(Value::as_str)
+        .map(str::to_ascii_lowercase)
+        .is_some_and(|haystack| haystack.contains(&needle.to_ascii_lowercase()))
+}
+
+fn is_api_method_not_found_response(body: &Value) -> bool {
+    let error_code = body
+        .get("error_code")
+        .or_else(|| body.get("code"))
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .trim()
+        .to_ascii_uppercase();
+    if error_code == "APIGW.0101" {
+        return true;
+    }
+    value_contains_case_insensitive(body.get("error_msg"), "method")
+        && value_contains_case_insensitive(body.get("error_msg"), "not found")
+}
+
+fn should_retry_nat_eip_delete(status: reqwest::StatusCode, body: &Value) -> bool {
+    if status != reqwest::StatusCode::CONFLICT {
+        return false;
+    }
+    let error_code = body
+        .get("error_code")
+        .or_else(|| body.get("code"))
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .trim()
+        .to_ascii_uppercase();
+    if error_code == "EIP.7922" {
+        return true;
+    }
+    value_contains_case_insensitive(body.get("error_msg"), "associated instance")
+        || value_contains_case_insensitive(body.get("message"), "associated instance")
+}
+
+fn is_success_or_not_found(status: reqwest::StatusCode) -> bool {
+    status.is_success() || status == reqwest::StatusCode::NOT_FOUND
+}
+
+fn extract_nat_gateway_id(raw_body: &str) -> Option<String> {
+    let payload: Value = serde_json::from_str(raw_body).ok()?;
+    payload
+        .get("nat_gateway")
+        .and_then(|item| item.get("id"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn extract_eip_id_and_address(raw_body: &str) -> (Option<String>, Option<String>) {
+    let payload: Value = match serde_json::from_str(raw_body) {
+        Ok(value) => value,
+        Err(_) => return (None, None),
+    };
+    let publicip = payload.get("publicip");
+    let id = publicip
+        .and_then(|item| item.get("id"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let address = publicip
+        .and_then(|item| {
+            item.get("public_ip_address")
+                .or_else(|| item.get("public_ip"))
+        })
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    (id, address)
+}
+
+fn extract_job_id(raw_body: &str) -> Option<String> {
+    let payload: Value = serde_json::from_str(raw_body).ok()?;
+    payload
+        .get("job_id")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+// ECS resize/reinstall/change OS reject running servers unless asked to stop them first.
+fn ecs_action_mode(stop_server: Option<bool>) -> Option<&'static str> {
+    if stop_server.unwrap_or(true) {
+        Some("withStopServer")
+    } else {
+        None
+    }
+}
+
+fn resolve_ecs_login(
+    admin_password: Option<&str>,
+    key_name: Option<&str>,
+) -> Result<(Option<String>, Option<String>), String> {
+    let admin_password = admin_password
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let key_name = key_name
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    match (&admin_password, &key_name) {
+        (None, None) => Err("Provide either a new admin password or a key pair name.".to_string()),
+        (Some(_), Some(_)) => {
+            Err("Use either an admin password or a key pair name, not both.".to_string())
+        }
+        _ => Ok((admin_password, key_name)),
+    }
+}
+
+fn flavor_architecture(flavor: &Flavor) -> String {
+    flavor
+        .os_extra_specs
+        .get("ecs:instance_architecture")
+        .map(|value| value.trim().to_ascii_lowercase())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "x86".to_string())
+}
+
+/// Reject retired targets, the server's own flavor and, when the current flavor's
+/// specs are known, a change of CPU architecture.
+fn validate_ecs_resize_target(
+    current_flavor_id: Option<&str>,
+    current: Option<&Flavor>,
+    target: &Flavor,
+) -> Result<(), String> {
+    if let Some(status) = target.os_extra_specs.get("cond:operation:status") {
+        let status = status.trim().to_ascii_lowercase();
+        if matches!(status.as_str(), "abandon" | "sellout" | "obsolete") {
+            return Err(format!(
+                "Flavor {} is not available for resize (status: {}).",
+                target.id, status
+            ));
+        }
+    }
+
+    if current_flavor_id == Some(target.id.as_str()) {
+        return Err(format!("Server already uses flavor {}.", target.id));
+    }
+    let Some(current) = current else {
+        return Ok(());
+    };
+    let current_arch = flavor_architecture(current);
+    let target_arch = flavor_architecture(target);
+    if current_arch != target_arch {
+        return Err(format!(
+            "Flavor {} uses {} architecture, but the server runs on {}.",
+            target.id, target_arch, current_arch
+        ));
+    }
+    Ok(())
+}
+
+fn port_spec_contains(spec: &str, port: u16) -> bool {
+    spec.split(',')
+        .map(str::trim)
+        .any(|entry| match entry.split_once('-') {
+            Some((start, end)) => match (start.trim().parse::<u16>(), end.trim().parse::<u16>()) {
+                (Ok(start), Ok(end)) => (start..=end).contains(&port),
+                _ => false,
+            },
+            None => entry.parse::<u16>() == Ok(port),
+        })
+}
+
+// Mirrors how VPC v3 evaluates a rule: unset protocol, ports or prefix mean "any".
+fn security_group_rule_allows_ingress(
+    rule: &SecurityGroupRule,
+    source_ip: std::net::Ipv4Addr,
+    port: u16,
+) -> bool {
+    let field = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_ascii_lowercase)
+    };
+    if field(&rule.direction).as_deref() != Some("ingress") {
+        return false;
+    }
+    if field(&rule.action).is_some_and(|action| action != "allow") {
+        return false;
+    }
+    if field(&rule.ethertype).is_some_and(|ethertype| ethertype != "ipv4") {
+        return false;
+    }
+    if field(&rule.protocol).is_some_and(|protocol| protocol != "tcp") {
+        return false;
+    }
+    if field(&rule.remote_group_id).is_some() {
+        return false;
+    }
+    if let Some(prefix) = field(&rule.remote_ip_prefix) {
+        match parse_ipv4_cidr(&prefix) {
+            Ok(cidr) if ipv4_cidr_contains(cidr, source_ip) => {}
+            _ => return false,
+        }
+    }
+    field(&rule.multiport).is_none_or(|spec| port_spec_contains(&spec, port))
+}
+
+/// Pick the attachment a detach targets: the one for `server_id`, or the only one.
+fn find_evs_attachment<'a>(
+    volume: &'a EvsVolume,
+    server_id: Option<&str>,
+) -> Result<Option<&'a EvsAttachment>, String> {
+    if let Some(server_id) = server_id {
+        return Ok(volume
+            .attachments
+            .iter()
+            .find(|attachment| attachment.server_id.as_deref() == Some(server_id)));
+    }
+    match volume.attachments.as_slice() {
+        [] => Ok(None),
+        [attachment] => Ok(Some(attachment)),
+        _ => Err(format!(
+            "EVS disk {} is attached to {} servers. Choose the server to detach from.",
+            volume.id.as_deref().unwrap_or("-"),
+            volume.attachments.len()
+        )),
+    }
+}
+
+fn ensure_evs_detachable(volume: &EvsVolume, attachment: &EvsAttachment) -> Result<(), String> {
+    let volume_id = volume.id.as_deref().unwrap_or("-");
+    match attachment
+        .device
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(device) if is_evs_system_device(device) => Err(format!(
+            "EVS disk {} is the system disk ({}) and cannot be detached.",
+            volume_id, device
+        )),
+        None if volume.bootable == Some(true) => Err(format!(
+            "EVS disk {} is bootable and its device is unknown; refusing to detach a possible system disk.",
+            volume_id
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn evs_status_is(volume: &EvsVolume, statuses: &[&str]) -> bool {
+    volume.status.as_deref().is_some_and(|status| {
+        statuses
+            .iter()
+            .any(|expected| status.trim().eq_ignore_ascii_case(expected))
+    })
+}
+
+/// Poll one EVS disk until `ready` holds, failing fast on error states.
+async fn wait_for_evs_volume<F>(
+    client: &HwcClient,
+    region: &str,
+    volume_id: &str,
+    ready: F,
+) -> Result<EvsVolume, String>
+where
+    F: Fn(&EvsVolume) -> bool,
+{
+    let mut last_status = String::from("unknown");
+    for attempt in 1..=EVS_STATUS_POLL_ATTEMPTS {
+        match client.get_evs_volume(region, volume_id).await {
+            Ok(volume) => {
+                if ready(&volume) {
+                    return Ok(volume);
+                }
+                let status = volume.status.as_deref().unwrap_or("").trim();
+                if status.to_ascii_lowercase().starts_with("error") {
+                    return Err(format!("EVS disk {} entered status {}.", volume_id, status));
+                }
+                if !status.is_empty() {
+                    last_status = status.to_string();
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to poll EVS disk status: region={} volume_id={} error={}",
+                    region, volume_id, err
+                );
+            }
+        }
+        if attempt < EVS_STATUS_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(EVS_STATUS_POLL_INTERVAL_SECS)).await;
+        }
+    }
+    Err(format!(
+        "Timed out waiting for EVS disk {} (last status: {}).",
+        volume_id, last_status
+    ))
+}
+
+/// Snapshot names are capped at 64 characters; the device keeps names readable per disk.
+fn build_evs_snapshot_name(
+    prefix: &str,
+    volume_id: &str,
+    device: Option<&str>,
+    stamp: &str,
+) -> String {
+    let disk = device
+        .and_then(|device| device.trim().rsplit('/').next())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| volume_id.get(..8).unwrap_or(volume_id));
+    let name = format!("{}-{}-{}", prefix.trim(), disk, stamp);
+    name.chars().take(EVS_SNAPSHOT_NAME_MAX).collect()
+}
+
+/// Poll one snapshot (listed under its disk) until it is available.
+async fn wait_for_evs_snapshot(
+    client: &HwcClient,
+    region: &str,
+    volume_id: &str,
+    snapshot_id: &str,
+) -> Result<EvsSnapshot, String> {
+    let mut last_status = String::from("unknown");
+    for attempt in 1..=EVS_STATUS_POLL_ATTEMPTS {
+        match client.list_evs_snapshots(region, Some(volume_id)).await {
+            Ok(response) => {
+                if let Some(snapshot) = response
+                    .snapshots
+                    .into_iter()
+                    .find(|snapshot| snapshot.id.as_deref() == Some(snapshot_id))
+                {
+                    let status = snapshot.status.as_deref().unwrap_or("").trim().to_string();
+                    if status.eq_ignore_ascii_case("available") {
+                        return Ok(snapshot);
+                    }
+                    if status.to_ascii_lowercase().starts_with("error") {
+                        return Err(format!(
+                            "EVS snapshot {} entered status {}.",
+                            snapshot_id, status
+                        ));
+                    }
+                    if !status.is_empty() {
+                        last_status = status;
+                    }
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to poll EVS snapshot status: region={} snapshot_id={} error={}",
+                    region, snapshot_id, err
+                );
+            }
+        }
+        if attempt < EVS_STATUS_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(EVS_STATUS_POLL_INTERVAL_SECS)).await;
+        }
+    }
+    Err(format!(
+        "Timed out waiting for EVS snapshot {} (last status: {}).",
+        snapshot_id, last_status
+    ))
+}
+
+/// Create one snapshot and wait for it; attached disks need `force`.
+async fn snapshot_evs_volume(
+    client: &HwcClient,
+    region: &str,
+    volume_id: &str,
+    name: String,
+    description: Option<String>,
+) -> Result<EvsSnapshot, String> {
+    let body = EvsSnapshotCreateBody {
+        volume_id: volume_id.to_string(),
+        name,
+        description,
+        force: true,
+    };
+    let snapshot = client
+        .create_evs_snapshot(region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create EVS snapshot: region={} volume_id={} error={}",
+                region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    let snapshot_id = snapshot
+        .id
+        .clone()
+        .ok_or_else(|| "EVS snapshot create response did not contain snapshot.id.".to_string())?;
+    wait_for_evs_snapshot(client, region, volume_id, &snapshot_id).await
+}
+
+fn validate_bandwidth_size(charge_mode: &str, size: u32) -> Result<(), String> {
+    let max = if charge_mode == "bandwidth" {
+        MAX_BANDWIDTH_SIZE_BANDWIDTH_MODE
+    } else {
+        MAX_BANDWIDTH_SIZE
+    };
+    if !(MIN_BANDWIDTH_SIZE..=max).contains(&size) {
+        return Err(format!(
+            "Bandwidth size must be between {} and {} Mbit/s for charge_mode={}.",
+            MIN_BANDWIDTH_SIZE, max, charge_mode
+        ));
+    }
+    Ok(())
+}
+
+fn validate_shared_bandwidth_size(size: u32) -> Result<(), String> {
+    if !(MIN_SHARED_BANDWIDTH_SIZE..=MAX_BANDWIDTH_SIZE_BANDWIDTH_MODE).contains(&size) {
+        return Err(format!(
+            "Shared bandwidth size must be between {} and {} Mbit/s.",
+            MIN_SHARED_BANDWIDTH_SIZE, MAX_BANDWIDTH_SIZE_BANDWIDTH_MODE
+        ));
+    }
+    Ok(())
+}
+
+/// Build the update body for a bandwidth, or `None` when nothing would change.
+/// Shared bandwidths keep their charge mode; the size is checked against the target mode.
+fn resolve_bandwidth_update(
+    current: &EipBandwidth,
+    size: Option<u32>,
+    charge_mode: Option<&str>,
+) -> Result<Option<BandwidthUpdateBody>, String> {
+    let is_shared = current
+        .share_type
+        .as_deref()
+        .is_some_and(|value| value.eq_ignore_ascii_case("WHOLE"));
+    let current_mode = current
+        .charge_mode
+        .as_deref()
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_else(|| DEFAULT_BANDWIDTH_CHARGE_MODE.to_string());
+    let requested_mode = charge_mode.map(str::trim).map(str::to_ascii_lowercase);
+    if let Some(mode) = requested_mode.as_deref() {
+        if !matches!(mode, "traffic" | "bandwidth") {
+            return Err(format!(
+                "Bandwidth charge mode must be 'traffic' or 'bandwidth', got '{}'.",
+                mode
+            ));
+        }
+    }
+    let mode_change = requested_mode.filter(|mode| *mode != current_mode);
+    if is_shared && mode_change.is_some() {
+        return Err("The charge mode of a shared bandwidth cannot be changed.".to_string());
+    }
+    let size_change = size.filter(|size| Some(*size) != current.size);
+
+    if let Some(size) = size_change {
+        if is_shared {
+            validate_shared_bandwidth_size(size)?;
+        } else {
+            validate_bandwidth_size(mode_change.as_deref().unwrap_or(&current_mode), size)?;
+        }
+    } else if let (Some(mode), Some(size)) = (mode_change.as_deref(), current.size) {
+        // Switching to traffic billing lowers the ceiling; the current size must still fit.
+        validate_bandwidth_size(mode, size)?;
+    }
+
+    if size_change.is_none() && mode_change.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(BandwidthUpdateBody {
+        name: None,
+        size: size_change,
+        charge_mode: mode_change,
+    }))
+}
+
+fn bandwidth_member_ids(bandwidth: &EipBandwidth) -> Vec<String> {
+    bandwidth
+        .publicip_info
+        .iter()
+        .filter_map(|info| info.publicip_id.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Split `eip_ids` into (members of `bandwidth`, non-members), keeping input order.
+fn partition_bandwidth_members(
+    bandwidth: &EipBandwidth,
+    eip_ids: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let members: HashSet<String> = bandwidth_member_ids(bandwidth).into_iter().collect();
+    eip_ids.iter().cloned().partition(|id| members.contains(id))
+}
+
+fn build_eip_allocate_request(params: &EipAllocateParams) -> Result<CreatePublicIpRequest, String> {
+    let trimmed = |value: Option<&str>| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let ip_type = trimmed(params.eip_type.as_deref()).unwrap_or_else(|| DEFAULT_EIP_TYPE.into());
+    let share_type = trimmed(params.share_type.as_deref())
+        .map(|value| value.to_ascii_uppercase())
+        .unwrap_or_else(|| DEFAULT_BANDWIDTH_SHARE_TYPE.into());
+    let alias = trimmed(params.alias.as_deref());
+
+    let bandwidth = match share_type.as_str() {
+        "PER" => {
+            let charge_mode = trimmed(params.charge_mode.as_deref())
+                .map(|value| value.to_ascii_lowercase())
+                .unwrap_or_else(|| DEFAULT_BANDWIDTH_CHARGE_MODE.into());
+            if !matches!(charge_mode.as_str(), "traffic" | "bandwidth") {
+                return Err(format!(
+                    "Bandwidth charge mode must be 'traffic' or 'bandwidth', got '{}'.",
+                    charge_mode
+                ));
+            }
+            let size = params.bandwidth_size.unwrap_or(DEFAULT_BANDWIDTH_SIZE);
+            validate_bandwidth_size(&charge_mode, size)?;
+            let name = trimmed(params.bandwidth_name.as_deref())
+                .unwrap_or_else(|| format!("hc-forge-eip-{}", Utc::now().format("%Y%m%d%H%M%S")));
+            CreatePublicIpBandwidth {
+                id: None,
+                name: Some(name),
+                size: Some(size),
+                share_type,
+                charge_mode: Some(charge_mode),
+            }
+        }
+        "WHOLE" => {
+            let id = trimmed(params.shared_bandwidth_id.as_deref()).ok_or_else(|| {
+                "Shared bandwidth ID is required when share type is WHOLE.".to_string()
+            })?;
+            CreatePublicIpBandwidth {
+                id: Some(id),
+                name: None,
+                size: None,
+                share_type,
+                charge_mode: None,
+            }
+        }
+        _ => {
+            return Err(format!(
+                "Bandwidth share type must be 'PER' or 'WHOLE', got '{}'.",
+                share_type
+            ))
+        }
+    };
+
+    let enterprise_project_id =
+        normalize_enterprise_project_id(params.enterprise_project_id.as_deref(), false)?;
+
+    Ok(CreatePublicIpRequest {
+        enterprise_project_id,
+        publicip: CreatePublicIpBody { ip_type, alias },
+        bandwidth,
+    })
+}
+
+fn check_cce_default_cidr_conflict(label: &str, cidr: &str) -> Result<(), String> {
+    let parsed = parse_ipv4_cidr(cidr)?;
+    for (kind, default_cidr) in [
+        ("container", CCE_DEFAULT_CONTAINER_CIDR),
+        ("service", CCE_DEFAULT_SERVICE_CIDR),
+    ] {
+        if ipv4_cidr_overlaps(parsed, parse_ipv4_cidr(default_cidr)?) {
+            return Err(format!(
+                "{} CIDR {} overlaps the default CCE {} CIDR {}. Choose another range or allow the overlap explicitly.",
+                label, cidr, kind, default_cidr
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_vpc_cidr(input: &str, allow_cce_overlap: bool) -> Result<String, String> {
+    let cidr = normalize_ipv4_network_cidr(input)?;
+    let (network, prefix) = parse_ipv4_cidr(&cidr)?;
+    let in_private_range = VPC_PRIVATE_CIDRS.iter().any(|range| {
+        parse_ipv4_cidr(range)
+            .map(|range| prefix >= range.1 && ipv4_cidr_contains(range, network))
+            .unwrap_or(false)
+    });
+    if !in_private_range {
+        return Err(format!(
+            "VPC CIDR {} must be inside {}.",
+            cidr,
+            VPC_PRIVATE_CIDRS.join(", ")
+        ));
+    }
+    if prefix > VPC_CIDR_MAX_PREFIX {
+        return Err(format!(
+            "VPC CIDR {} must use a prefix of /{} or shorter.",
+            cidr, VPC_CIDR_MAX_PREFIX
+        ));
+    }
+    if !allow_cce_overlap {
+        check_cce_default_cidr_conflict("VPC", &cidr)?;
+    }
+    Ok(cidr)
+}
+
+fn validate_subnet_cidr(
+    input: &str,
+    vpc_cidr: Option<&str>,
+    siblings: &[Subnet],
+    allow_cce_overlap: bool,
+) -> Result<String, String> {
+    let cidr = normalize_ipv4_network_cidr(input)?;
+    let parsed = parse_ipv4_cidr(&cidr)?;
+    if parsed.1 > SUBNET_CIDR_MAX_PREFIX {
+        return Err(format!(
+            "Subnet CIDR {} must use a prefix of /{} or shorter.",
+            cidr, SUBNET_CIDR_MAX_PREFIX
+        ));
+    }
+    if let Some(vpc_cidr) = vpc_cidr.map(str::trim).filter(|value| !value.is_empty()) {
+        let vpc = parse_ipv4_cidr(vpc_cidr)?;
+        if parsed.1 < vpc.1 || !ipv4_cidr_contains(vpc, parsed.0) {
+            return Err(format!(
+                "Subnet CIDR {} is outside the VPC CIDR {}.",
+                cidr, vpc_cidr
+            ));
+        }
+    }
+    for sibling in siblings {
+        // Existing subnets come from the API; an unparsable CIDR there is not the caller's fault.
+        let Ok(sibling_cidr) = parse_ipv4_cidr(&sibling.cidr) else {
+            continue;
+        };
+        if ipv4_cidr_overlaps(parsed, sibling_cidr) {
+            return Err(format!(
+                "Subnet CIDR {} overlaps subnet {} ({}).",
+                cidr, sibling.name, sibling.cidr
+            ));
+        }
+    }
+    if !allow_cce_overlap {
+        check_cce_default_cidr_conflict("Subnet", &cidr)?;
+    }
+    Ok(cidr)
+}
+
+fn resolve_subnet_gateway(cidr: &str, gateway_ip: Option<&str>) -> Result<String, String> {
+    let (network, prefix) = parse_ipv4_cidr(cidr)?;
+    let network_u32 = u32::from(network);
+    let broadcast_u32 = network_u32 | u32::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+    let Some(gateway_ip) = gateway_ip.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(std::net::Ipv4Addr::from(network_u32 + 1).to_string());
+    };
+    let gateway = gateway_ip
+        .parse::<std::net::Ipv4Addr>()
+        .map_err(|_| format!("Gateway IP '{}' is not a valid IPv4 address.", gateway_ip))?;
+    let gateway_u32 = u32::from(gateway);
+    if !ipv4_cidr_contains((network, prefix), gateway)
+        || gateway_u32 == network_u32
+        || gateway_u32 == broadcast_u32
+    {
+        return Err(format!(
+            "Gateway IP {} must be a usable address inside {}.",
+            gateway, cidr
+        ));
+    }
+    Ok(gateway.to_string())
+}
+
+fn normalize_id_list(input: Option<Vec<String>>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    input
+        .unwrap_or_default()
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty() && seen.insert(id.clone()))
+        .collect()
+}
+
+fn extract_cluster_kubeconfig(raw_body: &str) -> Option<String> {
+    let trimmed = raw_body.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let payload: Value = match serde_json::from_str(trimmed) {
+        Ok(value) => value,
+        Err(_) => return Some(trimmed.to_string()),
+    };
+    if let Some(value) = payload
+        .get("kubeconfig")
+        .or_else(|| payload.get("kube_config"))
+        .or_else(|| payload.get("kubeConfig"))
+        .or_else(|| payload.get("config"))
+        .and_then(Value::as_str)
+    {
+        let text = value.trim();
+        if !text.is_empty() {
+            return Some(text.to_string());
+        }
+    }
+    if let Some(certs) = payload.get("certs") {
+        if let Some(value) = certs
+            .get("kubeconfig")
+            .or_else(|| certs.get("kube_config"))
+            .or_else(|| certs.get("kubeConfig"))
+            .or_else(|| certs.get("config"))
+            .and_then(Value::as_str)
+        {
+            let text = value.trim();
+            if !text.is_empty() {
+                return Some(text.to_string());
+            }
+        }
+    }
+    if payload.get("clusters").is_some()
+        && payload.get("contexts").is_some()
+        && payload.get("users").is_some()
+    {
+        return serde_json::to_string_pretty(&payload).ok();
+    }
+    None
+}
+
+async fn load_quota_usages(
+    client: &HwcClient,
+    region: &str,
+    services: &[QuotaService],
+) -> (Vec<QuotaUsage>, Vec<String>) {
+    let results = stream::iter(services.iter().copied())
+        .map(|service| async move {
+            let result = match service {
+                QuotaService::Ecs => client
+                    .get_ecs_limits(region)
+                    .await
+                    .map(|response| ecs_quota_usages(&response)),
+                QuotaService::Evs => client
+                    .get_evs_quotas(region)
+                    .await
+                    .map(|response| evs_quota_usages(&response)),
+                QuotaService::Vpc => client
+                    .get_vpc_quotas(region)
+                    .await
+                    .map(|response| vpc_quota_usages(&response)),
+                QuotaService::Cce => client
+                    .get_cce_quotas(region)
+                    .await
+                    .map(|response| cce_quota_usages(&response)),
+            };
+            result.map_err(|err| format!("{:?}: {}", service, err))
+        })
+        .buffered(QUOTA_LOOKUP_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut usages = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(items) => usages.extend(items),
+            Err(err) => errors.push(err),
+        }
+    }
+    (usages, errors)
+}
+
+/// Fail fast when a create would exceed remaining quota. Quota lookups that fail
+/// are logged and skipped so an unavailable quota API never blocks a create.
+async fn quota_preflight(
+    client: &HwcClient,
+    region: &str,
+    demands: &[(QuotaResource, u64)],
+) -> Result<(), String> {
+    let (usages, errors) = load_quota_usages(client, region, &quota_services(demands)).await;
+    for err in errors {
+        warn!(
+            "Skipping quota pre-flight check: region={} error={}",
+            region, err
+        );
+    }
+    let shortfalls = quota_shortfalls(&usages, demands);
+    if shortfalls.is_empty() {
+        return Ok(());
+    }
+    let failure = quota_check_error(shortfalls);
+    warn!("{}", failure.message);
+    Err(serde_json::to_string_pretty(&failure).unwrap_or(failure.message))
+}
+
+/// Read ECS, EVS, VPC and CCE quotas with current usage for one region.
+#[tauri::command]
+async fn get_quotas(
+    region: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<QuotaReport, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Querying quotas: source={} region={}", source_label, region);
+
+    let client = HwcClient::new(credentials);
+    let services = [
+        QuotaService::Ecs,
+        QuotaService::Evs,
+        QuotaService::Vpc,
+        QuotaService::Cce,
+    ];
+    let (quotas, errors) = load_quota_usages(&client, &region, &services).await;
+    if quotas.is_empty() && !errors.is_empty() {
+        error!(
+            "Failed to query quotas: region={} errors={}",
+            region,
+            errors.join("; ")
+        );
+        return Err(errors.join("; "));
+    }
+    for err in &errors {
+        warn!(
+            "Partial quota lookup failure: region={} error={}",
+            region, err
+        );
+    }
+
+    Ok(QuotaReport {
+        region,
+        quotas,
+        errors,
+    })
+}
+
+/// List enabled enterprise projects so creates and filters can be scoped by team.
+#[tauri::command]
+async fn list_enterprise_projects(
+    credentials: Option<CredentialsInput>,
+) -> Result<EnterpriseProjectListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Listing enterprise projects: source={}", source_label);
+
+    let client = HwcClient::new(credentials);
+    client.list_enterprise_projects().await.map_err(|err| {
+        error!("Failed to list enterprise projects: error={}", err);
+        err.to_string()
+    })
+}
+
+/// List VPCs for the given region so the UI can populate a dropdown.
+#[tauri::command]
+async fn list_vpcs(
+    region: String,
+    enterprise_project_id: Option<String>,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<Vpc>, String> {
+    let enterprise_project_id =
+        resolve_enterprise_project(enterprise_project_id.as_deref(), credentials.as_ref(), true)?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Listing VPCs: source={} region={}", source_label, region);
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_vpcs(&region, enterprise_project_id.as_deref())
+        .await
+        .map_err(|err| {
+            error!("Failed to list VPCs: region={} error={}", region, err);
+            err.to_string()
+        })
+}
+
+/// List subnets for the selected VPC so the UI can populate a dropdown.
+#[tauri::command]
+async fn list_subnets(
+    region: String,
+    vpc_id: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<Subnet>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing subnets: source={} region={} vpc_id={}",
+        source_label, region, vpc_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client.list_subnets(&region, &vpc_id).await.map_err(|err| {
+        error!(
+            "Failed to list subnets: region={} vpc_id={} error={}",
+            region, vpc_id, err
+        );
+        err.to_string()
+    })
+}
+
+/// List images for the given region so the UI can populate a dropdown.
+#[tauri::command]
+async fn list_images(
+    region: String,
+    filters: Option<ImageFilters>,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<Image>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Listing images: source={} region={}", source_label, region);
+
+    let client = HwcClient::new(credentials);
+    let filters = filters.map(|input| ImageListFilters {
+        visibility: input.visibility,
+        image_type: input.image_type,
+        flavor_id: input.flavor_id,
+    });
+    let images = client.list_images(&region, filters).await.map_err(|err| {
+        error!("Failed to list images: region={} error={}", region, err);
+        err.to_string()
+    })?;
+
+    info!("Found {} images in region {}", images.len(), region);
+
+    Ok(images)
+}
+
+/// Capture one ECS as a private image: its system disk, or every disk with `whole_image`.
+#[tauri::command]
+async fn create_image_from_ecs(
+    params: ImageCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
+    }
+    let name = normalize_ims_image_name(&params.name)?;
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let whole_image = params.whole_image.unwrap_or(false);
+    let vault_id = params
+        .vault_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if whole_image && vault_id.is_none() {
+        return Err("Whole images need a CBR vault ID.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating image from ECS: source={} region={} server_id={} name={} whole_image={}",
+        source_label, params.region, server_id, name, whole_image
+    );
+
+    let client = HwcClient::new(credentials);
+    let result = match vault_id.filter(|_| whole_image) {
+        Some(vault_id) => {
+            let body = WholeImageCreateRequest {
+                name: name.clone(),
+                instance_id: server_id.to_string(),
+                vault_id: vault_id.to_string(),
+                description,
+                enterprise_project_id,
+            };
+            client
+                .create_whole_image_from_server(&params.region, &body)
+                .await
+        }
+        None => {
+            let body = ImageCreateFromServerRequest {
+                name: name.clone(),
+                instance_id: server_id.to_string(),
+                description,
+                enterprise_project_id,
+            };
+            client.create_image_from_server(&params.region, &body).await
+        }
+    };
+    let (status, body) = result.map_err(|err| {
+        error!(
+            "Failed to create image from ECS: region={} server_id={} name={} error={}",
+            params.region, server_id, name, err
+        );
+        err.to_string()
+    })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+fn build_image_import_request(
+    params: &ImageImportParams,
+    bucket_name: &str,
+    object_key: &str,
+    enterprise_project_id: Option<String>,
+) -> Result<ImageImportRequest, String> {
+    ims_import_file_format(object_key)?;
+    let name = normalize_ims_image_name(&params.name)?;
+    let os_version = params.os_version.trim();
+    if os_version.is_empty() {
+        return Err("OS version is required.".to_string());
+    }
+    if !(IMS_IMPORT_MIN_DISK_MIN..=IMS_IMPORT_MIN_DISK_MAX).contains(&params.min_disk) {
+        return Err(format!(
+            "Minimum disk must be between {} and {} GiB.",
+            IMS_IMPORT_MIN_DISK_MIN, IMS_IMPORT_MIN_DISK_MAX
+        ));
+    }
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    Ok(ImageImportRequest {
+        name,
+        description,
+        image_url: format!("{}:{}", bucket_name, object_key),
+        os_version: os_version.to_string(),
+        min_disk: params.min_disk,
+        image_type: "ECS".to_string(),
+        enterprise_project_id,
+    })
+}
+
+/// Part size that keeps an upload of `total_bytes` within the OBS part limit.
+fn obs_multipart_part_size(total_bytes: u64) -> u64 {
+    OBS_MULTIPART_PART_SIZE.max(total_bytes.div_ceil(OBS_MULTIPART_MAX_PARTS))
+}
+
+fn emit_image_import_progress(
+    app_handle: &tauri::AppHandle,
+    bucket_name: &str,
+    object_key: &str,
+    stage: &str,
+    uploaded_bytes: u64,
+    total_bytes: u64,
+) {
+    let payload = ImageImportProgressEvent {
+        bucket_name: bucket_name.to_string(),
+        object_key: object_key.to_string(),
+        stage: stage.to_string(),
+        uploaded_bytes,
+        total_bytes,
+        at: Utc::now().to_rfc3339(),
+    };
+    if let Err(err) = app_handle.emit("image-import-progress", payload) {
+        warn!("Failed to emit image-import-progress event: {}", err);
+    }
+}
+
+/// Upload `total_bytes` read from `reader` as one OBS object: a single PutObject
+/// when it fits in one part, otherwise a multipart upload that is aborted on
+/// failure so no parts are billed. `progress` gets the uploaded byte count after
+/// each part; the final OBS response is returned whatever its status.
+async fn upload_obs_content<R>(
+    client: &HwcClient,
+    region: &str,
+    bucket_name: &str,
+    object_key: &str,
+    content_type: Option<&str>,
+    mut reader: R,
+    total_bytes: u64,
+    progress: impl Fn(u64),
+) -> Result<(reqwest::StatusCode, String), String>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let part_size = obs_multipart_part_size(total_bytes);
+    if total_bytes <= part_size {
+        let mut content = Vec::with_capacity(total_bytes as usize);
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|err| format!("Failed to read upload content: {}", err))?;
+        return client
+            .put_obs_object(region, bucket_name, object_key, content, content_type)
+            .await
+            .map_err(|err| err.to_string());
+    }
+
+    let upload_id = client
+        .initiate_obs_multipart_upload(
+            region,
+            bucket_name,
+            object_key,
+            content_type.unwrap_or(OBS_DEFAULT_CONTENT_TYPE),
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+    let uploaded = async {
+        let mut parts = Vec::new();
+        let mut uploaded_bytes = 0;
+        for part_number in 1u32.. {
+            let mut chunk = Vec::new();
+            (&mut reader)
+                .take(part_size)
+                .read_to_end(&mut chunk)
+                .await
+                .map_err(|err| format!("Failed to read upload content: {}", err))?;
+            if chunk.is_empty() {
+                break;
+            }
+            let chunk_len = chunk.len() as u64;
+            let etag = client
+                .upload_obs_part(
+                    region,
+                    bucket_name,
+                    object_key,
+                    &upload_id,
+                    part_number,
+                    chunk,
+                )
+                .await
+                .map_err(|err| err.to_string())?;
+            parts.push((part_number, etag));
+            uploaded_bytes += chunk_len;
+            progress(uploaded_bytes);
+        }
+        client
+            .complete_obs_multipart_upload(region, bucket_name, object_key, &upload_id, &parts)
+            .await
+            .map_err(|err| err.to_string())
+    }
+    .await;
+    if !matches!(&uploaded, Ok((status, _)) if status.is_success()) {
+        if let Err(abort_err) = client
+            .abort_obs_multipart_upload(region, bucket_name, object_key, &upload_id)
+            .await
+        {
+            warn!(
+                "Failed to abort OBS multipart upload: bucket={} key={} upload_id={} error={}",
+                bucket_name, object_key, upload_id, abort_err
+            );
+        }
+    }
+    uploaded
+}
+
+/// Upload a local image file to OBS and return its size.
+async fn upload_image_file_to_obs(
+    client: &HwcClient,
+    app_handle: &tauri::AppHandle,
+    region: &str,
+    bucket_name: &str,
+    object_key: &str,
+    path: &std::path::Path,
+) -> Result<u64, String> {
+    let total_bytes = tokio::fs::metadata(path)
+        .await
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?
+        .len();
+    if total_bytes == 0 {
+        return Err(format!("Image file {} is empty.", path.display()));
+    }
+    let file = tokio::fs::File::open(path)
+        .await
+        .map_err(|err| format!("Failed to open {}: {}", path.display(), err))?;
+    let progress = |stage: &str, uploaded_bytes: u64| {
+        emit_image_import_progress(
+            app_handle,
+            bucket_name,
+            object_key,
+            stage,
+            uploaded_bytes,
+            total_bytes,
+        )
+    };
+    progress("uploading", 0);
+
+    let (status, body) = upload_obs_content(
+        client,
+        region,
+        bucket_name,
+        object_key,
+        Some(OBS_DEFAULT_CONTENT_TYPE),
+        file,
+        total_bytes,
+        |uploaded_bytes| progress("uploading", uploaded_bytes),
+    )
+    .await?;
+    if !status.is_success() {
+        return Err(format!("OBS upload returned {}: {}", status, body));
+    }
+
+    progress("uploaded", total_bytes);
+    Ok(total_bytes)
+}
+
+/// Register a system image from an image file already stored in OBS.
+#[tauri::command]
+async fn import_image_from_obs(
+    params: ImageImportParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let body =
+        build_image_import_request(&params, &bucket_name, &object_key, enterprise_project_id)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Importing image from OBS: source={} region={} image_url={} name={}",
+        source_label, params.region, body.image_url, body.name
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .import_image_from_obs(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to import image from OBS: region={} bucket={} key={} error={}",
+                params.region, bucket_name, object_key, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+/// Upload a local image file to OBS and register it in one step.
+/// Progress is reported through `image-import-progress` events.
+#[tauri::command]
+async fn import_image_from_file(
+    app_handle: tauri::AppHandle,
+    params: ImageFileImportParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let import = &params.import;
+    let enterprise_project_id = resolve_enterprise_project(
+        import.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let file_path = params.file_path.trim();
+    if file_path.is_empty() {
+        return Err("Image file path is required.".to_string());
+    }
+    let path = std::path::Path::new(file_path);
+    let bucket_name = normalize_obs_bucket_name(
+        &import.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = match import.object_key.trim() {
+        "" => path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(str::to_string)
+            .ok_or_else(|| format!("Cannot derive an object key from {}.", file_path))?,
+        key => key.to_string(),
+    };
+    let object_key = normalize_obs_object_key(&object_key)?;
+    let body =
+        build_image_import_request(import, &bucket_name, &object_key, enterprise_project_id)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Uploading and importing image: source={} region={} file={} image_url={} name={}",
+        source_label, import.region, file_path, body.image_url, body.name
+    );
+
+    let client = HwcClient::new(credentials);
+    let total_bytes = upload_image_file_to_obs(
+        &client,
+        &app_handle,
+        &import.region,
+        &bucket_name,
+        &object_key,
+        path,
+    )
+    .await
+    .map_err(|err| {
+        error!(
+            "Failed to upload image file: region={} file={} bucket={} key={} error={}",
+            import.region, file_path, bucket_name, object_key, err
+        );
+        emit_image_import_progress(&app_handle, &bucket_name, &object_key, "failed", 0, 0);
+        err
+    })?;
+
+    emit_image_import_progress(
+        &app_handle,
+        &bucket_name,
+        &object_key,
+        "registering",
+        total_bytes,
+        total_bytes,
+    );
+    let (status, body) = client
+        .import_image_from_obs(&import.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to import image from OBS: region={} bucket={} key={} error={}",
+                import.region, bucket_name, object_key, err
+            );
+            emit_image_import_progress(
+                &app_handle,
+                &bucket_name,
+                &object_key,
+                "failed",
+                total_bytes,
+                total_bytes,
+            );
+            err.to_string()
+        })?;
+    let stage = if status.is_success() {
+        "submitted"
+    } else {
+        "failed"
+    };
+    emit_image_import_progress(
+        &app_handle,
+        &bucket_name,
+        &object_key,
+        stage,
+        total_bytes,
+        total_bytes,
+    );
+
+    Ok(ims_job_result(status, body))
+}
+
+/// Export one private image to an OBS bucket.
+#[tauri::command]
+async fn export_image_to_obs(
+    params: ImageExportParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let image_id = params.image_id.trim();
+    if image_id.is_empty() {
+        return Err("Image ID is required.".to_string());
+    }
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let file_format = normalize_ims_export_format(&params.file_format)?;
+    let body = ImageExportRequest {
+        bucket_url: format!("{}:{}", bucket_name, object_key),
+        file_format,
+        is_quick_export: params.quick_export,
+    };
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Exporting image to OBS: source={} region={} image_id={} bucket_url={} format={}",
+        source_label, params.region, image_id, body.bucket_url, body.file_format
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .export_image_to_obs(&params.region, image_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to export image to OBS: region={} image_id={} error={}",
+                params.region, image_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+/// Query one IMS job, e.g. an image capture or share started above.
+#[tauri::command]
+async fn get_image_job(
+    params: ImageJobParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let job_id = params.job_id.trim();
+    if job_id.is_empty() {
+        return Err("IMS job ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Querying IMS job: source={} region={} job_id={}",
+        source_label, params.region, job_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .get_ims_job(&params.region, job_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to query IMS job: region={} job_id={} error={}",
+                params.region, job_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+/// Delete one private image.
+#[tauri::command]
+async fn delete_image(
+    params: ImageDeleteParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let image_id = params.image_id.trim();
+    if image_id.is_empty() {
+        return Err("Image ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting image: source={} region={} image_id={}",
+        source_label, params.region, image_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_image(&params.region, image_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete image: region={} image_id={} error={}",
+                params.region, image_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+fn build_image_members_request(params: ImageShareParams) -> Result<ImageMembersRequest, String> {
+    let images = normalize_id_list(Some(params.image_ids));
+    if images.is_empty() {
+        return Err("At least one image ID is required.".to_string());
+    }
+    let projects = normalize_id_list(Some(params.project_ids))
+        .iter()
+        .map(|project_id| normalize_project_id(project_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    if projects.is_empty() {
+        return Err("At least one project ID is required.".to_string());
+    }
+    Ok(ImageMembersRequest { images, projects })
+}
+
+async fn update_image_members(
+    params: ImageShareParams,
+    credentials: Option<CredentialsInput>,
+    add: bool,
+) -> Result<ImsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.clone();
+    let body = build_image_members_request(params)?;
+    let action = if add { "Sharing" } else { "Unsharing" };
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "{} images: source={} region={} images={} projects={}",
+        action,
+        source_label,
+        region,
+        body.images.join(","),
+        body.projects.join(",")
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .update_image_members(&region, add, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to update image members: region={} add={} error={}",
+                region, add, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+/// Share private images with other projects; they must accept before use.
+#[tauri::command]
+async fn share_images(
+    params: ImageShareParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    update_image_members(params, credentials, true).await
+}
+
+/// Stop sharing private images with other projects.
+#[tauri::command]
+async fn unshare_images(
+    params: ImageShareParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    update_image_members(params, credentials, false).await
+}
+
+/// Accept or reject images another project shared with this region's project.
+#[tauri::command]
+async fn respond_image_share(
+    params: ImageShareResponseParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ImsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let image_ids = normalize_id_list(Some(params.image_ids));
+    if image_ids.is_empty() {
+        return Err("At least one image ID is required.".to_string());
+    }
+    let vault_id = params
+        .vault_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Responding to image share: source={} region={} images={} accept={}",
+        source_label,
+        params.region,
+        image_ids.join(","),
+        params.accept
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .respond_image_share(&params.region, &image_ids, params.accept, vault_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to respond to image share: region={} error={}",
+                params.region, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(ims_job_result(status, body))
+}
+
+/// List flavors for the given region so the UI can populate a dropdown.
+#[tauri::command]
+async fn list_flavors(
+    region: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<Flavor>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Listing flavors: source={} region={}", source_label, region);
+
+    let client = HwcClient::new(credentials);
+    client.list_flavors(&region).await.map_err(|err| {
+        error!("Failed to list flavors: region={} error={}", region, err);
+        err.to_string()
+    })
+}
+
+fn split_list_params(
+    input: Option<ListParamsInput>,
+    credentials: Option<&CredentialsInput>,
+) -> Result<(ListParams, Vec<TagFilter>), String> {
+    let input = input.unwrap_or_default();
+    let tag_filters = normalize_tag_filters(input.tags)?;
+    // Tagged IDs are matched against one listed page; paging past it would skip matches.
+    if !tag_filters.is_empty()
+        && (input.marker.is_some() || input.limit.is_some() || input.offset.is_some())
+    {
+        return Err(
+            "Tag filters apply to the first page of results only; remove limit, marker and offset."
+                .to_string(),
+        );
+    }
+    let enterprise_project_id =
+        resolve_enterprise_project(input.enterprise_project_id.as_deref(), credentials, true)?;
+    let params = ListParams {
+        marker: input.marker,
+        limit: input.limit,
+        offset: input.offset,
+        enterprise_project_id,
+    };
+    Ok((params, tag_filters))
+}
+
+/// IDs of the resources carrying every filtered tag, or `None` when there is no filter.
+async fn tagged_resource_ids(
+    client: &HwcClient,
+    region: &str,
+    service: TagService,
+    filters: &[TagFilter],
+) -> Result<Option<HashSet<String>>, String> {
+    if filters.is_empty() {
+        return Ok(None);
+    }
+    let resources = client
+        .filter_resources_by_tags(region, service, filters)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to filter resources by tag: region={} service={:?} error={}",
+                region, service, err
+            );
+            err.to_string()
+        })?;
+    Ok(Some(
+        resources
+            .into_iter()
+            .filter_map(|resource| resource.resource_id)
+            .collect(),
+    ))
+}
+
+/// List elastic IPs for the given region.
+#[tauri::command]
+async fn list_eips(
+    region: String,
+    params: Option<ListParamsInput>,
+    credentials: Option<CredentialsInput>,
+) -> Result<EipListResponse, String> {
+    let (params, tag_filters) = split_list_params(params, credentials.as_ref())?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!("Listing EIPs: source={} region={}", source_label, region);
+
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_eips(&region, Some(params))
+        .await
+        .map_err(|err| {
+            error!("Failed to list EIPs: region={} error={:#}", region, err);
+            err.to_string()
+        })?;
+    if let Some(ids) = tagged_resource_ids(&client, &region, TagService::Eip, &tag_filters).await? {
+        response
+            .publicips
+            .retain(|item| item.id.as_ref().is_some_and(|id| ids.contains(id)));
+    }
+
+    Ok(response)
+}
+
+/// List ECS instances for the given region.
+#[tauri::command]
+async fn list_ecses(
+    region: String,
+    params: Option<ListParamsInput>,
+    credentials: Option<CredentialsInput>,
+) -> Result<EcsListResponse, String> {
+    let (params, tag_filters) = split_list_params(params, credentials.as_ref())?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing ECS instances: source={} region={}",
+        source_label, region
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_ecses(&region, Some(params))
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list ECS instances: region={} error={}",
+                region, err
+            );
+            err.to_string()
+        })?;
+    if let Some(ids) = tagged_resource_ids(&client, &region, TagService::Ecs, &tag_filters).await? {
+        response
+            .servers
+            .retain(|item| item.id.as_ref().is_some_and(|id| ids.contains(id)));
+    }
+
+    Ok(response)
+}
+
+/// List EVS disks for the given region.
+#[tauri::command]
+async fn list_evss(
+    region: String,
+    params: Option<ListParamsInput>,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsListResponse, String> {
+    let (params, tag_filters) = split_list_params(params, credentials.as_ref())?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing EVS disks: source={} region={}",
+        source_label, region
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_evss(&region, Some(params))
+        .await
+        .map_err(|err| {
+            error!("Failed to list EVS disks: region={} error={}", region, err);
+            err.to_string()
+        })?;
+    if let Some(ids) = tagged_resource_ids(&client, &region, TagService::Evs, &tag_filters).await? {
+        response
+            .volumes
+            .retain(|item| item.id.as_ref().is_some_and(|id| ids.contains(id)));
+    }
+
+    Ok(response)
+}
+
+/// Create one pay-per-use EVS disk and wait until it is available.
+#[tauri::command]
+async fn create_evs(
+    params: EvsCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let availability_zone = params.availability_zone.trim();
+    if availability_zone.is_empty() {
+        return Err("Availability zone is required.".to_string());
+    }
+    let volume_type = normalize_evs_volume_type(&params.volume_type)?;
+    if !(EVS_VOLUME_SIZE_MIN..=EVS_VOLUME_SIZE_MAX).contains(&params.size) {
+        return Err(format!(
+            "EVS disk size must be between {} and {} GiB.",
+            EVS_VOLUME_SIZE_MIN, EVS_VOLUME_SIZE_MAX
+        ));
+    }
+    let name = params
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("hc-forge-evs-{}", Utc::now().format("%Y%m%d%H%M%S")));
+
+    let tags = normalize_tags(params.tags.clone())?;
+    enforce_tag_policy(&tags, TagPolicyCheck::Create)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating EVS disk: source={} region={} az={} type={} size={} multiattach={}",
+        source_label,
+        params.region,
+        availability_zone,
+        volume_type,
+        params.size,
+        params.multiattach.unwrap_or(false)
+    );
+
+    let client = HwcClient::new(credentials);
+    quota_preflight(
+        &client,
+        &params.region,
+        &[
+            (QuotaResource::EvsVolumes, 1),
+            (QuotaResource::EvsGigabytes, u64::from(params.size)),
+        ],
+    )
+    .await?;
+    let body = EvsCreateBody {
+        availability_zone: availability_zone.to_string(),
+        volume_type,
+        size: params.size,
+        name,
+        multiattach: params.multiattach,
+        tags: tags.into_iter().map(|tag| (tag.key, tag.value)).collect(),
+        enterprise_project_id,
+    };
+    let (status, raw) = client
+        .create_evs_volume(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create EVS disk: region={} error={}",
+                params.region, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() {
+        return Ok(evs_operation_result(status, raw));
+    }
+
+    let created: EvsCreateResponse = serde_json::from_str(&raw).unwrap_or_default();
+    let mut summary = json!({
+        "job_id": created.job_id,
+        "volume_ids": created.volume_ids,
+        "response": parse_json_or_string(&raw),
+    });
+    if let Some(volume_id) = created.volume_ids.first() {
+        match wait_for_evs_volume(&client, &params.region, volume_id, |volume| {
+            evs_status_is(volume, &["available"])
+        })
+        .await
+        {
+            Ok(volume) => summary["final_status"] = json!(volume.status),
+            Err(err) => {
+                warn!(
+                    "EVS disk did not become available: region={} volume_id={} error={}",
+                    params.region, volume_id, err
+                );
+                summary["wait_error"] = json!(err);
+            }
+        }
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Attach one EVS disk to an ECS instance and wait until it is in use.
+#[tauri::command]
+async fn attach_evs(
+    params: EvsAttachParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = params.volume_id.trim();
+    if volume_id.is_empty() {
+        return Err("EVS disk ID is required.".to_string());
+    }
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
+    }
+    let device = normalize_evs_device_name(params.device.as_deref())?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Attaching EVS disk: source={} region={} volume_id={} server_id={} device={}",
+        source_label,
+        params.region,
+        volume_id,
+        server_id,
+        device.as_deref().unwrap_or("auto")
+    );
+
+    let client = HwcClient::new(credentials);
+    let volume = client
+        .get_evs_volume(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EVS disk before attach: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    if let Some(attachment) = find_evs_attachment(&volume, Some(server_id))? {
+        let summary = json!({
+            "volume_id": volume_id,
+            "server_id": server_id,
+            "device": attachment.device,
+            "status": "already_attached",
+        });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(evs_operation_result(reqwest::StatusCode::OK, body));
+    }
+    let shareable = volume.multiattach == Some(true) && evs_status_is(&volume, &["in-use"]);
+    if !evs_status_is(&volume, &["available"]) && !shareable {
+        return Err(format!(
+            "EVS disk {} is {} and cannot be attached.",
+            volume_id,
+            volume.status.as_deref().unwrap_or("in an unknown state")
+        ));
+    }
+
+    let (status, raw) = client
+        .attach_ecs_volume(&params.region, server_id, volume_id, device.as_deref())
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to attach EVS disk: region={} volume_id={} server_id={} error={}",
+                params.region, volume_id, server_id, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() {
+        return Ok(evs_operation_result(status, raw));
+    }
+
+    let mut summary = json!({
+        "volume_id": volume_id,
+        "server_id": server_id,
+        "job_id": extract_job_id(&raw),
+    });
+    match wait_for_evs_volume(&client, &params.region, volume_id, |volume| {
+        evs_status_is(volume, &["in-use"])
+            && volume
+                .attachments
+                .iter()
+                .any(|attachment| attachment.server_id.as_deref() == Some(server_id))
+    })
+    .await
+    {
+        Ok(volume) => {
+            summary["final_status"] = json!(volume.status);
+            summary["device"] = json!(find_evs_attachment(&volume, Some(server_id))
+                .ok()
+                .flatten()
+                .and_then(|attachment| attachment.device.clone()));
+        }
+        Err(err) => {
+            warn!(
+                "EVS disk did not reach in-use after attach: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            summary["wait_error"] = json!(err);
+        }
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Detach one data disk from an ECS instance; system disks are refused.
+#[tauri::command]
+async fn detach_evs(
+    params: EvsDetachParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = params.volume_id.trim();
+    if volume_id.is_empty() {
+        return Err("EVS disk ID is required.".to_string());
+    }
+    let server_id = params
+        .server_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Detaching EVS disk: source={} region={} volume_id={} server_id={}",
+        source_label,
+        params.region,
+        volume_id,
+        server_id.unwrap_or("-")
+    );
+
+    let client = HwcClient::new(credentials);
+    let volume = client
+        .get_evs_volume(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EVS disk before detach: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    let Some(attachment) = find_evs_attachment(&volume, server_id)? else {
+        let summary = json!({
+            "volume_id": volume_id,
+            "server_id": server_id,
+            "status": "not_attached",
+        });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(evs_operation_result(reqwest::StatusCode::OK, body));
+    };
+    ensure_evs_detachable(&volume, attachment)?;
+    let server_id = attachment
+        .server_id
+        .clone()
+        .ok_or_else(|| format!("EVS disk {} attachment has no server ID.", volume_id))?;
+
+    let (status, raw) = client
+        .detach_ecs_volume(&params.region, &server_id, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to detach EVS disk: region={} volume_id={} server_id={} error={}",
+                params.region, volume_id, server_id, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() {
+        return Ok(evs_operation_result(status, raw));
+    }
+
+    let mut summary = json!({
+        "volume_id": volume_id,
+        "server_id": server_id,
+        "job_id": extract_job_id(&raw),
+    });
+    match wait_for_evs_volume(&client, &params.region, volume_id, |volume| {
+        evs_status_is(volume, &["available", "in-use"])
+            && !volume
+                .attachments
+                .iter()
+                .any(|attachment| attachment.server_id.as_deref() == Some(server_id.as_str()))
+    })
+    .await
+    {
+        Ok(volume) => summary["final_status"] = json!(volume.status),
+        Err(err) => {
+            warn!(
+                "EVS disk did not settle after detach: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            summary["wait_error"] = json!(err);
+        }
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Expand one EVS disk and wait for the new size to apply.
+#[tauri::command]
+async fn expand_evs(
+    params: EvsExpandParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = params.volume_id.trim();
+    if volume_id.is_empty() {
+        return Err("EVS disk ID is required.".to_string());
+    }
+    if params.new_size > EVS_VOLUME_SIZE_MAX {
+        return Err(format!(
+            "EVS disk size cannot exceed {} GiB.",
+            EVS_VOLUME_SIZE_MAX
+        ));
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Expanding EVS disk: source={} region={} volume_id={} new_size={}",
+        source_label, params.region, volume_id, params.new_size
+    );
+
+    let client = HwcClient::new(credentials);
+    let volume = client
+        .get_evs_volume(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EVS disk before expand: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    if let Some(current) = volume.size {
+        if params.new_size <= current {
+            return Err(format!(
+                "New size must be larger than the current {} GiB; EVS disks cannot shrink.",
+                current
+            ));
+        }
+    }
+    if !evs_status_is(&volume, &["available", "in-use"]) {
+        return Err(format!(
+            "EVS disk {} is {} and cannot be expanded.",
+            volume_id,
+            volume.status.as_deref().unwrap_or("in an unknown state")
+        ));
+    }
+
+    let (status, raw) = client
+        .extend_evs_volume(&params.region, volume_id, params.new_size)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to expand EVS disk: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() {
+        return Ok(evs_operation_result(status, raw));
+    }
+
+    let new_size = params.new_size;
+    let mut summary = json!({
+        "volume_id": volume_id,
+        "new_size": new_size,
+        "job_id": extract_job_id(&raw),
+    });
+    match wait_for_evs_volume(&client, &params.region, volume_id, |volume| {
+        evs_status_is(volume, &["available", "in-use"])
+            && volume.size.is_some_and(|size| size >= new_size)
+    })
+    .await
+    {
+        Ok(volume) => {
+            summary["final_status"] = json!(volume.status);
+            summary["size"] = json!(volume.size);
+        }
+        Err(err) => {
+            warn!(
+                "EVS disk did not settle after expand: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            summary["wait_error"] = json!(err);
+        }
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Delete one detached EVS disk.
+#[tauri::command]
+async fn delete_evs(
+    params: EvsDeleteParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = params.volume_id.trim();
+    if volume_id.is_empty() {
+        return Err("EVS disk ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting EVS disk: source={} region={} volume_id={}",
+        source_label, params.region, volume_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let volume = client
+        .get_evs_volume(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EVS disk before delete: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    if !volume.attachments.is_empty() {
+        return Err(format!(
+            "EVS disk {} is attached to {} server(s). Detach it first.",
+            volume_id,
+            volume.attachments.len()
+        ));
+    }
+    if !evs_status_is(
+        &volume,
+        &["available", "error", "error_restoring", "error_extending"],
+    ) {
+        return Err(format!(
+            "EVS disk {} is {} and cannot be deleted.",
+            volume_id,
+            volume.status.as_deref().unwrap_or("in an unknown state")
+        ));
+    }
+
+    let (status, body) = client
+        .delete_evs_volume(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete EVS disk: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(evs_operation_result(status, body))
+}
+
+/// List EVS snapshots, optionally filtered to one disk.
+#[tauri::command]
+async fn list_evs_snapshots(
+    region: String,
+    volume_id: Option<String>,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsSnapshotListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = volume_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing EVS snapshots: source={} region={} volume_id={}",
+        source_label,
+        region,
+        volume_id.unwrap_or("-")
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_evs_snapshots(&region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list EVS snapshots: region={} error={}",
+                region, err
+            );
+            err.to_string()
+        })
+}
+
+/// Snapshot one EVS disk and wait until the snapshot is available.
+#[tauri::command]
+async fn create_evs_snapshot(
+    params: EvsSnapshotCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let volume_id = params.volume_id.trim();
+    if volume_id.is_empty() {
+        return Err("EVS disk ID is required.".to_string());
+    }
+    let stamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let name = params
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| build_evs_snapshot_name("hc-forge-snap", volume_id, None, &stamp));
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating EVS snapshot: source={} region={} volume_id={} name={}",
+        source_label, params.region, volume_id, name
+    );
+
+    let client = HwcClient::new(credentials);
+    let snapshot =
+        snapshot_evs_volume(&client, &params.region, volume_id, name, description).await?;
+    let body = serde_json::to_string_pretty(&snapshot).unwrap_or_default();
+    Ok(evs_operation_result(reqwest::StatusCode::OK, body))
+}
+
+/// Delete one EVS snapshot.
+#[tauri::command]
+async fn delete_evs_snapshot(
+    params: EvsSnapshotDeleteParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let snapshot_id = params.snapshot_id.trim();
+    if snapshot_id.is_empty() {
+        return Err("Snapshot ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting EVS snapshot: source={} region={} snapshot_id={}",
+        source_label, params.region, snapshot_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_evs_snapshot(&params.region, snapshot_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete EVS snapshot: region={} snapshot_id={} error={}",
+                params.region, snapshot_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(evs_operation_result(status, body))
+}
+
+/// Roll a detached EVS disk back to one of its snapshots.
+#[tauri::command]
+async fn rollback_evs_snapshot(
+    params: EvsSnapshotRollbackParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let snapshot_id = params.snapshot_id.trim();
+    if snapshot_id.is_empty() {
+        return Err("Snapshot ID is required.".to_string());
+    }
+    let volume_id = params
+        .volume_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Rolling back EVS snapshot: source={} region={} snapshot_id={} volume_id={}",
+        source_label,
+        params.region,
+        snapshot_id,
+        volume_id.unwrap_or("-")
+    );
+
+    let client = HwcClient::new(credentials);
+    let snapshot = client
+        .list_evs_snapshots(&params.region, volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list EVS snapshots before rollback: region={} error={}",
+                params.region, err
+            );
+            err.to_string()
+        })?
+        .snapshots
+        .into_iter()
+        .find(|snapshot| snapshot.id.as_deref() == Some(snapshot_id))
+        .ok_or_else(|| format!("EVS snapshot {} was not found.", snapshot_id))?;
+    if !snapshot
+        .status
+        .as_deref()
+        .is_some_and(|status| status.eq_ignore_ascii_case("available"))
+    {
+        return Err(format!(
+            "EVS snapshot {} is {} and cannot be rolled back.",
+            snapshot_id,
+            snapshot.status.as_deref().unwrap_or("in an unknown state")
+        ));
+    }
+    let volume_id = snapshot
+        .volume_id
+        .clone()
+        .ok_or_else(|| format!("EVS snapshot {} has no source disk.", snapshot_id))?;
+
+    let volume = client
+        .get_evs_volume(&params.region, &volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EVS disk before rollback: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            err.to_string()
+        })?;
+    if volume.bootable == Some(true) && !volume.attachments.is_empty() {
+        return Err(format!(
+            "EVS disk {} is a system disk. Stop its server and detach the disk before rollback, or create a new disk from the snapshot.",
+            volume_id
+        ));
+    }
+    if !volume.attachments.is_empty() || !evs_status_is(&volume, &["available"]) {
+        return Err(format!(
+            "EVS disk {} must be detached and available before rollback (status: {}).",
+            volume_id,
+            volume.status.as_deref().unwrap_or("unknown")
+        ));
+    }
+
+    let (status, raw) = client
+        .rollback_evs_snapshot(&params.region, snapshot_id, &volume_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to roll back EVS snapshot: region={} snapshot_id={} error={}",
+                params.region, snapshot_id, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() {
+        return Ok(evs_operation_result(status, raw));
+    }
+
+    let mut summary = json!({
+        "snapshot_id": snapshot_id,
+        "volume_id": volume_id,
+        "response": parse_json_or_string(&raw),
+    });
+    match wait_for_evs_volume(&client, &params.region, &volume_id, |volume| {
+        evs_status_is(volume, &["available"])
+    })
+    .await
+    {
+        Ok(volume) => summary["final_status"] = json!(volume.status),
+        Err(err) => {
+            warn!(
+                "EVS disk did not settle after rollback: region={} volume_id={} error={}",
+                params.region, volume_id, err
+            );
+            summary["wait_error"] = json!(err);
+        }
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Snapshot every disk attached to one ECS instance and wait for all snapshots.
+/// Used as a safety net before risky startup tasks. Each disk gets its own result;
+/// when any snapshot fails the call answers 207 Multi-Status.
+///
+/// Rollback needs a detached disk, so restoring the system disk means stopping the
+/// server and detaching it first; otherwise create a new disk from its snapshot.
+#[tauri::command]
+async fn snapshot_ecs_volumes(
+    params: EcsSnapshotVolumesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EvsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
+    }
+    let prefix = params
+        .name_prefix
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("hc-forge-pre-task")
+        .to_string();
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Snapshotting ECS disks: source={} region={} server_id={}",
+        source_label, params.region, server_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let volumes = client
+        .list_evss(
+            &params.region,
+            Some(ListParams {
+                limit: Some(EVS_LIST_LIMIT),
+                ..ListParams::default()
+            }),
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list EVS disks before snapshot: region={} error={}",
+                params.region, err
+            );
+            err.to_string()
+        })?
+        .volumes;
+    let targets = volumes
+        .iter()
+        .filter_map(|volume| {
+            let attachment = volume
+                .attachments
+                .iter()
+                .find(|attachment| attachment.server_id.as_deref() == Some(server_id))?;
+            Some((
+                volume.id.clone()?,
+                attachment.device.clone(),
+                volume.bootable.unwrap_or(false),
+            ))
+        })
+        .collect::<Vec<_>>();
+    if targets.is_empty() {
+        return Err(format!(
+            "No EVS disks are attached to server {}.",
+            server_id
+        ));
+    }
+
+    let stamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let description = format!("Taken by hc-forge before a startup task on {}", server_id);
+    let outcomes = stream::iter(targets)
+        .map(|(volume_id, device, bootable)| {
+            let client = &client;
+            let region = params.region.as_str();
+            let name = build_evs_snapshot_name(&prefix, &volume_id, device.as_deref(), &stamp);
+            let description = description.clone();
+            async move {
+                let outcome =
+                    snapshot_evs_volume(client, region, &volume_id, name, Some(description)).await;
+                (volume_id, device, bootable, outcome)
+            }
+        })
+        .buffer_unordered(EVS_SNAPSHOT_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut failed = 0;
+    let snapshots = outcomes
+        .into_iter()
+        .map(|(volume_id, device, bootable, outcome)| match outcome {
+            Ok(snapshot) => json!({
+                "volume_id": volume_id,
+                "device": device,
+                "system_disk": bootable,
+                "result": "created",
+                "snapshot_id": snapshot.id,
+                "name": snapshot.name,
+                "status": snapshot.status,
+            }),
+            Err(err) => {
+                failed += 1;
+                json!({
+                    "volume_id": volume_id,
+                    "device": device,
+                    "system_disk": bootable,
+                    "result": "failed",
+                    "error": err,
+                })
+            }
+        })
+        .collect::<Vec<_>>();
+    let summary = json!({
+        "server_id": server_id,
+        "snapshots": snapshots,
+        "succeeded": snapshots.len() - failed,
+        "failed": failed,
+    });
+    let status = if failed == 0 {
+        reqwest::StatusCode::OK
+    } else {
+        reqwest::StatusCode::MULTI_STATUS
+    };
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(evs_operation_result(status, body))
+}
+
+/// Report resources nothing uses (unbound EIPs, detached disks, NAT gateways without
+/// SNAT or DNAT rules, empty buckets) with their age and a rough monthly cost.
+#[tauri::command]
+async fn find_orphaned_resources(
+    region: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<OrphanReport, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Finding orphaned resources: source={} region={}",
+        source_label, region
+    );
+
+    let client = HwcClient::new(credentials);
+    let list_params = || {
+        Some(ListParams {
+            limit: Some(ORPHAN_LIST_LIMIT),
+            ..ListParams::default()
+        })
+    };
+    let (eips, volumes, nat_gateways, buckets) = futures::join!(
+        client.list_eips(&region, list_params()),
+        client.list_evss(&region, list_params()),
+        client.list_nat_gateways(&region, None, None, None),
+        client.list_obs_buckets(&region),
+    );
+
+    // Each listing is optional; a failed one only makes the report incomplete.
+    let mut warnings = Vec::new();
+    let mut unavailable = |label: &str, err: anyhow::Error| {
+        warn!(
+            "Orphan scan listing unavailable: region={} listing={} error={}",
+            region, label, err
+        );
+        warnings.push(format!("{} listing unavailable: {}", label, err));
+    };
+    let eips = eips.map(|body| body.publicips).unwrap_or_else(|err| {
+        unavailable("EIP", err);
+        Vec::new()
+    });
+    let volumes = volumes.map(|body| body.volumes).unwrap_or_else(|err| {
+        unavailable("EVS", err);
+        Vec::new()
+    });
+    let nat_gateways = nat_gateways
+        .map(|body| body.nat_gateways)
+        .unwrap_or_else(|err| {
+            unavailable("NAT gateway", err);
+            Vec::new()
+        });
+    // The bucket listing is account-wide; only buckets located in this region are checked.
+    let buckets = buckets
+        .map(|body| {
+            body.buckets
+                .into_iter()
+                .filter(|bucket| {
+                    bucket
+                        .location
+                        .as_deref()
+                        .is_some_and(|location| location.trim().eq_ignore_ascii_case(&region))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_else(|err| {
+            unavailable("OBS bucket", err);
+            Vec::new()
+        });
+    for (label, count) in [("EIP", eips.len()), ("EVS", volumes.len())] {
+        if count >= ORPHAN_LIST_LIMIT as usize {
+            warnings.push(format!(
+                "{} listing was truncated to the first {} items.",
+                label, ORPHAN_LIST_LIMIT
+            ));
+        }
+    }
+
+    let nat_gateway_ids: Vec<String> = nat_gateways
+        .iter()
+        .filter_map(|gateway| gateway.id.clone())
+        .collect();
+    let rule_results = stream::iter(nat_gateway_ids.into_iter().map(|nat_gateway_id| {
+        let client = &client;
+        let region = region.as_str();
+        async move {
+            let (snat_rules, dnat_rules) = futures::join!(
+                client.list_snat_rules(region, &nat_gateway_id),
+                client.list_dnat_rules(region, &nat_gateway_id),
+            );
+            (nat_gateway_id, nat_rule_count(snat_rules, dnat_rules))
+        }
+    }))
+    .buffer_unordered(ORPHAN_LOOKUP_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+    let mut nat_rule_counts = HashMap::new();
+    for (nat_gateway_id, result) in rule_results {
+        match result {
+            Ok(count) => {
+                nat_rule_counts.insert(nat_gateway_id, count);
+            }
+            Err(err) => warnings.push(format!(
+                "NAT rules of NAT gateway {} unavailable: {}",
+                nat_gateway_id, err
+            )),
+        }
+    }
+
+    let bucket_results = stream::iter(buckets.into_iter().map(|bucket| {
+        let client = &client;
+        let region = region.as_str();
+        async move {
+            let result = client
+                .list_obs_objects(region, &bucket.name, None, None, Some(1))
+                .await;
+            (bucket, result)
+        }
+    }))
+    .buffer_unordered(ORPHAN_LOOKUP_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+    let mut empty_buckets = Vec::new();
+    for (bucket, result) in bucket_results {
+        match result {
+            Ok(listing) if listing.objects.is_empty() && !listing.is_truncated => {
+                empty_buckets.push(bucket)
+            }
+            Ok(_) => {}
+            Err(err) => warnings.push(format!(
+                "Objects of OBS bucket {} unavailable: {}",
+                bucket.name, err
+            )),
+        }
+    }
+
+    let loaded = pricing::load_price_table();
+    let mut report = build_orphan_report(
+        &region,
+        &OrphanInputs {
+            eips: &eips,
+            volumes: &volumes,
+            nat_gateways: &nat_gateways,
+            nat_rule_counts: &nat_rule_counts,
+            empty_buckets: &empty_buckets,
+        },
+        &loaded.table,
+        Utc::now(),
+    );
+    warnings.extend(loaded.warnings);
+    warnings.append(&mut report.warnings);
+    report.warnings = warnings;
+    info!(
+        "Orphan scan finished: region={} resources={} monthly_cost={:.2}",
+        region,
+        report
+            .categories
+            .iter()
+            .map(|category| category.count)
+            .sum::<usize>(),
+        report.total_estimated_monthly_cost
+    );
+
+    Ok(report)
+}
+
+/// Re-check one reported orphan against its live state, then delete it.
+async fn cleanup_orphan(
+    client: &HwcClient,
+    region: &str,
+    kind: OrphanKind,
+    id: &str,
+) -> Result<(reqwest::StatusCode, String), String> {
+    let still_orphaned = match kind {
+        OrphanKind::Eip => client
+            .get_eip(region, id)
+            .await
+            .map(|eip| is_unbound_eip(&eip)),
+        OrphanKind::EvsVolume => client
+            .get_evs_volume(region, id)
+            .await
+            .map(|volume| is_detached_volume(&volume)),
+        OrphanKind::NatGateway => {
+            let (snat_rules, dnat_rules) = futures::join!(
+                client.list_snat_rules(region, id),
+                client.list_dnat_rules(region, id),
+            );
+            nat_rule_count(snat_rules, dnat_rules).map(|count| count == 0)
+        }
+        OrphanKind::ObsBucket => client
+            .list_obs_objects(region, id, None, None, Some(1))
+            .await
+            .map(|listing| listing.objects.is_empty() && !listing.is_truncated),
+    }
+    .map_err(|err| err.to_string())?;
+    if !still_orphaned {
+        return Err(format!("{} {} is in use again; skipped.", kind.label(), id));
+    }
+
+    match kind {
+        OrphanKind::Eip => client.delete_eip(region, id).await,
+        OrphanKind::EvsVolume => client.delete_evs_volume(region, id).await,
+        OrphanKind::NatGateway => client.delete_nat_gateway(region, id).await,
+        OrphanKind::ObsBucket => client.delete_obs_bucket(region, id).await,
+    }
+    .map_err(|err| err.to_string())
+}
+
+/// Delete resources picked from an orphan report, re-checking each one first.
+#[tauri::command]
+async fn cleanup_orphaned_resources(
+    params: OrphanCleanupParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<OrphanCleanupOutcome>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let mut seen = HashSet::new();
+    let items = params
+        .items
+        .into_iter()
+        .filter_map(|item| {
+            let id = item.id.trim().to_string();
+            (!id.is_empty() && seen.insert((item.kind, id.clone()))).then_some((item.kind, id))
+        })
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        return Err("Select at least one resource to clean up.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Cleaning up orphaned resources: source={} region={} items={}",
+        source_label,
+        params.region,
+        items.len()
+    );
+
+    let client = HwcClient::new(credentials);
+    let outcomes = stream::iter(items.into_iter().map(|(kind, id)| {
+        let client = &client;
+        let region = params.region.as_str();
+        async move {
+            match cleanup_orphan(client, region, kind, &id).await {
+                Ok((status, body)) => {
+                    let deleted = is_success_or_not_found(status);
+                    if !deleted {
+                        warn!(
+                            "Orphan cleanup rejected: region={} kind={} id={} status={}",
+                            region,
+                            kind.label(),
+                            id,
+                            status
+                        );
+                    }
+                    OrphanCleanupOutcome {
+                        kind,
+                        id,
+                        deleted,
+                        status_code: Some(status.as_u16()),
+                        message: if deleted { status.to_string() } else { body },
+                    }
+                }
+                Err(message) => {
+                    warn!(
+                        "Orphan cleanup skipped: region={} kind={} id={} reason={}",
+                        region,
+                        kind.label(),
+                        id,
+                        message
+                    );
+                    OrphanCleanupOutcome {
+                        kind,
+                        id,
+                        deleted: false,
+                        status_code: None,
+                        message,
+                    }
+                }
+            }
+        }
+    }))
+    .buffer_unordered(ORPHAN_LOOKUP_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+
+    Ok(outcomes)
+}
+
+/// Estimate the cost of `create_ecs` with the same params, fully offline.
+#[tauri::command]
+async fn estimate_ecs_cost(params: EcsCreateParams) -> Result<PriceEstimate, String> {
+    let region = params.region.trim().to_string();
+    let body = build_create_ecs_request(params)?;
+    let loaded = pricing::load_price_table();
+    info!(
+        "Estimating ECS cost: region={} flavor={} table_version={}",
+        region, body.server.flavor_ref, loaded.table.version
+    );
+    Ok(pricing::estimate_ecs(&loaded, &region, &body))
+}
+
+/// Estimate the control-plane cost of `create_cce_cluster`, fully offline.
+#[tauri::command]
+async fn estimate_cce_cluster_cost(
+    params: CceCreateClusterParams,
+) -> Result<PriceEstimate, String> {
+    let body = build_cce_cluster_request(&params)?;
+    let loaded = pricing::load_price_table();
+    info!(
+        "Estimating CCE cluster cost: region={} flavor={} table_version={}",
+        params.region, body.spec.flavor, loaded.table.version
+    );
+    Ok(pricing::estimate_cce_cluster(
+        &loaded,
+        &params.region,
+        &body,
+    ))
+}
+
+/// Estimate the cost of `create_cce_node_pool` at its initial node count, fully offline.
+#[tauri::command]
+async fn estimate_cce_node_pool_cost(
+    params: CceCreateNodePoolParams,
+) -> Result<PriceEstimate, String> {
+    let body = build_cce_node_pool_request(&params)?;
+    let loaded = pricing::load_price_table();
+    info!(
+        "Estimating CCE node pool cost: region={} flavor={} initial_count={} table_version={}",
+        params.region,
+        body.spec.node_template.flavor,
+        body.spec.initial_node_count,
+        loaded.table.version
+    );
+    Ok(pricing::estimate_cce_node_pool(
+        &loaded,
+        &params.region,
+        &body,
+    ))
+}
+
+/// Estimate the NAT gateway + EIP created by the CCE NAT bootstrap, fully offline.
+#[tauri::command]
+async fn estimate_cce_nat_gateway_cost(
+    params: CceCreateNatGatewayParams,
+) -> Result<PriceEstimate, String> {
+    let spec = normalize_cce_nat_spec(params.spec.as_deref())?;
+    let loaded = pricing::load_price_table();
+    info!(
+        "Estimating CCE NAT gateway cost: region={} spec={} table_version={}",
+        params.region, spec, loaded.table.version
+    );
+    Ok(pricing::estimate_nat_bootstrap(
+        &loaded,
+        &params.region,
+        spec,
+        DEFAULT_BANDWIDTH_SIZE,
+    ))
+}
+
+/// Describe the active price table (bundled or imported).
+#[tauri::command]
+async fn get_price_table_info() -> Result<PriceTableInfo, String> {
+    let loaded = pricing::load_price_table();
+    for warning in &loaded.warnings {
+        warn!("{}", warning);
+    }
+    Ok(loaded.table.info(loaded.source, loaded.warnings))
+}
+
+/// Validate a newer TOML price table and make it the active one.
+#[tauri::command]
+async fn import_price_table(params: PriceTableImportParams) -> Result<PriceTableInfo, String> {
+    let path = params.path.trim();
+    if path.is_empty() {
+        return Err("Price table path is required.".to_string());
+    }
+
+    info!("Importing price table: path={}", path);
+    let raw = std::fs::read_to_string(path).map_err(|err| {
+        error!("Failed to read price table: path={} error={}", path, err);
+        format!("Failed to read {}: {}", path, err)
+    })?;
+    let current = pricing::load_price_table();
+    let table = pricing::import_price_table(&raw, &current.table).map_err(|err| {
+        error!("Failed to import price table: path={} error={}", path, err);
+        err
+    })?;
+    info!(
+        "Imported price table: version={} regions={}",
+        table.version,
+        table.regions.len()
+    );
+
+    Ok(table.info(pricing::PriceTableSource::Imported, Vec::new()))
+}
+
+/// Validate tags against the stored tag policy before they are sent.
+fn enforce_tag_policy(tags: &[ResourceTag], check: TagPolicyCheck) -> Result<(), String> {
+    load_tag_policy()?.validate(tags, check).map_err(|err| {
+        warn!("{:?} blocked by tag policy: {}", check, err);
+        err
+    })
+}
+
+fn tag_step_summary(result: Result<(reqwest::StatusCode, String), String>) -> Value {
+    match result {
+        Ok((status, body)) => json!({
+            "status": status.to_string(),
+            "status_code": status.as_u16(),
+            "body": parse_json_or_string(&body)
+        }),
+        Err(err) => json!({ "error": err }),
+    }
+}
+
+/// Add or overwrite tags on one resource through its service's tag API.
+async fn apply_resource_tags(
+    client: &HwcClient,
+    region: &str,
+    resource: TaggableResource,
+    resource_id: &str,
+    tags: &[ResourceTag],
+) -> Result<(reqwest::StatusCode, String), String> {
+    let service = match resource {
+        TaggableResource::Ecs => TagService::Ecs,
+        TaggableResource::Evs => TagService::Evs,
+        TaggableResource::Eip => TagService::Eip,
+        TaggableResource::NatGateway => TagService::NatGateway,
+        TaggableResource::CceCluster => {
+            return client
+                .create_cce_cluster_tags(region, resource_id, tags)
+                .await
+                .map_err(|err| err.to_string());
+        }
+        TaggableResource::ObsBucket => {
+            // OBS only replaces the whole tag set, so merge with the current one.
+            let existing = client
+                .get_obs_bucket_tags(region, resource_id)
+                .await
+                .map_err(|err| err.to_string())?;
+            return client
+                .put_obs_bucket_tags(region, resource_id, &merge_tags(existing, tags))
+                .await
+                .map_err(|err| err.to_string());
+        }
+    };
+    let entries = tags
+        .iter()
+        .map(|tag| TagActionEntry {
+            key: tag.key.clone(),
+            value: Some(tag.value.clone()),
+        })
+        .collect();
+    client
+        .update_resource_tags(region, service, resource_id, "create", entries)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Remove tags by key from one resource through its service's tag API.
+async fn remove_resource_tags(
+    client: &HwcClient,
+    region: &str,
+    resource: TaggableResource,
+    resource_id: &str,
+    keys: &[String],
+) -> Result<(reqwest::StatusCode, String), String> {
+    let service = match resource {
+        TaggableResource::Ecs => TagService::Ecs,
+        TaggableResource::Evs => TagService::Evs,
+        TaggableResource::Eip => TagService::Eip,
+        TaggableResource::NatGateway => TagService::NatGateway,
+        TaggableResource::CceCluster => {
+            return client
+                .delete_cce_cluster_tags(region, resource_id, keys)
+                .await
+                .map_err(|err| err.to_string());
+        }
+        TaggableResource::ObsBucket => {
+            let remaining = client
+                .get_obs_bucket_tags(region, resource_id)
+                .await
+                .map_err(|err| err.to_string())?
+                .into_iter()
+                .filter(|tag| !keys.contains(&tag.key))
+                .collect::<Vec<_>>();
+            let result = if remaining.is_empty() {
+                client.delete_obs_bucket_tags(region, resource_id).await
+            } else {
+                client
+                    .put_obs_bucket_tags(region, resource_id, &remaining)
+                    .await
+            };
+            return result.map_err(|err| err.to_string());
+        }
+    };
+    let entries = keys
+        .iter()
+        .map(|key| TagActionEntry {
+            key: key.clone(),
+            value: None,
+        })
+        .collect();
+    client
+        .update_resource_tags(region, service, resource_id, "delete", entries)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Read the tag policy enforced before creates and tag updates.
+#[tauri::command]
+async fn get_tag_policy() -> Result<TagPolicy, String> {
+    load_tag_policy().map_err(|err| {
+        error!("Failed to load tag policy: {}", err);
+        err
+    })
+}
+
+/// Replace the tag policy enforced before creates and tag updates.
+#[tauri::command]
+async fn set_tag_policy(policy: TagPolicy) -> Result<TagPolicy, String> {
+    let policy = policy.normalized()?;
+    info!(
+        "Saving tag policy: required_keys={} restricted_keys={}",
+        policy.required_keys.join(","),
+        policy
+            .allowed_values
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    save_tag_policy(&policy).map_err(|err| {
+        error!("Failed to save tag policy: {}", err);
+        err
+    })?;
+    Ok(policy)
+}
+
+/// Add or overwrite tags on one existing resource.
+#[tauri::command]
+async fn tag_resource(
+    params: ResourceTagParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<TagOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let label = params.resource_type.label();
+    let resource_id = params.resource_id.trim();
+    if resource_id.is_empty() {
+        return Err(format!("{} ID is required.", label));
+    }
+    let tags = normalize_tags(Some(params.tags))?;
+    if tags.is_empty() {
+        return Err("At least one tag is required.".to_string());
+    }
+    enforce_tag_policy(&tags, TagPolicyCheck::Add)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Tagging {}: source={} region={} id={} keys={}",
+        label,
+        source_label,
+        params.region,
+        resource_id,
+        tags.iter()
+            .map(|tag| tag.key.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = apply_resource_tags(
+        &client,
+        &params.region,
+        params.resource_type,
+        resource_id,
+        &tags,
+    )
+    .await
+    .map_err(|err| {
+        error!(
+            "Failed to tag {}: region={} id={} error={}",
+            label, params.region, resource_id, err
+        );
+        err
+    })?;
+
+    Ok(tag_operation_result(status, body))
+}
+
+/// Remove tags by key from one existing resource.
+#[tauri::command]
+async fn untag_resource(
+    params: ResourceUntagParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<TagOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let label = params.resource_type.label();
+    let resource_id = params.resource_id.trim();
+    if resource_id.is_empty() {
+        return Err(format!("{} ID is required.", label));
+    }
+    let keys = normalize_tag_keys(params.keys)?;
+    if keys.is_empty() {
+        return Err("At least one tag key is required.".to_string());
+    }
+    load_tag_policy()?.check_removal(&keys)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Untagging {}: source={} region={} id={} keys={}",
+        label,
+        source_label,
+        params.region,
+        resource_id,
+        keys.join(",")
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = remove_resource_tags(
+        &client,
+        &params.region,
+        params.resource_type,
+        resource_id,
+        &keys,
+    )
+    .await
+    .map_err(|err| {
+        error!(
+            "Failed to untag {}: region={} id={} error={}",
+            label, params.region, resource_id, err
+        );
+        err
+    })?;
+
+    Ok(tag_operation_result(status, body))
+}
+
+/// List CCE clusters for the selected region.
+#[tauri::command]
+async fn list_cce_clusters(
+    region: String,
+    tags: Option<Vec<TagInput>>,
+    enterprise_project_id: Option<String>,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceClusterListResponse, String> {
+    let enterprise_project_id =
+        resolve_enterprise_project(enterprise_project_id.as_deref(), credentials.as_ref(), true)?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE clusters: source={} region={}",
+        source_label, region
+    );
+
+    let tag_filters = normalize_tag_filters(tags)?;
+    let client = HwcClient::new(credentials);
+    let mut response = client.list_cce_clusters(&region).await.map_err(|err| {
+        error!(
+            "Failed to list CCE clusters: region={} error={}",
+            region, err
+        );
+        err.to_string()
+    })?;
+    response
+        .items
+        .retain(|cluster| matches_tag_filters(cluster.tags(), &tag_filters));
+    // CCE has no enterprise project query; clusters without one belong to the default "0".
+    if let Some(project) =
+        enterprise_project_id.filter(|project| project != ALL_ENTERPRISE_PROJECTS)
+    {
+        response
+            .items
+            .retain(|cluster| cluster.enterprise_project_id() == project);
+    }
+
+    Ok(response)
+}
+
+fn normalize_cce_id_list(values: Option<&[String]>) -> Vec<String> {
+    values
+        .unwrap_or_default()
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Masters a control-plane flavor runs: cce.s1.* is single-master, cce.s2.* is HA.
+fn cce_flavor_master_count(flavor: &str) -> Option<usize> {
+    if flavor.contains(".s1.") {
+        Some(1)
+    } else if flavor.contains(".s2.") {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// Spread the masters over the requested AZs and pick the matching `clusterAZ`.
+fn build_cce_masters(
+    flavor: &str,
+    azs: &[String],
+) -> Result<(Vec<CceMasterSpec>, Option<String>), String> {
+    let Some(first) = azs.first() else {
+        return Ok((Vec::new(), None));
+    };
+    let azs = match (cce_flavor_master_count(flavor), azs.len()) {
+        (Some(1), count) if count > 1 => {
+            return Err(format!(
+                "Flavor {} runs a single master; choose one availability zone.",
+                flavor
+            ));
+        }
+        (Some(masters), 1) => vec![first.clone(); masters],
+        (Some(masters), count) if count != masters => {
+            return Err(format!(
+                "Flavor {} runs {} masters; choose 1 or {} availability zones.",
+                flavor, masters, masters
+            ));
+        }
+        _ => azs.to_vec(),
+    };
+    let cluster_az = if azs.iter().any(|az| az != first) {
+        "multi_az".to_string()
+    } else {
+        first.clone()
+    };
+    let masters = azs
+        .into_iter()
+        .map(|availability_zone| CceMasterSpec { availability_zone })
+        .collect();
+    Ok((masters, Some(cluster_az)))
+}
+
+/// Billing mode for the spec, filling the order period into `extend_param` when prepaid.
+fn apply_cce_cluster_billing(
+    params: &CceCreateClusterParams,
+    extend_param: &mut CceClusterExtendParam,
+) -> Result<u8, String> {
+    let billing_mode = params
+        .billing_mode
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("payPerUse");
+    match billing_mode {
+        "payPerUse" => {
+            if params.period_num.is_some() || params.period_type.is_some() {
+                return Err("A billing period applies only to yearlyMonthly clusters.".to_string());
+            }
+            Ok(0)
+        }
+        "yearlyMonthly" => {
+            let period_type = params
+                .period_type
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .unwrap_or("month");
+            let max_period = match period_type {
+                "month" => CCE_PREPAID_MAX_MONTHS,
+                "year" => CCE_PREPAID_MAX_YEARS,
+                other => {
+                    return Err(format!(
+                        "Unsupported billing period type {}. Use month or year.",
+                        other
+                    ));
+                }
+            };
+            let period_num = params
+                .period_num
+                .filter(|value| (1..=max_period).contains(value))
+                .ok_or_else(|| {
+                    format!(
+                        "Yearly/monthly clusters need a period of 1-{} {}s.",
+                        max_period, period_type
+                    )
+                })?;
+            extend_param.period_type = Some(period_type.to_string());
+            extend_param.period_num = Some(period_num);
+            extend_param.is_auto_renew = Some(params.auto_renew.unwrap_or(false).to_string());
+            extend_param.is_auto_pay = Some(params.auto_pay.unwrap_or(false).to_string());
+            Ok(1)
+        }
+        other => Err(format!(
+            "Unsupported CCE billing mode {}. Use payPerUse or yearlyMonthly.",
+            other
+        )),
+    }
+}
+
+/// Validate CCE cluster params into the exact request body sent to the API.
+fn build_cce_cluster_request(
+    params: &CceCreateClusterParams,
+) -> Result<CceCreateClusterRequest, String> {
+    let cluster_name = params.name.trim();
+    if cluster_name.is_empty() {
+        return Err("CCE cluster name is required.".to_string());
+    }
+    let flavor = params.flavor.trim();
+    if flavor.is_empty() {
+        return Err("CCE cluster flavor is required.".to_string());
+    }
+    let version = params.version.trim();
+    if version.is_empty() {
+        return Err("CCE Kubernetes version is required.".to_string());
+    }
+    let vpc_id = params.vpc_id.trim();
+    if vpc_id.is_empty() {
+        return Err("CCE VPC is required.".to_string());
+    }
+    let subnet_id = params.subnet_id.trim();
+    if subnet_id.is_empty() {
+        return Err("CCE subnet is required.".to_string());
+    }
+
+    let cluster_type = params
+        .cluster_type
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("VirtualMachine")
+        .to_string();
+    let container_network_mode = params
+        .container_network_mode
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("overlay_l2")
+        .to_string();
+    if !CCE_CONTAINER_NETWORK_MODES.contains(&container_network_mode.as_str()) {
+        return Err(format!(
+            "Unsupported container network mode {}. Use one of: {}.",
+            container_network_mode,
+            CCE_CONTAINER_NETWORK_MODES.join(", ")
+        ));
+    }
+    let eni_mode = container_network_mode == "eni";
+    let eni_subnet_ids = normalize_cce_id_list(params.eni_subnet_ids.as_deref());
+    if !eni_mode && !eni_subnet_ids.is_empty() {
+        return Err("ENI subnets apply only to the eni container network mode.".to_string());
+    }
+    let container_network_cidr = if eni_mode {
+        None
+    } else {
+        Some(
+            params
+                .container_network_cidr
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .unwrap_or(CCE_DEFAULT_CONTAINER_CIDR)
+                .to_string(),
+        )
+    };
+    let kubernetes_svc_ip_range = params
+        .kubernetes_svc_ip_range
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(CCE_DEFAULT_SERVICE_CIDR)
+        .to_string();
+    let service_cidr = parse_ipv4_cidr(&kubernetes_svc_ip_range)
+        .map_err(|err| format!("Invalid service CIDR: {}", err))?;
+    if let Some(container_cidr) = container_network_cidr.as_deref() {
+        let parsed = parse_ipv4_cidr(container_cidr)
+            .map_err(|err| format!("Invalid container CIDR: {}", err))?;
+        if ipv4_cidr_overlaps(parsed, service_cidr) {
+            return Err(format!(
+                "Service CIDR {} overlaps container CIDR {}.",
+                kubernetes_svc_ip_range, container_cidr
+            ));
+        }
+    }
+    let kube_proxy_mode = params
+        .kube_proxy_mode
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    if let Some(mode) = kube_proxy_mode.as_deref() {
+        if !CCE_KUBE_PROXY_MODES.contains(&mode) {
+            return Err(format!(
+                "Unsupported kube-proxy mode {}. Use one of: {}.",
+                mode,
+                CCE_KUBE_PROXY_MODES.join(", ")
+            ));
+        }
+    }
+    let authentication_mode = params
+        .authentication_mode
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("rbac")
+        .to_string();
+
+    let master_azs = normalize_cce_id_list(params.master_azs.as_deref());
+    let (masters, cluster_az) = build_cce_masters(flavor, &master_azs)?;
+    let cluster_external_ip = params
+        .eip_address
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse::<std::net::Ipv4Addr>()
+                .map(|_| value.to_string())
+                .map_err(|_| format!("EIP address {} is not a valid IPv4 address.", value))
+        })
+        .transpose()?;
+    let mut extend_param = CceClusterExtendParam {
+        enterprise_project_id: normalize_enterprise_project_id(
+            params.enterprise_project_id.as_deref(),
+            false,
+        )?,
+        cluster_az,
+        cluster_external_ip,
+        ..CceClusterExtendParam::default()
+    };
+    let billing_mode = apply_cce_cluster_billing(params, &mut extend_param)?;
+
+    let mut cluster_tags = normalize_tags(params.tags.clone())?;
+    if let Some(env) = params
+        .cluster_tag_env
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        if !cluster_tags.iter().any(|tag| tag.key == "env") {
+            cluster_tags.push(ResourceTag {
+                key: "env".to_string(),
+                value: env.to_string(),
+            });
+        }
+    }
+
+    let eni_network = eni_mode.then(|| {
+        let subnet_ids = if eni_subnet_ids.is_empty() {
+            vec![subnet_id.to_string()]
+        } else {
+            eni_subnet_ids
+        };
+        CceEniNetwork {
+            subnets: subnet_ids
+                .into_iter()
+                .map(|subnet_id| CceEniSubnet { subnet_id })
+                .collect(),
+        }
+    });
+
+    let body = CceCreateClusterRequest {
+        kind: "Cluster".to_string(),
+        api_version: "v3".to_string(),
+        metadata: CceClusterCreateMetadata {
+            name: cluster_name.to_string(),
+        },
+        spec: CceClusterCreateSpec {
+            category: if eni_mode { "Turbo" } else { "CCE" }.to_string(),
+            cluster_type,
+            flavor: flavor.to_string(),
+            version: version.to_string(),
+            description: params
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string),
+            ipv6_enable: params.ipv6_enable.unwrap_or(false),
+            host_network: CceHostNetwork {
+                vpc: vpc_id.to_string(),
+                subnet: subnet_id.to_string(),
+            },
+            container_network: CceContainerNetwork {
+                mode: container_network_mode,
+                cidr: container_network_cidr,
+            },
+            eni_network,
+            kubernetes_svc_ip_range,
+            kube_proxy_mode,
+            billing_mode,
+            masters,
+            authentication: Some(CceAuthentication {
+                mode: authentication_mode,
+            }),
+            cluster_tags,
+            extend_param: (extend_param != CceClusterExtendParam::default())
+                .then_some(extend_param),
+        },
+    };
+
+    Ok(body)
+}
+
+/// Check the cluster networks against its VPC: the cluster and ENI subnets must belong
+/// to it and the container and service CIDRs must stay clear of its address space.
+/// ENI subnets are rewritten to the IPv4 subnet IDs CCE expects.
+fn validate_cce_cluster_network(
+    spec: &mut CceClusterCreateSpec,
+    vpc: &Vpc,
+    subnets: &[Subnet],
+) -> Result<(), String> {
+    if !subnets
+        .iter()
+        .any(|subnet| subnet.id == spec.host_network.subnet)
+    {
+        return Err(format!(
+            "Subnet {} is not in VPC {}.",
+            spec.host_network.subnet, vpc.name
+        ));
+    }
+    for eni_subnet in spec
+        .eni_network
+        .iter_mut()
+        .flat_map(|network| network.subnets.iter_mut())
+    {
+        let subnet = subnets
+            .iter()
+            .find(|subnet| {
+                subnet.id == eni_subnet.subnet_id
+                    || subnet.neutron_subnet_id.as_deref() == Some(eni_subnet.subnet_id.as_str())
+            })
+            .ok_or_else(|| {
+                format!(
+                    "ENI subnet {} is not in VPC {}.",
+                    eni_subnet.subnet_id, vpc.name
+                )
+            })?;
+        eni_subnet.subnet_id = subnet
+            .neutron_subnet_id
+            .clone()
+            .filter(|value| !value.trim().is_empty())
+            .ok_or_else(|| format!("Subnet {} has no IPv4 subnet ID for ENI.", subnet.name))?;
+    }
+
+    let mut host_ranges = Vec::new();
+    if let Some(cidr) = vpc.cidr.as_deref().filter(|value| !value.trim().is_empty()) {
+        host_ranges.push((format!("VPC {}", vpc.name), cidr));
+    }
+    for subnet in subnets {
+        host_ranges.push((format!("subnet {}", subnet.name), subnet.cidr.as_str()));
+    }
+    let cluster_ranges = [
+        ("Service", Some(spec.kubernetes_svc_ip_range.as_str())),
+        ("Container", spec.container_network.cidr.as_deref()),
+    ];
+    for (label, cidr) in cluster_ranges {
+        let Some(cidr) = cidr else {
+            continue;
+        };
+        let parsed = parse_ipv4_cidr(cidr)?;
+        for (owner, host_cidr) in &host_ranges {
+            if ipv4_cidr_overlaps(parsed, parse_ipv4_cidr(host_cidr)?) {
+                return Err(format!(
+                    "{} CIDR {} overlaps {} ({}).",
+                    label, cidr, owner, host_cidr
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Create one CCE cluster.
+#[tauri::command]
+async fn create_cce_cluster(
+    mut params: CceCreateClusterParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    params.enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let mut body = build_cce_cluster_request(&params)?;
+    enforce_tag_policy(&body.spec.cluster_tags, TagPolicyCheck::Create)?;
+
+    let client = HwcClient::new(credentials);
+    let vpc_id = body.spec.host_network.vpc.clone();
+    let (vpc, subnets) = futures::join!(
+        client.get_vpc(&params.region, &vpc_id),
+        client.list_subnets(&params.region, &vpc_id),
+    );
+    let vpc = vpc.map_err(|err| err.to_string())?;
+    let subnets = subnets.map_err(|err| err.to_string())?;
+    validate_cce_cluster_network(&mut body.spec, &vpc, &subnets)?;
+    let cluster_name = body.metadata.name.as_str();
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating CCE cluster: source={} region={} name={} category={} flavor={} version={} vpc_id={} subnet_id={} network={}",
+        source_label,
+        params.region,
+        cluster_name,
+        body.spec.category,
+        body.spec.flavor,
+        body.spec.version,
+        body.spec.host_network.vpc,
+        body.spec.host_network.subnet,
+        body.spec.container_network.mode
+    );
+
+    quota_preflight(&client, &params.region, &[(QuotaResource::CceClusters, 1)]).await?;
+    let (status, body) = client
+        .create_cce_cluster(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create CCE cluster: region={} name={} error={}",
+                params.region, cluster_name, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Delete one CCE cluster.
+#[tauri::command]
+async fn delete_cce_cluster(
+    params: CceDeleteClusterParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting CCE cluster: source={} region={} cluster_id={}",
+        source_label, params.region, cluster_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_cce_cluster(&params.region, cluster_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete CCE cluster: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// List node pools for one CCE cluster.
+#[tauri::command]
+async fn list_cce_node_pools(
+    params: CceListNodePoolsParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceNodePoolListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE node pools: source={} region={} cluster_id={}",
+        source_label, params.region, cluster_id
+    );
+
+    let tag_filters = normalize_tag_filters(params.tags.clone())?;
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_cce_node_pools(&params.region, cluster_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list CCE node pools: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+    response
+        .items
+        .retain(|pool| matches_tag_filters(pool.user_tags(), &tag_filters));
+
+    Ok(response)
+}
+
+/// Validate CCE node pool params into the exact request body sent to the API.
+fn build_cce_node_pool_request(
+    params: &CceCreateNodePoolParams,
+) -> Result<CceCreateNodePoolRequest, String> {
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("CCE node pool name is required.".to_string());
+    }
+    let flavor = params.flavor.trim();
     if flavor.is_empty() {
-        return Err("CCE cluster flavor is required.".to_string());
+        return Err("CCE node pool flavor is required.".to_string());
+    }
+    let availability_zone = params.availability_zone.trim();
+    if availability_zone.is_empty() {
+        return Err("CCE node pool availability zone is required.".to_string());
+    }
+
+    let initial_node_count = sanitize_cce_node_pool_initial_count(params.initial_node_count);
+    let root_volume_size = sanitize_cce_node_pool_root_volume_size(params.root_volume_size);
+    let data_volume_size = sanitize_cce_node_pool_data_volume_size(params.data_volume_size);
+    let root_volume_type = params
+        .root_volume_type
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("GPSSD")
+        .to_string();
+    let data_volume_type = params
+        .data_volume_type
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(root_volume_type.as_str())
+        .to_string();
+    let node_os = params
+        .os
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let ssh_key = params
+        .ssh_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let subnet_id = params
+        .subnet_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let max_pods = sanitize_cce_node_pool_max_pods(params.max_pods);
+    let user_tags = normalize_tags(params.tags.clone())?;
+
+    let body = CceCreateNodePoolRequest {
+        kind: "NodePool".to_string(),
+        api_version: "v3".to_string(),
+        metadata: CceNodePoolCreateMetadata {
+            name: name.to_string(),
+        },
+        spec: CceNodePoolCreateSpec {
+            node_pool_type: "vm".to_string(),
+            initial_node_count,
+            node_template: CceNodePoolTemplateSpec {
+                flavor: flavor.to_string(),
+                az: availability_zone.to_string(),
+                os: node_os,
+                login: ssh_key.map(|value| CceNodePoolLogin { ssh_key: value }),
+                root_volume: CceNodePoolVolume {
+                    volumetype: root_volume_type,
+                    size: root_volume_size,
+                    extend_param: None,
+                },
+                data_volumes: vec![CceNodePoolVolume {
+                    volumetype: data_volume_type,
+                    size: data_volume_size,
+                    extend_param: Some(CceNodePoolVolumeExtendParam {
+                        use_type: "docker".to_string(),
+                    }),
+                }],
+                node_nic_spec: subnet_id.map(|value| CceNodePoolNicSpec {
+                    primary_nic: CceNodePoolPrimaryNic { subnet_id: value },
+                }),
+                billing_mode: 0,
+                user_tags,
+                extend_param: max_pods.map(|value| CceNodePoolExtendParam {
+                    max_pods: Some(value),
+                }),
+            },
+        },
+    };
+
+    Ok(body)
+}
+
+/// Quota demands of growing an existing node pool by `nodes`, from the flavor and
+/// disks of its node template.
+fn cce_node_pool_quota_demands(pool: &CceNodePool, nodes: u32) -> Vec<(QuotaResource, u64)> {
+    let template = pool.spec.node_template.clone().unwrap_or_default();
+    let volume_sizes = template
+        .root_volume
+        .iter()
+        .chain(template.data_volumes.iter().flatten())
+        .filter_map(|volume| volume.size)
+        .collect::<Vec<_>>();
+    cce_node_quota_demands(
+        template.flavor.as_deref().unwrap_or(""),
+        &volume_sizes,
+        nodes,
+    )
+}
+
+/// Create one node pool under a CCE cluster.
+#[tauri::command]
+async fn create_cce_node_pool(
+    params: CceCreateNodePoolParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for node pool creation.".to_string());
+    }
+    let body = build_cce_node_pool_request(&params)?;
+    enforce_tag_policy(&body.spec.node_template.user_tags, TagPolicyCheck::Create)?;
+    let name = body.metadata.name.as_str();
+    let template = &body.spec.node_template;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating CCE node pool: source={} region={} cluster_id={} name={} flavor={} az={} initial_count={}",
+        source_label,
+        params.region,
+        cluster_id,
+        name,
+        template.flavor,
+        template.az,
+        body.spec.initial_node_count
+    );
+
+    let client = HwcClient::new(credentials);
+    let volume_sizes = std::iter::once(template.root_volume.size)
+        .chain(template.data_volumes.iter().map(|volume| volume.size))
+        .collect::<Vec<_>>();
+    quota_preflight(
+        &client,
+        &params.region,
+        &cce_node_quota_demands(
+            &template.flavor,
+            &volume_sizes,
+            body.spec.initial_node_count,
+        ),
+    )
+    .await?;
+    let (status, body) = client
+        .create_cce_node_pool(&params.region, cluster_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create CCE node pool: region={} cluster_id={} name={} error={}",
+                params.region, cluster_id, name, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Build the node pool update body from the pool's current `spec`, overlaying only
+/// the requested changes. Returns `None` when nothing would change.
+fn build_cce_node_pool_update(
+    pool: &CceNodePool,
+    changes: &CceNodePoolChanges,
+) -> Result<Option<(Value, Vec<&'static str>)>, String> {
+    let name = pool
+        .metadata
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| "CCE node pool has no name to update.".to_string())?;
+    let spec = &pool.spec;
+    let template = spec.node_template.clone().unwrap_or_default();
+    let mut changed = Vec::new();
+
+    let current_count = spec.initial_node_count.unwrap_or(0);
+    let node_count = changes.node_count.unwrap_or(current_count);
+    if node_count != current_count {
+        changed.push("initialNodeCount");
+    }
+
+    let mut autoscaling = spec.autoscaling.clone().unwrap_or_default();
+    if let Some(enable) = changes.autoscaling_enabled {
+        if autoscaling.enable != Some(enable) {
+            autoscaling.enable = Some(enable);
+            changed.push("enable");
+        }
+    }
+    let counts = [
+        (
+            "minNodeCount",
+            changes.min_node_count,
+            &mut autoscaling.min_node_count,
+        ),
+        (
+            "maxNodeCount",
+            changes.max_node_count,
+            &mut autoscaling.max_node_count,
+        ),
+        (
+            "scaleDownCooldownTime",
+            changes.scale_down_cooldown_time,
+            &mut autoscaling.scale_down_cooldown_time,
+        ),
+    ];
+    for (key, requested, current) in counts {
+        if let Some(requested) = requested {
+            if *current != Some(requested) {
+                *current = Some(requested);
+                changed.push(key);
+            }
+        }
+    }
+    if let Some(priority) = changes.priority {
+        if autoscaling.priority != Some(priority) {
+            autoscaling.priority = Some(priority);
+            changed.push("priority");
+        }
+    }
+    if autoscaling.enable == Some(true) {
+        let min = autoscaling.min_node_count.unwrap_or(0);
+        let max = autoscaling.max_node_count.unwrap_or(0);
+        if max == 0 || min > max {
+            return Err(format!(
+                "Autoscaling needs 0 <= min ({}) <= max ({}) with max > 0.",
+                min, max
+            ));
+        }
+        if node_count < min || node_count > max {
+            return Err(format!(
+                "Node count {} must stay within the autoscaling range {}-{}.",
+                node_count, min, max
+            ));
+        }
+    }
+
+    let mut k8s_tags = template.k8s_tags.unwrap_or_default();
+    if let Some(labels) = &changes.labels {
+        let next = normalize_cce_node_labels(labels)?;
+        if next != k8s_tags {
+            k8s_tags = next;
+            changed.push("k8sTags");
+        }
+    }
+
+    let mut taints = template.taints.unwrap_or_default();
+    if let Some(input) = &changes.taints {
+        let next = normalize_cce_taints(input)?;
+        if next != taints {
+            taints = next;
+            changed.push("taints");
+        }
+    }
+
+    let mut user_tags = template.user_tags.unwrap_or_default();
+    if changes.tags.is_some() {
+        let next = normalize_tags(changes.tags.clone())?;
+        enforce_tag_policy(&next, TagPolicyCheck::Create)?;
+        if next != user_tags {
+            user_tags = next;
+            changed.push("userTags");
+        }
+    }
+
+    if changed.is_empty() {
+        return Ok(None);
+    }
+
+    let body = json!({
+        "metadata": { "name": name },
+        "spec": {
+            "initialNodeCount": node_count,
+            "autoscaling": autoscaling,
+            "nodeTemplate": {
+                "k8sTags": k8s_tags,
+                "taints": taints,
+                "userTags": user_tags,
+            },
+        },
+    });
+    Ok(Some((body, changed)))
+}
+
+fn normalize_cce_node_labels(
+    labels: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut normalized = BTreeMap::new();
+    for (key, value) in labels {
+        let key = key.trim();
+        if key.is_empty() {
+            return Err("CCE node label keys cannot be empty.".to_string());
+        }
+        normalized.insert(key.to_string(), value.trim().to_string());
+    }
+    Ok(normalized)
+}
+
+fn normalize_cce_taints(input: &[CceTaint]) -> Result<Vec<CceTaint>, String> {
+    input
+        .iter()
+        .map(|taint| {
+            let key = taint.key.trim();
+            if key.is_empty() {
+                return Err("CCE taint keys cannot be empty.".to_string());
+            }
+            let effect = taint.effect.trim();
+            if !CCE_TAINT_EFFECTS.contains(&effect) {
+                return Err(format!(
+                    "Unsupported taint effect '{}'. Use one of: {}.",
+                    effect,
+                    CCE_TAINT_EFFECTS.join(", ")
+                ));
+            }
+            Ok(CceTaint {
+                key: key.to_string(),
+                value: taint
+                    .value
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string),
+                effect: effect.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Set the desired node count of one CCE node pool.
+#[tauri::command]
+async fn scale_cce_node_pool(
+    params: CceScaleNodePoolParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let changes = CceNodePoolChanges {
+        node_count: Some(params.node_count),
+        ..CceNodePoolChanges::default()
+    };
+    apply_cce_node_pool_changes(
+        &params.region,
+        &params.cluster_id,
+        &params.node_pool_id,
+        &changes,
+        credentials,
+    )
+    .await
+}
+
+/// Update autoscaling, labels, taints and tags of one CCE node pool.
+#[tauri::command]
+async fn update_cce_node_pool(
+    params: CceUpdateNodePoolParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    apply_cce_node_pool_changes(
+        &params.region,
+        &params.cluster_id,
+        &params.node_pool_id,
+        &params.changes,
+        credentials,
+    )
+    .await
+}
+
+async fn apply_cce_node_pool_changes(
+    region: &str,
+    cluster_id: &str,
+    node_pool_id: &str,
+    changes: &CceNodePoolChanges,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for node pool updates.".to_string());
+    }
+    let node_pool_id = node_pool_id.trim();
+    if node_pool_id.is_empty() {
+        return Err("CCE node pool ID is required.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let pool = client
+        .get_cce_node_pool(region, cluster_id, node_pool_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get CCE node pool: region={} cluster_id={} node_pool_id={} error={}",
+                region, cluster_id, node_pool_id, err
+            );
+            err.to_string()
+        })?;
+    let Some((body, changed)) = build_cce_node_pool_update(&pool, changes)? else {
+        return Err("The node pool already matches the requested settings.".to_string());
+    };
+    let added_nodes = changes
+        .node_count
+        .unwrap_or(0)
+        .saturating_sub(pool.spec.initial_node_count.unwrap_or(0));
+    if added_nodes > 0 {
+        quota_preflight(
+            &client,
+            region,
+            &cce_node_pool_quota_demands(&pool, added_nodes),
+        )
+        .await?;
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Updating CCE node pool: source={} region={} cluster_id={} node_pool_id={} fields={}",
+        source_label,
+        region,
+        cluster_id,
+        node_pool_id,
+        changed.join(",")
+    );
+
+    let (status, body) = client
+        .update_cce_node_pool(region, cluster_id, node_pool_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to update CCE node pool: region={} cluster_id={} node_pool_id={} error={}",
+                region, cluster_id, node_pool_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Delete one node pool under a CCE cluster.
+#[tauri::command]
+async fn delete_cce_node_pool(
+    params: CceDeleteNodePoolParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for node pool deletion.".to_string());
+    }
+    let node_pool_id = params.node_pool_id.trim();
+    if node_pool_id.is_empty() {
+        return Err("CCE node pool ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting CCE node pool: source={} region={} cluster_id={} node_pool_id={}",
+        source_label, params.region, cluster_id, node_pool_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_cce_node_pool(&params.region, cluster_id, node_pool_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete CCE node pool: region={} cluster_id={} node_pool_id={} error={}",
+                params.region, cluster_id, node_pool_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// List nodes for one CCE cluster, optionally scoped to one node pool.
+#[tauri::command]
+async fn list_cce_nodes(
+    params: CceListNodesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceNodeListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+    let node_pool_id = params
+        .node_pool_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE nodes: source={} region={} cluster_id={} node_pool_id={}",
+        source_label,
+        params.region,
+        cluster_id,
+        node_pool_id.unwrap_or("<all>")
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_cce_nodes(&params.region, cluster_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list CCE nodes: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+    if let Some(node_pool_id) = node_pool_id {
+        response
+            .items
+            .retain(|node| node.node_pool_id() == Some(node_pool_id));
+    }
+
+    Ok(response)
+}
+
+/// Fetch one node of a CCE cluster.
+#[tauri::command]
+async fn get_cce_node(
+    params: CceNodeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceNode, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let (cluster_id, node_id) = require_cce_node_ids(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Getting CCE node: source={} region={} cluster_id={} node_id={}",
+        source_label, params.region, cluster_id, node_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .get_cce_node(&params.region, cluster_id, node_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get CCE node: region={} cluster_id={} node_id={} error={}",
+                params.region, cluster_id, node_id, err
+            );
+            err.to_string()
+        })
+}
+
+/// Delete one node of a CCE cluster together with its ECS.
+#[tauri::command]
+async fn delete_cce_node(
+    params: CceNodeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let (cluster_id, node_id) = require_cce_node_ids(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting CCE node: source={} region={} cluster_id={} node_id={}",
+        source_label, params.region, cluster_id, node_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_cce_node(&params.region, cluster_id, node_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete CCE node: region={} cluster_id={} node_id={} error={}",
+                params.region, cluster_id, node_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Reinstall the OS of CCE nodes and rejoin them to the cluster.
+#[tauri::command]
+async fn reset_cce_nodes(
+    params: CceResetNodesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for node reset.".to_string());
+    }
+    let body = build_cce_reset_nodes_request(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Resetting CCE nodes: source={} region={} cluster_id={} nodes={}",
+        source_label,
+        params.region,
+        cluster_id,
+        body.node_list.len()
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .reset_cce_nodes(&params.region, cluster_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to reset CCE nodes: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Remove CCE nodes from their cluster and node pool, keeping the ECS.
+#[tauri::command]
+async fn remove_cce_nodes(
+    params: CceRemoveNodesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for node removal.".to_string());
+    }
+    let body = build_cce_remove_nodes_request(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Removing CCE nodes: source={} region={} cluster_id={} nodes={}",
+        source_label,
+        params.region,
+        cluster_id,
+        body.spec.nodes.len()
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .remove_cce_nodes(&params.region, cluster_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to remove CCE nodes: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+fn require_cce_node_ids(params: &CceNodeParams) -> Result<(&str, &str), String> {
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+    let node_id = params.node_id.trim();
+    if node_id.is_empty() {
+        return Err("CCE node ID is required.".to_string());
+    }
+    Ok((cluster_id, node_id))
+}
+
+fn normalize_cce_node_ids(node_ids: &[String]) -> Result<Vec<String>, String> {
+    let node_ids = normalize_id_list(Some(node_ids.to_vec()));
+    if node_ids.is_empty() {
+        return Err("At least one CCE node ID is required.".to_string());
+    }
+    Ok(node_ids)
+}
+
+fn require_cce_node_login(ssh_key: &str) -> Result<CceNodePoolLogin, String> {
+    let ssh_key = ssh_key.trim();
+    if ssh_key.is_empty() {
+        return Err("A key pair name is required to log in to the reinstalled nodes.".to_string());
+    }
+    Ok(CceNodePoolLogin {
+        ssh_key: ssh_key.to_string(),
+    })
+}
+
+fn build_cce_reset_nodes_request(
+    params: &CceResetNodesParams,
+) -> Result<CceResetNodesRequest, String> {
+    let node_ids = normalize_cce_node_ids(&params.node_ids)?;
+    let os = params.os.trim();
+    if os.is_empty() {
+        return Err("CCE node OS is required for node reset.".to_string());
+    }
+    let login = require_cce_node_login(&params.ssh_key)?;
+
+    Ok(CceResetNodesRequest {
+        kind: "List".to_string(),
+        api_version: "v3".to_string(),
+        node_list: node_ids
+            .into_iter()
+            .map(|node_id| CceResetNodeItem {
+                node_id,
+                spec: CceResetNodeSpec {
+                    os: os.to_string(),
+                    login: login.clone(),
+                },
+            })
+            .collect(),
+    })
+}
+
+fn build_cce_remove_nodes_request(
+    params: &CceRemoveNodesParams,
+) -> Result<CceRemoveNodesRequest, String> {
+    let node_ids = normalize_cce_node_ids(&params.node_ids)?;
+    let login = require_cce_node_login(&params.ssh_key)?;
+
+    Ok(CceRemoveNodesRequest {
+        kind: "RemoveNodesTask".to_string(),
+        api_version: "v3".to_string(),
+        spec: CceRemoveNodesSpec {
+            login,
+            nodes: node_ids.into_iter().map(|uid| CceNodeRef { uid }).collect(),
+        },
+    })
+}
+
+/// Query one CCE job status.
+#[tauri::command]
+async fn get_cce_job(
+    params: CceGetJobParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let job_id = params.job_id.trim();
+    if job_id.is_empty() {
+        return Err("CCE job ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Querying CCE job: source={} region={} job_id={}",
+        source_label, params.region, job_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .get_cce_job(&params.region, job_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to query CCE job: region={} job_id={} error={}",
+                params.region, job_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+fn resolve_cce_upgrade_target(info: &CceUpgradeInfo, requested: &str) -> Result<String, String> {
+    let requested = requested.trim();
+    if requested.is_empty() {
+        return Err("CCE target version is required.".to_string());
+    }
+    let targets = info.target_versions();
+    if targets.is_empty() {
+        return Err("This cluster has no Kubernetes versions to upgrade to.".to_string());
+    }
+    if !targets.iter().any(|target| target == requested) {
+        return Err(format!(
+            "Version {} is not an upgrade target for this cluster. Available: {}.",
+            requested,
+            targets.join(", ")
+        ));
+    }
+    Ok(requested.to_string())
+}
+
+/// CCE tasks and jobs report Init/Queuing/Running until they settle on Success or Failed.
+fn cce_phase_is_pending(phase: Option<&str>) -> bool {
+    matches!(
+        phase.unwrap_or("").trim().to_ascii_lowercase().as_str(),
+        "" | "init" | "initializing" | "queuing" | "pending" | "running"
+    )
+}
+
+/// Cluster operations return their job as a top-level `jobID` or in `status.jobID`.
+fn extract_cce_job_id(raw_body: &str) -> Option<String> {
+    let payload: Value = serde_json::from_str(raw_body).ok()?;
+    for candidate in [&payload["jobID"], &payload["status"]["jobID"]] {
+        if let Some(value) = candidate
+            .as_str()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Why a CCE job poll failed: transport errors and 5xx responses may clear up on
+/// the next poll; a 4xx (wrong or expired job ID) or an unreadable job will not.
+enum CceJobPollError {
+    Retryable(String),
+    Fatal(String),
+}
+
+async fn fetch_cce_job(
+    client: &HwcClient,
+    region: &str,
+    job_id: &str,
+) -> Result<CceJob, CceJobPollError> {
+    let (status, body) = client
+        .get_cce_job(region, job_id)
+        .await
+        .map_err(|err| CceJobPollError::Retryable(err.to_string()))?;
+    if status.is_server_error() {
+        return Err(CceJobPollError::Retryable(format!(
+            "HTTP {}: {}",
+            status, body
+        )));
+    }
+    if !status.is_success() {
+        return Err(CceJobPollError::Fatal(format!("HTTP {}: {}", status, body)));
+    }
+    serde_json::from_str(&body).map_err(|err| CceJobPollError::Fatal(err.to_string()))
+}
+
+/// Sub-jobs whose phase changed since the last poll, as `(step, phase)` pairs.
+fn cce_job_step_changes(seen: &mut HashMap<String, String>, job: &CceJob) -> Vec<(String, String)> {
+    let mut changes = Vec::new();
+    for (index, sub_job) in job.spec.sub_jobs.iter().flatten().enumerate() {
+        let step = sub_job
+            .spec
+            .job_type
+            .clone()
+            .unwrap_or_else(|| format!("step-{}", index + 1));
+        let key = sub_job.metadata.uid.clone().unwrap_or_else(|| step.clone());
+        let phase = sub_job.status.phase.clone().unwrap_or_default();
+        if seen.insert(key, phase.clone()).as_ref() != Some(&phase) {
+            changes.push((step, phase));
+        }
+    }
+    changes
+}
+
+fn emit_cce_upgrade_progress(
+    app_handle: &tauri::AppHandle,
+    cluster_id: &str,
+    stage: &str,
+    step: &str,
+    phase: &str,
+    message: Option<&str>,
+) {
+    let payload = CceUpgradeProgressEvent {
+        cluster_id: cluster_id.to_string(),
+        stage: stage.to_string(),
+        step: step.to_string(),
+        phase: phase.to_string(),
+        message: message
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+        at: Utc::now().to_rfc3339(),
+    };
+    if let Err(err) = app_handle.emit("cce-upgrade-progress", payload) {
+        warn!("Failed to emit cce-upgrade-progress event: {}", err);
+    }
+}
+
+/// List the Kubernetes versions a CCE cluster can be upgraded to.
+#[tauri::command]
+async fn get_cce_upgrade_info(
+    params: CceClusterUpgradeInfoParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceUpgradeInfo, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Getting CCE upgrade info: source={} region={} cluster_id={}",
+        source_label, params.region, cluster_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .get_cce_upgrade_info(&params.region, cluster_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get CCE upgrade info: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })
+}
+
+/// Run the pre-upgrade check for a target version and wait for its verdict.
+#[tauri::command]
+async fn precheck_cce_cluster_upgrade(
+    app_handle: tauri::AppHandle,
+    params: CceClusterUpgradeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceClusterTask, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.as_str();
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for the pre-upgrade check.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let info = client
+        .get_cce_upgrade_info(region, cluster_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    let target_version = resolve_cce_upgrade_target(&info, &params.target_version)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Starting CCE pre-upgrade check: source={} region={} cluster_id={} target={}",
+        source_label, region, cluster_id, target_version
+    );
+
+    let body = CcePrecheckRequest {
+        kind: "PreCheckTask".to_string(),
+        api_version: "v3".to_string(),
+        spec: CcePrecheckSpec {
+            cluster_upgrade_action: CceUpgradeAction {
+                version: None,
+                target_version,
+                strategy: None,
+            },
+        },
+    };
+    let task = client
+        .create_cce_upgrade_precheck(region, cluster_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to start CCE pre-upgrade check: region={} cluster_id={} error={}",
+                region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+    let task_id = task
+        .metadata
+        .uid
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| "CCE did not return a pre-upgrade check task ID.".to_string())?;
+
+    let mut last_phase: Option<String> = None;
+    for attempt in 1..=CCE_UPGRADE_PRECHECK_POLL_ATTEMPTS {
+        match client
+            .get_cce_upgrade_precheck(region, cluster_id, &task_id)
+            .await
+        {
+            Ok(task) => {
+                let phase = task.status.phase.clone().unwrap_or_default();
+                if last_phase.as_deref() != Some(phase.as_str()) {
+                    emit_cce_upgrade_progress(
+                        &app_handle,
+                        cluster_id,
+                        "precheck",
+                        "precheck",
+                        &phase,
+                        task.status.message.as_deref(),
+                    );
+                    last_phase = Some(phase);
+                }
+                if !cce_phase_is_pending(task.status.phase.as_deref()) {
+                    info!(
+                        "CCE pre-upgrade check finished: region={} cluster_id={} task_id={} phase={}",
+                        region,
+                        cluster_id,
+                        task_id,
+                        last_phase.as_deref().unwrap_or("")
+                    );
+                    return Ok(task);
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to poll CCE pre-upgrade check: region={} cluster_id={} task_id={} error={}",
+                    region, cluster_id, task_id, err
+                );
+            }
+        }
+        if attempt < CCE_UPGRADE_PRECHECK_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(CCE_JOB_POLL_INTERVAL_SECS)).await;
+        }
+    }
+    Err(format!(
+        "Timed out waiting for CCE pre-upgrade check {} (last phase: {}).",
+        task_id,
+        last_phase.as_deref().unwrap_or("unknown")
+    ))
+}
+
+/// Start upgrading a CCE cluster; follow the returned job with `follow_cce_cluster_upgrade`.
+#[tauri::command]
+async fn start_cce_cluster_upgrade(
+    params: CceClusterUpgradeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceUpgradeStartResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.as_str();
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required for the upgrade.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let (cluster, info) = futures::join!(
+        client.get_cce_cluster(region, cluster_id),
+        client.get_cce_upgrade_info(region, cluster_id),
+    );
+    let cluster = cluster.map_err(|err| err.to_string())?;
+    let info = info.map_err(|err| err.to_string())?;
+    let target_version = resolve_cce_upgrade_target(&info, &params.target_version)?;
+    let current_version = cluster.spec.version.clone();
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Starting CCE cluster upgrade: source={} region={} cluster_id={} from={} to={}",
+        source_label,
+        region,
+        cluster_id,
+        current_version.as_deref().unwrap_or("unknown"),
+        target_version
+    );
+
+    let body = CceUpgradeRequest {
+        metadata: CceUpgradeRequestMetadata {
+            api_version: "v3".to_string(),
+            kind: "UpgradeTask".to_string(),
+        },
+        spec: CceUpgradeSpec {
+            cluster_upgrade_action: CceUpgradeAction {
+                version: current_version,
+                target_version,
+                strategy: Some(CceUpgradeStrategy {
+                    strategy_type: "inPlaceRollingUpdate".to_string(),
+                }),
+            },
+        },
+    };
+    let (status, body) = client
+        .upgrade_cce_cluster(region, cluster_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to start CCE cluster upgrade: region={} cluster_id={} error={}",
+                region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+    let job_id = if status.is_success() {
+        extract_cce_job_id(&body)
+    } else {
+        None
+    };
+
+    Ok(CceUpgradeStartResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+        job_id,
+    })
+}
+
+/// Poll an upgrade job until it settles, emitting each sub-job transition.
+#[tauri::command]
+async fn follow_cce_cluster_upgrade(
+    app_handle: tauri::AppHandle,
+    params: CceFollowUpgradeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceJob, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.as_str();
+    let cluster_id = params.cluster_id.trim();
+    let job_id = params.job_id.trim();
+    if cluster_id.is_empty() || job_id.is_empty() {
+        return Err("CCE cluster ID and upgrade job ID are required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Following CCE cluster upgrade: source={} region={} cluster_id={} job_id={}",
+        source_label, region, cluster_id, job_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut seen_steps = HashMap::new();
+    let mut last_phase: Option<String> = None;
+    for attempt in 1..=CCE_UPGRADE_JOB_POLL_ATTEMPTS {
+        match fetch_cce_job(&client, region, job_id).await {
+            Ok(job) => {
+                for (step, phase) in cce_job_step_changes(&mut seen_steps, &job) {
+                    emit_cce_upgrade_progress(
+                        &app_handle,
+                        cluster_id,
+                        "upgrade",
+                        &step,
+                        &phase,
+                        None,
+                    );
+                }
+                let phase = job.status.phase.clone().unwrap_or_default();
+                if last_phase.as_deref() != Some(phase.as_str()) {
+                    emit_cce_upgrade_progress(
+                        &app_handle,
+                        cluster_id,
+                        "upgrade",
+                        "upgrade",
+                        &phase,
+                        job.status.reason.as_deref(),
+                    );
+                    last_phase = Some(phase);
+                }
+                if !cce_phase_is_pending(job.status.phase.as_deref()) {
+                    info!(
+                        "CCE cluster upgrade finished: region={} cluster_id={} job_id={} phase={}",
+                        region,
+                        cluster_id,
+                        job_id,
+                        last_phase.as_deref().unwrap_or("")
+                    );
+                    return Ok(job);
+                }
+            }
+            Err(CceJobPollError::Retryable(err)) => {
+                warn!(
+                    "Failed to poll CCE upgrade job: region={} cluster_id={} job_id={} error={}",
+                    region, cluster_id, job_id, err
+                );
+            }
+            Err(CceJobPollError::Fatal(err)) => {
+                error!(
+                    "CCE upgrade job cannot be followed: region={} cluster_id={} job_id={} error={}",
+                    region, cluster_id, job_id, err
+                );
+                return Err(format!(
+                    "Failed to follow CCE upgrade job {}: {}",
+                    job_id, err
+                ));
+            }
+        }
+        if attempt < CCE_UPGRADE_JOB_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(CCE_JOB_POLL_INTERVAL_SECS)).await;
+        }
+    }
+    Err(format!(
+        "Timed out following CCE upgrade job {} (last phase: {}).",
+        job_id,
+        last_phase.as_deref().unwrap_or("unknown")
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CceClusterPowerAction {
+    Hibernate,
+    Awake,
+}
+
+impl CceClusterPowerAction {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hibernate => "hibernate",
+            Self::Awake => "awake",
+        }
+    }
+
+    /// Cluster phase reported once the action has taken effect.
+    fn target_phase(self) -> &'static str {
+        match self {
+            Self::Hibernate => "Hibernation",
+            Self::Awake => "Available",
+        }
+    }
+}
+
+/// Wait for the operation job, when CCE returned one, to succeed and then for the
+/// cluster itself to report `target_phase`.
+async fn wait_for_cce_cluster_phase(
+    client: &HwcClient,
+    region: &str,
+    cluster_id: &str,
+    job_id: Option<&str>,
+    target_phase: &str,
+) -> Result<CceCluster, String> {
+    if let Some(job_id) = job_id {
+        let mut settled = false;
+        for attempt in 1..=CCE_CLUSTER_PHASE_POLL_ATTEMPTS {
+            match fetch_cce_job(client, region, job_id).await {
+                Ok(job) if !cce_phase_is_pending(job.status.phase.as_deref()) => {
+                    let phase = job.status.phase.unwrap_or_default();
+                    if !phase.eq_ignore_ascii_case("success") {
+                        return Err(format!(
+                            "CCE job {} ended in phase {}: {}",
+                            job_id,
+                            phase,
+                            job.status.reason.as_deref().unwrap_or("no reason given")
+                        ));
+                    }
+                    settled = true;
+                    break;
+                }
+                Ok(_) => {}
+                Err(CceJobPollError::Retryable(err)) => {
+                    warn!(
+                        "Failed to poll CCE job: region={} cluster_id={} job_id={} error={}",
+                        region, cluster_id, job_id, err
+                    );
+                }
+                Err(CceJobPollError::Fatal(err)) => {
+                    return Err(format!("Failed to poll CCE job {}: {}", job_id, err));
+                }
+            }
+            if attempt < CCE_CLUSTER_PHASE_POLL_ATTEMPTS {
+                tokio::time::sleep(Duration::from_secs(CCE_JOB_POLL_INTERVAL_SECS)).await;
+            }
+        }
+        if !settled {
+            return Err(format!("Timed out waiting for CCE job {}.", job_id));
+        }
+    }
+
+    let mut last_phase: Option<String> = None;
+    for attempt in 1..=CCE_CLUSTER_PHASE_POLL_ATTEMPTS {
+        match client.get_cce_cluster(region, cluster_id).await {
+            Ok(cluster) => {
+                let phase = cluster.status.phase.clone().unwrap_or_default();
+                if phase.eq_ignore_ascii_case(target_phase) {
+                    return Ok(cluster);
+                }
+                if phase.eq_ignore_ascii_case("error") {
+                    return Err(format!(
+                        "CCE cluster {} entered the Error phase while waiting for {}.",
+                        cluster_id, target_phase
+                    ));
+                }
+                last_phase = Some(phase);
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to poll CCE cluster: region={} cluster_id={} error={}",
+                    region, cluster_id, err
+                );
+            }
+        }
+        if attempt < CCE_CLUSTER_PHASE_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(CCE_JOB_POLL_INTERVAL_SECS)).await;
+        }
+    }
+    Err(format!(
+        "Timed out waiting for CCE cluster {} to reach {} (last phase: {}).",
+        cluster_id,
+        target_phase,
+        last_phase.as_deref().unwrap_or("unknown")
+    ))
+}
+
+/// Request hibernation or wake-up and wait for the cluster to reach the matching phase.
+async fn run_cce_cluster_power_action(
+    client: &HwcClient,
+    region: &str,
+    cluster_id: &str,
+    action: CceClusterPowerAction,
+) -> Result<CceCluster, String> {
+    let requested = match action {
+        CceClusterPowerAction::Hibernate => client.hibernate_cce_cluster(region, cluster_id).await,
+        CceClusterPowerAction::Awake => client.awake_cce_cluster(region, cluster_id).await,
+    };
+    let (status, body) = requested.map_err(|err| {
+        error!(
+            "Failed to {} CCE cluster: region={} cluster_id={} error={}",
+            action.label(),
+            region,
+            cluster_id,
+            err
+        );
+        err.to_string()
+    })?;
+    if !status.is_success() {
+        return Err(format!(
+            "CCE rejected the {} request: HTTP {}: {}",
+            action.label(),
+            status,
+            body
+        ));
+    }
+    let job_id = extract_cce_job_id(&body);
+    let cluster = wait_for_cce_cluster_phase(
+        client,
+        region,
+        cluster_id,
+        job_id.as_deref(),
+        action.target_phase(),
+    )
+    .await?;
+    info!(
+        "CCE cluster {} finished: region={} cluster_id={} phase={}",
+        action.label(),
+        region,
+        cluster_id,
+        action.target_phase()
+    );
+    Ok(cluster)
+}
+
+async fn change_cce_cluster_power(
+    params: CceClusterPowerParams,
+    credentials: Option<CredentialsInput>,
+    action: CceClusterPowerAction,
+) -> Result<CceCluster, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Requesting CCE cluster {}: source={} region={} cluster_id={}",
+        action.label(),
+        source_label,
+        params.region,
+        cluster_id
+    );
+
+    let client = HwcClient::new(credentials);
+    run_cce_cluster_power_action(&client, &params.region, cluster_id, action).await
+}
+
+/// Hibernate a CCE cluster and wait until it reports the Hibernation phase.
+#[tauri::command]
+async fn hibernate_cce_cluster(
+    params: CceClusterPowerParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceCluster, String> {
+    change_cce_cluster_power(params, credentials, CceClusterPowerAction::Hibernate).await
+}
+
+/// Wake a hibernated CCE cluster and wait until it is Available again.
+#[tauri::command]
+async fn awake_cce_cluster(
+    params: CceClusterPowerParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceCluster, String> {
+    change_cce_cluster_power(params, credentials, CceClusterPowerAction::Awake).await
+}
+
+/// Hand the scheduler fresh credentials and start its loop on first use.
+fn arm_cce_hibernate_scheduler(
+    app_handle: &tauri::AppHandle,
+    state: &CceScheduleState,
+    credentials: Credentials,
+) {
+    state.set_credentials(credentials);
+    if state.claim_loop() {
+        tauri::async_runtime::spawn(run_cce_hibernate_scheduler(app_handle.clone()));
+    }
+}
+
+/// Check the stored schedules every minute and hibernate due clusters one at a time.
+async fn run_cce_hibernate_scheduler(app_handle: tauri::AppHandle) {
+    info!("CCE hibernate scheduler started");
+    loop {
+        tokio::time::sleep(Duration::from_secs(CCE_HIBERNATE_SCHEDULER_TICK_SECS)).await;
+        let Some(credentials) = app_handle.state::<CceScheduleState>().credentials() else {
+            continue;
+        };
+        let now = Local::now().naive_local();
+        let due: Vec<CceHibernateSchedule> = match load_cce_hibernate_schedules() {
+            Ok(schedules) => schedules
+                .into_iter()
+                .filter(|schedule| schedule.is_due(now))
+                .collect(),
+            Err(err) => {
+                warn!("Failed to load CCE hibernate schedules: {}", err);
+                continue;
+            }
+        };
+        for schedule in due {
+            run_scheduled_cce_hibernation(&app_handle, &credentials, &schedule, now.date()).await;
+        }
+    }
+}
+
+async fn run_scheduled_cce_hibernation(
+    app_handle: &tauri::AppHandle,
+    credentials: &Credentials,
+    schedule: &CceHibernateSchedule,
+    today: NaiveDate,
+) {
+    info!(
+        "Running scheduled CCE hibernation: region={} cluster_id={} at={}",
+        schedule.region, schedule.cluster_id, schedule.hibernate_at
+    );
+    let client = HwcClient::new(credentials.clone());
+    let result = run_cce_cluster_power_action(
+        &client,
+        &schedule.region,
+        &schedule.cluster_id,
+        CceClusterPowerAction::Hibernate,
+    )
+    .await;
+    let error = result.as_ref().err().cloned();
+    if let Some(err) = &error {
+        warn!(
+            "Scheduled CCE hibernation failed: region={} cluster_id={} error={}",
+            schedule.region, schedule.cluster_id, err
+        );
+    }
+
+    // Mark the stored copy so edits made while the cluster was hibernating are kept.
+    let marked = update_cce_hibernate_schedules(|schedules| {
+        if let Some(stored) = schedules
+            .iter_mut()
+            .find(|stored| stored.matches(&schedule.region, &schedule.cluster_id))
+        {
+            stored.mark_run(today, error.clone());
+        }
+    });
+    if let Err(err) = marked {
+        warn!("Failed to store CCE hibernate schedule: {}", err);
+    }
+
+    let payload = CceHibernateScheduleEvent {
+        region: schedule.region.clone(),
+        cluster_id: schedule.cluster_id.clone(),
+        phase: result.ok().and_then(|cluster| cluster.status.phase),
+        error,
+        at: Utc::now().to_rfc3339(),
+    };
+    if let Err(err) = app_handle.emit("cce-hibernate-schedule", payload) {
+        warn!("Failed to emit cce-hibernate-schedule event: {}", err);
+    }
+}
+
+/// Save the daily hibernate time of a cluster and arm the scheduler with these credentials.
+#[tauri::command]
+async fn set_cce_hibernate_schedule(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, CceScheduleState>,
+    params: CceSetHibernateScheduleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<CceHibernateSchedule>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.trim();
+    let cluster_id = params.cluster_id.trim();
+    if region.is_empty() || cluster_id.is_empty() {
+        return Err("Region and CCE cluster ID are required for a hibernate schedule.".to_string());
+    }
+    let hibernate_at = parse_hibernate_time(&params.hibernate_at)?;
+
+    let cluster_name = params
+        .cluster_name
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let mut schedule = CceHibernateSchedule::new(
+        region,
+        cluster_id,
+        cluster_name,
+        hibernate_at,
+        Local::now().naive_local(),
+    );
+    schedule.enabled = params.enabled.unwrap_or(true);
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Saving CCE hibernate schedule: source={} region={} cluster_id={} at={} enabled={}",
+        source_label, region, cluster_id, schedule.hibernate_at, schedule.enabled
+    );
+
+    let schedules = update_cce_hibernate_schedules(|schedules| {
+        match schedules
+            .iter_mut()
+            .find(|stored| stored.matches(region, cluster_id))
+        {
+            Some(stored) => {
+                schedule.created_at = stored.created_at.clone();
+                if schedule.cluster_name.is_none() {
+                    schedule.cluster_name = stored.cluster_name.clone();
+                }
+                *stored = schedule;
+            }
+            None => schedules.push(schedule),
+        }
+    })?;
+    arm_cce_hibernate_scheduler(&app_handle, &state, credentials);
+    Ok(schedules)
+}
+
+/// Stored hibernate schedules. Passing credentials arms the scheduler for this session;
+/// the frontend does so once stored credentials load and again after they are saved.
+#[tauri::command]
+async fn list_cce_hibernate_schedules(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, CceScheduleState>,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<CceHibernateSchedule>, String> {
+    if credentials.is_some() {
+        let (credentials, _) = resolve_credentials(credentials)?;
+        arm_cce_hibernate_scheduler(&app_handle, &state, credentials);
+    }
+    load_cce_hibernate_schedules()
+}
+
+/// Stop hibernating a cluster on schedule.
+#[tauri::command]
+async fn delete_cce_hibernate_schedule(
+    params: CceHibernateScheduleKeyParams,
+) -> Result<Vec<CceHibernateSchedule>, String> {
+    info!(
+        "Removing CCE hibernate schedule: region={} cluster_id={}",
+        params.region, params.cluster_id
+    );
+    let (region, cluster_id) = (params.region.trim(), params.cluster_id.trim());
+    update_cce_hibernate_schedules(|schedules| {
+        schedules.retain(|schedule| !schedule.matches(region, cluster_id))
+    })
+}
+
+fn find_cce_addon_template_version<'a>(
+    templates: &'a [CceAddonTemplate],
+    template_name: &str,
+    version: &str,
+) -> Result<&'a CceAddonTemplateVersion, String> {
+    let template = templates
+        .iter()
+        .find(|template| template.metadata.name.as_deref() == Some(template_name))
+        .ok_or_else(|| format!("CCE add-on template {} was not found.", template_name))?;
+    template.version(version).ok_or_else(|| {
+        let available = template
+            .spec
+            .versions
+            .iter()
+            .map(|candidate| candidate.version.as_str())
+            .collect::<Vec<_>>();
+        format!(
+            "Add-on {} has no version {}. Available: {}.",
+            template_name,
+            version,
+            available.join(", ")
+        )
+    })
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Overlay caller values on one section, keeping the JSON type of each template default.
+fn merge_cce_addon_section(
+    section: &str,
+    values: &mut Map<String, Value>,
+    defaults: &Map<String, Value>,
+    overrides: Option<&Map<String, Value>>,
+) -> Result<(), String> {
+    for (key, value) in overrides.into_iter().flatten() {
+        if let Some(default) = defaults.get(key).filter(|default| !default.is_null()) {
+            if !value.is_null() && json_kind(value) != json_kind(default) {
+                return Err(format!(
+                    "Add-on value {}.{} must be a {}.",
+                    section,
+                    key,
+                    json_kind(default)
+                ));
+            }
+        }
+        values.insert(key.clone(), value.clone());
+    }
+    Ok(())
+}
+
+/// Values for installing or upgrading to one template version: the template defaults,
+/// then the running add-on's settings (on upgrade), then the caller overrides. CCE
+/// leaves `cluster_id` and `tenant_id` blank in its templates, so they are filled in
+/// here, and any value the template declares as `null` must end up set.
+fn build_cce_addon_values(
+    template_name: &str,
+    version: &CceAddonTemplateVersion,
+    current: Option<&CceAddonValues>,
+    overrides: &CceAddonValueOverrides,
+    cluster_id: &str,
+    project_id: &str,
+) -> Result<CceAddonValues, String> {
+    let input = &version.input;
+    let mut basic = input.basic.clone();
+    let mut custom = input.parameters.custom.clone();
+    let mut flavor = None;
+    if let Some(current) = current {
+        // Basic values hold image coordinates, which follow the target version.
+        custom.extend(current.custom.clone());
+        flavor = current.flavor.clone();
+    }
+    merge_cce_addon_section("basic", &mut basic, &input.basic, overrides.basic.as_ref())?;
+    merge_cce_addon_section(
+        "custom",
+        &mut custom,
+        &input.parameters.custom,
+        overrides.custom.as_ref(),
+    )?;
+
+    let flavors = input.parameters.flavors();
+    match overrides
+        .flavor
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(name) => {
+            let (_, value) = flavors
+                .iter()
+                .find(|(candidate, _)| *candidate == name)
+                .ok_or_else(|| {
+                    let available = flavors.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+                    format!(
+                        "Add-on {} {} has no flavor {}. Available: {}.",
+                        template_name,
+                        version.version,
+                        name,
+                        available.join(", ")
+                    )
+                })?;
+            flavor = Some((*value).clone());
+        }
+        None if flavor.is_none() => {
+            flavor = flavors.first().map(|(_, value)| (*value).clone());
+        }
+        None => {}
+    }
+
+    for (key, fill) in [
+        ("cluster_id", cluster_id),
+        ("tenant_id", project_id),
+        ("project_id", project_id),
+    ] {
+        if let Some(value) = custom
+            .get_mut(key)
+            .filter(|value| value.as_str() == Some(""))
+        {
+            *value = Value::String(fill.to_string());
+        }
+    }
+
+    let missing = basic
+        .iter()
+        .map(|(key, value)| ("basic", key, value))
+        .chain(custom.iter().map(|(key, value)| ("custom", key, value)))
+        .filter(|(_, _, value)| value.is_null())
+        .map(|(section, key, _)| format!("{}.{}", section, key))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Add-on {} {} needs values for: {}.",
+            template_name,
+            version.version,
+            missing.join(", ")
+        ));
+    }
+
+    Ok(CceAddonValues {
+        basic,
+        custom,
+        flavor,
+        extra: Map::new(),
+    })
+}
+
+fn build_cce_addon_request(
+    action: &str,
+    cluster_id: &str,
+    template_name: &str,
+    version: &str,
+    values: CceAddonValues,
+) -> CceAddonRequest {
+    let annotation = format!("addon.{}/type", action);
+    CceAddonRequest {
+        kind: "Addon".to_string(),
+        api_version: "v3".to_string(),
+        metadata: CceAddonRequestMetadata {
+            annotations: BTreeMap::from([(annotation, action.to_string())]),
+        },
+        spec: CceAddonRequestSpec {
+            cluster_id: cluster_id.to_string(),
+            version: version.to_string(),
+            addon_template_name: template_name.to_string(),
+            values,
+        },
+    }
+}
+
+/// List add-on templates from the CCE catalog with their versions and default values.
+#[tauri::command]
+async fn list_cce_addon_templates(
+    params: CceListAddonTemplatesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<CceAddonTemplate>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE add-on templates: source={} region={} name={}",
+        source_label,
+        params.region,
+        params.template_name.as_deref().unwrap_or("")
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_cce_addon_templates(&params.region, params.template_name.as_deref())
+        .await
+        .map(|response| response.items)
+        .map_err(|err| {
+            error!(
+                "Failed to list CCE add-on templates: region={} error={}",
+                params.region, err
+            );
+            err.to_string()
+        })
+}
+
+/// List add-ons installed in one CCE cluster.
+#[tauri::command]
+async fn list_cce_addons(
+    params: CceListAddonsParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<CceAddonInstance>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE add-ons: source={} region={} cluster_id={}",
+        source_label, params.region, cluster_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_cce_addons(&params.region, cluster_id)
+        .await
+        .map(|response| response.items)
+        .map_err(|err| {
+            error!(
+                "Failed to list CCE add-ons: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })
+}
+
+/// Install an add-on from the catalog with the template defaults plus custom values.
+#[tauri::command]
+async fn install_cce_addon(
+    params: CceInstallAddonParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.as_str();
+    let cluster_id = params.cluster_id.trim();
+    let template_name = params.template_name.trim();
+    let version = params.version.trim();
+    if cluster_id.is_empty() || template_name.is_empty() || version.is_empty() {
+        return Err("CCE cluster ID, add-on name and version are required.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let (templates, project_id) = futures::join!(
+        client.list_cce_addon_templates(region, Some(template_name)),
+        client.project_id(region),
+    );
+    let templates = templates.map_err(|err| err.to_string())?.items;
+    let project_id = project_id.map_err(|err| err.to_string())?;
+    let template_version = find_cce_addon_template_version(&templates, template_name, version)?;
+    let values = build_cce_addon_values(
+        template_name,
+        template_version,
+        None,
+        &params.values,
+        cluster_id,
+        &project_id,
+    )?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Installing CCE add-on: source={} region={} cluster_id={} addon={} version={}",
+        source_label, region, cluster_id, template_name, version
+    );
+
+    let body = build_cce_addon_request("install", cluster_id, template_name, version, values);
+    let (status, body) = client
+        .create_cce_addon(region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to install CCE add-on: region={} cluster_id={} addon={} error={}",
+                region, cluster_id, template_name, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Move an installed add-on to another template version, keeping its current settings.
+#[tauri::command]
+async fn upgrade_cce_addon(
+    params: CceUpgradeAddonParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.as_str();
+    let cluster_id = params.cluster_id.trim();
+    let addon_id = params.addon_id.trim();
+    let version = params.version.trim();
+    if cluster_id.is_empty() || addon_id.is_empty() || version.is_empty() {
+        return Err("CCE cluster ID, add-on ID and version are required.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let addon = client
+        .get_cce_addon(region, cluster_id, addon_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    let template_name = addon
+        .spec
+        .addon_template_name
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| "CCE did not return the template of this add-on.".to_string())?;
+    let (templates, project_id) = futures::join!(
+        client.list_cce_addon_templates(region, Some(&template_name)),
+        client.project_id(region),
+    );
+    let templates = templates.map_err(|err| err.to_string())?.items;
+    let project_id = project_id.map_err(|err| err.to_string())?;
+    let template_version = find_cce_addon_template_version(&templates, &template_name, version)?;
+    let values = build_cce_addon_values(
+        &template_name,
+        template_version,
+        Some(&addon.spec.values),
+        &params.values,
+        cluster_id,
+        &project_id,
+    )?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Upgrading CCE add-on: source={} region={} cluster_id={} addon={} from={} to={}",
+        source_label,
+        region,
+        cluster_id,
+        template_name,
+        addon.spec.version.as_deref().unwrap_or("unknown"),
+        version
+    );
+
+    let body = build_cce_addon_request("upgrade", cluster_id, &template_name, version, values);
+    let (status, body) = client
+        .update_cce_addon(region, addon_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to upgrade CCE add-on: region={} cluster_id={} addon_id={} error={}",
+                region, cluster_id, addon_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Uninstall an add-on from a CCE cluster.
+#[tauri::command]
+async fn uninstall_cce_addon(
+    params: CceAddonParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    let addon_id = params.addon_id.trim();
+    if cluster_id.is_empty() || addon_id.is_empty() {
+        return Err("CCE cluster ID and add-on ID are required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Uninstalling CCE add-on: source={} region={} cluster_id={} addon_id={}",
+        source_label, params.region, cluster_id, addon_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_cce_addon(&params.region, cluster_id, addon_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to uninstall CCE add-on: region={} cluster_id={} addon_id={} error={}",
+                params.region, cluster_id, addon_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// List NAT gateways scoped to the selected CCE VPC/subnet.
+#[tauri::command]
+async fn list_cce_nat_gateways(
+    params: CceListNatGatewaysParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<NatGatewayListResponse, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        true,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let vpc_id = params.vpc_id.trim();
+    if vpc_id.is_empty() {
+        return Err("CCE NAT requires a VPC.".to_string());
+    }
+    let subnet_id = params.subnet_id.trim();
+    if subnet_id.is_empty() {
+        return Err("CCE NAT requires a subnet.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing CCE NAT gateways: source={} region={} vpc_id={} subnet_id={}",
+        source_label, params.region, vpc_id, subnet_id
+    );
+
+    let tag_filters = normalize_tag_filters(params.tags.clone())?;
+    let client = HwcClient::new(credentials);
+    let mut response = client
+        .list_nat_gateways(
+            &params.region,
+            Some(vpc_id),
+            Some(subnet_id),
+            enterprise_project_id.as_deref(),
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list CCE NAT gateways: region={} vpc_id={} subnet_id={} error={}",
+                params.region, vpc_id, subnet_id, err
+            );
+            err.to_string()
+        })?;
+    if let Some(ids) = tagged_resource_ids(
+        &client,
+        &params.region,
+        TagService::NatGateway,
+        &tag_filters,
+    )
+    .await?
+    {
+        response
+            .nat_gateways
+            .retain(|gateway| gateway.id.as_ref().is_some_and(|id| ids.contains(id)));
+    }
+
+    Ok(response)
+}
+
+/// NAT gateway specs: 1 small, 2 medium, 3 large, 4 extra-large.
+fn normalize_cce_nat_spec(spec: Option<&str>) -> Result<&str, String> {
+    let spec = spec
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("1");
+    if !matches!(spec, "1" | "2" | "3" | "4") {
+        return Err("Unsupported NAT gateway spec. Use 1, 2, 3 or 4.".to_string());
+    }
+    Ok(spec)
+}
+
+fn normalize_snat_eip_ids(input: Option<Vec<String>>) -> Result<Vec<String>, String> {
+    let eip_ids = normalize_id_list(input);
+    if eip_ids.len() > NAT_SNAT_EIP_MAX {
+        return Err(format!(
+            "An SNAT rule supports up to {} EIPs.",
+            NAT_SNAT_EIP_MAX
+        ));
+    }
+    Ok(eip_ids)
+}
+
+fn build_snat_rule_create_body(
+    params: &CceCreateSnatRuleParams,
+) -> Result<SnatRuleCreateBody, String> {
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let network_id = params
+        .subnet_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let cidr = params
+        .cidr
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(normalize_ipv4_network_cidr)
+        .transpose()?;
+    if network_id.is_some() == cidr.is_some() {
+        return Err("SNAT rules need exactly one of a subnet or a CIDR.".to_string());
+    }
+    let eip_ids = normalize_snat_eip_ids(Some(params.eip_ids.clone()))?;
+    if eip_ids.is_empty() {
+        return Err("SNAT rules require at least one EIP.".to_string());
+    }
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    Ok(SnatRuleCreateBody {
+        nat_gateway_id: nat_gateway_id.to_string(),
+        source_type: cidr.as_ref().map(|_| 0),
+        network_id,
+        cidr,
+        floating_ip_id: eip_ids.join(","),
+        description,
+    })
+}
+
+/// Change the name, description or spec of one NAT gateway.
+#[tauri::command]
+async fn update_cce_nat_gateway(
+    params: CceUpdateNatGatewayParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let name = params
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let spec = match params
+        .spec
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(spec) => Some(normalize_cce_nat_spec(Some(spec))?.to_string()),
+        None => None,
+    };
+    // An empty description clears it, so only trim.
+    let description = params
+        .description
+        .as_deref()
+        .map(|value| value.trim().to_string());
+    if name.is_none() && spec.is_none() && description.is_none() {
+        return Err("Nothing to update: set a name, description or spec.".to_string());
+    }
+    let body = NatGatewayUpdateBody {
+        name,
+        description,
+        spec,
+    };
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Updating CCE NAT gateway: source={} region={} nat_gateway_id={} name={:?} spec={:?}",
+        source_label, params.region, nat_gateway_id, body.name, body.spec
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .update_nat_gateway(&params.region, nat_gateway_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to update CCE NAT gateway: region={} nat_gateway_id={} error={}",
+                params.region, nat_gateway_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// List SNAT rules on one NAT gateway.
+#[tauri::command]
+async fn list_cce_snat_rules(
+    params: CceListSnatRulesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<SnatRuleListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing SNAT rules: source={} region={} nat_gateway_id={}",
+        source_label, params.region, nat_gateway_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_snat_rules(&params.region, nat_gateway_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list SNAT rules: region={} nat_gateway_id={} error={}",
+                params.region, nat_gateway_id, err
+            );
+            err.to_string()
+        })
+}
+
+/// Add an SNAT rule for another subnet or CIDR, bound to one or more existing EIPs.
+#[tauri::command]
+async fn create_cce_snat_rule(
+    params: CceCreateSnatRuleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let body = build_snat_rule_create_body(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating SNAT rule: source={} region={} nat_gateway_id={} subnet_id={:?} cidr={:?} eip_ids={}",
+        source_label,
+        params.region,
+        body.nat_gateway_id,
+        body.network_id,
+        body.cidr,
+        body.floating_ip_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .create_snat_rule(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create SNAT rule: region={} nat_gateway_id={} error={}",
+                params.region, params.nat_gateway_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Replace the EIPs or description of one SNAT rule.
+#[tauri::command]
+async fn update_cce_snat_rule(
+    params: CceUpdateSnatRuleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let snat_rule_id = params.snat_rule_id.trim();
+    if snat_rule_id.is_empty() {
+        return Err("SNAT rule ID is required.".to_string());
+    }
+    let eip_addresses = match params.eip_addresses.clone() {
+        Some(addresses) => {
+            let addresses = normalize_snat_eip_ids(Some(addresses))?;
+            if addresses.is_empty() {
+                return Err("SNAT rules require at least one EIP.".to_string());
+            }
+            if let Some(invalid) = addresses
+                .iter()
+                .find(|address| address.parse::<std::net::Ipv4Addr>().is_err())
+            {
+                return Err(format!("Invalid EIP address '{}'.", invalid));
+            }
+            Some(addresses.join(","))
+        }
+        None => None,
+    };
+    let description = params
+        .description
+        .as_deref()
+        .map(|value| value.trim().to_string());
+    if eip_addresses.is_none() && description.is_none() {
+        return Err("Nothing to update: set EIP addresses or a description.".to_string());
+    }
+    let body = SnatRuleUpdateBody {
+        nat_gateway_id: nat_gateway_id.to_string(),
+        public_ip_address: eip_addresses,
+        description,
+    };
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Updating SNAT rule: source={} region={} nat_gateway_id={} snat_rule_id={} eip_addresses={:?}",
+        source_label, params.region, nat_gateway_id, snat_rule_id, body.public_ip_address
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .update_snat_rule(&params.region, snat_rule_id, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to update SNAT rule: region={} snat_rule_id={} error={}",
+                params.region, snat_rule_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Delete one SNAT rule; its EIPs are kept.
+#[tauri::command]
+async fn delete_cce_snat_rule(
+    params: CceDeleteSnatRuleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let snat_rule_id = params.snat_rule_id.trim();
+    if snat_rule_id.is_empty() {
+        return Err("SNAT rule ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting SNAT rule: source={} region={} nat_gateway_id={} snat_rule_id={}",
+        source_label, params.region, nat_gateway_id, snat_rule_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_snat_rule(&params.region, nat_gateway_id, snat_rule_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete SNAT rule: region={} nat_gateway_id={} snat_rule_id={} error={}",
+                params.region, nat_gateway_id, snat_rule_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Why a NAT bootstrap step stopped: the API answered with an error status,
+/// already recorded in the summary, or the request itself failed.
+enum NatBootstrapStepError {
+    Status(reqwest::StatusCode),
+    Request(String),
+}
+
+fn persist_nat_bootstrap_run(run: &NatBootstrapRun) {
+    if let Err(err) = store_nat_bootstrap_run(run) {
+        warn!(
+            "Failed to persist NAT bootstrap run: region={} name={} error={}",
+            run.region, run.name, err
+        );
+    }
+}
+
+fn nat_bootstrap_result(status: reqwest::StatusCode, summary: &Value) -> CceOperationResult {
+    let body = serde_json::to_string_pretty(summary).unwrap_or_else(|_| summary.to_string());
+    cce_operation_result(status, body)
+}
+
+/// Adopt a gateway with the run's name on its subnet, or create one, then wait for ACTIVE.
+async fn run_nat_gateway_step(
+    client: &HwcClient,
+    run: &mut NatBootstrapRun,
+    summary: &mut Value,
+) -> Result<reqwest::StatusCode, NatBootstrapStepError> {
+    let existing = client
+        .list_nat_gateways(
+            &run.region,
+            Some(&run.vpc_id),
+            Some(&run.subnet_id),
+            run.enterprise_project_id.as_deref(),
+        )
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?;
+    let mut status = reqwest::StatusCode::OK;
+    let nat_gateway_id =
+        match find_adoptable_nat_gateway(&existing.nat_gateways, &run.name, &run.subnet_id) {
+            Some(gateway) => {
+                summary["nat_gateway"] = json!({
+                    "status": "adopted",
+                    "body": gateway
+                });
+                run.nat_gateway_adopted = true;
+                gateway.id.clone().unwrap_or_default()
+            }
+            None => {
+                let nat_request = NatGatewayCreateBody {
+                    name: run.name.clone(),
+                    description: run.description.clone(),
+                    spec: run.spec.clone(),
+                    router_id: run.vpc_id.clone(),
+                    internal_network_id: run.subnet_id.clone(),
+                    enterprise_project_id: run.enterprise_project_id.clone(),
+                };
+                let (nat_status, nat_body) = client
+                    .create_nat_gateway(&run.region, &nat_request)
+                    .await
+                    .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?;
+                summary["nat_gateway"] = json!({
+                    "status": nat_status.to_string(),
+                    "status_code": nat_status.as_u16(),
+                    "body": parse_json_or_string(&nat_body)
+                });
+                if !nat_status.is_success() {
+                    return Err(NatBootstrapStepError::Status(nat_status));
+                }
+                let Some(nat_gateway_id) = extract_nat_gateway_id(&nat_body) else {
+                    summary["error"] = json!(
+                        "NAT gateway create succeeded but response did not contain nat_gateway.id."
+                    );
+                    return Err(NatBootstrapStepError::Status(
+                        reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                };
+                if !run.tags.is_empty() {
+                    summary["nat_gateway"]["tags"] = tag_step_summary(
+                        apply_resource_tags(
+                            client,
+                            &run.region,
+                            TaggableResource::NatGateway,
+                            &nat_gateway_id,
+                            &run.tags,
+                        )
+                        .await,
+                    );
+                }
+                status = nat_status;
+                nat_gateway_id
+            }
+        };
+    summary["nat_gateway"]["id"] = json!(nat_gateway_id.clone());
+    run.nat_gateway_id = Some(nat_gateway_id.clone());
+
+    let mut last_nat_status = String::new();
+    for attempt in 1..=8 {
+        match client.get_nat_gateway(&run.region, &nat_gateway_id).await {
+            Ok(response) => {
+                let status_text = response
+                    .nat_gateway
+                    .status
+                    .as_deref()
+                    .map(str::trim)
+                    .unwrap_or("");
+                if !status_text.is_empty() {
+                    last_nat_status = status_text.to_string();
+                }
+                if status_text.eq_ignore_ascii_case("ACTIVE") {
+                    summary["nat_gateway"]["ready_status"] = json!(status_text);
+                    summary["nat_gateway"]["ready_attempt"] = json!(attempt);
+                    break;
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to poll NAT gateway status after create: region={} nat_gateway_id={} error={}",
+                    run.region, nat_gateway_id, err
+                );
+            }
+        }
+        if attempt < 8 {
+            tokio::time::sleep(Duration::from_secs(4)).await;
+        }
+    }
+    if !last_nat_status.is_empty() {
+        summary["nat_gateway"]["last_observed_status"] = json!(last_nat_status);
+    }
+
+    Ok(status)
+}
+
+/// Adopt the EIP of an earlier run (through its SNAT rule or its bandwidth name),
+/// or allocate a new one.
+async fn run_nat_eip_step(
+    client: &HwcClient,
+    run: &mut NatBootstrapRun,
+    summary: &mut Value,
+) -> Result<reqwest::StatusCode, NatBootstrapStepError> {
+    let nat_gateway_id = run.nat_gateway_id.clone().unwrap_or_default();
+    let rules = client
+        .list_snat_rules(&run.region, &nat_gateway_id)
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?
+        .snat_rules;
+    if let Some(rule) = find_adoptable_snat_rule(&rules, &run.subnet_id) {
+        let eip_ids = rule
+            .floating_ip_id
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if !eip_ids.is_empty() {
+            // The rule's EIPs may predate this run, so rollback keeps them.
+            summary["eip"] = json!({
+                "status": "adopted",
+                "ids": eip_ids.clone()
+            });
+            summary["snat_rule"] = json!({
+                "status": "adopted",
+                "body": rule
+            });
+            run.reused_eip_ids = eip_ids;
+            run.snat_rule_id = rule.id.clone();
+            return Ok(reqwest::StatusCode::OK);
+        }
+    }
+
+    let eip_name = run.eip_name();
+    let eips = client
+        .list_eips(&run.region, None)
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?;
+    if let Some(eip) = find_adoptable_eip(&eips.publicips, &eip_name) {
+        summary["eip"] = json!({
+            "status": "adopted",
+            "id": eip.id.clone(),
+            "address": eip.public_ip_address.clone()
+        });
+        run.eip_id = eip.id.clone();
+        run.eip_address = eip.public_ip_address.clone();
+        return Ok(reqwest::StatusCode::OK);
+    }
+
+    let (eip_status, eip_body) = client
+        .create_eip(
+            &run.region,
+            DEFAULT_BANDWIDTH_SIZE,
+            Some(&eip_name),
+            run.enterprise_project_id.as_deref(),
+        )
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?;
+    summary["eip"] = json!({
+        "status": eip_status.to_string(),
+        "status_code": eip_status.as_u16(),
+        "body": parse_json_or_string(&eip_body)
+    });
+    if !eip_status.is_success() {
+        return Err(NatBootstrapStepError::Status(eip_status));
+    }
+
+    let (eip_id, eip_address) = extract_eip_id_and_address(&eip_body);
+    let Some(eip_id) = eip_id else {
+        summary["error"] = json!("EIP create succeeded but response did not contain publicip.id.");
+        return Err(NatBootstrapStepError::Status(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    };
+    summary["eip"]["id"] = json!(eip_id.clone());
+    if let Some(address) = eip_address.as_deref() {
+        summary["eip"]["address"] = json!(address);
+    }
+    if !run.tags.is_empty() {
+        summary["eip"]["tags"] = tag_step_summary(
+            apply_resource_tags(
+                client,
+                &run.region,
+                TaggableResource::Eip,
+                &eip_id,
+                &run.tags,
+            )
+            .await,
+        );
+    }
+    run.eip_id = Some(eip_id);
+    run.eip_address = eip_address;
+
+    Ok(eip_status)
+}
+
+/// Adopt the SNAT rule for the run's subnet, or create it on the run's EIPs.
+async fn run_nat_snat_step(
+    client: &HwcClient,
+    run: &mut NatBootstrapRun,
+    summary: &mut Value,
+) -> Result<reqwest::StatusCode, NatBootstrapStepError> {
+    let nat_gateway_id = run.nat_gateway_id.clone().unwrap_or_default();
+    let rules = client
+        .list_snat_rules(&run.region, &nat_gateway_id)
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?
+        .snat_rules;
+    if let Some(rule) = find_adoptable_snat_rule(&rules, &run.subnet_id) {
+        summary["snat_rule"] = json!({
+            "status": "adopted",
+            "body": rule
+        });
+        run.snat_rule_id = rule.id.clone();
+        return Ok(reqwest::StatusCode::OK);
+    }
+
+    let snat_request = SnatRuleCreateBody {
+        nat_gateway_id,
+        network_id: Some(run.subnet_id.clone()),
+        cidr: None,
+        source_type: None,
+        floating_ip_id: run.floating_ip_ids(),
+        description: None,
+    };
+    let (snat_status, snat_body) = client
+        .create_snat_rule(&run.region, &snat_request)
+        .await
+        .map_err(|err| NatBootstrapStepError::Request(err.to_string()))?;
+    let parsed_body = parse_json_or_string(&snat_body);
+    let snat_rule_id = parsed_body
+        .pointer("/snat_rule/id")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    summary["snat_rule"] = json!({
+        "status": snat_status.to_string(),
+        "status_code": snat_status.as_u16(),
+        "body": parsed_body
+    });
+    if !snat_status.is_success() {
+        return Err(NatBootstrapStepError::Status(snat_status));
+    }
+    let Some(snat_rule_id) = snat_rule_id else {
+        summary["error"] =
+            json!("SNAT rule create succeeded but response did not contain snat_rule.id.");
+        return Err(NatBootstrapStepError::Status(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    };
+    run.snat_rule_id = Some(snat_rule_id);
+
+    Ok(snat_status)
+}
+
+/// Run the remaining bootstrap steps, storing the run after each one so a
+/// failure can be resumed or rolled back.
+async fn drive_nat_bootstrap(
+    client: &HwcClient,
+    mut run: NatBootstrapRun,
+) -> Result<CceOperationResult, String> {
+    let mut summary = json!({
+        "requested": {
+            "region": run.region.clone(),
+            "name": run.name.clone(),
+            "vpc_id": run.vpc_id.clone(),
+            "subnet_id": run.subnet_id.clone(),
+            "spec": run.spec.clone(),
+            "auto_bind_eip": run.reused_eip_ids.is_empty(),
+            "auto_create_snat": true
+        }
+    });
+    if !run.reused_eip_ids.is_empty() {
+        summary["eip"] = json!({
+            "status": "reused",
+            "ids": run.reused_eip_ids.clone()
+        });
+    }
+
+    let mut last_status = reqwest::StatusCode::OK;
+    while let Some(step) = run.next_step() {
+        let outcome = match step {
+            NatBootstrapStep::NatGateway => {
+                run_nat_gateway_step(client, &mut run, &mut summary).await
+            }
+            NatBootstrapStep::Eip => run_nat_eip_step(client, &mut run, &mut summary).await,
+            NatBootstrapStep::SnatRule => run_nat_snat_step(client, &mut run, &mut summary).await,
+        };
+        match outcome {
+            Ok(status) => {
+                last_status = status;
+                run.mark_step_done();
+                persist_nat_bootstrap_run(&run);
+            }
+            Err(NatBootstrapStepError::Status(status)) => {
+                run.mark_failed(step, format!("{} step returned {}", step.label(), status));
+                persist_nat_bootstrap_run(&run);
+                summary["run"] = json!(run);
+                return Ok(nat_bootstrap_result(status, &summary));
+            }
+            Err(NatBootstrapStepError::Request(err)) => {
+                error!(
+                    "NAT bootstrap step failed: region={} name={} step={} error={}",
+                    run.region,
+                    run.name,
+                    step.label(),
+                    err
+                );
+                run.mark_failed(step, err.clone());
+                persist_nat_bootstrap_run(&run);
+                return Err(err);
+            }
+        }
+    }
+
+    run.mark_step_done();
+    persist_nat_bootstrap_run(&run);
+    summary["run"] = json!(run);
+    Ok(nat_bootstrap_result(last_status, &summary))
+}
+
+/// Create one NAT gateway for the selected CCE VPC/subnet, with an EIP and SNAT rule.
+/// Calling again with the same name resumes an unfinished run, and gateways, EIPs
+/// and SNAT rules left by earlier runs are adopted instead of duplicated.
+#[tauri::command]
+async fn create_cce_nat_gateway(
+    params: CceCreateNatGatewayParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("CCE NAT gateway name is required.".to_string());
+    }
+    let vpc_id = params.vpc_id.trim();
+    if vpc_id.is_empty() {
+        return Err("CCE NAT requires a VPC.".to_string());
+    }
+    let subnet_id = params.subnet_id.trim();
+    if subnet_id.is_empty() {
+        return Err("CCE NAT requires a subnet.".to_string());
+    }
+    let spec = normalize_cce_nat_spec(params.spec.as_deref())?;
+    let reused_eip_ids = normalize_snat_eip_ids(params.eip_ids.clone())?;
+    let tags = normalize_tags(params.tags.clone())?;
+    enforce_tag_policy(&tags, TagPolicyCheck::Create)?;
+
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let stored = find_nat_bootstrap_run(&params.region, name)?
+        .filter(|run| run.status != NatBootstrapStatus::Completed);
+    let run = match stored {
+        Some(run) => {
+            if run.vpc_id != vpc_id || run.subnet_id != subnet_id {
+                return Err(format!(
+                    "NAT bootstrap '{}' is unfinished on subnet {} in VPC {}. Resume or roll it back first.",
+                    name, run.subnet_id, run.vpc_id
+                ));
+            }
+            let requested_spec = params.spec.as_deref().map(|_| spec);
+            let conflicts = run.conflicting_settings(requested_spec, &tags, &reused_eip_ids);
+            if !conflicts.is_empty() {
+                return Err(format!(
+                    "NAT bootstrap '{}' is unfinished with a different {}. Resume it as stored or roll it back first.",
+                    name,
+                    conflicts.join(", ")
+                ));
+            }
+            run
+        }
+        None => {
+            let mut run = NatBootstrapRun::new(
+                &params.region,
+                name,
+                vpc_id,
+                subnet_id,
+                spec,
+                description.map(str::to_string),
+                enterprise_project_id,
+            );
+            run.tags = tags;
+            run.reused_eip_ids = reused_eip_ids;
+            run
+        }
+    };
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating CCE NAT gateway with EIP+SNAT bootstrap: source={} region={} name={} vpc_id={} subnet_id={} spec={} next_step={:?}",
+        source_label,
+        params.region,
+        name,
+        vpc_id,
+        subnet_id,
+        run.spec,
+        run.next_step()
+    );
+
+    let client = HwcClient::new(credentials);
+    if run.nat_gateway_id.is_none() && run.reused_eip_ids.is_empty() && run.eip_id.is_none() {
+        quota_preflight(&client, &params.region, &[(QuotaResource::VpcEips, 1)]).await?;
+    }
+    drive_nat_bootstrap(&client, run).await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NatBootstrapRunParams {
+    region: String,
+    name: String,
+}
+
+/// Stored NAT bootstrap runs, including finished ones.
+#[tauri::command]
+async fn list_nat_bootstrap_runs() -> Result<Vec<NatBootstrapRun>, String> {
+    load_nat_bootstrap_runs()
+}
+
+/// Continue a failed or interrupted NAT bootstrap from its first unfinished step.
+#[tauri::command]
+async fn resume_nat_bootstrap(
+    params: NatBootstrapRunParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let name = params.name.trim();
+    let run = find_nat_bootstrap_run(&params.region, name)?
+        .ok_or_else(|| format!("No NAT bootstrap named '{}' in {}.", name, params.region))?;
+    if run.status == NatBootstrapStatus::Completed {
+        return Err(format!("NAT bootstrap '{}' already completed.", name));
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Resuming NAT bootstrap: source={} region={} name={} next_step={:?}",
+        source_label,
+        params.region,
+        name,
+        run.next_step()
+    );
+
+    let client = HwcClient::new(credentials);
+    drive_nat_bootstrap(&client, run).await
+}
+
+/// Undo a NAT bootstrap: delete its SNAT rule, the EIP it allocated and the gateway
+/// it created. Reused EIPs and adopted gateways are kept. The run is forgotten once
+/// everything is gone.
+#[tauri::command]
+async fn rollback_nat_bootstrap(
+    params: NatBootstrapRunParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let name = params.name.trim();
+    let mut run = find_nat_bootstrap_run(&params.region, name)?
+        .ok_or_else(|| format!("No NAT bootstrap named '{}' in {}.", name, params.region))?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Rolling back NAT bootstrap: source={} region={} name={} nat_gateway_id={:?} eip_id={:?} snat_rule_id={:?}",
+        source_label, run.region, name, run.nat_gateway_id, run.eip_id, run.snat_rule_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut summary = json!({
+        "requested": {
+            "region": run.region.clone(),
+            "name": run.name.clone()
+        }
+    });
+    let mut failed_step = None;
+
+    if let (Some(nat_gateway_id), Some(snat_rule_id)) =
+        (run.nat_gateway_id.clone(), run.snat_rule_id.clone())
+    {
+        let outcomes = delete_nat_rules(
+            &client,
+            &run.region,
+            &nat_gateway_id,
+            NatRuleKind::Snat,
+            vec![snat_rule_id],
+        )
+        .await;
+        for (result, failed) in outcomes {
+            summary["snat_rule"] = result;
+            if failed {
+                failed_step = Some(NatBootstrapStep::SnatRule);
+            } else {
+                run.snat_rule_id = None;
+            }
+        }
+    }
+
+    if let Some(eip_id) = run.eip_id.clone().filter(|_| failed_step.is_none()) {
+        let nat_gateway_id = run.nat_gateway_id.clone().unwrap_or_default();
+        let (result, failed) =
+            delete_nat_eip_with_retry(&client, &run.region, &nat_gateway_id, eip_id).await;
+        summary["eip"] = result;
+        if failed {
+            failed_step = Some(NatBootstrapStep::Eip);
+        } else {
+            run.eip_id = None;
+            run.eip_address = None;
+        }
+    }
+
+    if let Some(nat_gateway_id) = run
+        .nat_gateway_id
+        .clone()
+        .filter(|_| failed_step.is_none() && run.nat_gateway_adopted)
+    {
+        summary["nat_gateway"] = json!({
+            "id": nat_gateway_id,
+            "status": "kept",
+            "reason": "The gateway existed before this bootstrap."
+        });
+        run.nat_gateway_id = None;
+        run.nat_gateway_adopted = false;
+    }
+    if let Some(nat_gateway_id) = run.nat_gateway_id.clone().filter(|_| failed_step.is_none()) {
+        match client
+            .delete_nat_gateway(&run.region, &nat_gateway_id)
+            .await
+        {
+            Ok((status, body)) => {
+                summary["nat_gateway"] = json!({
+                    "id": nat_gateway_id,
+                    "status": status.to_string(),
+                    "status_code": status.as_u16(),
+                    "body": parse_json_or_string(&body)
+                });
+                if is_success_or_not_found(status) {
+                    run.nat_gateway_id = None;
+                } else {
+                    failed_step = Some(NatBootstrapStep::NatGateway);
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to delete NAT gateway during bootstrap rollback: region={} nat_gateway_id={} error={}",
+                    run.region, nat_gateway_id, err
+                );
+                summary["nat_gateway"] = json!({
+                    "id": nat_gateway_id,
+                    "status": "error",
+                    "error": err.to_string()
+                });
+                failed_step = Some(NatBootstrapStep::NatGateway);
+            }
+        }
+    }
+
+    match failed_step {
+        None => {
+            remove_nat_bootstrap_run(&run.region, &run.name)?;
+            summary["status"] = json!("rolled_back");
+            Ok(nat_bootstrap_result(reqwest::StatusCode::OK, &summary))
+        }
+        Some(step) => {
+            run.mark_failed(
+                step,
+                format!("Rollback stopped at the {} step.", step.label()),
+            );
+            persist_nat_bootstrap_run(&run);
+            summary["status"] = json!("rollback_incomplete");
+            summary["run"] = json!(run);
+            Ok(nat_bootstrap_result(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                &summary,
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NatRuleKind {
+    Snat,
+    Dnat,
+}
+
+impl NatRuleKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Snat => "SNAT",
+            Self::Dnat => "DNAT",
+        }
+    }
+}
+
+/// Split `(id, floating_ip_id, raw rule)` entries into deletable rule IDs and
+/// error results for rules without an ID, collecting each EIP into `eip_ids` once.
+/// SNAT rules may list several comma-separated EIPs in `floating_ip_id`.
+fn collect_nat_rule_ids(
+    rules: Vec<(Option<String>, Option<String>, Value)>,
+    kind: NatRuleKind,
+    eip_ids: &mut Vec<String>,
+) -> (Vec<String>, Vec<Value>) {
+    let mut rule_ids = Vec::with_capacity(rules.len());
+    let mut errors = Vec::new();
+    for (rule_id, floating_ip_id, raw) in rules {
+        let rule_id = rule_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+        for eip_id in floating_ip_id
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            if !eip_ids.iter().any(|known| known == eip_id) {
+                eip_ids.push(eip_id.to_string());
+            }
+        }
+
+        match rule_id {
+            Some(rule_id) => rule_ids.push(rule_id),
+            None => errors.push(json!({
+                "status": "error",
+                "error": format!("{} rule payload did not include an id.", kind.label()),
+                "rule": raw
+            })),
+        }
+    }
+    (rule_ids, errors)
+}
+
+/// Delete NAT rules with bounded parallelism; each outcome carries a failed flag.
+async fn delete_nat_rules(
+    client: &HwcClient,
+    region: &str,
+    nat_gateway_id: &str,
+    kind: NatRuleKind,
+    rule_ids: Vec<String>,
+) -> Vec<(Value, bool)> {
+    stream::iter(rule_ids.into_iter().map(|rule_id| async move {
+        let result = match kind {
+            NatRuleKind::Snat => {
+                client
+                    .delete_snat_rule(region, nat_gateway_id, &rule_id)
+                    .await
+            }
+            NatRuleKind::Dnat => {
+                client
+                    .delete_dnat_rule(region, nat_gateway_id, &rule_id)
+                    .await
+            }
+        };
+        match result {
+            Ok((status, body)) => {
+                let parsed_body = parse_json_or_string(&body);
+                let not_found_ok = status == reqwest::StatusCode::NOT_FOUND
+                    && !is_api_method_not_found_response(&parsed_body);
+                (
+                    json!({
+                        "id": rule_id,
+                        "status": status.to_string(),
+                        "status_code": status.as_u16(),
+                        "body": parsed_body
+                    }),
+                    !status.is_success() && !not_found_ok,
+                )
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to delete {} rule during NAT teardown: region={} nat_gateway_id={} rule_id={} error={}",
+                    kind.label(),
+                    region,
+                    nat_gateway_id,
+                    rule_id,
+                    err
+                );
+                (
+                    json!({
+                        "id": rule_id,
+                        "status": "error",
+                        "error": err.to_string()
+                    }),
+                    true,
+                )
+            }
+        }
+    }))
+    .buffer_unordered(NAT_DELETE_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await
+}
+
+/// Delete one EIP freed by NAT teardown, retrying while the NAT still holds it.
+async fn delete_nat_eip_with_retry(
+    client: &HwcClient,
+    region: &str,
+    nat_gateway_id: &str,
+    eip_id: String,
+) -> (Value, bool) {
+    let mut last_status = reqwest::StatusCode::INTERNAL_SERVER_ERROR;
+    let mut last_body = Value::String(String::new());
+    for attempt in 1..=NAT_EIP_DELETE_MAX_ATTEMPTS {
+        match client.delete_eip(region, &eip_id).await {
+            Ok((status, body)) => {
+                let parsed_body = parse_json_or_string(&body);
+                let should_retry = should_retry_nat_eip_delete(status, &parsed_body)
+                    && attempt < NAT_EIP_DELETE_MAX_ATTEMPTS;
+                last_status = status;
+                last_body = parsed_body;
+                if should_retry {
+                    tokio::time::sleep(Duration::from_millis(NAT_EIP_DELETE_RETRY_DELAY_MS)).await;
+                    continue;
+                }
+                return (
+                    json!({
+                        "id": eip_id,
+                        "status": status.to_string(),
+                        "status_code": status.as_u16(),
+                        "attempts": attempt,
+                        "body": last_body
+                    }),
+                    !is_success_or_not_found(status),
+                );
+            }
+            Err(err) => {
+                warn!(
+                        "Failed to delete EIP during NAT teardown: region={} nat_gateway_id={} eip_id={} error={}",
+                        region, nat_gateway_id, eip_id, err
+                    );
+                return (
+                    json!({
+                        "id": eip_id,
+                        "status": "error",
+                        "error": err.to_string()
+                    }),
+                    true,
+                );
+            }
+        }
+    }
+    (
+        json!({
+            "id": eip_id,
+            "status": last_status.to_string(),
+            "status_code": last_status.as_u16(),
+            "attempts": NAT_EIP_DELETE_MAX_ATTEMPTS,
+            "body": last_body
+        }),
+        !is_success_or_not_found(last_status),
+    )
+}
+
+/// Split the EIPs freed by NAT teardown into (release, keep), keeping input
+/// order. The EIP recorded by the NAT bootstrap is released; EIPs bound to DNAT
+/// rules only when `release_dnat_eips` is set. Any other EIP was supplied by the
+/// caller and is kept.
+fn partition_nat_teardown_eips(
+    eip_ids: Vec<String>,
+    dnat_eip_ids: &[String],
+    bootstrap_eip_id: Option<&str>,
+    release_dnat_eips: bool,
+) -> (Vec<String>, Vec<String>) {
+    eip_ids.into_iter().partition(|eip_id| {
+        bootstrap_eip_id == Some(eip_id.as_str())
+            || (release_dnat_eips && dnat_eip_ids.contains(eip_id))
+    })
+}
+
+/// Delete one NAT gateway by ID, after its SNAT and DNAT rules. The EIP the NAT
+/// bootstrap allocated is released, plus DNAT EIPs when requested; other EIPs
+/// are reported as kept.
+#[tauri::command]
+async fn delete_cce_nat_gateway(
+    params: CceDeleteNatGatewayParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let region = params.region.trim().to_string();
+    if region.is_empty() {
+        return Err("CCE region is required.".to_string());
+    }
+    let nat_gateway_id = params.nat_gateway_id.trim().to_string();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+
+    let release_dnat_eips = params.release_dnat_eips.unwrap_or(false);
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting CCE NAT gateway: source={} region={} nat_gateway_id={} release_dnat_eips={}",
+        source_label, region, nat_gateway_id, release_dnat_eips
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut summary = json!({
+        "requested": {
+            "region": region.clone(),
+            "nat_gateway_id": nat_gateway_id.clone(),
+            "release_dnat_eips": release_dnat_eips
+        },
+        "snat_rules": {
+            "total": 0,
+            "deleted": [],
+            "delete_failures": 0
+        },
+        "dnat_rules": {
+            "total": 0,
+            "deleted": [],
+            "delete_failures": 0
+        },
+        "eips": {
+            "total": 0,
+            "deleted": [],
+            "kept": [],
+            "delete_failures": 0
+        },
+        "nat_gateway": {
+            "status": "not_requested"
+        }
+    });
+
+    let snat_rules = client
+        .list_snat_rules(&region, &nat_gateway_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list SNAT rules before NAT delete: region={} nat_gateway_id={} error={}",
+                region, nat_gateway_id, err
+            );
+            err.to_string()
+        })?
+        .snat_rules;
+    let dnat_rules = client
+        .list_dnat_rules(&region, &nat_gateway_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list DNAT rules before NAT delete: region={} nat_gateway_id={} error={}",
+                region, nat_gateway_id, err
+            );
+            err.to_string()
+        })?
+        .dnat_rules;
+
+    // Rules must be gone before their EIPs; an EIP can back both an SNAT and a DNAT rule.
+    let mut eip_ids = Vec::new();
+    summary["snat_rules"]["total"] = json!(snat_rules.len());
+    let (snat_rule_ids, mut snat_delete_results) = collect_nat_rule_ids(
+        snat_rules
+            .into_iter()
+            .map(|rule| (rule.id.clone(), rule.floating_ip_id.clone(), json!(rule)))
+            .collect(),
+        NatRuleKind::Snat,
+        &mut eip_ids,
+    );
+    summary["dnat_rules"]["total"] = json!(dnat_rules.len());
+    let mut dnat_eip_ids = Vec::new();
+    let (dnat_rule_ids, mut dnat_delete_results) = collect_nat_rule_ids(
+        dnat_rules
+            .into_iter()
+            .map(|rule| (rule.id.clone(), rule.floating_ip_id.clone(), json!(rule)))
+            .collect(),
+        NatRuleKind::Dnat,
+        &mut dnat_eip_ids,
+    );
+    for eip_id in &dnat_eip_ids {
+        if !eip_ids.contains(eip_id) {
+            eip_ids.push(eip_id.clone());
+        }
+    }
+
+    let mut snat_delete_failures = snat_delete_results.len() as u32;
+    for (result, failed) in delete_nat_rules(
+        &client,
+        &region,
+        &nat_gateway_id,
+        NatRuleKind::Snat,
+        snat_rule_ids,
+    )
+    .await
+    {
+        if failed {
+            snat_delete_failures += 1;
+        }
+        snat_delete_results.push(result);
+    }
+    let mut dnat_delete_failures = dnat_delete_results.len() as u32;
+    for (result, failed) in delete_nat_rules(
+        &client,
+        &region,
+        &nat_gateway_id,
+        NatRuleKind::Dnat,
+        dnat_rule_ids,
+    )
+    .await
+    {
+        if failed {
+            dnat_delete_failures += 1;
+        }
+        dnat_delete_results.push(result);
+    }
+
+    summary["snat_rules"]["deleted"] = Value::Array(snat_delete_results);
+    summary["snat_rules"]["delete_failures"] = json!(snat_delete_failures);
+    summary["dnat_rules"]["deleted"] = Value::Array(dnat_delete_results);
+    summary["dnat_rules"]["delete_failures"] = json!(dnat_delete_failures);
+
+    // Ownership comes from the bootstrap run that allocated the EIP, not from names
+    // that change when the gateway is renamed. Without the run every EIP is kept.
+    let bootstrap_run = find_nat_bootstrap_run_for_gateway(&region, &nat_gateway_id)
+        .unwrap_or_else(|err| {
+            warn!(
+                "Failed to load NAT bootstrap runs before EIP cleanup: region={} nat_gateway_id={} error={}",
+                region, nat_gateway_id, err
+            );
+            None
+        });
+    let bootstrap_eip_id = bootstrap_run.as_ref().and_then(|run| run.eip_id.clone());
+    // The allocated EIP is released even when no rule references it any more.
+    if let Some(eip_id) = bootstrap_eip_id.as_ref() {
+        if !eip_ids.contains(eip_id) {
+            eip_ids.push(eip_id.clone());
+        }
+    }
+    summary["eips"]["total"] = json!(eip_ids.len());
+    let (owned_eip_ids, kept_eip_ids) = partition_nat_teardown_eips(
+        eip_ids,
+        &dnat_eip_ids,
+        bootstrap_eip_id.as_deref(),
+        release_dnat_eips,
+    );
+    let kept_eips = kept_eip_ids.into_iter().map(Value::String).collect();
+    summary["eips"]["kept"] = Value::Array(kept_eips);
+
+    let mut eip_delete_results = Vec::with_capacity(owned_eip_ids.len());
+    let mut eip_delete_failures = 0u32;
+    let eip_outcomes = stream::iter(
+        owned_eip_ids
+            .into_iter()
+            .map(|eip_id| delete_nat_eip_with_retry(&client, &region, &nat_gateway_id, eip_id)),
+    )
+    .buffer_unordered(NAT_DELETE_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+
+    for (result, failed) in eip_outcomes {
+        if failed {
+            eip_delete_failures += 1;
+        }
+        eip_delete_results.push(result);
+    }
+
+    summary["eips"]["deleted"] = Value::Array(eip_delete_results);
+    summary["eips"]["delete_failures"] = json!(eip_delete_failures);
+
+    let (nat_status, nat_body) = client
+        .delete_nat_gateway(&region, &nat_gateway_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete CCE NAT gateway: region={} nat_gateway_id={} error={}",
+                region, nat_gateway_id, err
+            );
+            err.to_string()
+        })?;
+    summary["nat_gateway"] = json!({
+        "status": nat_status.to_string(),
+        "status_code": nat_status.as_u16(),
+        "body": parse_json_or_string(&nat_body)
+    });
+
+    if let Some(run) = bootstrap_run.filter(|_| is_success_or_not_found(nat_status)) {
+        if let Err(err) = remove_nat_bootstrap_run(&run.region, &run.name) {
+            warn!(
+                "Failed to remove NAT bootstrap run after NAT delete: region={} name={} error={}",
+                run.region, run.name, err
+            );
+        }
+    }
+
+    if nat_status.is_success()
+        && (snat_delete_failures > 0 || dnat_delete_failures > 0 || eip_delete_failures > 0)
+    {
+        summary["warning"] = json!(
+            "NAT gateway deleted, but one or more SNAT/DNAT/EIP cleanup steps reported errors."
+        );
+    }
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(cce_operation_result(nat_status, body))
+}
+
+fn build_dnat_rule_create_body(
+    params: &CceCreateDnatRuleParams,
+) -> Result<DnatRuleCreateBody, String> {
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let floating_ip_id = params.eip_id.trim();
+    if floating_ip_id.is_empty() {
+        return Err("DNAT rules require an EIP.".to_string());
+    }
+    let protocol = params.protocol.trim().to_ascii_lowercase();
+    let internal_service_port = params.internal_port.unwrap_or(0);
+    let external_service_port = params.external_port.unwrap_or(0);
+    match protocol.as_str() {
+        "tcp" | "udp" => {
+            for (label, port) in [
+                ("Internal", internal_service_port),
+                ("External", external_service_port),
+            ] {
+                if !(1..=65535).contains(&port) {
+                    return Err(format!("{} port must be between 1 and 65535.", label));
+                }
+            }
+        }
+        "any" => {
+            if internal_service_port != 0 || external_service_port != 0 {
+                return Err(
+                    "DNAT rules for any protocol forward every port; leave ports empty."
+                        .to_string(),
+                );
+            }
+        }
+        _ => return Err("DNAT protocol must be tcp, udp or any.".to_string()),
+    }
+
+    let port_id = params
+        .port_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let private_ip = params
+        .private_ip
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse::<std::net::Ipv4Addr>()
+                .map(|ip| ip.to_string())
+                .map_err(|_| format!("Invalid DNAT private IP '{}'.", value))
+        })
+        .transpose()?;
+    if port_id.is_some() == private_ip.is_some() {
+        return Err("DNAT rules need exactly one of a port ID or a private IP.".to_string());
+    }
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    Ok(DnatRuleCreateBody {
+        nat_gateway_id: nat_gateway_id.to_string(),
+        port_id,
+        private_ip,
+        protocol,
+        internal_service_port,
+        external_service_port,
+        floating_ip_id: floating_ip_id.to_string(),
+        description,
+    })
+}
+
+/// List DNAT rules on one NAT gateway.
+#[tauri::command]
+async fn list_cce_dnat_rules(
+    params: CceListDnatRulesParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<DnatRuleListResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing DNAT rules: source={} region={} nat_gateway_id={}",
+        source_label, params.region, nat_gateway_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_dnat_rules(&params.region, nat_gateway_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list DNAT rules: region={} nat_gateway_id={} error={}",
+                params.region, nat_gateway_id, err
+            );
+            err.to_string()
+        })
+}
+
+/// Forward one EIP port to a private ECS port through a NAT gateway.
+#[tauri::command]
+async fn create_cce_dnat_rule(
+    params: CceCreateDnatRuleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let body = build_dnat_rule_create_body(&params)?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating DNAT rule: source={} region={} nat_gateway_id={} protocol={} external_port={} internal_port={} eip_id={}",
+        source_label,
+        params.region,
+        body.nat_gateway_id,
+        body.protocol,
+        body.external_service_port,
+        body.internal_service_port,
+        body.floating_ip_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .create_dnat_rule(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create DNAT rule: region={} nat_gateway_id={} error={}",
+                params.region, params.nat_gateway_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Delete one DNAT rule.
+#[tauri::command]
+async fn delete_cce_dnat_rule(
+    params: CceDeleteDnatRuleParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let nat_gateway_id = params.nat_gateway_id.trim();
+    if nat_gateway_id.is_empty() {
+        return Err("CCE NAT gateway ID is required.".to_string());
+    }
+    let dnat_rule_id = params.dnat_rule_id.trim();
+    if dnat_rule_id.is_empty() {
+        return Err("DNAT rule ID is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting DNAT rule: source={} region={} nat_gateway_id={} dnat_rule_id={}",
+        source_label, params.region, nat_gateway_id, dnat_rule_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_dnat_rule(&params.region, nat_gateway_id, dnat_rule_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete DNAT rule: region={} nat_gateway_id={} dnat_rule_id={} error={}",
+                params.region, nat_gateway_id, dnat_rule_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Bind a public EIP to one CCE cluster API endpoint for remote kubeconfig access.
+#[tauri::command]
+async fn bind_cce_cluster_api_eip(
+    params: CceBindClusterApiEipParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+    let eip_address = params.eip_address.trim();
+    if eip_address.is_empty() {
+        return Err("CCE API EIP address is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Binding CCE cluster API EIP: source={} region={} cluster_id={} eip_address={}",
+        source_label, params.region, cluster_id, eip_address
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .update_cce_cluster_external_ip(&params.region, cluster_id, eip_address)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to bind CCE cluster API EIP: region={} cluster_id={} eip_address={} error={}",
+                params.region, cluster_id, eip_address, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(cce_operation_result(status, body))
+}
+
+/// Create a new public EIP and bind it to one CCE cluster API endpoint.
+#[tauri::command]
+async fn create_and_bind_cce_cluster_api_eip(
+    params: CceCreateBindClusterApiEipParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceOperationResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(None, credentials.as_ref(), false)?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
     }
-    let version = params.version.trim();
-    if version.is_empty() {
-        return Err("CCE Kubernetes version is required.".to_string());
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating and binding CCE cluster API EIP: source={} region={} cluster_id={}",
+        source_label, params.region, cluster_id
+    );
+
+    let client = HwcClient::new(credentials);
+    quota_preflight(&client, &params.region, &[(QuotaResource::VpcEips, 1)]).await?;
+    let eip_name = format!("cce-api-{}", Utc::now().format("%Y%m%d%H%M%S"));
+    let (eip_status, eip_body) = client
+        .create_eip(
+            &params.region,
+            DEFAULT_BANDWIDTH_SIZE,
+            Some(&eip_name),
+            enterprise_project_id.as_deref(),
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create EIP for CCE API binding: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+
+    let mut summary = json!({
+        "requested": {
+            "region": params.region,
+            "cluster_id": cluster_id,
+            "action": "create_and_bind_api_eip"
+        },
+        "eip": {
+            "status": eip_status.to_string(),
+            "status_code": eip_status.as_u16(),
+            "body": parse_json_or_string(&eip_body)
+        }
+    });
+
+    if !eip_status.is_success() {
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(cce_operation_result(eip_status, body));
     }
-    let vpc_id = params.vpc_id.trim();
-    if vpc_id.is_empty() {
-        return Err("CCE VPC is required.".to_string());
+
+    let (eip_id, eip_address) = extract_eip_id_and_address(&eip_body);
+    if let Some(value) = eip_id {
+        summary["eip"]["id"] = json!(value);
+    }
+    let eip_address = match eip_address {
+        Some(value) => value,
+        None => {
+            summary["error"] = json!("EIP create succeeded but no public address was returned.");
+            let body =
+                serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+            return Ok(cce_operation_result(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                body,
+            ));
+        }
+    };
+    summary["eip"]["address"] = json!(eip_address.clone());
+
+    let (bind_status, bind_body) = client
+        .update_cce_cluster_external_ip(&params.region, cluster_id, &eip_address)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to bind created EIP to CCE cluster API: region={} cluster_id={} eip_address={} error={}",
+                params.region, cluster_id, eip_address, err
+            );
+            err.to_string()
+        })?;
+    summary["bind"] = json!({
+        "status": bind_status.to_string(),
+        "status_code": bind_status.as_u16(),
+        "body": parse_json_or_string(&bind_body),
+        "cluster_external_ip": eip_address
+    });
+
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(cce_operation_result(bind_status, body))
+}
+
+/// Request cluster kubeconfig payload (clustercert API) for local kubectl access.
+#[tauri::command]
+async fn get_cce_cluster_kubeconfig(
+    params: CceDownloadKubeconfigParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CceKubeconfigResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let cluster_id = params.cluster_id.trim();
+    if cluster_id.is_empty() {
+        return Err("CCE cluster ID is required.".to_string());
+    }
+
+    let context = params
+        .context
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("external");
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Requesting CCE cluster kubeconfig: source={} region={} cluster_id={} context={}",
+        source_label, params.region, cluster_id, context
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .get_cce_cluster_kubeconfig(&params.region, cluster_id, Some(context))
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to request CCE cluster kubeconfig: region={} cluster_id={} error={}",
+                params.region, cluster_id, err
+            );
+            err.to_string()
+        })?;
+    let kubeconfig = if status.is_success() {
+        extract_cluster_kubeconfig(&body)
+    } else {
+        None
+    };
+
+    Ok(CceKubeconfigResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+        kubeconfig,
+    })
+}
+
+/// List OBS buckets for the selected region.
+#[tauri::command]
+async fn list_obs_buckets(
+    region: String,
+    tags: Option<Vec<TagInput>>,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsListBucketsResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing OBS buckets: source={} region={}",
+        source_label, region
+    );
+
+    let tag_filters = normalize_tag_filters(tags)?;
+    let client = HwcClient::new(credentials);
+    let mut response = client.list_obs_buckets(&region).await.map_err(|err| {
+        error!(
+            "Failed to list OBS buckets: region={} error={}",
+            region, err
+        );
+        err.to_string()
+    })?;
+    if tag_filters.is_empty() {
+        return Ok(response);
+    }
+
+    // OBS has no tag query, so read each bucket's tags from its own region.
+    let client = &client;
+    let tag_filters = &tag_filters;
+    let buckets = std::mem::take(&mut response.buckets);
+    response.buckets = stream::iter(buckets)
+        .map(|bucket| {
+            let bucket_region = bucket.location.clone().unwrap_or_else(|| region.clone());
+            async move {
+                match client
+                    .get_obs_bucket_tags(&bucket_region, &bucket.name)
+                    .await
+                {
+                    Ok(bucket_tags) => {
+                        matches_tag_filters(&bucket_tags, tag_filters).then_some(bucket)
+                    }
+                    Err(err) => {
+                        warn!(
+                            "Skipping OBS bucket in tag filter: bucket={} error={}",
+                            bucket.name, err
+                        );
+                        None
+                    }
+                }
+            }
+        })
+        .buffered(OBS_TAG_LOOKUP_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+
+    Ok(response)
+}
+
+/// Create one OBS bucket.
+#[tauri::command]
+async fn create_obs_bucket(
+    params: ObsCreateBucketParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let tags = normalize_tags(params.tags.clone())?;
+    enforce_tag_policy(&tags, TagPolicyCheck::Create)?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating OBS bucket: source={} region={} bucket={}",
+        source_label, params.region, bucket_name
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .create_obs_bucket(
+            &params.region,
+            &bucket_name,
+            params.default_storage_class.as_deref(),
+            params.acl.as_deref(),
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create OBS bucket: region={} bucket={} error={}",
+                params.region, bucket_name, err
+            );
+            err.to_string()
+        })?;
+    if !status.is_success() || tags.is_empty() {
+        return Ok(obs_operation_result(status, body));
+    }
+
+    let summary = json!({
+        "bucket": {
+            "status": status.to_string(),
+            "status_code": status.as_u16(),
+            "body": parse_json_or_string(&body)
+        },
+        "tags": tag_step_summary(
+            apply_resource_tags(
+                &client,
+                &params.region,
+                TaggableResource::ObsBucket,
+                &bucket_name,
+                &tags,
+            )
+            .await,
+        ),
+    });
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(obs_operation_result(status, body))
+}
+
+/// Delete one OBS bucket.
+#[tauri::command]
+async fn delete_obs_bucket(
+    params: ObsDeleteBucketParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting OBS bucket: source={} region={} bucket={}",
+        source_label, params.region, bucket_name
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_obs_bucket(&params.region, &bucket_name)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete OBS bucket: region={} bucket={} error={}",
+                params.region, bucket_name, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(obs_operation_result(status, body))
+}
+
+/// List objects for one OBS bucket.
+#[tauri::command]
+async fn list_obs_objects(
+    params: ObsListObjectsParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsListObjectsResponse, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing OBS objects: source={} region={} bucket={}",
+        source_label, params.region, bucket_name
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_obs_objects(
+            &params.region,
+            &bucket_name,
+            params.prefix.as_deref(),
+            params.marker.as_deref(),
+            params.max_keys,
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list OBS objects: region={} bucket={} error={}",
+                params.region, bucket_name, err
+            );
+            err.to_string()
+        })
+}
+
+/// Scan all OBS object pages for one bucket and return total bytes/object count.
+#[tauri::command]
+async fn get_obs_bucket_totals(
+    params: ObsBucketTotalsParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsBucketTotalsResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Calculating OBS totals: source={} region={} bucket={}",
+        source_label, params.region, bucket_name
+    );
+
+    let client = HwcClient::new(credentials);
+    let mut marker: Option<String> = None;
+    let mut seen_markers = HashSet::new();
+    let mut pages_scanned: usize = 0;
+    let mut total_object_count: u64 = 0;
+    let mut total_size_bytes: u64 = 0;
+
+    loop {
+        pages_scanned += 1;
+        if pages_scanned > OBS_BUCKET_TOTALS_MAX_PAGES {
+            return Err(format!(
+                "OBS totals aborted after {} pages to avoid infinite pagination.",
+                OBS_BUCKET_TOTALS_MAX_PAGES
+            ));
+        }
+
+        let response = client
+            .list_obs_objects(
+                &params.region,
+                &bucket_name,
+                None,
+                marker.as_deref(),
+                Some(OBS_LIST_MAX_KEYS),
+            )
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to scan OBS totals: region={} bucket={} marker={} error={}",
+                    params.region,
+                    bucket_name,
+                    marker.clone().unwrap_or_else(|| "<start>".to_string()),
+                    err
+                );
+                err.to_string()
+            })?;
+
+        let objects = response.objects;
+        total_object_count = total_object_count.saturating_add(objects.len() as u64);
+        for object in objects {
+            if let Some(size) = object.size {
+                total_size_bytes = total_size_bytes.saturating_add(size);
+            }
+        }
+
+        let next_marker = response
+            .next_marker
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+        if !response.is_truncated || next_marker.is_none() {
+            break;
+        }
+        let next_marker = next_marker.unwrap_or_default();
+        if !seen_markers.insert(next_marker.clone()) {
+            return Err(format!(
+                "OBS totals pagination loop detected for marker '{}'.",
+                next_marker
+            ));
+        }
+        marker = Some(next_marker);
     }
-    let subnet_id = params.subnet_id.trim();
-    if subnet_id.is_empty() {
-        return Err("CCE subnet is required.".to_string());
+
+    Ok(ObsBucketTotalsResult {
+        total_size_bytes,
+        total_object_count,
+        pages_scanned: pages_scanned as u32,
+    })
+}
+
+/// Upload one object to OBS, in parts when it is larger than one part.
+#[tauri::command]
+async fn put_obs_object(
+    params: ObsPutObjectParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Uploading OBS object: source={} region={} bucket={} key={}",
+        source_label, params.region, bucket_name, object_key
+    );
+
+    let content = base64::engine::general_purpose::STANDARD
+        .decode(params.content_base64.trim())
+        .map_err(|err| format!("Failed to decode base64 object payload: {}", err))?;
+    if content.is_empty() {
+        return Err("OBS upload payload is empty.".to_string());
     }
 
-    let cluster_type = params
-        .cluster_type
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("VirtualMachine")
-        .to_string();
-    let container_network_mode = params
-        .container_network_mode
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("overlay_l2")
-        .to_string();
-    let container_network_cidr = params
-        .container_network_cidr
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("172.16.0.0/16")
-        .to_string();
-    let kubernetes_svc_ip_range = params
-        .kubernetes_svc_ip_range
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("10.247.0.0/16")
-        .to_string();
-    let authentication_mode = params
-        .authentication_mode
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("rbac")
-        .to_string();
+    let client = HwcClient::new(credentials);
+    let (status, body) = upload_obs_content(
+        &client,
+        &params.region,
+        &bucket_name,
+        &object_key,
+        params.content_type.as_deref(),
+        content.as_slice(),
+        content.len() as u64,
+        |_| {},
+    )
+    .await
+    .map_err(|err| {
+        error!(
+            "Failed to upload OBS object: region={} bucket={} key={} error={}",
+            params.region, bucket_name, object_key, err
+        );
+        err
+    })?;
+
+    Ok(obs_operation_result(status, body))
+}
+
+/// Download one object from OBS.
+#[tauri::command]
+async fn get_obs_object(
+    params: ObsGetObjectParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsGetObjectResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Downloading OBS object: source={} region={} bucket={} key={}",
+        source_label, params.region, bucket_name, object_key
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, content, content_type) = client
+        .get_obs_object(&params.region, &bucket_name, &object_key)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to download OBS object: region={} bucket={} key={} error={}",
+                params.region, bucket_name, object_key, err
+            );
+            err.to_string()
+        })?;
+
+    let status_code = status.as_u16();
+    let success = status.is_success();
+    let body = if success {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&content).into_owned())
+    };
+
+    Ok(ObsGetObjectResult {
+        status: status.to_string(),
+        status_code,
+        content_base64: if success {
+            Some(base64::engine::general_purpose::STANDARD.encode(content))
+        } else {
+            None
+        },
+        content_type,
+        body,
+    })
+}
+
+/// Delete one object from OBS.
+#[tauri::command]
+async fn delete_obs_object(
+    params: ObsDeleteObjectParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<ObsOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let bucket_name = normalize_obs_bucket_name(
+        &params.bucket_name,
+        OBS_BUCKET_NAME_MIN,
+        OBS_BUCKET_NAME_MAX,
+    )?;
+    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting OBS object: source={} region={} bucket={} key={}",
+        source_label, params.region, bucket_name, object_key
+    );
 
-    let mut cluster_tags = Vec::new();
-    if let Some(env) = params
-        .cluster_tag_env
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .delete_obs_object(&params.region, &bucket_name, &object_key)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete OBS object: region={} bucket={} key={} error={}",
+                params.region, bucket_name, object_key, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(obs_operation_result(status, body))
+}
+
+/// Validate ECS create params into the exact request body sent to the API.
+fn build_create_ecs_request(params: EcsCreateParams) -> Result<CreateEcsRequest, String> {
+    let server_name = normalize_server_name(&params.name);
+    let admin_password = params
+        .admin_password
         .as_deref()
         .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        cluster_tags.push(CceClusterTag {
-            key: "env".to_string(),
-            value: env.to_string(),
-        });
+        .filter(|password| !password.is_empty())
+        .map(|password| password.to_string());
+
+    let eip_bandwidth_size = params.eip_bandwidth_size.unwrap_or(DEFAULT_BANDWIDTH_SIZE);
+    if params.eip && !(MIN_BANDWIDTH_SIZE..=MAX_BANDWIDTH_SIZE).contains(&eip_bandwidth_size) {
+        return Err(format!(
+            "EIP bandwidth size must be between {} and {} Mbit/s for charge_mode=traffic.",
+            MIN_BANDWIDTH_SIZE, MAX_BANDWIDTH_SIZE
+        ));
     }
 
-    let body = CceCreateClusterRequest {
-        kind: "Cluster".to_string(),
-        api_version: "v3".to_string(),
-        metadata: CceClusterCreateMetadata {
-            name: cluster_name.to_string(),
-        },
-        spec: CceClusterCreateSpec {
-            cluster_type,
-            flavor: flavor.to_string(),
-            version: version.to_string(),
-            description: params
-                .description
-                .as_deref()
-                .map(str::trim)
-                .filter(|value| !value.is_empty())
-                .map(str::to_string),
-            host_network: CceHostNetwork {
-                vpc: vpc_id.to_string(),
-                subnet: subnet_id.to_string(),
+    let data_volumes = params
+        .data_volumes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|volume| {
+            let volume_type = volume.volume_type.trim().to_string();
+            if volume_type.is_empty() {
+                return Err("Data disk volume type is required.".to_string());
+            }
+            if volume.size == 0 {
+                return Err("Data disk size must be greater than 0 GB.".to_string());
+            }
+            let count = volume.count.unwrap_or(1);
+            if count == 0 {
+                return Err("Data disk count must be at least 1.".to_string());
+            }
+            Ok(DataVolume {
+                volumetype: volume_type,
+                size: volume.size,
+                count: Some(count),
+                multiattach: volume.multiattach,
+                hw_passthrough: volume.hw_passthrough,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let security_groups = normalize_id_list(params.security_group_ids)
+        .into_iter()
+        .map(|id| SecurityGroupRef { id })
+        .collect::<Vec<_>>();
+    let server_tags = normalize_tags(params.tags)?;
+    let extendparam =
+        normalize_enterprise_project_id(params.enterprise_project_id.as_deref(), false)?.map(
+            |enterprise_project_id| ServerExtendParam {
+                enterprise_project_id,
             },
-            container_network: CceContainerNetwork {
-                mode: container_network_mode,
-                cidr: container_network_cidr,
+        );
+
+    let publicip = if params.eip {
+        Some(PublicIp {
+            eip: Eip {
+                ip_type: DEFAULT_EIP_TYPE.into(),
+                bandwidth: Bandwidth {
+                    size: eip_bandwidth_size,
+                    share_type: DEFAULT_BANDWIDTH_SHARE_TYPE.into(),
+                    charge_mode: DEFAULT_BANDWIDTH_CHARGE_MODE.into(),
+                },
             },
-            kubernetes_svc_ip_range,
-            authentication: Some(CceAuthentication {
-                mode: authentication_mode,
-            }),
-            cluster_tags,
+        })
+    } else {
+        None
+    };
+
+    let body = CreateEcsRequest {
+        server: Server {
+            name: server_name,
+            image_ref: params.image_id,
+            flavor_ref: params.flavor_id,
+            vpcid: params.vpc_id,
+            nics: vec![Nic {
+                subnet_id: params.subnet_id,
+            }],
+            root_volume: RootVolume {
+                volumetype: params.root_volume_type,
+                size: params.root_volume_size,
+            },
+            data_volumes,
+            publicip,
+            admin_pass: admin_password,
+            security_groups,
+            server_tags,
+            extendparam,
         },
     };
 
+    Ok(body)
+}
+
+/// Create an ECS instance using the same core flow as the old CLI.
+#[tauri::command]
+async fn create_ecs(
+    mut params: EcsCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<CreateEcsResult, String> {
+    params.enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating ECS instance: source={} region={} vpc_id={} subnet_id={} allocate_eip={}",
+        source_label, params.region, params.vpc_id, params.subnet_id, params.eip
+    );
+
+    let region = params.region.clone();
+    let body = build_create_ecs_request(params)?;
+    enforce_tag_policy(&body.server.server_tags, TagPolicyCheck::Create)?;
+
+    let client = HwcClient::new(credentials);
+    quota_preflight(&client, &region, &ecs_quota_demands(&body)).await?;
+    let (status, body) = client.create_ecs(&region, &body).await.map_err(|err| {
+        error!("Failed to create ECS: region={} error={}", region, err);
+        err.to_string()
+    })?;
+
+    Ok(CreateEcsResult {
+        status: status.to_string(),
+        status_code: status.as_u16(),
+        body,
+    })
+}
+
+/// Delete an ECS instance and, when possible, its attached EIP.
+#[tauri::command]
+async fn delete_ecs_with_eip(
+    params: EcsDeleteParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<DeleteEcsResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Deleting ECS instance: source={} region={} server_id={}",
+        source_label, params.region, params.server_id
+    );
+
+    let delete_volume = params.delete_volume.unwrap_or(true);
+    let client = HwcClient::new(credentials);
+    let (ecs_status, ecs_body) = client
+        .delete_ecs(&params.region, &params.server_id, true, delete_volume)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete ECS: region={} server_id={} error={}",
+                params.region, params.server_id, err
+            );
+            err.to_string()
+        })?;
+
+    let ecs_result = operation_result(ecs_status, ecs_body);
+    let ecs_success = ecs_result
+        .status_code
+        .is_some_and(|code| (200..300).contains(&code));
+    let eip_id = params
+        .eip_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| id.to_string());
+
+    let eip_result = if !ecs_success {
+        eip_id.map(|id| {
+            operation_error_result(
+                "skipped",
+                format!(
+                    "Skipped EIP deletion for {} because ECS deletion did not return success.",
+                    id
+                ),
+            )
+        })
+    } else if let Some(eip_id) = eip_id {
+        match client.delete_eip(&params.region, &eip_id).await {
+            Ok((status, body)) => Some(operation_result(status, body)),
+            Err(err) => {
+                warn!(
+                    "Failed to delete EIP after ECS delete: region={} eip_id={} error={}",
+                    params.region, eip_id, err
+                );
+                Some(operation_error_result("error", err.to_string()))
+            }
+        }
+    } else {
+        None
+    };
+
+    Ok(DeleteEcsResult {
+        ecs: ecs_result,
+        eip: eip_result,
+    })
+}
+
+/// Delete one elastic IP by ID.
+#[tauri::command]
+async fn delete_eip(
+    params: EipDeleteParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<DeleteOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let eip_id = params.eip_id.trim();
+    if eip_id.is_empty() {
+        return Err("EIP ID is required.".to_string());
+    }
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Creating CCE cluster: source={} region={} name={} flavor={} version={} vpc_id={} subnet_id={}",
-        source_label, params.region, cluster_name, flavor, version, vpc_id, subnet_id
+        "Deleting EIP: source={} region={} eip_id={}",
+        source_label, params.region, eip_id
     );
 
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .create_cce_cluster(&params.region, &body)
+        .delete_eip(&params.region, eip_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create CCE cluster: region={} name={} error={}",
-                params.region, cluster_name, err
+                "Failed to delete EIP: region={} eip_id={} error={}",
+                params.region, eip_id, err
             );
             err.to_string()
         })?;
 
-    Ok(cce_operation_result(status, body))
+    Ok(operation_result(status, body))
 }
 
-/// Delete one CCE cluster.
+/// Allocate one standalone EIP.
 #[tauri::command]
-async fn delete_cce_cluster(
-    params: CceDeleteClusterParams,
+async fn allocate_eip(
+    mut params: EipAllocateParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
+    params.enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required.".to_string());
-    }
+    let body = build_eip_allocate_request(&params)?;
+    let tags = normalize_tags(params.tags.clone())?;
+    enforce_tag_policy(&tags, TagPolicyCheck::Create)?;
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Deleting CCE cluster: source={} region={} cluster_id={}",
-        source_label, params.region, cluster_id
+        "Allocating EIP: source={} region={} type={} share_type={} size={:?} charge_mode={:?}",
+        source_label,
+        params.region,
+        body.publicip.ip_type,
+        body.bandwidth.share_type,
+        body.bandwidth.size,
+        body.bandwidth.charge_mode
     );
 
     let client = HwcClient::new(credentials);
+    quota_preflight(&client, &params.region, &[(QuotaResource::VpcEips, 1)]).await?;
     let (status, body) = client
-        .delete_cce_cluster(&params.region, cluster_id)
+        .allocate_eip(&params.region, &body)
         .await
         .map_err(|err| {
             error!(
-                "Failed to delete CCE cluster: region={} cluster_id={} error={}",
-                params.region, cluster_id, err
+                "Failed to allocate EIP: region={} error={}",
+                params.region, err
             );
             err.to_string()
         })?;
+    if !status.is_success() || tags.is_empty() {
+        return Ok(vpc_operation_result(status, body));
+    }
 
-    Ok(cce_operation_result(status, body))
+    let tag_result = match extract_eip_id_and_address(&body).0 {
+        Some(eip_id) => tag_step_summary(
+            apply_resource_tags(
+                &client,
+                &params.region,
+                TaggableResource::Eip,
+                &eip_id,
+                &tags,
+            )
+            .await,
+        ),
+        None => {
+            json!({ "error": "EIP response did not contain publicip.id; tags were not applied." })
+        }
+    };
+    let summary = json!({
+        "publicip": parse_json_or_string(&body),
+        "tags": tag_result,
+    });
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(vpc_operation_result(status, body))
 }
 
-/// List node pools for one CCE cluster.
+/// Bind one EIP to a port, resolving an ECS's primary NIC port when needed.
 #[tauri::command]
-async fn list_cce_node_pools(
-    params: CceListNodePoolsParams,
+async fn bind_eip(
+    params: EipBindParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceNodePoolListResponse, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required.".to_string());
+    let eip_id = params.eip_id.trim();
+    if eip_id.is_empty() {
+        return Err("EIP ID is required.".to_string());
     }
+    let port_id = params
+        .port_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    let server_id = params
+        .server_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Listing CCE node pools: source={} region={} cluster_id={}",
-        source_label, params.region, cluster_id
+        "Binding EIP: source={} region={} eip_id={} port_id={} server_id={}",
+        source_label,
+        params.region,
+        eip_id,
+        port_id.unwrap_or("-"),
+        server_id.unwrap_or("-")
     );
 
     let client = HwcClient::new(credentials);
-    client
-        .list_cce_node_pools(&params.region, cluster_id)
+    let port_id = match (port_id, server_id) {
+        (Some(port_id), _) => port_id.to_string(),
+        (None, Some(server_id)) => {
+            let server = client
+                .get_ecs(&params.region, server_id)
+                .await
+                .map_err(|err| {
+                    error!(
+                        "Failed to get ECS for EIP bind: region={} server_id={} error={}",
+                        params.region, server_id, err
+                    );
+                    err.to_string()
+                })?;
+            server
+                .primary_port_id()
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    format!(
+                        "Could not find the primary NIC port of server {}; pass a port ID.",
+                        server_id
+                    )
+                })?
+        }
+        (None, None) => return Err("Port ID or server ID is required.".to_string()),
+    };
+
+    let eip = client
+        .get_eip(&params.region, eip_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to list CCE node pools: region={} cluster_id={} error={}",
-                params.region, cluster_id, err
+                "Failed to get EIP before bind: region={} eip_id={} error={}",
+                params.region, eip_id, err
             );
             err.to_string()
-        })
+        })?;
+    let bound_port = eip
+        .vnic
+        .as_ref()
+        .and_then(|vnic| vnic.port_id.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if bound_port == Some(port_id.as_str()) {
+        let summary = json!({
+            "eip_id": eip_id,
+            "port_id": port_id,
+            "status": "already_bound",
+        });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(vpc_operation_result(reqwest::StatusCode::OK, body));
+    }
+    if let Some(instance_id) = eip
+        .associate_instance_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        return Err(format!(
+            "EIP {} is already bound to {} {}. Unbind it first.",
+            eip_id,
+            eip.associate_instance_type.as_deref().unwrap_or("instance"),
+            instance_id
+        ));
+    }
+
+    let (status, body) = client
+        .associate_eip(&params.region, eip_id, "PORT", &port_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to bind EIP: region={} eip_id={} port_id={} error={}",
+                params.region, eip_id, port_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Create one node pool under a CCE cluster.
+/// Unbind one EIP from its port.
 #[tauri::command]
-async fn create_cce_node_pool(
-    params: CceCreateNodePoolParams,
+async fn unbind_eip(
+    params: EipUnbindParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required for node pool creation.".to_string());
-    }
-    let name = params.name.trim();
-    if name.is_empty() {
-        return Err("CCE node pool name is required.".to_string());
-    }
-    let flavor = params.flavor.trim();
-    if flavor.is_empty() {
-        return Err("CCE node pool flavor is required.".to_string());
-    }
-    let availability_zone = params.availability_zone.trim();
-    if availability_zone.is_empty() {
-        return Err("CCE node pool availability zone is required.".to_string());
+    let eip_id = params.eip_id.trim();
+    if eip_id.is_empty() {
+        return Err("EIP ID is required.".to_string());
     }
 
-    let initial_node_count = sanitize_cce_node_pool_initial_count(params.initial_node_count);
-    let root_volume_size = sanitize_cce_node_pool_root_volume_size(params.root_volume_size);
-    let data_volume_size = sanitize_cce_node_pool_data_volume_size(params.data_volume_size);
-    let root_volume_type = params
-        .root_volume_type
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("GPSSD")
-        .to_string();
-    let data_volume_type = params
-        .data_volume_type
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or(root_volume_type.as_str())
-        .to_string();
-    let node_os = params
-        .os
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string);
-    let ssh_key = params
-        .ssh_key
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Unbinding EIP: source={} region={} eip_id={}",
+        source_label, params.region, eip_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let eip = client
+        .get_eip(&params.region, eip_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get EIP before unbind: region={} eip_id={} error={}",
+                params.region, eip_id, err
+            );
+            err.to_string()
+        })?;
+    // NAT gateway EIPs are released through their SNAT/DNAT rules, not by unbinding.
+    if eip
+        .associate_instance_type
         .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string);
-    let subnet_id = params
-        .subnet_id
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("NATGW"))
+    {
+        return Err(format!(
+            "EIP {} is used by NAT gateway {}. Remove its NAT rules instead.",
+            eip_id,
+            eip.associate_instance_id.as_deref().unwrap_or("-")
+        ));
+    }
+    let is_bound = eip
+        .associate_instance_id
         .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string);
-    let max_pods = sanitize_cce_node_pool_max_pods(params.max_pods);
+        .is_some_and(|value| !value.trim().is_empty())
+        || eip
+            .vnic
+            .as_ref()
+            .and_then(|vnic| vnic.port_id.as_deref())
+            .is_some_and(|value| !value.trim().is_empty());
+    if !is_bound {
+        let summary = json!({ "eip_id": eip_id, "status": "not_bound" });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(vpc_operation_result(reqwest::StatusCode::OK, body));
+    }
 
-    let body = CceCreateNodePoolRequest {
-        kind: "NodePool".to_string(),
-        api_version: "v3".to_string(),
-        metadata: CceNodePoolCreateMetadata {
-            name: name.to_string(),
-        },
-        spec: CceNodePoolCreateSpec {
-            node_pool_type: "vm".to_string(),
-            initial_node_count,
-            node_template: CceNodePoolTemplateSpec {
-                flavor: flavor.to_string(),
-                az: availability_zone.to_string(),
-                os: node_os,
-                login: ssh_key.map(|value| CceNodePoolLogin { ssh_key: value }),
-                root_volume: CceNodePoolVolume {
-                    volumetype: root_volume_type,
-                    size: root_volume_size,
-                    extend_param: None,
-                },
-                data_volumes: vec![CceNodePoolVolume {
-                    volumetype: data_volume_type,
-                    size: data_volume_size,
-                    extend_param: Some(CceNodePoolVolumeExtendParam {
-                        use_type: "docker".to_string(),
-                    }),
-                }],
-                node_nic_spec: subnet_id.map(|value| CceNodePoolNicSpec {
-                    primary_nic: CceNodePoolPrimaryNic { subnet_id: value },
-                }),
-                billing_mode: 0,
-                extend_param: max_pods.map(|value| CceNodePoolExtendParam {
-                    max_pods: Some(value),
-                }),
-            },
-        },
+    let (status, body) = client
+        .disassociate_eip(&params.region, eip_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to unbind EIP: region={} eip_id={} error={}",
+                params.region, eip_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(vpc_operation_result(status, body))
+}
+
+/// Update alias and/or description of one EIP.
+#[tauri::command]
+async fn update_eip(
+    params: EipUpdateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<VpcOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let eip_id = params.eip_id.trim();
+    if eip_id.is_empty() {
+        return Err("EIP ID is required.".to_string());
+    }
+    // Empty strings are kept so the caller can clear a field.
+    let alias = params.alias.as_deref().map(str::trim);
+    let description = params.description.as_deref().map(str::trim);
+    if alias.is_none() && description.is_none() {
+        return Err("Provide an alias or a description to update.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Updating EIP: source={} region={} eip_id={}",
+        source_label, params.region, eip_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .update_eip(&params.region, eip_id, alias, description)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to update EIP: region={} eip_id={} error={}",
+                params.region, eip_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(vpc_operation_result(status, body))
+}
+
+/// Resize the bandwidth of one EIP (or a shared bandwidth) and/or switch its charge mode.
+#[tauri::command]
+async fn resize_eip_bandwidth(
+    params: EipBandwidthResizeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<VpcOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let trimmed = |value: Option<&str>| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let eip_id = trimmed(params.eip_id.as_deref());
+    let bandwidth_id = trimmed(params.bandwidth_id.as_deref());
+    let charge_mode = trimmed(params.charge_mode.as_deref());
+    if params.size.is_none() && charge_mode.is_none() {
+        return Err("Provide a bandwidth size or a charge mode to update.".to_string());
+    }
+
+    let client = HwcClient::new(credentials);
+    let bandwidth_id = match (bandwidth_id, eip_id.as_deref()) {
+        (Some(id), _) => id,
+        (None, Some(eip_id)) => {
+            let eip = client
+                .get_eip(&params.region, eip_id)
+                .await
+                .map_err(|err| {
+                    error!(
+                        "Failed to get EIP before bandwidth resize: region={} eip_id={} error={}",
+                        params.region, eip_id, err
+                    );
+                    err.to_string()
+                })?;
+            eip.bandwidth
+                .as_ref()
+                .and_then(|bandwidth| bandwidth.id.as_deref())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| format!("EIP {} has no bandwidth ID.", eip_id))?
+        }
+        (None, None) => return Err("EIP ID or bandwidth ID is required.".to_string()),
     };
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Creating CCE node pool: source={} region={} cluster_id={} name={} flavor={} az={} initial_count={}",
-        source_label, params.region, cluster_id, name, flavor, availability_zone, initial_node_count
+        "Resizing EIP bandwidth: source={} region={} eip_id={} bandwidth_id={} size={:?} charge_mode={:?}",
+        source_label,
+        params.region,
+        eip_id.as_deref().unwrap_or("-"),
+        bandwidth_id,
+        params.size,
+        charge_mode
     );
 
-    let client = HwcClient::new(credentials);
+    let current = client
+        .get_bandwidth(&params.region, &bandwidth_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get bandwidth: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
+            );
+            err.to_string()
+        })?;
+    let Some(update) = resolve_bandwidth_update(&current, params.size, charge_mode.as_deref())?
+    else {
+        let summary = json!({
+            "bandwidth_id": bandwidth_id,
+            "size": current.size,
+            "charge_mode": current.charge_mode,
+            "status": "unchanged",
+        });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(vpc_operation_result(reqwest::StatusCode::OK, body));
+    };
+
     let (status, body) = client
-        .create_cce_node_pool(&params.region, cluster_id, &body)
+        .update_bandwidth(&params.region, &bandwidth_id, &update)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create CCE node pool: region={} cluster_id={} name={} error={}",
-                params.region, cluster_id, name, err
+                "Failed to update bandwidth: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
             );
             err.to_string()
         })?;
 
-    Ok(cce_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Delete one node pool under a CCE cluster.
+/// List shared (WHOLE) bandwidths with their member EIPs.
 #[tauri::command]
-async fn delete_cce_node_pool(
-    params: CceDeleteNodePoolParams,
+async fn list_shared_bandwidths(
+    region: String,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<Vec<EipBandwidth>, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required for node pool deletion.".to_string());
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing shared bandwidths: source={} region={}",
+        source_label, region
+    );
+
+    let client = HwcClient::new(credentials);
+    client.list_shared_bandwidths(&region).await.map_err(|err| {
+        error!(
+            "Failed to list shared bandwidths: region={} error={}",
+            region, err
+        );
+        err.to_string()
+    })
+}
+
+/// Create one shared (WHOLE) bandwidth.
+#[tauri::command]
+async fn create_shared_bandwidth(
+    params: SharedBandwidthCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<VpcOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("Shared bandwidth name is required.".to_string());
     }
-    let node_pool_id = params.node_pool_id.trim();
-    if node_pool_id.is_empty() {
-        return Err("CCE node pool ID is required.".to_string());
+    let charge_mode = params
+        .charge_mode
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_else(|| "bandwidth".to_string());
+    if !matches!(charge_mode.as_str(), "traffic" | "bandwidth") {
+        return Err(format!(
+            "Bandwidth charge mode must be 'traffic' or 'bandwidth', got '{}'.",
+            charge_mode
+        ));
     }
+    validate_shared_bandwidth_size(params.size)?;
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Deleting CCE node pool: source={} region={} cluster_id={} node_pool_id={}",
-        source_label, params.region, cluster_id, node_pool_id
+        "Creating shared bandwidth: source={} region={} name={} size={} charge_mode={}",
+        source_label, params.region, name, params.size, charge_mode
     );
 
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .delete_cce_node_pool(&params.region, cluster_id, node_pool_id)
+        .create_shared_bandwidth(&params.region, name, params.size, Some(&charge_mode))
         .await
         .map_err(|err| {
             error!(
-                "Failed to delete CCE node pool: region={} cluster_id={} node_pool_id={} error={}",
-                params.region, cluster_id, node_pool_id, err
+                "Failed to create shared bandwidth: region={} name={} error={}",
+                params.region, name, err
             );
             err.to_string()
         })?;
 
-    Ok(cce_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Query one CCE job status.
+/// Delete one shared bandwidth once no EIP uses it.
 #[tauri::command]
-async fn get_cce_job(
-    params: CceGetJobParams,
+async fn delete_shared_bandwidth(
+    params: SharedBandwidthDeleteParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let job_id = params.job_id.trim();
-    if job_id.is_empty() {
-        return Err("CCE job ID is required.".to_string());
+    let bandwidth_id = params.bandwidth_id.trim();
+    if bandwidth_id.is_empty() {
+        return Err("Bandwidth ID is required.".to_string());
     }
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Querying CCE job: source={} region={} job_id={}",
-        source_label, params.region, job_id
+        "Deleting shared bandwidth: source={} region={} bandwidth_id={}",
+        source_label, params.region, bandwidth_id
     );
 
     let client = HwcClient::new(credentials);
+    let bandwidth = client
+        .get_bandwidth(&params.region, bandwidth_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get bandwidth before delete: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
+            );
+            err.to_string()
+        })?;
+    if !bandwidth
+        .share_type
+        .as_deref()
+        .is_some_and(|value| value.eq_ignore_ascii_case("WHOLE"))
+    {
+        return Err(format!(
+            "Bandwidth {} is not a shared bandwidth. Release its EIP instead.",
+            bandwidth_id
+        ));
+    }
+    let members = bandwidth_member_ids(&bandwidth);
+    if !members.is_empty() {
+        return Err(format!(
+            "Shared bandwidth {} still has {} EIP(s): {}. Remove them first.",
+            bandwidth_id,
+            members.len(),
+            members.join(", ")
+        ));
+    }
+
     let (status, body) = client
-        .get_cce_job(&params.region, job_id)
+        .delete_shared_bandwidth(&params.region, bandwidth_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to query CCE job: region={} job_id={} error={}",
-                params.region, job_id, err
+                "Failed to delete shared bandwidth: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
             );
             err.to_string()
         })?;
 
-    Ok(cce_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// List NAT gateways scoped to the selected CCE VPC/subnet.
+/// Add EIPs to one shared bandwidth; EIPs already in it are skipped.
 #[tauri::command]
-async fn list_cce_nat_gateways(
-    params: CceListNatGatewaysParams,
+async fn add_eips_to_shared_bandwidth(
+    params: SharedBandwidthAddEipsParams,
     credentials: Option<CredentialsInput>,
-) -> Result<NatGatewayListResponse, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let vpc_id = params.vpc_id.trim();
-    if vpc_id.is_empty() {
-        return Err("CCE NAT requires a VPC.".to_string());
+    let bandwidth_id = params.bandwidth_id.trim();
+    if bandwidth_id.is_empty() {
+        return Err("Bandwidth ID is required.".to_string());
     }
-    let subnet_id = params.subnet_id.trim();
-    if subnet_id.is_empty() {
-        return Err("CCE NAT requires a subnet.".to_string());
+    let eip_ids = normalize_id_list(Some(params.eip_ids));
+    if eip_ids.is_empty() {
+        return Err("At least one EIP ID is required.".to_string());
     }
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Listing CCE NAT gateways: source={} region={} vpc_id={} subnet_id={}",
-        source_label, params.region, vpc_id, subnet_id
+        "Adding EIPs to shared bandwidth: source={} region={} bandwidth_id={} eips={}",
+        source_label,
+        params.region,
+        bandwidth_id,
+        eip_ids.join(",")
     );
 
     let client = HwcClient::new(credentials);
-    client
-        .list_nat_gateways(&params.region, Some(vpc_id), Some(subnet_id))
+    let bandwidth = client
+        .get_bandwidth(&params.region, bandwidth_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to list CCE NAT gateways: region={} vpc_id={} subnet_id={} error={}",
-                params.region, vpc_id, subnet_id, err
+                "Failed to get bandwidth before insert: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
             );
             err.to_string()
-        })
+        })?;
+    let (already_members, to_add) = partition_bandwidth_members(&bandwidth, &eip_ids);
+    if to_add.is_empty() {
+        let summary = json!({
+            "bandwidth_id": bandwidth_id,
+            "skipped": already_members,
+            "status": "already_members",
+        });
+        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+        return Ok(vpc_operation_result(reqwest::StatusCode::OK, body));
+    }
+    if !already_members.is_empty() {
+        info!(
+            "Skipping EIPs already in shared bandwidth: bandwidth_id={} eips={}",
+            bandwidth_id,
+            already_members.join(",")
+        );
+    }
+
+    let (status, body) = client
+        .insert_bandwidth_eips(&params.region, bandwidth_id, &to_add)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to add EIPs to shared bandwidth: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Create one NAT gateway for the selected CCE VPC/subnet.
+/// Remove EIPs from one shared bandwidth, giving each a new dedicated bandwidth.
 #[tauri::command]
-async fn create_cce_nat_gateway(
-    params: CceCreateNatGatewayParams,
+async fn remove_eips_from_shared_bandwidth(
+    params: SharedBandwidthRemoveEipsParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let name = params.name.trim();
-    if name.is_empty() {
-        return Err("CCE NAT gateway name is required.".to_string());
-    }
-    let vpc_id = params.vpc_id.trim();
-    if vpc_id.is_empty() {
-        return Err("CCE NAT requires a VPC.".to_string());
+    let bandwidth_id = params.bandwidth_id.trim();
+    if bandwidth_id.is_empty() {
+        return Err("Bandwidth ID is required.".to_string());
     }
-    let subnet_id = params.subnet_id.trim();
-    if subnet_id.is_empty() {
-        return Err("CCE NAT requires a subnet.".to_string());
+    let eip_ids = normalize_id_list(Some(params.eip_ids));
+    if eip_ids.is_empty() {
+        return Err("At least one EIP ID is required.".to_string());
     }
-    let spec = params
-        .spec
+    let charge_mode = params
+        .charge_mode
         .as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
-        .unwrap_or("1");
-    if spec != "1" {
-        return Err("Unsupported NAT gateway spec. Use spec 1.".to_string());
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_else(|| DEFAULT_BANDWIDTH_CHARGE_MODE.to_string());
+    if !matches!(charge_mode.as_str(), "traffic" | "bandwidth") {
+        return Err(format!(
+            "Bandwidth charge mode must be 'traffic' or 'bandwidth', got '{}'.",
+            charge_mode
+        ));
     }
+    let size = params.size.unwrap_or(DEFAULT_BANDWIDTH_SIZE);
+    validate_bandwidth_size(&charge_mode, size)?;
 
-    let description = params
-        .description
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Removing EIPs from shared bandwidth: source={} region={} bandwidth_id={} eips={} charge_mode={} size={}",
+        source_label,
+        params.region,
+        bandwidth_id,
+        eip_ids.join(","),
+        charge_mode,
+        size
+    );
+
+    let client = HwcClient::new(credentials);
+    let bandwidth = client
+        .get_bandwidth(&params.region, bandwidth_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to get bandwidth before remove: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
+            );
+            err.to_string()
+        })?;
+    let (members, not_members) = partition_bandwidth_members(&bandwidth, &eip_ids);
+    if !not_members.is_empty() {
+        return Err(format!(
+            "EIP(s) not in shared bandwidth {}: {}.",
+            bandwidth_id,
+            not_members.join(", ")
+        ));
+    }
+
+    let (status, body) = client
+        .remove_bandwidth_eips(&params.region, bandwidth_id, &members, &charge_mode, size)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to remove EIPs from shared bandwidth: region={} bandwidth_id={} error={}",
+                params.region, bandwidth_id, err
+            );
+            err.to_string()
+        })?;
+
+    Ok(vpc_operation_result(status, body))
+}
+
+/// Stop one ECS instance using SOFT or HARD stop type.
+#[tauri::command]
+async fn stop_ecs(
+    params: EcsStopParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<StopEcsResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    let requested_type = params
+        .stop_type
         .as_deref()
         .map(str::trim)
-        .filter(|value| !value.is_empty());
+        .filter(|kind| !kind.is_empty())
+        .unwrap_or("SOFT")
+        .to_ascii_uppercase();
+    let stop_type = if requested_type == "HARD" {
+        "HARD"
+    } else {
+        "SOFT"
+    };
 
-    let source_label = credentials_source_label(&source);
     info!(
-        "Creating CCE NAT gateway with EIP+SNAT bootstrap: source={} region={} name={} vpc_id={} subnet_id={} spec={}",
-        source_label, params.region, name, vpc_id, subnet_id, spec
+        "Stopping ECS instance: source={} region={} server_id={} type={}",
+        source_label, params.region, params.server_id, stop_type
     );
 
     let client = HwcClient::new(credentials);
-    let (nat_status, nat_body) = client
-        .create_nat_gateway(&params.region, name, description, spec, vpc_id, subnet_id)
+    let (status, body) = client
+        .stop_ecs(&params.region, &params.server_id, stop_type)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create CCE NAT gateway: region={} name={} error={}",
-                params.region, name, err
+                "Failed to stop ECS: region={} server_id={} error={}",
+                params.region, params.server_id, err
             );
             err.to_string()
         })?;
-    let mut summary = json!({
-        "requested": {
-            "region": params.region,
-            "name": name,
-            "vpc_id": vpc_id,
-            "subnet_id": subnet_id,
-            "spec": spec,
-            "auto_bind_eip": true,
-            "auto_create_snat": true
-        },
-        "nat_gateway": {
-            "status": nat_status.to_string(),
-            "status_code": nat_status.as_u16(),
-            "body": parse_json_or_string(&nat_body)
-        }
-    });
 
-    if !nat_status.is_success() {
-        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-        return Ok(cce_operation_result(nat_status, body));
-    }
+    Ok(StopEcsResult {
+        ecs: operation_result(status, body),
+    })
+}
+
+/// Resize one ECS instance to a flavor from its resize-compatible list.
+#[tauri::command]
+async fn resize_ecs(
+    params: EcsResizeParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EcsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
+    }
+    let flavor_id = params.flavor_id.trim();
+    if flavor_id.is_empty() {
+        return Err("Target flavor is required.".to_string());
+    }
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Resizing ECS instance: source={} region={} server_id={} flavor_id={}",
+        source_label, params.region, server_id, flavor_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let resize_flavors = client
+        .list_resize_flavors(&params.region, server_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list ECS resize flavors: region={} server_id={} error={}",
+                params.region, server_id, err
+            );
+            err.to_string()
+        })?;
+    let target = resize_flavors
+        .iter()
+        .find(|flavor| flavor.id == flavor_id)
+        .ok_or_else(|| {
+            format!(
+                "Flavor {} is not in the resize-compatible list for server {}.",
+                flavor_id, server_id
+            )
+        })?;
 
-    let nat_gateway_id = match extract_nat_gateway_id(&nat_body) {
-        Some(value) => value,
-        None => {
-            summary["error"] =
-                json!("NAT gateway create succeeded but response did not contain nat_gateway.id.");
-            let body =
-                serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-            return Ok(cce_operation_result(
-                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
-                body,
-            ));
+    // The current flavor only feeds pre-checks the resize API repeats, so lookup
+    // failures skip them instead of blocking the resize.
+    let current_flavor_id = match client.get_ecs(&params.region, server_id).await {
+        Ok(server) => server.flavor.and_then(|flavor| flavor.id),
+        Err(err) => {
+            warn!(
+                "Failed to read current ECS flavor before resize: region={} server_id={} error={}",
+                params.region, server_id, err
+            );
+            None
         }
     };
-    summary["nat_gateway"]["id"] = json!(nat_gateway_id.clone());
-
-    let mut last_nat_status = String::new();
-    for attempt in 1..=8 {
-        match client
-            .get_nat_gateway(&params.region, &nat_gateway_id)
-            .await
-        {
-            Ok(response) => {
-                let status_text = response
-                    .nat_gateway
-                    .status
-                    .as_deref()
-                    .map(str::trim)
-                    .unwrap_or("");
-                if !status_text.is_empty() {
-                    last_nat_status = status_text.to_string();
-                }
-                if status_text.eq_ignore_ascii_case("ACTIVE") {
-                    summary["nat_gateway"]["ready_status"] = json!(status_text);
-                    summary["nat_gateway"]["ready_attempt"] = json!(attempt);
-                    break;
-                }
-            }
+    // Server details carry no flavor specs, so the architecture comes from the flavor list.
+    let current = match current_flavor_id.as_deref() {
+        Some(current_id) => match client.list_flavors(&params.region).await {
+            Ok(flavors) => flavors.into_iter().find(|flavor| flavor.id == current_id),
             Err(err) => {
                 warn!(
-                    "Failed to poll NAT gateway status after create: region={} nat_gateway_id={} error={}",
-                    params.region, nat_gateway_id, err
+                    "Failed to list flavors before resize; skipping the architecture check: region={} error={}",
+                    params.region, err
                 );
+                None
             }
-        }
-        if attempt < 8 {
-            tokio::time::sleep(Duration::from_secs(4)).await;
-        }
-    }
-    if !last_nat_status.is_empty() {
-        summary["nat_gateway"]["last_observed_status"] = json!(last_nat_status);
-    }
+        },
+        None => None,
+    };
+    validate_ecs_resize_target(current_flavor_id.as_deref(), current.as_ref(), target)?;
 
-    let eip_name = format!("{}-eip", name);
-    let (eip_status, eip_body) = client
-        .create_eip(&params.region, DEFAULT_BANDWIDTH_SIZE, Some(&eip_name))
+    let (status, body) = client
+        .resize_ecs(
+            &params.region,
+            server_id,
+            flavor_id,
+            ecs_action_mode(params.stop_server),
+        )
         .await
         .map_err(|err| {
             error!(
-                "Failed to create EIP for CCE NAT bootstrap: region={} nat_gateway_id={} error={}",
-                params.region, nat_gateway_id, err
+                "Failed to resize ECS: region={} server_id={} flavor_id={} error={}",
+                params.region, server_id, flavor_id, err
             );
             err.to_string()
         })?;
-    summary["eip"] = json!({
-        "status": eip_status.to_string(),
-        "status_code": eip_status.as_u16(),
-        "body": parse_json_or_string(&eip_body)
-    });
 
-    if !eip_status.is_success() {
-        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-        return Ok(cce_operation_result(eip_status, body));
-    }
+    Ok(ecs_job_result(status, body))
+}
 
-    let (eip_id, eip_address) = extract_eip_id_and_address(&eip_body);
-    let eip_id = match eip_id {
-        Some(value) => value,
-        None => {
-            summary["error"] =
-                json!("EIP create succeeded but response did not contain publicip.id.");
-            let body =
-                serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-            return Ok(cce_operation_result(
-                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
-                body,
-            ));
-        }
-    };
-    summary["eip"]["id"] = json!(eip_id.clone());
-    if let Some(address) = eip_address {
-        summary["eip"]["address"] = json!(address);
+/// List flavors one ECS instance can be resized to.
+#[tauri::command]
+async fn list_ecs_resize_flavors(
+    region: String,
+    server_id: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<Vec<Flavor>, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let server_id = server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
     }
 
-    let (snat_status, snat_body) = client
-        .create_snat_rule(&params.region, &nat_gateway_id, subnet_id, &eip_id)
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Listing ECS resize flavors: source={} region={} server_id={}",
+        source_label, region, server_id
+    );
+
+    let client = HwcClient::new(credentials);
+    client
+        .list_resize_flavors(&region, server_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create SNAT rule for CCE NAT bootstrap: region={} nat_gateway_id={} eip_id={} error={}",
-                params.region, nat_gateway_id, eip_id, err
+                "Failed to list ECS resize flavors: region={} server_id={} error={}",
+                region, server_id, err
             );
             err.to_string()
-        })?;
-    summary["snat_rule"] = json!({
-        "status": snat_status.to_string(),
-        "status_code": snat_status.as_u16(),
-        "body": parse_json_or_string(&snat_body)
-    });
-
-    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-    Ok(cce_operation_result(snat_status, body))
+        })
 }
 
-/// Delete one NAT gateway by ID.
+/// Reinstall the current OS of one ECS instance with a new password or key pair.
 #[tauri::command]
-async fn delete_cce_nat_gateway(
-    params: CceDeleteNatGatewayParams,
+async fn reinstall_ecs(
+    params: EcsReinstallParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<EcsJobResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let region = params.region.trim().to_string();
-    if region.is_empty() {
-        return Err("CCE region is required.".to_string());
-    }
-    let nat_gateway_id = params.nat_gateway_id.trim().to_string();
-    if nat_gateway_id.is_empty() {
-        return Err("CCE NAT gateway ID is required.".to_string());
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
     }
+    let (admin_password, key_name) =
+        resolve_ecs_login(params.admin_password.as_deref(), params.key_name.as_deref())?;
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Deleting CCE NAT gateway: source={} region={} nat_gateway_id={}",
-        source_label, region, nat_gateway_id
+        "Reinstalling ECS OS: source={} region={} server_id={} key_login={}",
+        source_label,
+        params.region,
+        server_id,
+        key_name.is_some()
     );
 
     let client = HwcClient::new(credentials);
-    let mut summary = json!({
-        "requested": {
-            "region": region.clone(),
-            "nat_gateway_id": nat_gateway_id.clone()
-        },
-        "snat_rules": {
-            "total": 0,
-            "deleted": [],
-            "delete_failures": 0
-        },
-        "eips": {
-            "total": 0,
-            "deleted": [],
-            "delete_failures": 0
-        },
-        "nat_gateway": {
-            "status": "not_requested"
-        }
-    });
-
-    let snat_rules = client
-        .list_snat_rules(&region, &nat_gateway_id)
+    let (status, body) = client
+        .reinstall_ecs(
+            &params.region,
+            server_id,
+            admin_password.as_deref(),
+            key_name.as_deref(),
+            ecs_action_mode(params.stop_server),
+        )
         .await
         .map_err(|err| {
             error!(
-                "Failed to list SNAT rules before NAT delete: region={} nat_gateway_id={} error={}",
-                region, nat_gateway_id, err
+                "Failed to reinstall ECS OS: region={} server_id={} error={}",
+                params.region, server_id, err
             );
             err.to_string()
-        })?
-        .snat_rules;
-
-    summary["snat_rules"]["total"] = json!(snat_rules.len());
-    let mut snat_delete_results = Vec::with_capacity(snat_rules.len());
-    let mut snat_delete_failures = 0u32;
-    let mut eip_ids_seen = HashSet::new();
-    let mut eip_ids = Vec::new();
-    let mut snat_rule_ids = Vec::with_capacity(snat_rules.len());
+        })?;
 
-    for snat_rule in snat_rules {
-        let snat_rule_id = snat_rule
-            .id
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(str::to_string);
-        let floating_ip_id = snat_rule
-            .floating_ip_id
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(str::to_string);
+    Ok(ecs_job_result(status, body))
+}
 
-        if let Some(eip_id) = floating_ip_id {
-            if eip_ids_seen.insert(eip_id.clone()) {
-                eip_ids.push(eip_id);
-            }
-        }
+/// Switch one ECS instance to a different image with a new password or key pair.
+#[tauri::command]
+async fn change_ecs_os(
+    params: EcsChangeOsParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EcsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
 
-        let Some(snat_rule_id) = snat_rule_id else {
-            snat_delete_failures += 1;
-            snat_delete_results.push(json!({
-                "status": "error",
-                "error": "SNAT rule payload did not include an id.",
-                "snat_rule": snat_rule
-            }));
-            continue;
-        };
-        snat_rule_ids.push(snat_rule_id);
+    let server_id = params.server_id.trim();
+    if server_id.is_empty() {
+        return Err("ECS server ID is required.".to_string());
+    }
+    let image_id = params.image_id.trim();
+    if image_id.is_empty() {
+        return Err("Target image is required.".to_string());
     }
+    let (admin_password, key_name) =
+        resolve_ecs_login(params.admin_password.as_deref(), params.key_name.as_deref())?;
 
-    // NAT teardown can involve multiple SNAT/EIP resources; delete with bounded parallelism.
-    let snat_outcomes = stream::iter(snat_rule_ids.into_iter().map(|snat_rule_id| {
-        let client = client.clone();
-        let region = region.clone();
-        let nat_gateway_id = nat_gateway_id.clone();
-        async move {
-            match client
-                .delete_snat_rule(&region, &nat_gateway_id, &snat_rule_id)
-                .await
-            {
-                Ok((status, body)) => {
-                    let parsed_body = parse_json_or_string(&body);
-                    let not_found_ok = status == reqwest::StatusCode::NOT_FOUND
-                        && !is_api_method_not_found_response(&parsed_body);
-                    (
-                        json!({
-                            "id": snat_rule_id,
-                            "status": status.to_string(),
-                            "status_code": status.as_u16(),
-                            "body": parsed_body
-                        }),
-                        !status.is_success() && !not_found_ok,
-                    )
-                }
-                Err(err) => {
-                    warn!(
-                        "Failed to delete SNAT rule during NAT teardown: region={} nat_gateway_id={} snat_rule_id={} error={}",
-                        region, nat_gateway_id, snat_rule_id, err
-                    );
-                    (
-                        json!({
-                            "id": snat_rule_id,
-                            "status": "error",
-                            "error": err.to_string()
-                        }),
-                        true,
-                    )
-                }
-            }
-        }
-    }))
-    .buffer_unordered(NAT_DELETE_CONCURRENCY)
-    .collect::<Vec<_>>()
-    .await;
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Changing ECS OS: source={} region={} server_id={} image_id={} key_login={}",
+        source_label,
+        params.region,
+        server_id,
+        image_id,
+        key_name.is_some()
+    );
 
-    for (result, failed) in snat_outcomes {
-        if failed {
-            snat_delete_failures += 1;
-        }
-        snat_delete_results.push(result);
-    }
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .change_ecs_os(
+            &params.region,
+            server_id,
+            image_id,
+            admin_password.as_deref(),
+            key_name.as_deref(),
+            ecs_action_mode(params.stop_server),
+        )
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to change ECS OS: region={} server_id={} image_id={} error={}",
+                params.region, server_id, image_id, err
+            );
+            err.to_string()
+        })?;
 
-    summary["snat_rules"]["deleted"] = Value::Array(snat_delete_results);
-    summary["snat_rules"]["delete_failures"] = json!(snat_delete_failures);
-    summary["eips"]["total"] = json!(eip_ids.len());
+    Ok(ecs_job_result(status, body))
+}
 
-    let mut eip_delete_results = Vec::with_capacity(eip_ids.len());
-    let mut eip_delete_failures = 0u32;
-    let eip_outcomes = stream::iter(eip_ids.into_iter().map(|eip_id| {
-        let client = client.clone();
-        let region = region.clone();
-        let nat_gateway_id = nat_gateway_id.clone();
-        async move {
-            let mut last_status = reqwest::StatusCode::INTERNAL_SERVER_ERROR;
-            let mut last_body = Value::String(String::new());
-            for attempt in 1..=NAT_EIP_DELETE_MAX_ATTEMPTS {
-                match client.delete_eip(&region, &eip_id).await {
-                    Ok((status, body)) => {
-                        let parsed_body = parse_json_or_string(&body);
-                        let should_retry = should_retry_nat_eip_delete(status, &parsed_body)
-                            && attempt < NAT_EIP_DELETE_MAX_ATTEMPTS;
-                        last_status = status;
-                        last_body = parsed_body;
-                        if should_retry {
-                            tokio::time::sleep(Duration::from_millis(
-                                NAT_EIP_DELETE_RETRY_DELAY_MS,
-                            ))
-                            .await;
-                            continue;
-                        }
-                        return (
-                            json!({
-                                "id": eip_id,
-                                "status": status.to_string(),
-                                "status_code": status.as_u16(),
-                                "attempts": attempt,
-                                "body": last_body
-                            }),
-                            !is_success_or_not_found(status),
-                        );
-                    }
-                    Err(err) => {
-                        warn!(
-                            "Failed to delete EIP during NAT teardown: region={} nat_gateway_id={} eip_id={} error={}",
-                            region, nat_gateway_id, eip_id, err
-                        );
-                        return (
-                            json!({
-                                "id": eip_id,
-                                "status": "error",
-                                "error": err.to_string()
-                            }),
-                            true,
-                        );
-                    }
-                }
-            }
-            (
-                json!({
-                    "id": eip_id,
-                    "status": last_status.to_string(),
-                    "status_code": last_status.as_u16(),
-                    "attempts": NAT_EIP_DELETE_MAX_ATTEMPTS,
-                    "body": last_body
-                }),
-                !is_success_or_not_found(last_status),
-            )
-        }
-    }))
-    .buffer_unordered(NAT_DELETE_CONCURRENCY)
-    .collect::<Vec<_>>()
-    .await;
+/// Query one ECS job status.
+#[tauri::command]
+async fn get_ecs_job(
+    params: EcsGetJobParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<EcsJobResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
 
-    for (result, failed) in eip_outcomes {
-        if failed {
-            eip_delete_failures += 1;
-        }
-        eip_delete_results.push(result);
+    let job_id = params.job_id.trim();
+    if job_id.is_empty() {
+        return Err("ECS job ID is required.".to_string());
     }
 
-    summary["eips"]["deleted"] = Value::Array(eip_delete_results);
-    summary["eips"]["delete_failures"] = json!(eip_delete_failures);
-
-    let (nat_status, nat_body) = client
-        .delete_nat_gateway(&region, &nat_gateway_id)
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Querying ECS job: source={} region={} job_id={}",
+        source_label, params.region, job_id
+    );
+
+    let client = HwcClient::new(credentials);
+    let (status, body) = client
+        .get_ecs_job(&params.region, job_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to delete CCE NAT gateway: region={} nat_gateway_id={} error={}",
-                region, nat_gateway_id, err
+                "Failed to query ECS job: region={} job_id={} error={}",
+                params.region, job_id, err
             );
             err.to_string()
         })?;
-    summary["nat_gateway"] = json!({
-        "status": nat_status.to_string(),
-        "status_code": nat_status.as_u16(),
-        "body": parse_json_or_string(&nat_body)
-    });
-
-    if nat_status.is_success() && (snat_delete_failures > 0 || eip_delete_failures > 0) {
-        summary["warning"] =
-            json!("NAT gateway deleted, but one or more SNAT/EIP cleanup steps reported errors.");
-    }
 
-    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-    Ok(cce_operation_result(nat_status, body))
+    Ok(ecs_job_result(status, body))
 }
 
-/// Bind a public EIP to one CCE cluster API endpoint for remote kubeconfig access.
+/// Create one VPC after validating its CIDR locally.
 #[tauri::command]
-async fn bind_cce_cluster_api_eip(
-    params: CceBindClusterApiEipParams,
+async fn create_vpc(
+    params: VpcCreateParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
+    let enterprise_project_id = resolve_enterprise_project(
+        params.enterprise_project_id.as_deref(),
+        credentials.as_ref(),
+        false,
+    )?;
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required.".to_string());
-    }
-    let eip_address = params.eip_address.trim();
-    if eip_address.is_empty() {
-        return Err("CCE API EIP address is required.".to_string());
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("VPC name is required.".to_string());
     }
+    let cidr = validate_vpc_cidr(&params.cidr, params.allow_cce_cidr_overlap.unwrap_or(false))?;
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Binding CCE cluster API EIP: source={} region={} cluster_id={} eip_address={}",
-        source_label, params.region, cluster_id, eip_address
+        "Creating VPC: source={} region={} name={} cidr={}",
+        source_label, params.region, name, cidr
     );
 
+    let body = VpcCreateBody {
+        name: name.to_string(),
+        cidr,
+        description,
+        enterprise_project_id,
+    };
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .update_cce_cluster_external_ip(&params.region, cluster_id, eip_address)
+        .create_vpc(&params.region, &body)
         .await
         .map_err(|err| {
             error!(
-                "Failed to bind CCE cluster API EIP: region={} cluster_id={} eip_address={} error={}",
-                params.region, cluster_id, eip_address, err
+                "Failed to create VPC: region={} name={} error={}",
+                params.region, name, err
             );
             err.to_string()
         })?;
 
-    Ok(cce_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Create a new public EIP and bind it to one CCE cluster API endpoint.
+/// Delete one VPC once it has no subnets left.
 #[tauri::command]
-async fn create_and_bind_cce_cluster_api_eip(
-    params: CceCreateBindClusterApiEipParams,
+async fn delete_vpc(
+    params: VpcDeleteParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required.".to_string());
+    let vpc_id = params.vpc_id.trim();
+    if vpc_id.is_empty() {
+        return Err("VPC ID is required.".to_string());
     }
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Creating and binding CCE cluster API EIP: source={} region={} cluster_id={}",
-        source_label, params.region, cluster_id
+        "Deleting VPC: source={} region={} vpc_id={}",
+        source_label, params.region, vpc_id
     );
 
     let client = HwcClient::new(credentials);
-    let eip_name = format!("cce-api-{}", Utc::now().format("%Y%m%d%H%M%S"));
-    let (eip_status, eip_body) = client
-        .create_eip(&params.region, DEFAULT_BANDWIDTH_SIZE, Some(&eip_name))
+    let subnets = client
+        .list_subnets(&params.region, vpc_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create EIP for CCE API binding: region={} cluster_id={} error={}",
-                params.region, cluster_id, err
+                "Failed to list subnets before VPC delete: region={} vpc_id={} error={}",
+                params.region, vpc_id, err
             );
             err.to_string()
         })?;
+    if !subnets.is_empty() {
+        let names = subnets
+            .iter()
+            .map(|subnet| format!("{} ({})", subnet.name, subnet.cidr))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "VPC {} still has {} subnet(s): {}. Delete them first.",
+            vpc_id,
+            subnets.len(),
+            names
+        ));
+    }
 
-    let mut summary = json!({
-        "requested": {
-            "region": params.region,
-            "cluster_id": cluster_id,
-            "action": "create_and_bind_api_eip"
-        },
-        "eip": {
-            "status": eip_status.to_string(),
-            "status_code": eip_status.as_u16(),
-            "body": parse_json_or_string(&eip_body)
-        }
-    });
+    let (status, body) = client
+        .delete_vpc(&params.region, vpc_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to delete VPC: region={} vpc_id={} error={}",
+                params.region, vpc_id, err
+            );
+            err.to_string()
+        })?;
 
-    if !eip_status.is_success() {
-        let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-        return Ok(cce_operation_result(eip_status, body));
-    }
+    Ok(vpc_operation_result(status, body))
+}
 
-    let (eip_id, eip_address) = extract_eip_id_and_address(&eip_body);
-    if let Some(value) = eip_id {
-        summary["eip"]["id"] = json!(value);
+/// Create one subnet after checking its CIDR against the VPC and sibling subnets.
+#[tauri::command]
+async fn create_subnet(
+    params: SubnetCreateParams,
+    credentials: Option<CredentialsInput>,
+) -> Result<VpcOperationResult, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let vpc_id = params.vpc_id.trim();
+    if vpc_id.is_empty() {
+        return Err("VPC ID is required.".to_string());
     }
-    let eip_address = match eip_address {
-        Some(value) => value,
-        None => {
-            summary["error"] = json!("EIP create succeeded but no public address was returned.");
-            let body =
-                serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-            return Ok(cce_operation_result(
-                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
-                body,
-            ));
-        }
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("Subnet name is required.".to_string());
+    }
+    let normalize_optional = |value: Option<&str>| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
     };
-    summary["eip"]["address"] = json!(eip_address.clone());
+    let availability_zone = normalize_optional(params.availability_zone.as_deref());
+    let primary_dns = normalize_optional(params.primary_dns.as_deref());
+    let secondary_dns = normalize_optional(params.secondary_dns.as_deref());
+    for dns in primary_dns.iter().chain(secondary_dns.iter()) {
+        if dns.parse::<std::net::Ipv4Addr>().is_err() {
+            return Err(format!("DNS server '{}' is not a valid IPv4 address.", dns));
+        }
+    }
+    if primary_dns.is_none() && secondary_dns.is_some() {
+        return Err("Secondary DNS requires a primary DNS server.".to_string());
+    }
 
-    let (bind_status, bind_body) = client
-        .update_cce_cluster_external_ip(&params.region, cluster_id, &eip_address)
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Creating subnet: source={} region={} vpc_id={} name={} cidr={}",
+        source_label,
+        params.region,
+        vpc_id,
+        name,
+        params.cidr.trim()
+    );
+
+    let client = HwcClient::new(credentials);
+    quota_preflight(&client, &params.region, &[(QuotaResource::VpcSubnets, 1)]).await?;
+    let vpc = client
+        .get_vpc(&params.region, vpc_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to bind created EIP to CCE cluster API: region={} cluster_id={} eip_address={} error={}",
-                params.region, cluster_id, eip_address, err
+                "Failed to get VPC before subnet create: region={} vpc_id={} error={}",
+                params.region, vpc_id, err
+            );
+            err.to_string()
+        })?;
+    let siblings = client
+        .list_subnets(&params.region, vpc_id)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to list subnets before subnet create: region={} vpc_id={} error={}",
+                params.region, vpc_id, err
+            );
+            err.to_string()
+        })?;
+    let cidr = validate_subnet_cidr(
+        &params.cidr,
+        vpc.cidr.as_deref(),
+        &siblings,
+        params.allow_cce_cidr_overlap.unwrap_or(false),
+    )?;
+    let gateway_ip = resolve_subnet_gateway(&cidr, params.gateway_ip.as_deref())?;
+
+    let body = SubnetCreateBody {
+        name: name.to_string(),
+        cidr,
+        gateway_ip,
+        vpc_id: vpc_id.to_string(),
+        dhcp_enable: true,
+        availability_zone,
+        primary_dns,
+        secondary_dns,
+    };
+    let (status, body) = client
+        .create_subnet(&params.region, &body)
+        .await
+        .map_err(|err| {
+            error!(
+                "Failed to create subnet: region={} vpc_id={} name={} error={}",
+                params.region, vpc_id, name, err
             );
             err.to_string()
         })?;
-    summary["bind"] = json!({
-        "status": bind_status.to_string(),
-        "status_code": bind_status.as_u16(),
-        "body": parse_json_or_string(&bind_body),
-        "cluster_external_ip": eip_address
-    });
 
-    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
-    Ok(cce_operation_result(bind_status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Request cluster kubeconfig payload (clustercert API) for local kubectl access.
+/// Delete one subnet.
 #[tauri::command]
-async fn get_cce_cluster_kubeconfig(
-    params: CceDownloadKubeconfigParams,
+async fn delete_subnet(
+    params: SubnetDeleteParams,
     credentials: Option<CredentialsInput>,
-) -> Result<CceKubeconfigResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let cluster_id = params.cluster_id.trim();
-    if cluster_id.is_empty() {
-        return Err("CCE cluster ID is required.".to_string());
+    let vpc_id = params.vpc_id.trim();
+    let subnet_id = params.subnet_id.trim();
+    if vpc_id.is_empty() || subnet_id.is_empty() {
+        return Err("VPC ID and subnet ID are required.".to_string());
     }
 
-    let context = params
-        .context
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("external");
-
     let source_label = credentials_source_label(&source);
     info!(
-        "Requesting CCE cluster kubeconfig: source={} region={} cluster_id={} context={}",
-        source_label, params.region, cluster_id, context
+        "Deleting subnet: source={} region={} vpc_id={} subnet_id={}",
+        source_label, params.region, vpc_id, subnet_id
     );
 
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .get_cce_cluster_kubeconfig(&params.region, cluster_id, Some(context))
+        .delete_subnet(&params.region, vpc_id, subnet_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to request CCE cluster kubeconfig: region={} cluster_id={} error={}",
-                params.region, cluster_id, err
+                "Failed to delete subnet: region={} vpc_id={} subnet_id={} error={}",
+                params.region, vpc_id, subnet_id, err
             );
             err.to_string()
         })?;
-    let kubeconfig = if status.is_success() {
-        extract_cluster_kubeconfig(&body)
-    } else {
-        None
+
+    Ok(vpc_operation_result(status, body))
+}
+
+/// Join VPCs, subnets, ECS NICs, EIPs, NAT gateways and CCE clusters into one graph.
+#[tauri::command]
+async fn get_network_topology(
+    region: String,
+    credentials: Option<CredentialsInput>,
+) -> Result<NetworkTopology, String> {
+    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
+        error!("Failed to resolve credentials: {}", err);
+        err
+    })?;
+
+    let source_label = credentials_source_label(&source);
+    info!(
+        "Building network topology: source={} region={}",
+        source_label, region
+    );
+
+    let client = HwcClient::new(credentials);
+    let vpcs = client.list_vpcs(&region, None).await.map_err(|err| {
+        error!(
+            "Failed to list VPCs for topology: region={} error={}",
+            region, err
+        );
+        err.to_string()
+    })?;
+
+    let vpc_ids: Vec<String> = vpcs.iter().map(|vpc| vpc.id.clone()).collect();
+    let subnet_results = stream::iter(vpc_ids.into_iter().map(|vpc_id| {
+        let client = client.clone();
+        let region = region.clone();
+        async move {
+            let result = client.list_subnets(&region, &vpc_id).await;
+            (vpc_id, result)
+        }
+    }))
+    .buffer_unordered(TOPOLOGY_SUBNET_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+
+    let list_params = || {
+        Some(ListParams {
+            limit: Some(TOPOLOGY_LIST_LIMIT),
+            ..ListParams::default()
+        })
     };
+    let (servers, eips, nat_gateways, clusters) = futures::join!(
+        client.list_ecses(&region, list_params()),
+        client.list_eips(&region, list_params()),
+        client.list_nat_gateways(&region, None, None, None),
+        client.list_cce_clusters(&region),
+    );
 
-    Ok(CceKubeconfigResult {
-        status: status.to_string(),
-        status_code: status.as_u16(),
-        body,
-        kubeconfig,
-    })
+    // Only the VPC listing is mandatory; other services may be unavailable in a region.
+    let mut warnings = Vec::new();
+    let mut unavailable = |label: &str, err: anyhow::Error| {
+        warn!(
+            "Topology listing unavailable: region={} listing={} error={}",
+            region, label, err
+        );
+        warnings.push(format!("{} listing unavailable: {}", label, err));
+    };
+
+    let mut subnets = Vec::new();
+    for (vpc_id, result) in subnet_results {
+        match result {
+            Ok(items) => subnets.extend(items.into_iter().map(|mut subnet| {
+                subnet.vpc_id.get_or_insert_with(|| vpc_id.clone());
+                subnet
+            })),
+            Err(err) => unavailable(&format!("Subnet (VPC {vpc_id})"), err),
+        }
+    }
+    let servers = servers.map(|body| body.servers).unwrap_or_else(|err| {
+        unavailable("ECS", err);
+        Vec::new()
+    });
+    let eips = eips.map(|body| body.publicips).unwrap_or_else(|err| {
+        unavailable("EIP", err);
+        Vec::new()
+    });
+    let nat_gateways = nat_gateways
+        .map(|body| body.nat_gateways)
+        .unwrap_or_else(|err| {
+            unavailable("NAT gateway", err);
+            Vec::new()
+        });
+    let clusters = clusters.map(|body| body.items).unwrap_or_else(|err| {
+        unavailable("CCE cluster", err);
+        Vec::new()
+    });
+    for (label, count) in [("ECS", servers.len()), ("EIP", eips.len())] {
+        if count >= TOPOLOGY_LIST_LIMIT as usize {
+            warnings.push(format!(
+                "{} listing was truncated to the first {} items.",
+                label, TOPOLOGY_LIST_LIMIT
+            ));
+        }
+    }
+
+    let mut topology = build_network_topology(&TopologyInputs {
+        vpcs: &vpcs,
+        subnets: &subnets,
+        servers: &servers,
+        eips: &eips,
+        nat_gateways: &nat_gateways,
+        clusters: &clusters,
+    });
+    warnings.append(&mut topology.warnings);
+    topology.warnings = warnings;
+    Ok(topology)
 }
 
-/// List OBS buckets for the selected region.
+/// List security groups for the given region.
 #[tauri::command]
-async fn list_obs_buckets(
+async fn list_security_groups(
     region: String,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsListBucketsResponse, String> {
+) -> Result<Vec<SecurityGroup>, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
@@ -2068,689 +11419,761 @@ async fn list_obs_buckets(
 
     let source_label = credentials_source_label(&source);
     info!(
-        "Listing OBS buckets: source={} region={}",
+        "Listing security groups: source={} region={}",
         source_label, region
     );
 
     let client = HwcClient::new(credentials);
-    client.list_obs_buckets(&region).await.map_err(|err| {
+    client.list_security_groups(&region).await.map_err(|err| {
         error!(
-            "Failed to list OBS buckets: region={} error={}",
+            "Failed to list security groups: region={} error={}",
             region, err
         );
         err.to_string()
     })
 }
 
-/// Create one OBS bucket.
+/// Create one security group.
 #[tauri::command]
-async fn create_obs_bucket(
-    params: ObsCreateBucketParams,
+async fn create_security_group(
+    params: SecurityGroupCreateParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("Security group name is required.".to_string());
+    }
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Creating OBS bucket: source={} region={} bucket={}",
-        source_label, params.region, bucket_name
+        "Creating security group: source={} region={} name={}",
+        source_label, params.region, name
     );
 
     let client = HwcClient::new(credentials);
+    quota_preflight(
+        &client,
+        &params.region,
+        &[(QuotaResource::VpcSecurityGroups, 1)],
+    )
+    .await?;
     let (status, body) = client
-        .create_obs_bucket(
-            &params.region,
-            &bucket_name,
-            params.default_storage_class.as_deref(),
-            params.acl.as_deref(),
-        )
+        .create_security_group(&params.region, name, description)
         .await
         .map_err(|err| {
             error!(
-                "Failed to create OBS bucket: region={} bucket={} error={}",
-                params.region, bucket_name, err
+                "Failed to create security group: region={} name={} error={}",
+                params.region, name, err
             );
             err.to_string()
         })?;
 
-    Ok(obs_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Delete one OBS bucket.
+/// Delete one security group.
 #[tauri::command]
-async fn delete_obs_bucket(
-    params: ObsDeleteBucketParams,
+async fn delete_security_group(
+    params: SecurityGroupDeleteParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
+    let security_group_id = params.security_group_id.trim();
+    if security_group_id.is_empty() {
+        return Err("Security group ID is required.".to_string());
+    }
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Deleting OBS bucket: source={} region={} bucket={}",
-        source_label, params.region, bucket_name
+        "Deleting security group: source={} region={} security_group_id={}",
+        source_label, params.region, security_group_id
     );
 
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .delete_obs_bucket(&params.region, &bucket_name)
+        .delete_security_group(&params.region, security_group_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to delete OBS bucket: region={} bucket={} error={}",
-                params.region, bucket_name, err
+                "Failed to delete security group: region={} security_group_id={} error={}",
+                params.region, security_group_id, err
             );
             err.to_string()
         })?;
 
-    Ok(obs_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// List objects for one OBS bucket.
+/// List rules for one security group.
 #[tauri::command]
-async fn list_obs_objects(
-    params: ObsListObjectsParams,
+async fn list_security_group_rules(
+    params: SecurityGroupRuleListParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsListObjectsResponse, String> {
+) -> Result<SecurityGroupRuleListResponse, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
+    let security_group_id = params.security_group_id.trim();
+    if security_group_id.is_empty() {
+        return Err("Security group ID is required.".to_string());
+    }
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Listing OBS objects: source={} region={} bucket={}",
-        source_label, params.region, bucket_name
+        "Listing security group rules: source={} region={} security_group_id={}",
+        source_label, params.region, security_group_id
     );
 
     let client = HwcClient::new(credentials);
     client
-        .list_obs_objects(
-            &params.region,
-            &bucket_name,
-            params.prefix.as_deref(),
-            params.marker.as_deref(),
-            params.max_keys,
-        )
+        .list_security_group_rules(&params.region, security_group_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to list OBS objects: region={} bucket={} error={}",
-                params.region, bucket_name, err
+                "Failed to list security group rules: region={} security_group_id={} error={}",
+                params.region, security_group_id, err
             );
             err.to_string()
         })
 }
 
-/// Scan all OBS object pages for one bucket and return total bytes/object count.
+/// Add one rule to a security group.
 #[tauri::command]
-async fn get_obs_bucket_totals(
-    params: ObsBucketTotalsParams,
+async fn add_security_group_rule(
+    params: SecurityGroupRuleAddParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsBucketTotalsResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Calculating OBS totals: source={} region={} bucket={}",
-        source_label, params.region, bucket_name
-    );
-
-    let client = HwcClient::new(credentials);
-    let mut marker: Option<String> = None;
-    let mut seen_markers = HashSet::new();
-    let mut pages_scanned: usize = 0;
-    let mut total_object_count: u64 = 0;
-    let mut total_size_bytes: u64 = 0;
-
-    loop {
-        pages_scanned += 1;
-        if pages_scanned > OBS_BUCKET_TOTALS_MAX_PAGES {
-            return Err(format!(
-                "OBS totals aborted after {} pages to avoid infinite pagination.",
-                OBS_BUCKET_TOTALS_MAX_PAGES
-            ));
-        }
-
-        let response = client
-            .list_obs_objects(
-                &params.region,
-                &bucket_name,
-                None,
-                marker.as_deref(),
-                Some(OBS_LIST_MAX_KEYS),
-            )
-            .await
-            .map_err(|err| {
-                error!(
-                    "Failed to scan OBS totals: region={} bucket={} marker={} error={}",
-                    params.region,
-                    bucket_name,
-                    marker.clone().unwrap_or_else(|| "<start>".to_string()),
-                    err
-                );
-                err.to_string()
-            })?;
-
-        let objects = response.objects;
-        total_object_count = total_object_count.saturating_add(objects.len() as u64);
-        for object in objects {
-            if let Some(size) = object.size {
-                total_size_bytes = total_size_bytes.saturating_add(size);
+    let security_group_id = params.security_group_id.trim();
+    if security_group_id.is_empty() {
+        return Err("Security group ID is required.".to_string());
+    }
+    let direction = normalize_security_group_direction(params.direction.as_deref())?;
+    let protocol = normalize_security_group_protocol(params.protocol.as_deref())?;
+    let action = normalize_security_group_action(params.action.as_deref())?;
+    let multiport = match params
+        .ports
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(ports) => {
+            if !matches!(protocol.as_deref(), Some("tcp") | Some("udp")) {
+                return Err("Ports can only be set for tcp or udp rules.".to_string());
             }
+            Some(normalize_security_group_ports(
+                ports,
+                SECURITY_GROUP_RULE_MAX_PORTS,
+            )?)
         }
+        None => None,
+    };
+    let remote_ip_prefix = params
+        .remote_ip_prefix
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(normalize_remote_ip_prefix)
+        .transpose()?;
+    let description = params
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
 
-        let next_marker = response
-            .next_marker
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(str::to_string);
-        if !response.is_truncated || next_marker.is_none() {
-            break;
-        }
-        let next_marker = next_marker.unwrap_or_default();
-        if !seen_markers.insert(next_marker.clone()) {
-            return Err(format!(
-                "OBS totals pagination loop detected for marker '{}'.",
-                next_marker
-            ));
-        }
-        marker = Some(next_marker);
-    }
-
-    Ok(ObsBucketTotalsResult {
-        total_size_bytes,
-        total_object_count,
-        pages_scanned: pages_scanned as u32,
-    })
-}
-
-/// Upload one object to OBS.
-#[tauri::command]
-async fn put_obs_object(
-    params: ObsPutObjectParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<ObsOperationResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
-
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
-    let object_key = normalize_obs_object_key(&params.object_key)?;
     let source_label = credentials_source_label(&source);
     info!(
-        "Uploading OBS object: source={} region={} bucket={} key={}",
-        source_label, params.region, bucket_name, object_key
+        "Adding security group rule: source={} region={} security_group_id={} direction={} protocol={} ports={} remote_ip_prefix={}",
+        source_label,
+        params.region,
+        security_group_id,
+        direction,
+        protocol.as_deref().unwrap_or("any"),
+        multiport.as_deref().unwrap_or("all"),
+        remote_ip_prefix.as_deref().unwrap_or("any")
     );
 
-    let content = base64::engine::general_purpose::STANDARD
-        .decode(params.content_base64.trim())
-        .map_err(|err| format!("Failed to decode base64 object payload: {}", err))?;
-    if content.is_empty() {
-        return Err("OBS upload payload is empty.".to_string());
-    }
-    if content.len() > OBS_PUT_OBJECT_MAX_BYTES {
-        return Err(format!(
-            "OBS PutObject supports up to {} bytes (5 GB). Use multipart upload for larger files.",
-            OBS_PUT_OBJECT_MAX_BYTES
-        ));
-    }
-
+    let rule = SecurityGroupRuleCreateBody {
+        security_group_id: security_group_id.to_string(),
+        direction,
+        description,
+        ethertype: Some("IPv4".to_string()),
+        protocol,
+        multiport,
+        remote_ip_prefix,
+        action,
+        priority: params.priority,
+    };
     let client = HwcClient::new(credentials);
     let (status, body) = client
-        .put_obs_object(
-            &params.region,
-            &bucket_name,
-            &object_key,
-            content,
-            params.content_type.as_deref(),
-        )
+        .create_security_group_rule(&params.region, &rule)
         .await
         .map_err(|err| {
             error!(
-                "Failed to upload OBS object: region={} bucket={} key={} error={}",
-                params.region, bucket_name, object_key, err
+                "Failed to add security group rule: region={} security_group_id={} error={}",
+                params.region, security_group_id, err
             );
             err.to_string()
         })?;
 
-    Ok(obs_operation_result(status, body))
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Download one object from OBS.
+/// Remove one rule from a security group.
 #[tauri::command]
-async fn get_obs_object(
-    params: ObsGetObjectParams,
+async fn delete_security_group_rule(
+    params: SecurityGroupRuleDeleteParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsGetObjectResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
-    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let rule_id = params.rule_id.trim();
+    if rule_id.is_empty() {
+        return Err("Security group rule ID is required.".to_string());
+    }
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Downloading OBS object: source={} region={} bucket={} key={}",
-        source_label, params.region, bucket_name, object_key
+        "Deleting security group rule: source={} region={} rule_id={}",
+        source_label, params.region, rule_id
     );
 
     let client = HwcClient::new(credentials);
-    let (status, content, content_type) = client
-        .get_obs_object(&params.region, &bucket_name, &object_key)
+    let (status, body) = client
+        .delete_security_group_rule(&params.region, rule_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to download OBS object: region={} bucket={} key={} error={}",
-                params.region, bucket_name, object_key, err
+                "Failed to delete security group rule: region={} rule_id={} error={}",
+                params.region, rule_id, err
             );
             err.to_string()
         })?;
 
-    let status_code = status.as_u16();
-    let success = status.is_success();
-    let body = if success {
-        None
-    } else {
-        Some(String::from_utf8_lossy(&content).into_owned())
-    };
-
-    Ok(ObsGetObjectResult {
-        status: status.to_string(),
-        status_code,
-        content_base64: if success {
-            Some(base64::engine::general_purpose::STANDARD.encode(content))
-        } else {
-            None
-        },
-        content_type,
-        body,
-    })
+    Ok(vpc_operation_result(status, body))
 }
 
-/// Delete one object from OBS.
+/// Allow TCP ingress from the caller's public IP (SSH/RDP) before a startup task connects.
 #[tauri::command]
-async fn delete_obs_object(
-    params: ObsDeleteObjectParams,
+async fn allow_current_ip_ingress(
+    params: SecurityGroupAllowCurrentIpParams,
     credentials: Option<CredentialsInput>,
-) -> Result<ObsOperationResult, String> {
+) -> Result<VpcOperationResult, String> {
     let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
         error!("Failed to resolve credentials: {}", err);
         err
     })?;
 
-    let bucket_name = normalize_obs_bucket_name(
-        &params.bucket_name,
-        OBS_BUCKET_NAME_MIN,
-        OBS_BUCKET_NAME_MAX,
-    )?;
-    let object_key = normalize_obs_object_key(&params.object_key)?;
+    let mut ports = params.ports.unwrap_or_default();
+    if ports.is_empty() {
+        ports.push(SECURITY_GROUP_DEFAULT_ACCESS_PORT);
+    }
+    if ports.contains(&0) {
+        return Err("Ports must be between 1 and 65535.".to_string());
+    }
+    ports.sort_unstable();
+    ports.dedup();
+
+    let source_ip = match params
+        .source_ip
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(value) => value
+            .parse::<std::net::Ipv4Addr>()
+            .map_err(|_| format!("Source IP '{}' is not a valid IPv4 address.", value))?,
+        None if params.detect_public_ip.unwrap_or(false) => {
+            lookup_public_ipv4().await.map_err(|err| {
+                error!("Failed to detect public IP: error={}", err);
+                err.to_string()
+            })?
+        }
+        None => {
+            return Err(
+                "Source IP is required when public IP detection is turned off.".to_string(),
+            );
+        }
+    };
+
+    let client = HwcClient::new(credentials);
+    let security_group_id = match params
+        .security_group_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(value) => value.to_string(),
+        None => {
+            let server_id = params
+                .server_id
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| "Server ID or security group ID is required.".to_string())?;
+            let server = client
+                .get_ecs(&params.region, server_id)
+                .await
+                .map_err(|err| {
+                    error!(
+                        "Failed to get ECS for ingress rule: region={} server_id={} error={}",
+                        params.region, server_id, err
+                    );
+                    err.to_string()
+                })?;
+            server
+                .security_groups
+                .into_iter()
+                .filter_map(|group| group.id)
+                .map(|id| id.trim().to_string())
+                .find(|id| !id.is_empty())
+                .ok_or_else(|| format!("Server {} has no security group to update.", server_id))?
+        }
+    };
+
     let source_label = credentials_source_label(&source);
     info!(
-        "Deleting OBS object: source={} region={} bucket={} key={}",
-        source_label, params.region, bucket_name, object_key
+        "Allowing current IP ingress: source={} region={} security_group_id={} source_ip={} ports={:?}",
+        source_label, params.region, security_group_id, source_ip, ports
     );
 
-    let client = HwcClient::new(credentials);
-    let (status, body) = client
-        .delete_obs_object(&params.region, &bucket_name, &object_key)
+    let existing_rules = client
+        .list_security_group_rules(&params.region, &security_group_id)
         .await
         .map_err(|err| {
             error!(
-                "Failed to delete OBS object: region={} bucket={} key={} error={}",
-                params.region, bucket_name, object_key, err
+                "Failed to list security group rules: region={} security_group_id={} error={}",
+                params.region, security_group_id, err
             );
             err.to_string()
-        })?;
+        })?
+        .security_group_rules;
+
+    let remote_ip_prefix = format!("{source_ip}/32");
+    let mut overall_status = reqwest::StatusCode::OK;
+    let mut rules = Vec::with_capacity(ports.len());
+    for port in ports {
+        if existing_rules
+            .iter()
+            .any(|rule| security_group_rule_allows_ingress(rule, source_ip, port))
+        {
+            rules.push(json!({ "port": port, "status": "already_allowed" }));
+            continue;
+        }
 
-    Ok(obs_operation_result(status, body))
+        let rule = SecurityGroupRuleCreateBody {
+            security_group_id: security_group_id.clone(),
+            direction: "ingress".to_string(),
+            description: Some(format!("HC Forge access from {remote_ip_prefix}")),
+            ethertype: Some("IPv4".to_string()),
+            protocol: Some("tcp".to_string()),
+            multiport: Some(port.to_string()),
+            remote_ip_prefix: Some(remote_ip_prefix.clone()),
+            action: Some("allow".to_string()),
+            priority: None,
+        };
+        let (status, body) = client
+            .create_security_group_rule(&params.region, &rule)
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to add ingress rule: region={} security_group_id={} port={} error={}",
+                    params.region, security_group_id, port, err
+                );
+                err.to_string()
+            })?;
+        if !status.is_success() && overall_status.is_success() {
+            overall_status = status;
+        }
+        rules.push(json!({
+            "port": port,
+            "status": status.to_string(),
+            "status_code": status.as_u16(),
+            "body": parse_json_or_string(&body),
+        }));
+    }
+
+    let summary = json!({
+        "security_group_id": security_group_id,
+        "source_ip": source_ip.to_string(),
+        "remote_ip_prefix": remote_ip_prefix,
+        "rules": rules,
+    });
+    let body = serde_json::to_string_pretty(&summary).unwrap_or_else(|_| summary.to_string());
+    Ok(vpc_operation_result(overall_status, body))
 }
 
-/// Create an ECS instance using the same core flow as the old CLI.
-#[tauri::command]
-async fn create_ecs(
-    params: EcsCreateParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<CreateEcsResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+fn lock_ssh_sessions<'a>(
+    state: &'a tauri::State<'_, SshSessionStore>,
+) -> Result<std::sync::MutexGuard<'a, HashMap<String, SshSessionEntry>>, String> {
+    state
+        .sessions
+        .lock()
+        .map_err(|_| "SSH session store is unavailable.".to_string())
+}
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Creating ECS instance: source={} region={} vpc_id={} subnet_id={} allocate_eip={}",
-        source_label, params.region, params.vpc_id, params.subnet_id, params.eip
-    );
+fn emit_ssh_event(app_handle: &tauri::AppHandle, session_id: &str, kind: &str, text: &str) {
+    if text.is_empty() {
+        return;
+    }
 
-    let server_name = normalize_server_name(&params.name);
-    let admin_password = params
-        .admin_password
+    let payload = SshStreamEvent {
+        session_id: session_id.to_string(),
+        kind: kind.to_string(),
+        text: text.to_string(),
+        at: Utc::now().to_rfc3339(),
+    };
+    if let Err(err) = app_handle.emit("ssh-output", payload) {
+        warn!("Failed to emit ssh-output event: {}", err);
+    }
+}
+
+/// Connect to an ECS instance over SSH and store the live session.
+#[tauri::command]
+async fn ssh_connect(
+    params: SshConnectParams,
+    state: tauri::State<'_, SshSessionStore>,
+    app_handle: tauri::AppHandle,
+) -> Result<SshConnectResult, String> {
+    let session_id = normalize_ssh_session_id(&params.session_id)?;
+    let host = params.host.trim().to_string();
+    if host.is_empty() {
+        return Err("SSH host is required.".to_string());
+    }
+
+    let port = params.port.unwrap_or(22);
+    let username = params
+        .username
         .as_deref()
         .map(str::trim)
-        .filter(|password| !password.is_empty())
-        .map(|password| password.to_string());
+        .filter(|value| !value.is_empty())
+        .unwrap_or("root")
+        .to_string();
+    let password = params.password.trim().to_string();
+    if password.is_empty() {
+        return Err("SSH password is required.".to_string());
+    }
 
-    let eip_bandwidth_size = params.eip_bandwidth_size.unwrap_or(DEFAULT_BANDWIDTH_SIZE);
-    if params.eip && !(MIN_BANDWIDTH_SIZE..=MAX_BANDWIDTH_SIZE).contains(&eip_bandwidth_size) {
+    let existing = {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions.remove(&session_id)
+    };
+    if let Some(stale) = existing {
+        stale.shell_reader_task.abort();
+        let _ = stale
+            .handle
+            .disconnect(Disconnect::ByApplication, "", "en")
+            .await;
+    }
+
+    let config = Arc::new(client::Config {
+        inactivity_timeout: Some(Duration::from_secs(30)),
+        ..<_>::default()
+    });
+    let mut handle = client::connect(config, (host.as_str(), port), SshClientHandler)
+        .await
+        .map_err(|err| format!("SSH connection failed to {}:{}: {}", host, port, err))?;
+    let auth = handle
+        .authenticate_password(username.clone(), password)
+        .await
+        .map_err(|err| {
+            format!(
+                "SSH authentication failed for {}@{}:{}: {}",
+                username, host, port, err
+            )
+        })?;
+    if !auth.success() {
         return Err(format!(
-            "EIP bandwidth size must be between {} and {} Mbit/s for charge_mode=traffic.",
-            MIN_BANDWIDTH_SIZE, MAX_BANDWIDTH_SIZE
+            "SSH authentication rejected for {}@{}:{}.",
+            username, host, port
         ));
     }
 
-    let data_volumes = params
-        .data_volumes
-        .unwrap_or_default()
-        .into_iter()
-        .map(|volume| {
-            let volume_type = volume.volume_type.trim().to_string();
-            if volume_type.is_empty() {
-                return Err("Data disk volume type is required.".to_string());
-            }
-            if volume.size == 0 {
-                return Err("Data disk size must be greater than 0 GB.".to_string());
-            }
-            let count = volume.count.unwrap_or(1);
-            if count == 0 {
-                return Err("Data disk count must be at least 1.".to_string());
-            }
-            Ok(DataVolume {
-                volumetype: volume_type,
-                size: volume.size,
-                count: Some(count),
-                multiattach: volume.multiattach,
-                hw_passthrough: volume.hw_passthrough,
-            })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+    let channel = handle
+        .channel_open_session()
+        .await
+        .map_err(|err| format!("Failed to open interactive SSH channel: {}", err))?;
+    channel
+        .request_pty(false, "xterm-256color", 220, 64, 0, 0, &[])
+        .await
+        .map_err(|err| format!("Failed to request SSH PTY: {}", err))?;
+    channel
+        .request_shell(false)
+        .await
+        .map_err(|err| format!("Failed to request SSH shell: {}", err))?;
+    let (mut shell_reader, shell_writer) = channel.split();
 
-    let publicip = if params.eip {
-        Some(PublicIp {
-            eip: Eip {
-                ip_type: DEFAULT_EIP_TYPE.into(),
-                bandwidth: Bandwidth {
-                    size: eip_bandwidth_size,
-                    share_type: DEFAULT_BANDWIDTH_SHARE_TYPE.into(),
-                    charge_mode: DEFAULT_BANDWIDTH_CHARGE_MODE.into(),
-                },
-            },
-        })
-    } else {
-        None
-    };
+    let session_id_for_task = session_id.clone();
+    let app_handle_for_task = app_handle.clone();
+    let shell_reader_task = tokio::spawn(async move {
+        while let Some(message) = shell_reader.wait().await {
+            match message {
+                ChannelMsg::Data { data } => {
+                    emit_ssh_event(
+                        &app_handle_for_task,
+                        &session_id_for_task,
+                        "stdout",
+                        &String::from_utf8_lossy(data.as_ref()),
+                    );
+                }
+                ChannelMsg::ExtendedData { data, .. } => {
+                    emit_ssh_event(
+                        &app_handle_for_task,
+                        &session_id_for_task,
+                        "stderr",
+                        &String::from_utf8_lossy(data.as_ref()),
+                    );
+                }
+                ChannelMsg::ExitStatus { exit_status } => {
+                    emit_ssh_event(
+                        &app_handle_for_task,
+                        &session_id_for_task,
+                        "meta",
+                        &format!("Exit status: {}", exit_status),
+                    );
+                }
+                ChannelMsg::Eof => {
+                    emit_ssh_event(
+                        &app_handle_for_task,
+                        &session_id_for_task,
+                        "meta",
+                        "Remote shell sent EOF.",
+                    );
+                }
+                ChannelMsg::Close => {
+                    emit_ssh_event(
+                        &app_handle_for_task,
+                        &session_id_for_task,
+                        "meta",
+                        "Remote shell closed.",
+                    );
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
 
-    let body = CreateEcsRequest {
-        server: Server {
-            name: server_name,
-            image_ref: params.image_id,
-            flavor_ref: params.flavor_id,
-            vpcid: params.vpc_id,
-            nics: vec![Nic {
-                subnet_id: params.subnet_id,
-            }],
-            root_volume: RootVolume {
-                volumetype: params.root_volume_type,
-                size: params.root_volume_size,
+    let connected_at = Utc::now().to_rfc3339();
+    {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions.insert(
+            session_id.clone(),
+            SshSessionEntry {
+                handle,
+                shell_writer,
+                shell_reader_task,
+                host: host.clone(),
+                port,
+                username: username.clone(),
             },
-            data_volumes,
-            publicip,
-            admin_pass: admin_password,
-        },
-    };
+        );
+    }
 
-    let client = HwcClient::new(credentials);
-    let (status, body) = client
-        .create_ecs(&params.region, &body)
-        .await
-        .map_err(|err| {
-            error!(
-                "Failed to create ECS: region={} error={}",
-                params.region, err
-            );
-            err.to_string()
-        })?;
+    info!(
+        "SSH connected: session_id={} target={}@{}:{}",
+        session_id, username, host, port
+    );
 
-    Ok(CreateEcsResult {
-        status: status.to_string(),
-        status_code: status.as_u16(),
-        body,
+    Ok(SshConnectResult {
+        session_id,
+        host,
+        port,
+        username,
+        connected_at,
     })
 }
 
-/// Delete an ECS instance and, when possible, its attached EIP.
+/// Execute one command over an existing SSH connection.
 #[tauri::command]
-async fn delete_ecs_with_eip(
-    params: EcsDeleteParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<DeleteEcsResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+async fn ssh_exec(
+    params: SshExecParams,
+    state: tauri::State<'_, SshSessionStore>,
+) -> Result<SshExecResult, String> {
+    let session_id = normalize_ssh_session_id(&params.session_id)?;
+    let command = params.command.trim().to_string();
+    if command.is_empty() {
+        return Err("SSH command is required.".to_string());
+    }
+
+    let entry = {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions
+            .remove(&session_id)
+            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
+    };
 
-    let source_label = credentials_source_label(&source);
     info!(
-        "Deleting ECS instance: source={} region={} server_id={}",
-        source_label, params.region, params.server_id
+        "Running SSH command: session_id={} target={}@{}:{} command={}",
+        session_id, entry.username, entry.host, entry.port, command
     );
 
-    let delete_volume = params.delete_volume.unwrap_or(true);
-    let client = HwcClient::new(credentials);
-    let (ecs_status, ecs_body) = client
-        .delete_ecs(&params.region, &params.server_id, true, delete_volume)
+    let payload = format!("{}\n", command);
+    let send_result = entry
+        .shell_writer
+        .data(Cursor::new(payload.into_bytes()))
         .await
-        .map_err(|err| {
-            error!(
-                "Failed to delete ECS: region={} server_id={} error={}",
-                params.region, params.server_id, err
-            );
-            err.to_string()
-        })?;
+        .map_err(|err| format!("Failed to send command to live SSH shell: {}", err));
 
-    let ecs_result = operation_result(ecs_status, ecs_body);
-    let ecs_success = ecs_result
-        .status_code
-        .is_some_and(|code| (200..300).contains(&code));
-    let eip_id = params
-        .eip_id
-        .as_deref()
-        .map(str::trim)
-        .filter(|id| !id.is_empty())
-        .map(|id| id.to_string());
+    if let Err(err) = send_result {
+        entry.shell_reader_task.abort();
+        warn!(
+            "SSH command failed; dropping session_id={} target={}@{}:{} error={}",
+            session_id, entry.username, entry.host, entry.port, err
+        );
+        return Err(err);
+    }
 
-    let eip_result = if !ecs_success {
-        eip_id.map(|id| {
-            operation_error_result(
-                "skipped",
-                format!(
-                    "Skipped EIP deletion for {} because ECS deletion did not return success.",
-                    id
-                ),
-            )
-        })
-    } else if let Some(eip_id) = eip_id {
-        match client.delete_eip(&params.region, &eip_id).await {
-            Ok((status, body)) => Some(operation_result(status, body)),
-            Err(err) => {
-                warn!(
-                    "Failed to delete EIP after ECS delete: region={} eip_id={} error={}",
-                    params.region, eip_id, err
-                );
-                Some(operation_error_result("error", err.to_string()))
-            }
-        }
-    } else {
-        None
-    };
+    let mut sessions = lock_ssh_sessions(&state)?;
+    sessions.insert(session_id.clone(), entry);
 
-    Ok(DeleteEcsResult {
-        ecs: ecs_result,
-        eip: eip_result,
+    Ok(SshExecResult {
+        session_id,
+        command,
+        stdout: String::new(),
+        stderr: String::new(),
+        exit_status: None,
     })
 }
 
-/// Delete one elastic IP by ID.
+/// Resize the PTY for an existing SSH shell session.
 #[tauri::command]
-async fn delete_eip(
-    params: EipDeleteParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<DeleteOperationResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
-
-    let eip_id = params.eip_id.trim();
-    if eip_id.is_empty() {
-        return Err("EIP ID is required.".to_string());
-    }
+async fn ssh_resize(
+    params: SshResizeParams,
+    state: tauri::State<'_, SshSessionStore>,
+) -> Result<SshResizeResult, String> {
+    let session_id = normalize_ssh_session_id(&params.session_id)?;
+    let cols = params.cols.clamp(40, 400);
+    let rows = params.rows.clamp(10, 180);
+    let pixel_width = params.pixel_width.unwrap_or(0);
+    let pixel_height = params.pixel_height.unwrap_or(0);
 
-    let source_label = credentials_source_label(&source);
-    info!(
-        "Deleting EIP: source={} region={} eip_id={}",
-        source_label, params.region, eip_id
-    );
+    let entry = {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions
+            .remove(&session_id)
+            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
+    };
 
-    let client = HwcClient::new(credentials);
-    let (status, body) = client
-        .delete_eip(&params.region, eip_id)
+    let resize_result = entry
+        .shell_writer
+        .window_change(cols, rows, pixel_width, pixel_height)
         .await
         .map_err(|err| {
-            error!(
-                "Failed to delete EIP: region={} eip_id={} error={}",
-                params.region, eip_id, err
-            );
-            err.to_string()
-        })?;
+            format!(
+                "Failed to resize SSH PTY for session {}: {}",
+                session_id, err
+            )
+        });
 
-    Ok(operation_result(status, body))
+    if let Err(err) = resize_result {
+        entry.shell_reader_task.abort();
+        warn!(
+            "SSH PTY resize failed; dropping session_id={} target={}@{}:{} error={}",
+            session_id, entry.username, entry.host, entry.port, err
+        );
+        return Err(err);
+    }
+
+    let mut sessions = lock_ssh_sessions(&state)?;
+    sessions.insert(session_id.clone(), entry);
+
+    Ok(SshResizeResult {
+        session_id,
+        cols,
+        rows,
+    })
 }
 
-/// Stop one ECS instance using SOFT or HARD stop type.
+/// Send interactive control bytes (Ctrl+C/Ctrl+D/Ctrl+U) to an SSH shell session.
 #[tauri::command]
-async fn stop_ecs(
-    params: EcsStopParams,
-    credentials: Option<CredentialsInput>,
-) -> Result<StopEcsResult, String> {
-    let (credentials, source) = resolve_credentials(credentials).map_err(|err| {
-        error!("Failed to resolve credentials: {}", err);
-        err
-    })?;
+async fn ssh_send_control(
+    params: SshSendControlParams,
+    state: tauri::State<'_, SshSessionStore>,
+) -> Result<SshSendControlResult, String> {
+    let session_id = normalize_ssh_session_id(&params.session_id)?;
+    let control = params.control.trim().to_string();
+    let control_byte = control_char_from_input(&control)?;
 
-    let source_label = credentials_source_label(&source);
-    let requested_type = params
-        .stop_type
-        .as_deref()
-        .map(str::trim)
-        .filter(|kind| !kind.is_empty())
-        .unwrap_or("SOFT")
-        .to_ascii_uppercase();
-    let stop_type = if requested_type == "HARD" {
-        "HARD"
-    } else {
-        "SOFT"
+    let entry = {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions
+            .remove(&session_id)
+            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
     };
 
-    info!(
-        "Stopping ECS instance: source={} region={} server_id={} type={}",
-        source_label, params.region, params.server_id, stop_type
-    );
-
-    let client = HwcClient::new(credentials);
-    let (status, body) = client
-        .stop_ecs(&params.region, &params.server_id, stop_type)
-        .await
-        .map_err(|err| {
-            error!(
-                "Failed to stop ECS: region={} server_id={} error={}",
-                params.region, params.server_id, err
-            );
-            err.to_string()
-        })?;
-
-    Ok(StopEcsResult {
-        ecs: operation_result(status, body),
-    })
-}
-
-fn lock_ssh_sessions<'a>(
-    state: &'a tauri::State<'_, SshSessionStore>,
-) -> Result<std::sync::MutexGuard<'a, HashMap<String, SshSessionEntry>>, String> {
-    state
-        .sessions
-        .lock()
-        .map_err(|_| "SSH session store is unavailable.".to_string())
-}
+    let payload = vec![control_byte];
+    let send_result = entry
+        .shell_writer
+        .data(Cursor::new(payload))
+        .await
+        .map_err(|err| {
+            format!(
+                "Failed to send {} to SSH session {}: {}",
+                control, session_id, err
+            )
+        });
 
-fn emit_ssh_event(app_handle: &tauri::AppHandle, session_id: &str, kind: &str, text: &str) {
-    if text.is_empty() {
-        return;
+    if let Err(err) = send_result {
+        entry.shell_reader_task.abort();
+        warn!(
+            "SSH control send failed; dropping session_id={} target={}@{}:{} error={}",
+            session_id, entry.username, entry.host, entry.port, err
+        );
+        return Err(err);
     }
 
-    let payload = SshStreamEvent {
-        session_id: session_id.to_string(),
-        kind: kind.to_string(),
-        text: text.to_string(),
-        at: Utc::now().to_rfc3339(),
-    };
-    if let Err(err) = app_handle.emit("ssh-output", payload) {
-        warn!("Failed to emit ssh-output event: {}", err);
-    }
+    let mut sessions = lock_ssh_sessions(&state)?;
+    sessions.insert(session_id.clone(), entry);
+
+    Ok(SshSendControlResult {
+        session_id,
+        control,
+        sent: true,
+    })
 }
 
-/// Connect to an ECS instance over SSH and store the live session.
+/// Execute one remote command by creating a short-lived SSH connection.
 #[tauri::command]
-async fn ssh_connect(
-    params: SshConnectParams,
-    state: tauri::State<'_, SshSessionStore>,
+async fn ssh_exec_one_shot(
+    params: SshExecOneShotParams,
     app_handle: tauri::AppHandle,
-) -> Result<SshConnectResult, String> {
+) -> Result<SshExecOneShotResult, String> {
     let session_id = normalize_ssh_session_id(&params.session_id)?;
     let host = params.host.trim().to_string();
     if host.is_empty() {
         return Err("SSH host is required.".to_string());
     }
 
+    let command = params.command.trim().to_string();
+    if command.is_empty() {
+        return Err("SSH command is required.".to_string());
+    }
+
     let port = params.port.unwrap_or(22);
     let username = params
         .username
@@ -2764,20 +12187,8 @@ async fn ssh_connect(
         return Err("SSH password is required.".to_string());
     }
 
-    let existing = {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions.remove(&session_id)
-    };
-    if let Some(stale) = existing {
-        stale.shell_reader_task.abort();
-        let _ = stale
-            .handle
-            .disconnect(Disconnect::ByApplication, "", "en")
-            .await;
-    }
-
     let config = Arc::new(client::Config {
-        inactivity_timeout: Some(Duration::from_secs(30)),
+        inactivity_timeout: Some(Duration::from_secs(60)),
         ..<_>::default()
     });
     let mut handle = client::connect(config, (host.as_str(), port), SshClientHandler)
@@ -2799,595 +12210,1654 @@ async fn ssh_connect(
         ));
     }
 
-    let channel = handle
+    let mut channel = handle
         .channel_open_session()
         .await
-        .map_err(|err| format!("Failed to open interactive SSH channel: {}", err))?;
+        .map_err(|err| format!("Failed to open SSH exec channel: {}", err))?;
     channel
         .request_pty(false, "xterm-256color", 220, 64, 0, 0, &[])
         .await
         .map_err(|err| format!("Failed to request SSH PTY: {}", err))?;
     channel
-        .request_shell(false)
+        .exec(true, command.clone())
         .await
-        .map_err(|err| format!("Failed to request SSH shell: {}", err))?;
-    let (mut shell_reader, shell_writer) = channel.split();
+        .map_err(|err| format!("Failed to execute remote command: {}", err))?;
 
-    let session_id_for_task = session_id.clone();
-    let app_handle_for_task = app_handle.clone();
-    let shell_reader_task = tokio::spawn(async move {
-        while let Some(message) = shell_reader.wait().await {
-            match message {
-                ChannelMsg::Data { data } => {
-                    emit_ssh_event(
-                        &app_handle_for_task,
-                        &session_id_for_task,
-                        "stdout",
-                        &String::from_utf8_lossy(data.as_ref()),
-                    );
-                }
-                ChannelMsg::ExtendedData { data, .. } => {
-                    emit_ssh_event(
-                        &app_handle_for_task,
-                        &session_id_for_task,
-                        "stderr",
-                        &String::from_utf8_lossy(data.as_ref()),
-                    );
-                }
-                ChannelMsg::ExitStatus { exit_status } => {
-                    emit_ssh_event(
-                        &app_handle_for_task,
-                        &session_id_for_task,
-                        "meta",
-                        &format!("Exit status: {}", exit_status),
-                    );
-                }
-                ChannelMsg::Eof => {
-                    emit_ssh_event(
-                        &app_handle_for_task,
-                        &session_id_for_task,
-                        "meta",
-                        "Remote shell sent EOF.",
-                    );
-                }
-                ChannelMsg::Close => {
-                    emit_ssh_event(
-                        &app_handle_for_task,
-                        &session_id_for_task,
-                        "meta",
-                        "Remote shell closed.",
-                    );
-                    break;
-                }
-                _ => {}
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    let mut exit_status: Option<u32> = None;
+    while let Some(message) = channel.wait().await {
+        match message {
+            ChannelMsg::Data { data } => {
+                let text = String::from_utf8_lossy(data.as_ref()).to_string();
+                stdout.push_str(&text);
+                emit_ssh_event(&app_handle, &session_id, "stdout", &text);
+            }
+            ChannelMsg::ExtendedData { data, .. } => {
+                let text = String::from_utf8_lossy(data.as_ref()).to_string();
+                stderr.push_str(&text);
+                emit_ssh_event(&app_handle, &session_id, "stderr", &text);
+            }
+            ChannelMsg::ExitStatus {
+                exit_status: remote_status,
+            } => {
+                exit_status = Some(remote_status);
+                emit_ssh_event(
+                    &app_handle,
+                    &session_id,
+                    "meta",
+                    &format!("Exit status: {}", remote_status),
+                );
+            }
+            ChannelMsg::Eof => {
+                emit_ssh_event(&app_handle, &session_id, "meta", "Remote command sent EOF.");
+            }
+            ChannelMsg::Close => {
+                emit_ssh_event(
+                    &app_handle,
+                    &session_id,
+                    "meta",
+                    "Remote command channel closed.",
+                );
             }
+            _ => {}
         }
-    });
+    }
+    let _ = channel.eof().await;
+    let _ = channel.close().await;
+    if let Err(err) = handle.disconnect(Disconnect::ByApplication, "", "en").await {
+        warn!(
+            "SSH one-shot disconnect returned error: target={}@{}:{} error={}",
+            username, host, port, err
+        );
+    }
+
+    Ok(SshExecOneShotResult {
+        session_id,
+        host,
+        port,
+        username,
+        command,
+        stdout,
+        stderr,
+        exit_status,
+    })
+}
+
+/// Disconnect and remove one SSH session.
+#[tauri::command]
+async fn ssh_disconnect(
+    params: SshDisconnectParams,
+    state: tauri::State<'_, SshSessionStore>,
+) -> Result<SshDisconnectResult, String> {
+    let session_id = normalize_ssh_session_id(&params.session_id)?;
+    let existing = {
+        let mut sessions = lock_ssh_sessions(&state)?;
+        sessions.remove(&session_id)
+    };
+
+    if let Some(session) = existing {
+        let _ = session.shell_writer.close().await;
+        session.shell_reader_task.abort();
+        if let Err(err) = session
+            .handle
+            .disconnect(Disconnect::ByApplication, "", "en")
+            .await
+        {
+            warn!(
+                "SSH disconnect returned error: session_id={} target={}@{}:{} error={}",
+                session_id, session.username, session.host, session.port, err
+            );
+        }
+
+        info!(
+            "SSH disconnected: session_id={} target={}@{}:{}",
+            session_id, session.username, session.host, session.port
+        );
+        return Ok(SshDisconnectResult {
+            session_id,
+            disconnected: true,
+        });
+    }
+
+    Ok(SshDisconnectResult {
+        session_id,
+        disconnected: false,
+    })
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_os::init())
+        .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(
+            tauri_plugin_log::Builder::new()
+                .level(tauri_plugin_log::log::LevelFilter::Info)
+                .build(),
+        )
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .plugin(tauri_plugin_opener::init())
+        .manage(SshSessionStore::default())
+        .manage(CceScheduleState::default())
+        .invoke_handler(tauri::generate_handler![
+            list_enterprise_projects,
+            get_quotas,
+            list_vpcs,
+            list_subnets,
+            list_images,
+            create_image_from_ecs,
+            import_image_from_obs,
+            import_image_from_file,
+            export_image_to_obs,
+            get_image_job,
+            delete_image,
+            share_images,
+            unshare_images,
+            respond_image_share,
+            list_flavors,
+            list_eips,
+            list_ecses,
+            list_evss,
+            create_evs,
+            attach_evs,
+            detach_evs,
+            expand_evs,
+            delete_evs,
+            list_evs_snapshots,
+            create_evs_snapshot,
+            delete_evs_snapshot,
+            rollback_evs_snapshot,
+            snapshot_ecs_volumes,
+            find_orphaned_resources,
+            cleanup_orphaned_resources,
+            estimate_ecs_cost,
+            estimate_cce_cluster_cost,
+            estimate_cce_node_pool_cost,
+            estimate_cce_nat_gateway_cost,
+            get_price_table_info,
+            import_price_table,
+            get_tag_policy,
+            set_tag_policy,
+            tag_resource,
+            untag_resource,
+            list_cce_clusters,
+            create_cce_cluster,
+            delete_cce_cluster,
+            list_cce_node_pools,
+            create_cce_node_pool,
+            delete_cce_node_pool,
+            scale_cce_node_pool,
+            update_cce_node_pool,
+            list_cce_nodes,
+            get_cce_node,
+            delete_cce_node,
+            reset_cce_nodes,
+            remove_cce_nodes,
+            get_cce_job,
+            get_cce_upgrade_info,
+            precheck_cce_cluster_upgrade,
+            start_cce_cluster_upgrade,
+            follow_cce_cluster_upgrade,
+            hibernate_cce_cluster,
+            awake_cce_cluster,
+            set_cce_hibernate_schedule,
+            list_cce_hibernate_schedules,
+            delete_cce_hibernate_schedule,
+            list_cce_addon_templates,
+            list_cce_addons,
+            install_cce_addon,
+            upgrade_cce_addon,
+            uninstall_cce_addon,
+            list_cce_nat_gateways,
+            create_cce_nat_gateway,
+            list_nat_bootstrap_runs,
+            resume_nat_bootstrap,
+            rollback_nat_bootstrap,
+            update_cce_nat_gateway,
+            delete_cce_nat_gateway,
+            list_cce_snat_rules,
+            create_cce_snat_rule,
+            update_cce_snat_rule,
+            delete_cce_snat_rule,
+            list_cce_dnat_rules,
+            create_cce_dnat_rule,
+            delete_cce_dnat_rule,
+            bind_cce_cluster_api_eip,
+            create_and_bind_cce_cluster_api_eip,
+            get_cce_cluster_kubeconfig,
+            list_obs_buckets,
+            create_obs_bucket,
+            delete_obs_bucket,
+            list_obs_objects,
+            get_obs_bucket_totals,
+            put_obs_object,
+            get_obs_object,
+            delete_obs_object,
+            create_ecs,
+            delete_ecs_with_eip,
+            delete_eip,
+            allocate_eip,
+            bind_eip,
+            unbind_eip,
+            update_eip,
+            resize_eip_bandwidth,
+            list_shared_bandwidths,
+            create_shared_bandwidth,
+            delete_shared_bandwidth,
+            add_eips_to_shared_bandwidth,
+            remove_eips_from_shared_bandwidth,
+            stop_ecs,
+            resize_ecs,
+            list_ecs_resize_flavors,
+            reinstall_ecs,
+            change_ecs_os,
+            get_ecs_job,
+            create_vpc,
+            delete_vpc,
+            create_subnet,
+            delete_subnet,
+            get_network_topology,
+            list_security_groups,
+            create_security_group,
+            delete_security_group,
+            list_security_group_rules,
+            add_security_group_rule,
+            delete_security_group_rule,
+            allow_current_ip_ingress,
+            ssh_connect,
+            ssh_exec,
+            ssh_resize,
+            ssh_send_control,
+            ssh_exec_one_shot,
+            ssh_disconnect
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
 
-    let connected_at = Utc::now().to_rfc3339();
-    {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions.insert(
-            session_id.clone(),
-            SshSessionEntry {
-                handle,
-                shell_writer,
-                shell_reader_task,
-                host: host.clone(),
-                port,
-                username: username.clone(),
-            },
-        );
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_cce_addon_values, build_cce_cluster_request, build_cce_node_pool_update,
+        build_cce_remove_nodes_request, build_cce_reset_nodes_request, build_dnat_rule_create_body,
+        build_eip_allocate_request, build_evs_snapshot_name, build_snat_rule_create_body,
+        cce_job_step_changes, cce_node_pool_quota_demands, cce_phase_is_pending,
+        collect_nat_rule_ids, ecs_action_mode, ensure_evs_detachable, extract_cce_job_id,
+        extract_cluster_kubeconfig, extract_eip_id_and_address, extract_job_id,
+        extract_nat_gateway_id, find_cce_addon_template_version, find_evs_attachment,
+        is_api_method_not_found_response, is_success_or_not_found, normalize_cce_nat_spec,
+        normalize_id_list, normalize_server_name, obs_multipart_part_size,
+        partition_bandwidth_members, partition_nat_teardown_eips, port_spec_contains,
+        resolve_bandwidth_update, resolve_cce_upgrade_target, resolve_ecs_login,
+        resolve_enterprise_project, resolve_subnet_gateway,
+        sanitize_cce_node_pool_data_volume_size, sanitize_cce_node_pool_initial_count,
+        sanitize_cce_node_pool_max_pods, sanitize_cce_node_pool_root_volume_size,
+        security_group_rule_allows_ingress, should_retry_nat_eip_delete, split_list_params,
+        validate_bandwidth_size, validate_cce_cluster_network, validate_ecs_resize_target,
+        validate_subnet_cidr, validate_vpc_cidr, CceAddonTemplate, CceAddonValueOverrides,
+        CceAddonValues, CceCreateClusterParams, CceCreateDnatRuleParams, CceCreateSnatRuleParams,
+        CceJob, CceNodePool, CceNodePoolChanges, CceRemoveNodesParams, CceResetNodesParams,
+        CceTaint, CceUpgradeInfo, CredentialsInput, EipAllocateParams, ListParamsInput,
+        NatRuleKind, RANDOM_NAME_PLACEHOLDER,
+    };
+    use crate::api::models::ecs::Flavor;
+    use crate::api::models::eip::EipBandwidth;
+    use crate::api::models::evs::EvsVolume;
+    use crate::api::models::vpc::{SecurityGroupRule, Subnet, Vpc};
+    use crate::quotas::QuotaResource;
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn normalize_server_name_keeps_custom_value() {
+        assert_eq!(normalize_server_name("my-ecs-prod"), "my-ecs-prod");
     }
 
-    info!(
-        "SSH connected: session_id={} target={}@{}:{}",
-        session_id, username, host, port
-    );
+    #[test]
+    fn normalize_server_name_generates_when_placeholder_or_blank() {
+        let from_placeholder = normalize_server_name(RANDOM_NAME_PLACEHOLDER);
+        let from_blank = normalize_server_name("");
 
-    Ok(SshConnectResult {
-        session_id,
-        host,
-        port,
-        username,
-        connected_at,
-    })
-}
+        assert!(from_placeholder.starts_with("ecs-"));
+        assert!(from_blank.starts_with("ecs-"));
+        assert_ne!(from_placeholder, from_blank);
+    }
 
-/// Execute one command over an existing SSH connection.
-#[tauri::command]
-async fn ssh_exec(
-    params: SshExecParams,
-    state: tauri::State<'_, SshSessionStore>,
-) -> Result<SshExecResult, String> {
-    let session_id = normalize_ssh_session_id(&params.session_id)?;
-    let command = params.command.trim().to_string();
-    if command.is_empty() {
-        return Err("SSH command is required.".to_string());
+    #[test]
+    fn extract_nat_gateway_id_reads_nested_payload() {
+        let raw = r#"{"nat_gateway":{"id":"nat-123","name":"cce-nat"}}"#;
+        assert_eq!(extract_nat_gateway_id(raw).as_deref(), Some("nat-123"));
     }
 
-    let entry = {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions
-            .remove(&session_id)
-            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
-    };
+    #[test]
+    fn success_or_not_found_status_helper_handles_expected_codes() {
+        assert!(is_success_or_not_found(reqwest::StatusCode::NO_CONTENT));
+        assert!(is_success_or_not_found(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_success_or_not_found(reqwest::StatusCode::CONFLICT));
+    }
 
-    info!(
-        "Running SSH command: session_id={} target={}@{}:{} command={}",
-        session_id, entry.username, entry.host, entry.port, command
-    );
+    #[test]
+    fn api_method_not_found_detection_handles_gateway_payload() {
+        let payload = serde_json::json!({
+            "error_code": "APIGW.0101",
+            "error_msg": "The API does not exist: method DELETE not found"
+        });
+        assert!(is_api_method_not_found_response(&payload));
+        assert!(!is_api_method_not_found_response(&serde_json::json!({
+            "error_code": "NAT.0007",
+            "error_msg": "SNAT rule not found"
+        })));
+    }
 
-    let payload = format!("{}\n", command);
-    let send_result = entry
-        .shell_writer
-        .data(Cursor::new(payload.into_bytes()))
-        .await
-        .map_err(|err| format!("Failed to send command to live SSH shell: {}", err));
+    #[test]
+    fn nat_eip_delete_retry_detection_matches_expected_conflict() {
+        assert!(should_retry_nat_eip_delete(
+            reqwest::StatusCode::CONFLICT,
+            &serde_json::json!({
+                "error_code": "EIP.7922",
+                "error_msg": "Publicip abc has associated instance."
+            })
+        ));
+        assert!(!should_retry_nat_eip_delete(
+            reqwest::StatusCode::NOT_FOUND,
+            &serde_json::json!({
+                "error_code": "EIP.0004"
+            })
+        ));
+    }
 
-    if let Err(err) = send_result {
-        entry.shell_reader_task.abort();
-        warn!(
-            "SSH command failed; dropping session_id={} target={}@{}:{} error={}",
-            session_id, entry.username, entry.host, entry.port, err
+    #[test]
+    fn extract_eip_id_and_address_reads_publicip_payload() {
+        let raw = r#"{"publicip":{"id":"eip-123","public_ip_address":"1.2.3.4"}}"#;
+        let (id, address) = extract_eip_id_and_address(raw);
+        assert_eq!(id.as_deref(), Some("eip-123"));
+        assert_eq!(address.as_deref(), Some("1.2.3.4"));
+    }
+
+    #[test]
+    fn extract_cluster_kubeconfig_reads_common_fields() {
+        let raw = r#"{"kubeconfig":"apiVersion: v1\nclusters: []"}"#;
+        assert_eq!(
+            extract_cluster_kubeconfig(raw).as_deref(),
+            Some("apiVersion: v1\nclusters: []")
         );
-        return Err(err);
     }
 
-    let mut sessions = lock_ssh_sessions(&state)?;
-    sessions.insert(session_id.clone(), entry);
+    #[test]
+    fn extract_cluster_kubeconfig_reads_nested_certs_fields() {
+        let raw = r#"{"certs":{"kube_config":"apiVersion: v1\nclusters: []"}}"#;
+        assert_eq!(
+            extract_cluster_kubeconfig(raw).as_deref(),
+            Some("apiVersion: v1\nclusters: []")
+        );
+    }
 
-    Ok(SshExecResult {
-        session_id,
-        command,
-        stdout: String::new(),
-        stderr: String::new(),
-        exit_status: None,
-    })
-}
+    #[test]
+    fn sanitize_cce_node_pool_ranges_follow_documented_limits() {
+        assert_eq!(sanitize_cce_node_pool_initial_count(None), 0);
+        assert_eq!(sanitize_cce_node_pool_initial_count(Some(0)), 0);
+        assert_eq!(sanitize_cce_node_pool_initial_count(Some(3)), 3);
 
-/// Resize the PTY for an existing SSH shell session.
-#[tauri::command]
-async fn ssh_resize(
-    params: SshResizeParams,
-    state: tauri::State<'_, SshSessionStore>,
-) -> Result<SshResizeResult, String> {
-    let session_id = normalize_ssh_session_id(&params.session_id)?;
-    let cols = params.cols.clamp(40, 400);
-    let rows = params.rows.clamp(10, 180);
-    let pixel_width = params.pixel_width.unwrap_or(0);
-    let pixel_height = params.pixel_height.unwrap_or(0);
+        assert_eq!(sanitize_cce_node_pool_root_volume_size(None), 40);
+        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(1)), 40);
+        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(40)), 40);
+        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(2048)), 1024);
 
-    let entry = {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions
-            .remove(&session_id)
-            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
-    };
+        assert_eq!(sanitize_cce_node_pool_data_volume_size(None), 100);
+        assert_eq!(sanitize_cce_node_pool_data_volume_size(Some(10)), 100);
+        assert_eq!(sanitize_cce_node_pool_data_volume_size(Some(100)), 100);
+        assert_eq!(
+            sanitize_cce_node_pool_data_volume_size(Some(50_000)),
+            32_768
+        );
 
-    let resize_result = entry
-        .shell_writer
-        .window_change(cols, rows, pixel_width, pixel_height)
-        .await
-        .map_err(|err| {
-            format!(
-                "Failed to resize SSH PTY for session {}: {}",
-                session_id, err
-            )
-        });
+        assert_eq!(sanitize_cce_node_pool_max_pods(None), None);
+        assert_eq!(sanitize_cce_node_pool_max_pods(Some(1)), Some(16));
+        assert_eq!(sanitize_cce_node_pool_max_pods(Some(110)), Some(110));
+        assert_eq!(sanitize_cce_node_pool_max_pods(Some(999)), Some(256));
+    }
 
-    if let Err(err) = resize_result {
-        entry.shell_reader_task.abort();
-        warn!(
-            "SSH PTY resize failed; dropping session_id={} target={}@{}:{} error={}",
-            session_id, entry.username, entry.host, entry.port, err
+    #[test]
+    fn extract_job_id_reads_top_level_field() {
+        assert_eq!(
+            extract_job_id(r#"{"job_id":"job-123"}"#).as_deref(),
+            Some("job-123")
         );
-        return Err(err);
+        assert!(extract_job_id(r#"{"error":{"message":"bad"}}"#).is_none());
+    }
+
+    #[test]
+    fn ecs_action_mode_defaults_to_stopping_the_server() {
+        assert_eq!(ecs_action_mode(None), Some("withStopServer"));
+        assert_eq!(ecs_action_mode(Some(false)), None);
     }
 
-    let mut sessions = lock_ssh_sessions(&state)?;
-    sessions.insert(session_id.clone(), entry);
+    #[test]
+    fn resolve_ecs_login_requires_exactly_one_method() {
+        assert!(resolve_ecs_login(None, Some("  ")).is_err());
+        assert!(resolve_ecs_login(Some("Passw0rd!"), Some("keypair")).is_err());
+        assert_eq!(
+            resolve_ecs_login(Some(" Passw0rd! "), None).expect("password login"),
+            (Some("Passw0rd!".to_string()), None)
+        );
+        assert_eq!(
+            resolve_ecs_login(Some(""), Some("keypair")).expect("key login"),
+            (None, Some("keypair".to_string()))
+        );
+    }
+
+    #[test]
+    fn validate_ecs_resize_target_rejects_incompatible_flavors() {
+        let current: Flavor = serde_json::from_value(json!({
+            "id": "c7.large.2",
+            "name": "c7.large.2",
+            "os_extra_specs": { "ecs:instance_architecture": "x86" }
+        }))
+        .expect("flavor");
+        let same_arch: Flavor =
+            serde_json::from_value(json!({ "id": "c7.xlarge.2", "name": "c7.xlarge.2" }))
+                .expect("flavor");
+        let arm: Flavor = serde_json::from_value(json!({
+            "id": "kc1.large.2",
+            "name": "kc1.large.2",
+            "os_extra_specs": { "ecs:instance_architecture": "arm64" }
+        }))
+        .expect("flavor");
+        let retired: Flavor = serde_json::from_value(json!({
+            "id": "s3.large.2",
+            "name": "s3.large.2",
+            "os_extra_specs": { "cond:operation:status": "abandon" }
+        }))
+        .expect("flavor");
+
+        let current_id = Some("c7.large.2");
+        assert!(validate_ecs_resize_target(current_id, Some(&current), &same_arch).is_ok());
+        assert!(validate_ecs_resize_target(None, None, &arm).is_ok());
+        assert!(validate_ecs_resize_target(current_id, Some(&current), &current).is_err());
+        assert!(validate_ecs_resize_target(current_id, Some(&current), &arm).is_err());
+        assert!(validate_ecs_resize_target(None, None, &retired).is_err());
+        // Without the flavor specs only the architecture check is skipped.
+        assert!(validate_ecs_resize_target(current_id, None, &arm).is_ok());
+        assert!(validate_ecs_resize_target(current_id, None, &current).is_err());
+    }
+
+    #[test]
+    fn port_spec_contains_matches_ports_and_ranges() {
+        assert!(port_spec_contains("22", 22));
+        assert!(port_spec_contains("80,3380-3390", 3389));
+        assert!(!port_spec_contains("80,443", 22));
+        assert!(!port_spec_contains("bogus", 22));
+    }
+
+    #[test]
+    fn security_group_rule_allows_ingress_respects_prefix_and_ports() {
+        let source_ip = "203.0.113.7".parse().expect("ip");
+        let open_ssh = SecurityGroupRule {
+            direction: Some("ingress".to_string()),
+            ethertype: Some("IPv4".to_string()),
+            protocol: Some("tcp".to_string()),
+            multiport: Some("22".to_string()),
+            remote_ip_prefix: Some("0.0.0.0/0".to_string()),
+            action: Some("allow".to_string()),
+            ..SecurityGroupRule::default()
+        };
+        assert!(security_group_rule_allows_ingress(&open_ssh, source_ip, 22));
+        assert!(!security_group_rule_allows_ingress(
+            &open_ssh, source_ip, 3389
+        ));
+
+        let other_network = SecurityGroupRule {
+            remote_ip_prefix: Some("198.51.100.0/24".to_string()),
+            ..open_ssh.clone()
+        };
+        assert!(!security_group_rule_allows_ingress(
+            &other_network,
+            source_ip,
+            22
+        ));
 
-    Ok(SshResizeResult {
-        session_id,
-        cols,
-        rows,
-    })
-}
+        let denied = SecurityGroupRule {
+            action: Some("deny".to_string()),
+            ..open_ssh.clone()
+        };
+        assert!(!security_group_rule_allows_ingress(&denied, source_ip, 22));
 
-/// Send interactive control bytes (Ctrl+C/Ctrl+D/Ctrl+U) to an SSH shell session.
-#[tauri::command]
-async fn ssh_send_control(
-    params: SshSendControlParams,
-    state: tauri::State<'_, SshSessionStore>,
-) -> Result<SshSendControlResult, String> {
-    let session_id = normalize_ssh_session_id(&params.session_id)?;
-    let control = params.control.trim().to_string();
-    let control_byte = control_char_from_input(&control)?;
+        let group_scoped = SecurityGroupRule {
+            remote_ip_prefix: None,
+            remote_group_id: Some("sg-peer".to_string()),
+            ..open_ssh.clone()
+        };
+        assert!(!security_group_rule_allows_ingress(
+            &group_scoped,
+            source_ip,
+            22
+        ));
 
-    let entry = {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions
-            .remove(&session_id)
-            .ok_or_else(|| format!("No SSH connection found for session {}.", session_id))?
-    };
+        let allow_all = SecurityGroupRule {
+            direction: Some("ingress".to_string()),
+            ..SecurityGroupRule::default()
+        };
+        assert!(security_group_rule_allows_ingress(
+            &allow_all, source_ip, 3389
+        ));
 
-    let payload = vec![control_byte];
-    let send_result = entry
-        .shell_writer
-        .data(Cursor::new(payload))
-        .await
-        .map_err(|err| {
-            format!(
-                "Failed to send {} to SSH session {}: {}",
-                control, session_id, err
-            )
-        });
+        let egress = SecurityGroupRule {
+            direction: Some("egress".to_string()),
+            ..SecurityGroupRule::default()
+        };
+        assert!(!security_group_rule_allows_ingress(&egress, source_ip, 22));
+    }
 
-    if let Err(err) = send_result {
-        entry.shell_reader_task.abort();
-        warn!(
-            "SSH control send failed; dropping session_id={} target={}@{}:{} error={}",
-            session_id, entry.username, entry.host, entry.port, err
+    #[test]
+    fn normalize_id_list_trims_and_dedupes() {
+        assert_eq!(
+            normalize_id_list(Some(vec![
+                " sg-1 ".to_string(),
+                String::new(),
+                "sg-2".to_string(),
+                "sg-1".to_string(),
+            ])),
+            vec!["sg-1".to_string(), "sg-2".to_string()]
         );
-        return Err(err);
+        assert!(normalize_id_list(None).is_empty());
     }
 
-    let mut sessions = lock_ssh_sessions(&state)?;
-    sessions.insert(session_id.clone(), entry);
+    #[test]
+    fn validate_vpc_cidr_requires_private_ranges_and_avoids_cce_defaults() {
+        assert_eq!(
+            validate_vpc_cidr("192.168.0.0/16", false).expect("vpc cidr"),
+            "192.168.0.0/16"
+        );
+        assert!(validate_vpc_cidr("8.8.0.0/16", false).is_err());
+        assert!(validate_vpc_cidr("192.168.0.0/25", false).is_err());
+        assert!(validate_vpc_cidr("192.168.0.1/16", false).is_err());
+        assert!(validate_vpc_cidr("172.16.0.0/12", false).is_err());
+        assert!(validate_vpc_cidr("10.0.0.0/8", false).is_err());
+        assert!(validate_vpc_cidr("172.16.0.0/12", true).is_ok());
+    }
 
-    Ok(SshSendControlResult {
-        session_id,
-        control,
-        sent: true,
-    })
-}
+    #[test]
+    fn validate_subnet_cidr_checks_containment_and_siblings() {
+        let siblings = vec![Subnet {
+            id: "subnet-1".to_string(),
+            name: "existing".to_string(),
+            cidr: "192.168.1.0/24".to_string(),
+            ..Subnet::default()
+        }];
 
-/// Execute one remote command by creating a short-lived SSH connection.
-#[tauri::command]
-async fn ssh_exec_one_shot(
-    params: SshExecOneShotParams,
-    app_handle: tauri::AppHandle,
-) -> Result<SshExecOneShotResult, String> {
-    let session_id = normalize_ssh_session_id(&params.session_id)?;
-    let host = params.host.trim().to_string();
-    if host.is_empty() {
-        return Err("SSH host is required.".to_string());
+        assert_eq!(
+            validate_subnet_cidr("192.168.2.0/24", Some("192.168.0.0/16"), &siblings, false)
+                .expect("subnet cidr"),
+            "192.168.2.0/24"
+        );
+        let outside = validate_subnet_cidr("10.0.0.0/24", Some("192.168.0.0/16"), &siblings, false)
+            .expect_err("outside vpc");
+        assert!(outside.contains("outside the VPC CIDR"));
+        let overlap =
+            validate_subnet_cidr("192.168.1.128/25", Some("192.168.0.0/16"), &siblings, false)
+                .expect_err("overlaps sibling");
+        assert!(overlap.contains("existing"));
+        assert!(validate_subnet_cidr("192.168.0.0/30", None, &[], false).is_err());
+        assert!(validate_subnet_cidr("10.247.1.0/24", Some("10.0.0.0/8"), &[], false).is_err());
+        assert!(validate_subnet_cidr("10.247.1.0/24", Some("10.0.0.0/8"), &[], true).is_ok());
     }
 
-    let command = params.command.trim().to_string();
-    if command.is_empty() {
-        return Err("SSH command is required.".to_string());
+    #[test]
+    fn resolve_subnet_gateway_defaults_to_first_host() {
+        assert_eq!(
+            resolve_subnet_gateway("192.168.1.0/24", None).expect("gateway"),
+            "192.168.1.1"
+        );
+        assert_eq!(
+            resolve_subnet_gateway("192.168.1.0/24", Some("192.168.1.254")).expect("gateway"),
+            "192.168.1.254"
+        );
+        assert!(resolve_subnet_gateway("192.168.1.0/24", Some("192.168.1.0")).is_err());
+        assert!(resolve_subnet_gateway("192.168.1.0/24", Some("192.168.1.255")).is_err());
+        assert!(resolve_subnet_gateway("192.168.1.0/24", Some("192.168.2.1")).is_err());
     }
 
-    let port = params.port.unwrap_or(22);
-    let username = params
-        .username
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .unwrap_or("root")
-        .to_string();
-    let password = params.password.trim().to_string();
-    if password.is_empty() {
-        return Err("SSH password is required.".to_string());
+    #[test]
+    fn build_eip_allocate_request_uses_dedicated_defaults() {
+        let params: EipAllocateParams =
+            serde_json::from_value(json!({ "region": "sa-brazil-1" })).expect("params");
+        let request = build_eip_allocate_request(&params).expect("request");
+        assert_eq!(request.publicip.ip_type, "5_bgp");
+        assert_eq!(request.bandwidth.share_type, "PER");
+        assert_eq!(request.bandwidth.size, Some(100));
+        assert_eq!(request.bandwidth.charge_mode.as_deref(), Some("traffic"));
+        assert!(request
+            .bandwidth
+            .name
+            .as_deref()
+            .is_some_and(|name| name.starts_with("hc-forge-eip-")));
     }
 
-    let config = Arc::new(client::Config {
-        inactivity_timeout: Some(Duration::from_secs(60)),
-        ..<_>::default()
-    });
-    let mut handle = client::connect(config, (host.as_str(), port), SshClientHandler)
-        .await
-        .map_err(|err| format!("SSH connection failed to {}:{}: {}", host, port, err))?;
-    let auth = handle
-        .authenticate_password(username.clone(), password)
-        .await
-        .map_err(|err| {
-            format!(
-                "SSH authentication failed for {}@{}:{}: {}",
-                username, host, port, err
-            )
-        })?;
-    if !auth.success() {
-        return Err(format!(
-            "SSH authentication rejected for {}@{}:{}.",
-            username, host, port
-        ));
+    #[test]
+    fn resolve_enterprise_project_skips_an_invalid_saved_default_when_listing() {
+        let credentials: CredentialsInput = serde_json::from_value(json!({
+            "accessKey": "ak",
+            "secretKey": "sk",
+            "enterpriseProjectId": "team-a"
+        }))
+        .expect("credentials");
+        assert_eq!(
+            resolve_enterprise_project(None, Some(&credentials), true),
+            Ok(None)
+        );
+        assert_eq!(
+            resolve_enterprise_project(Some("0"), Some(&credentials), true),
+            Ok(Some("0".to_string()))
+        );
+        assert!(resolve_enterprise_project(None, Some(&credentials), false).is_err());
     }
 
-    let mut channel = handle
-        .channel_open_session()
-        .await
-        .map_err(|err| format!("Failed to open SSH exec channel: {}", err))?;
-    channel
-        .request_pty(false, "xterm-256color", 220, 64, 0, 0, &[])
-        .await
-        .map_err(|err| format!("Failed to request SSH PTY: {}", err))?;
-    channel
-        .exec(true, command.clone())
-        .await
-        .map_err(|err| format!("Failed to execute remote command: {}", err))?;
+    #[test]
+    fn split_list_params_rejects_paging_with_tag_filters() {
+        let input: ListParamsInput = serde_json::from_value(json!({
+            "limit": 100,
+            "tags": [{ "key": "env", "value": "prod" }]
+        }))
+        .expect("params");
+        assert!(split_list_params(Some(input), None).is_err());
+
+        let input: ListParamsInput = serde_json::from_value(json!({
+            "limit": 100,
+            "enterpriseProjectId": "0"
+        }))
+        .expect("params");
+        let (params, tag_filters) = split_list_params(Some(input), None).expect("params");
+        assert_eq!(params.limit, Some(100));
+        assert!(tag_filters.is_empty());
+    }
 
-    let mut stdout = String::new();
-    let mut stderr = String::new();
-    let mut exit_status: Option<u32> = None;
-    while let Some(message) = channel.wait().await {
-        match message {
-            ChannelMsg::Data { data } => {
-                let text = String::from_utf8_lossy(data.as_ref()).to_string();
-                stdout.push_str(&text);
-                emit_ssh_event(&app_handle, &session_id, "stdout", &text);
-            }
-            ChannelMsg::ExtendedData { data, .. } => {
-                let text = String::from_utf8_lossy(data.as_ref()).to_string();
-                stderr.push_str(&text);
-                emit_ssh_event(&app_handle, &session_id, "stderr", &text);
-            }
-            ChannelMsg::ExitStatus {
-                exit_status: remote_status,
-            } => {
-                exit_status = Some(remote_status);
-                emit_ssh_event(
-                    &app_handle,
-                    &session_id,
-                    "meta",
-                    &format!("Exit status: {}", remote_status),
-                );
-            }
-            ChannelMsg::Eof => {
-                emit_ssh_event(&app_handle, &session_id, "meta", "Remote command sent EOF.");
-            }
-            ChannelMsg::Close => {
-                emit_ssh_event(
-                    &app_handle,
-                    &session_id,
-                    "meta",
-                    "Remote command channel closed.",
-                );
-            }
-            _ => {}
+    #[test]
+    fn build_eip_allocate_request_validates_enterprise_project() {
+        let scoped: EipAllocateParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "enterpriseProjectId": " 0 "
+        }))
+        .expect("params");
+        let request = build_eip_allocate_request(&scoped).expect("scoped request");
+        assert_eq!(request.enterprise_project_id.as_deref(), Some("0"));
+
+        let all: EipAllocateParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "enterpriseProjectId": "all_granted_eps"
+        }))
+        .expect("params");
+        assert!(build_eip_allocate_request(&all).is_err());
+    }
+
+    #[test]
+    fn build_eip_allocate_request_validates_share_type_and_size() {
+        let whole: EipAllocateParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "shareType": "whole",
+            "sharedBandwidthId": " bw-1 "
+        }))
+        .expect("params");
+        let request = build_eip_allocate_request(&whole).expect("request");
+        assert_eq!(request.bandwidth.id.as_deref(), Some("bw-1"));
+        assert_eq!(request.bandwidth.size, None);
+
+        for (params, valid) in [
+            (
+                json!({ "region": "sa-brazil-1", "shareType": "WHOLE" }),
+                false,
+            ),
+            (
+                json!({ "region": "sa-brazil-1", "chargeMode": "bandwidth", "bandwidthSize": 1000 }),
+                true,
+            ),
+            (
+                json!({ "region": "sa-brazil-1", "bandwidthSize": 1000 }),
+                false,
+            ),
+            (
+                json!({ "region": "sa-brazil-1", "chargeMode": "95peak" }),
+                false,
+            ),
+        ] {
+            let params: EipAllocateParams = serde_json::from_value(params).expect("params");
+            assert_eq!(
+                build_eip_allocate_request(&params).is_ok(),
+                valid,
+                "{params:?}"
+            );
         }
     }
-    let _ = channel.eof().await;
-    let _ = channel.close().await;
-    if let Err(err) = handle.disconnect(Disconnect::ByApplication, "", "en").await {
-        warn!(
-            "SSH one-shot disconnect returned error: target={}@{}:{} error={}",
-            username, host, port, err
+
+    #[test]
+    fn validate_bandwidth_size_depends_on_charge_mode() {
+        assert!(validate_bandwidth_size("traffic", 300).is_ok());
+        assert!(validate_bandwidth_size("traffic", 301).is_err());
+        assert!(validate_bandwidth_size("bandwidth", 2000).is_ok());
+        assert!(validate_bandwidth_size("bandwidth", 0).is_err());
+    }
+
+    #[test]
+    fn resolve_bandwidth_update_only_sends_changes() {
+        let current: EipBandwidth = serde_json::from_value(json!({
+            "id": "bw-1",
+            "size": 100,
+            "share_type": "PER",
+            "charge_mode": "traffic"
+        }))
+        .expect("bandwidth");
+        assert!(
+            resolve_bandwidth_update(&current, Some(100), Some("traffic"))
+                .expect("no-op")
+                .is_none()
         );
+
+        let update = resolve_bandwidth_update(&current, Some(500), Some("bandwidth"))
+            .expect("switch to bandwidth billing")
+            .expect("update body");
+        assert_eq!(update.size, Some(500));
+        assert_eq!(update.charge_mode.as_deref(), Some("bandwidth"));
+
+        let update = resolve_bandwidth_update(&current, Some(50), None)
+            .expect("resize")
+            .expect("update body");
+        assert_eq!(update.size, Some(50));
+        assert_eq!(update.charge_mode, None);
     }
 
-    Ok(SshExecOneShotResult {
-        session_id,
-        host,
-        port,
-        username,
-        command,
-        stdout,
-        stderr,
-        exit_status,
-    })
-}
+    #[test]
+    fn resolve_bandwidth_update_validates_limits_and_shared_mode() {
+        for (current, size, charge_mode) in [
+            (
+                json!({ "size": 100, "share_type": "PER", "charge_mode": "traffic" }),
+                Some(500),
+                None,
+            ),
+            (
+                json!({ "size": 500, "share_type": "PER", "charge_mode": "bandwidth" }),
+                None,
+                Some("traffic"),
+            ),
+            (
+                json!({ "size": 100, "share_type": "PER", "charge_mode": "traffic" }),
+                None,
+                Some("monthly"),
+            ),
+        ] {
+            let current: EipBandwidth = serde_json::from_value(current).expect("bandwidth");
+            assert!(resolve_bandwidth_update(&current, size, charge_mode).is_err());
+        }
 
-/// Disconnect and remove one SSH session.
-#[tauri::command]
-async fn ssh_disconnect(
-    params: SshDisconnectParams,
-    state: tauri::State<'_, SshSessionStore>,
-) -> Result<SshDisconnectResult, String> {
-    let session_id = normalize_ssh_session_id(&params.session_id)?;
-    let existing = {
-        let mut sessions = lock_ssh_sessions(&state)?;
-        sessions.remove(&session_id)
-    };
+        let shared: EipBandwidth = serde_json::from_value(json!({
+            "id": "bw-1",
+            "size": 50,
+            "share_type": "WHOLE",
+            "charge_mode": "bandwidth"
+        }))
+        .expect("bandwidth");
+        assert!(resolve_bandwidth_update(&shared, None, Some("traffic")).is_err());
+        assert!(resolve_bandwidth_update(&shared, Some(2), None).is_err());
+        let update = resolve_bandwidth_update(&shared, Some(1000), Some("bandwidth"))
+            .expect("resize shared")
+            .expect("update body");
+        assert_eq!(update.size, Some(1000));
+        assert_eq!(update.charge_mode, None);
+    }
 
-    if let Some(session) = existing {
-        let _ = session.shell_writer.close().await;
-        session.shell_reader_task.abort();
-        if let Err(err) = session
-            .handle
-            .disconnect(Disconnect::ByApplication, "", "en")
-            .await
-        {
-            warn!(
-                "SSH disconnect returned error: session_id={} target={}@{}:{} error={}",
-                session_id, session.username, session.host, session.port, err
+    #[test]
+    fn partition_bandwidth_members_keeps_input_order() {
+        let bandwidth: EipBandwidth = serde_json::from_value(json!({
+            "id": "bw-1",
+            "share_type": "WHOLE",
+            "publicip_info": [{ "publicip_id": "eip-a" }, { "publicip_id": "eip-c" }]
+        }))
+        .expect("bandwidth");
+        let ids = ["eip-c", "eip-b", "eip-a"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        let (members, others) = partition_bandwidth_members(&bandwidth, &ids);
+        assert_eq!(members, vec!["eip-c".to_string(), "eip-a".to_string()]);
+        assert_eq!(others, vec!["eip-b".to_string()]);
+    }
+
+    #[test]
+    fn find_evs_attachment_requires_server_when_shared() {
+        let single: EvsVolume = serde_json::from_value(json!({
+            "id": "vol-1",
+            "attachments": [{ "server_id": "srv-1", "device": "/dev/vdb" }]
+        }))
+        .expect("volume");
+        assert_eq!(
+            find_evs_attachment(&single, None)
+                .expect("single attachment")
+                .and_then(|attachment| attachment.server_id.as_deref()),
+            Some("srv-1")
+        );
+        assert!(find_evs_attachment(&single, Some("srv-2"))
+            .expect("lookup")
+            .is_none());
+
+        let shared: EvsVolume = serde_json::from_value(json!({
+            "id": "vol-1",
+            "multiattach": true,
+            "attachments": [
+                { "server_id": "srv-1", "device": "/dev/vdb" },
+                { "server_id": "srv-2", "device": "/dev/vdc" }
+            ]
+        }))
+        .expect("volume");
+        assert!(find_evs_attachment(&shared, None).is_err());
+        assert_eq!(
+            find_evs_attachment(&shared, Some("srv-2"))
+                .expect("lookup")
+                .and_then(|attachment| attachment.device.as_deref()),
+            Some("/dev/vdc")
+        );
+    }
+
+    #[test]
+    fn ensure_evs_detachable_rejects_system_disks() {
+        for (volume, detachable) in [
+            (
+                json!({ "bootable": "true", "attachments": [{ "device": "/dev/vda" }] }),
+                false,
+            ),
+            (json!({ "bootable": "true", "attachments": [{}] }), false),
+            (
+                json!({ "bootable": "false", "attachments": [{ "device": "/dev/vdb" }] }),
+                true,
+            ),
+            (json!({ "bootable": "false", "attachments": [{}] }), true),
+        ] {
+            let volume: EvsVolume = serde_json::from_value(volume).expect("volume");
+            assert_eq!(
+                ensure_evs_detachable(&volume, &volume.attachments[0]).is_ok(),
+                detachable,
+                "{volume:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn build_evs_snapshot_name_prefers_device_and_caps_length() {
+        assert_eq!(
+            build_evs_snapshot_name("pre-task", "0f1e2d3c-aaaa", Some("/dev/vdb"), "20260101"),
+            "pre-task-vdb-20260101"
+        );
+        assert_eq!(
+            build_evs_snapshot_name("pre-task", "0f1e2d3c-aaaa", None, "20260101"),
+            "pre-task-0f1e2d3c-20260101"
+        );
+        let long = build_evs_snapshot_name(&"x".repeat(80), "vol", Some("/dev/vda"), "1");
+        assert_eq!(long.chars().count(), 64);
+    }
+
+    #[test]
+    fn obs_multipart_part_size_stays_within_part_limit() {
+        assert_eq!(obs_multipart_part_size(1), 16 * 1024 * 1024);
+        let huge = 400 * 1024 * 1024 * 1024_u64;
+        let part_size = obs_multipart_part_size(huge);
+        assert!(part_size > 16 * 1024 * 1024);
+        assert!(huge.div_ceil(part_size) <= 10_000);
+    }
+
+    #[test]
+    fn build_dnat_rule_create_body_validates_ports_and_target() {
+        let params: CceCreateDnatRuleParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "natGatewayId": "nat-1",
+            "protocol": "TCP",
+            "internalPort": 22,
+            "externalPort": 2222,
+            "privateIp": " 192.168.0.10 ",
+            "eipId": "eip-1"
+        }))
+        .expect("params");
+        let body = build_dnat_rule_create_body(&params).expect("tcp rule");
+        assert_eq!(body.protocol, "tcp");
+        assert_eq!(body.private_ip.as_deref(), Some("192.168.0.10"));
+
+        for (protocol, internal_port, external_port, port_id, valid) in [
+            ("tcp", Some(22), None, None, false),
+            ("any", None, None, None, true),
+            ("any", Some(22), None, None, false),
+            ("udp", Some(53), Some(53), Some("port-1"), false),
+        ] {
+            let params: CceCreateDnatRuleParams = serde_json::from_value(json!({
+                "region": "sa-brazil-1",
+                "natGatewayId": "nat-1",
+                "protocol": protocol,
+                "internalPort": internal_port,
+                "externalPort": external_port,
+                "portId": port_id,
+                "privateIp": "192.168.0.10",
+                "eipId": "eip-1"
+            }))
+            .expect("params");
+            assert_eq!(
+                build_dnat_rule_create_body(&params).is_ok(),
+                valid,
+                "{params:?}"
             );
         }
+    }
 
-        info!(
-            "SSH disconnected: session_id={} target={}@{}:{}",
-            session_id, session.username, session.host, session.port
+    #[test]
+    fn collect_nat_rule_ids_dedupes_eips_and_reports_missing_ids() {
+        let mut eip_ids = vec!["eip-1".to_string()];
+        let (rule_ids, errors) = collect_nat_rule_ids(
+            vec![
+                (
+                    Some(" dnat-1 ".to_string()),
+                    Some("eip-1".to_string()),
+                    json!({}),
+                ),
+                (None, Some("eip-2".to_string()), json!({})),
+            ],
+            NatRuleKind::Dnat,
+            &mut eip_ids,
+        );
+        assert_eq!(rule_ids, vec!["dnat-1".to_string()]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(eip_ids, vec!["eip-1".to_string(), "eip-2".to_string()]);
+
+        let (_, errors) = collect_nat_rule_ids(
+            vec![(
+                Some("snat-1".to_string()),
+                Some("eip-2, eip-3,".to_string()),
+                json!({}),
+            )],
+            NatRuleKind::Snat,
+            &mut eip_ids,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(
+            eip_ids,
+            vec![
+                "eip-1".to_string(),
+                "eip-2".to_string(),
+                "eip-3".to_string()
+            ]
         );
-        return Ok(SshDisconnectResult {
-            session_id,
-            disconnected: true,
-        });
     }
 
-    Ok(SshDisconnectResult {
-        session_id,
-        disconnected: false,
-    })
-}
+    #[test]
+    fn partition_nat_teardown_eips_releases_only_owned_eips() {
+        let eip_ids = vec![
+            "eip-reused".to_string(),
+            "eip-bootstrap".to_string(),
+            "eip-dnat".to_string(),
+        ];
+        let dnat_eip_ids = vec!["eip-dnat".to_string()];
+
+        let (release, keep) = partition_nat_teardown_eips(
+            eip_ids.clone(),
+            &dnat_eip_ids,
+            Some("eip-bootstrap"),
+            false,
+        );
+        assert_eq!(release, vec!["eip-bootstrap".to_string()]);
+        assert_eq!(keep, vec!["eip-reused".to_string(), "eip-dnat".to_string()]);
 
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_os::init())
-        .plugin(tauri_plugin_fs::init())
-        .plugin(tauri_plugin_notification::init())
-        .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_clipboard_manager::init())
-        .plugin(
-            tauri_plugin_log::Builder::new()
-                .level(tauri_plugin_log::log::LevelFilter::Info)
-                .build(),
-        )
-        .plugin(tauri_plugin_store::Builder::new().build())
-        .plugin(tauri_plugin_opener::init())
-        .manage(SshSessionStore::default())
-        .invoke_handler(tauri::generate_handler![
-            list_vpcs,
-            list_subnets,
-            list_images,
-            list_flavors,
-            list_eips,
-            list_ecses,
-            list_evss,
-            list_cce_clusters,
-            create_cce_cluster,
-            delete_cce_cluster,
-            list_cce_node_pools,
-            create_cce_node_pool,
-            delete_cce_node_pool,
-            get_cce_job,
-            list_cce_nat_gateways,
-            create_cce_nat_gateway,
-            delete_cce_nat_gateway,
-            bind_cce_cluster_api_eip,
-            create_and_bind_cce_cluster_api_eip,
-            get_cce_cluster_kubeconfig,
-            list_obs_buckets,
-            create_obs_bucket,
-            delete_obs_bucket,
-            list_obs_objects,
-            get_obs_bucket_totals,
-            put_obs_object,
-            get_obs_object,
-            delete_obs_object,
-            create_ecs,
-            delete_ecs_with_eip,
-            delete_eip,
-            stop_ecs,
-            ssh_connect,
-            ssh_exec,
-            ssh_resize,
-            ssh_send_control,
-            ssh_exec_one_shot,
-            ssh_disconnect
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
-}
+        let (release, keep) =
+            partition_nat_teardown_eips(eip_ids.clone(), &dnat_eip_ids, None, true);
+        assert_eq!(release, vec!["eip-dnat".to_string()]);
+        assert_eq!(
+            keep,
+            vec!["eip-reused".to_string(), "eip-bootstrap".to_string()]
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::{
-        extract_cluster_kubeconfig, extract_eip_id_and_address, extract_nat_gateway_id,
-        is_api_method_not_found_response, is_success_or_not_found, normalize_server_name,
-        sanitize_cce_node_pool_data_volume_size, sanitize_cce_node_pool_initial_count,
-        sanitize_cce_node_pool_max_pods, sanitize_cce_node_pool_root_volume_size,
-        should_retry_nat_eip_delete, RANDOM_NAME_PLACEHOLDER,
-    };
+    #[test]
+    fn normalize_cce_nat_spec_accepts_specs_one_to_four() {
+        assert_eq!(normalize_cce_nat_spec(None), Ok("1"));
+        assert_eq!(normalize_cce_nat_spec(Some(" 4 ")), Ok("4"));
+        assert!(normalize_cce_nat_spec(Some("5")).is_err());
+    }
 
     #[test]
-    fn normalize_server_name_keeps_custom_value() {
-        assert_eq!(normalize_server_name("my-ecs-prod"), "my-ecs-prod");
+    fn build_snat_rule_create_body_joins_eips_and_sets_cidr_source() {
+        let params = CceCreateSnatRuleParams {
+            region: "sa-brazil-1".to_string(),
+            nat_gateway_id: "nat-1".to_string(),
+            subnet_id: None,
+            cidr: Some("10.0.1.0/24".to_string()),
+            eip_ids: vec![
+                "eip-1".to_string(),
+                " eip-2 ".to_string(),
+                "eip-1".to_string(),
+            ],
+            description: None,
+        };
+        let body = build_snat_rule_create_body(&params).expect("cidr rule");
+        assert_eq!(body.floating_ip_id, "eip-1,eip-2");
+        assert_eq!(body.source_type, Some(0));
+        assert!(body.network_id.is_none());
+
+        let both = CceCreateSnatRuleParams {
+            subnet_id: Some("subnet-1".to_string()),
+            ..params
+        };
+        assert!(build_snat_rule_create_body(&both).is_err());
     }
 
     #[test]
-    fn normalize_server_name_generates_when_placeholder_or_blank() {
-        let from_placeholder = normalize_server_name(RANDOM_NAME_PLACEHOLDER);
-        let from_blank = normalize_server_name("");
+    fn build_cce_reset_nodes_request_dedupes_ids_and_requires_login() {
+        let params = CceResetNodesParams {
+            region: "sa-brazil-1".to_string(),
+            cluster_id: "cluster-1".to_string(),
+            node_ids: vec![
+                " node-1 ".to_string(),
+                "node-2".to_string(),
+                "node-1".to_string(),
+            ],
+            os: " EulerOS 2.9 ".to_string(),
+            ssh_key: "my-key".to_string(),
+        };
+        let body = build_cce_reset_nodes_request(&params).expect("reset request");
+        assert_eq!(body.node_list.len(), 2);
+        assert_eq!(body.node_list[0].node_id, "node-1");
+        assert_eq!(body.node_list[1].spec.os, "EulerOS 2.9");
+        assert_eq!(body.node_list[1].spec.login.ssh_key, "my-key");
+
+        let missing_key = CceResetNodesParams {
+            ssh_key: "  ".to_string(),
+            ..params
+        };
+        assert!(build_cce_reset_nodes_request(&missing_key).is_err());
+    }
 
-        assert!(from_placeholder.starts_with("ecs-"));
-        assert!(from_blank.starts_with("ecs-"));
-        assert_ne!(from_placeholder, from_blank);
+    #[test]
+    fn build_cce_remove_nodes_request_requires_node_ids() {
+        let params = CceRemoveNodesParams {
+            region: "sa-brazil-1".to_string(),
+            cluster_id: "cluster-1".to_string(),
+            node_ids: vec!["node-1".to_string()],
+            ssh_key: " my-key ".to_string(),
+        };
+        let body = build_cce_remove_nodes_request(&params).expect("remove request");
+        assert_eq!(body.kind, "RemoveNodesTask");
+        assert_eq!(body.spec.nodes.len(), 1);
+        assert_eq!(body.spec.nodes[0].uid, "node-1");
+        assert_eq!(body.spec.login.ssh_key, "my-key");
+
+        let empty = CceRemoveNodesParams {
+            node_ids: vec![" ".to_string()],
+            ..params
+        };
+        assert!(build_cce_remove_nodes_request(&empty).is_err());
     }
 
     #[test]
-    fn extract_nat_gateway_id_reads_nested_payload() {
-        let raw = r#"{"nat_gateway":{"id":"nat-123","name":"cce-nat"}}"#;
-        assert_eq!(extract_nat_gateway_id(raw).as_deref(), Some("nat-123"));
+    fn build_cce_node_pool_update_preserves_unchanged_fields() {
+        let pool: CceNodePool = serde_json::from_value(json!({
+            "kind": "NodePool",
+            "metadata": { "name": "pool-a", "uid": "pool-uid" },
+            "spec": {
+                "initialNodeCount": 2,
+                "autoscaling": {
+                    "enable": true,
+                    "minNodeCount": 1,
+                    "maxNodeCount": 5,
+                    "scaleDownCooldownTime": 10,
+                    "priority": 1
+                },
+                "nodeTemplate": {
+                    "flavor": "c6.large.2",
+                    "k8sTags": { "team": "core" },
+                    "taints": [],
+                    "userTags": [{ "key": "env", "value": "dev" }]
+                }
+            }
+        }))
+        .expect("node pool");
+        let changes = CceNodePoolChanges {
+            node_count: Some(4),
+            priority: Some(3),
+            ..CceNodePoolChanges::default()
+        };
+        let (body, changed) = build_cce_node_pool_update(&pool, &changes)
+            .expect("valid update")
+            .expect("changes detected");
+        assert_eq!(changed, vec!["initialNodeCount", "priority"]);
+        assert_eq!(body["metadata"]["name"], "pool-a");
+        assert_eq!(body["spec"]["initialNodeCount"], 4);
+        assert_eq!(body["spec"]["autoscaling"]["priority"], 3);
+        assert_eq!(body["spec"]["autoscaling"]["maxNodeCount"], 5);
+        assert_eq!(body["spec"]["autoscaling"]["scaleDownCooldownTime"], 10);
+        assert_eq!(body["spec"]["nodeTemplate"]["k8sTags"]["team"], "core");
+        assert_eq!(body["spec"]["nodeTemplate"]["userTags"][0]["key"], "env");
+        assert!(body["spec"]["nodeTemplate"].get("flavor").is_none());
+
+        let unchanged = CceNodePoolChanges {
+            node_count: Some(2),
+            labels: Some(BTreeMap::from([("team".to_string(), " core ".to_string())])),
+            ..CceNodePoolChanges::default()
+        };
+        assert!(build_cce_node_pool_update(&pool, &unchanged)
+            .expect("valid update")
+            .is_none());
     }
 
     #[test]
-    fn success_or_not_found_status_helper_handles_expected_codes() {
-        assert!(is_success_or_not_found(reqwest::StatusCode::NO_CONTENT));
-        assert!(is_success_or_not_found(reqwest::StatusCode::NOT_FOUND));
-        assert!(!is_success_or_not_found(reqwest::StatusCode::CONFLICT));
+    fn build_cce_node_pool_update_validates_autoscaling_and_taints() {
+        let pool: CceNodePool = serde_json::from_value(json!({
+            "metadata": { "name": "pool-a" },
+            "spec": {
+                "initialNodeCount": 2,
+                "autoscaling": { "enable": true, "minNodeCount": 1, "maxNodeCount": 5 },
+                "nodeTemplate": { "taints": [] }
+            }
+        }))
+        .expect("node pool");
+        let out_of_range = CceNodePoolChanges {
+            node_count: Some(9),
+            ..CceNodePoolChanges::default()
+        };
+        assert!(build_cce_node_pool_update(&pool, &out_of_range).is_err());
+
+        let inverted = CceNodePoolChanges {
+            min_node_count: Some(6),
+            ..CceNodePoolChanges::default()
+        };
+        assert!(build_cce_node_pool_update(&pool, &inverted).is_err());
+
+        let disabled = CceNodePoolChanges {
+            autoscaling_enabled: Some(false),
+            node_count: Some(9),
+            ..CceNodePoolChanges::default()
+        };
+        assert!(build_cce_node_pool_update(&pool, &disabled)
+            .expect("autoscaling disabled")
+            .is_some());
+
+        let bad_taint = CceNodePoolChanges {
+            taints: Some(vec![CceTaint {
+                key: "dedicated".to_string(),
+                value: None,
+                effect: "Sometimes".to_string(),
+            }]),
+            ..CceNodePoolChanges::default()
+        };
+        assert!(build_cce_node_pool_update(&pool, &bad_taint).is_err());
+
+        let taint = CceNodePoolChanges {
+            taints: Some(vec![CceTaint {
+                key: " dedicated ".to_string(),
+                value: Some("gpu".to_string()),
+                effect: "NoSchedule".to_string(),
+            }]),
+            ..CceNodePoolChanges::default()
+        };
+        let (body, changed) = build_cce_node_pool_update(&pool, &taint)
+            .expect("valid taint")
+            .expect("taint change");
+        assert_eq!(changed, vec!["taints"]);
+        assert_eq!(
+            body["spec"]["nodeTemplate"]["taints"][0],
+            json!({ "key": "dedicated", "value": "gpu", "effect": "NoSchedule" })
+        );
     }
 
     #[test]
-    fn api_method_not_found_detection_handles_gateway_payload() {
-        let payload = serde_json::json!({
-            "error_code": "APIGW.0101",
-            "error_msg": "The API does not exist: method DELETE not found"
-        });
-        assert!(is_api_method_not_found_response(&payload));
-        assert!(!is_api_method_not_found_response(&serde_json::json!({
-            "error_code": "NAT.0007",
-            "error_msg": "SNAT rule not found"
-        })));
+    fn cce_node_pool_quota_demands_read_the_node_template() {
+        let pool: CceNodePool = serde_json::from_value(json!({
+            "metadata": { "name": "pool-a" },
+            "spec": {
+                "initialNodeCount": 2,
+                "nodeTemplate": {
+                    "flavor": "c7.xlarge.2",
+                    "rootVolume": { "volumetype": "GPSSD", "size": 50 },
+                    "dataVolumes": [{ "volumetype": "GPSSD", "size": 100 }]
+                }
+            }
+        }))
+        .expect("node pool");
+        let demands = cce_node_pool_quota_demands(&pool, 3);
+        assert!(demands.contains(&(QuotaResource::EcsInstances, 3)));
+        assert!(demands.contains(&(QuotaResource::EcsCores, 12)));
+        assert!(demands.contains(&(QuotaResource::EvsVolumes, 6)));
+        assert!(demands.contains(&(QuotaResource::EvsGigabytes, 450)));
     }
 
     #[test]
-    fn nat_eip_delete_retry_detection_matches_expected_conflict() {
-        assert!(should_retry_nat_eip_delete(
-            reqwest::StatusCode::CONFLICT,
-            &serde_json::json!({
-                "error_code": "EIP.7922",
-                "error_msg": "Publicip abc has associated instance."
-            })
-        ));
-        assert!(!should_retry_nat_eip_delete(
-            reqwest::StatusCode::NOT_FOUND,
-            &serde_json::json!({
-                "error_code": "EIP.0004"
-            })
-        ));
+    fn resolve_cce_upgrade_target_requires_listed_version() {
+        let info: CceUpgradeInfo = serde_json::from_value(json!({
+            "spec": { "versionInfo": { "release": "v1.27", "targetVersions": ["v1.28.5-r0"] } }
+        }))
+        .expect("upgrade info");
+        assert_eq!(
+            resolve_cce_upgrade_target(&info, " v1.28.5-r0 "),
+            Ok("v1.28.5-r0".to_string())
+        );
+        assert!(resolve_cce_upgrade_target(&info, "v1.29.1-r0")
+            .unwrap_err()
+            .contains("v1.28.5-r0"));
+        assert!(resolve_cce_upgrade_target(&info, " ").is_err());
+        assert!(resolve_cce_upgrade_target(&CceUpgradeInfo::default(), "v1.28.5-r0").is_err());
     }
 
     #[test]
-    fn extract_eip_id_and_address_reads_publicip_payload() {
-        let raw = r#"{"publicip":{"id":"eip-123","public_ip_address":"1.2.3.4"}}"#;
-        let (id, address) = extract_eip_id_and_address(raw);
-        assert_eq!(id.as_deref(), Some("eip-123"));
-        assert_eq!(address.as_deref(), Some("1.2.3.4"));
+    fn cce_upgrade_job_helpers_track_phases() {
+        assert!(cce_phase_is_pending(None));
+        assert!(cce_phase_is_pending(Some("Running")));
+        assert!(cce_phase_is_pending(Some("Init")));
+        assert!(!cce_phase_is_pending(Some("Success")));
+        assert!(!cce_phase_is_pending(Some("Failed")));
+
+        assert_eq!(
+            extract_cce_job_id(r#"{"metadata":{"uid":"task-1"},"status":{"jobID":"job-1"}}"#)
+                .as_deref(),
+            Some("job-1")
+        );
+        assert!(extract_cce_job_id(r#"{"metadata":{"uid":"task-1"}}"#).is_none());
+        assert_eq!(
+            extract_cce_job_id(r#"{"jobID":"job-2"}"#).as_deref(),
+            Some("job-2")
+        );
+        assert!(extract_cce_job_id("").is_none());
+        assert!(extract_cce_job_id("not json").is_none());
+
+        let job = |master: &str, nodes: &str| -> CceJob {
+            serde_json::from_value(json!({
+                "spec": { "subJobs": [
+                    { "metadata": { "uid": "sub-1" }, "spec": { "type": "UpgradeMaster" }, "status": { "phase": master } },
+                    { "metadata": { "uid": "sub-2" }, "spec": { "type": "UpgradeNodes" }, "status": { "phase": nodes } }
+                ] },
+                "status": { "phase": "Running" }
+            }))
+            .expect("cce job")
+        };
+        let mut seen = HashMap::new();
+        assert_eq!(
+            cce_job_step_changes(&mut seen, &job("Running", "Init")),
+            vec![
+                ("UpgradeMaster".to_string(), "Running".to_string()),
+                ("UpgradeNodes".to_string(), "Init".to_string()),
+            ]
+        );
+        assert!(cce_job_step_changes(&mut seen, &job("Running", "Init")).is_empty());
+        assert_eq!(
+            cce_job_step_changes(&mut seen, &job("Success", "Init")),
+            vec![("UpgradeMaster".to_string(), "Success".to_string())]
+        );
     }
 
     #[test]
-    fn extract_cluster_kubeconfig_reads_common_fields() {
-        let raw = r#"{"kubeconfig":"apiVersion: v1\nclusters: []"}"#;
+    fn find_cce_addon_template_version_lists_available_versions() {
+        let templates: Vec<CceAddonTemplate> = serde_json::from_value(json!([{
+            "metadata": { "name": "autoscaler" },
+            "spec": { "versions": [{ "version": "1.29.17" }] }
+        }]))
+        .expect("addon templates");
+        assert!(find_cce_addon_template_version(&templates, "autoscaler", "1.29.17").is_ok());
         assert_eq!(
-            extract_cluster_kubeconfig(raw).as_deref(),
-            Some("apiVersion: v1\nclusters: []")
+            find_cce_addon_template_version(&templates, "autoscaler", "1.0.0").err(),
+            Some("Add-on autoscaler has no version 1.0.0. Available: 1.29.17.".to_string())
         );
+        assert!(find_cce_addon_template_version(&templates, "coredns", "1.29.17").is_err());
     }
 
     #[test]
-    fn extract_cluster_kubeconfig_reads_nested_certs_fields() {
-        let raw = r#"{"certs":{"kube_config":"apiVersion: v1\nclusters: []"}}"#;
+    fn build_cce_addon_values_requires_null_defaults_and_keeps_types() {
+        let template: CceAddonTemplate = serde_json::from_value(json!({
+            "metadata": {"name": "autoscaler"},
+            "spec": {
+                "versions": [{
+                    "version": "1.29.17",
+                    "input": {
+                        "basic": {"swr_addr": "swr.example", "image_version": "1.29.17"},
+                        "parameters": {
+                            "custom": {
+                                "cluster_id": "",
+                                "tenant_id": "",
+                                "coresTotal": 32000,
+                                "scaleDownEnabled": false,
+                                "nodeGroup": null
+                            },
+                            "flavor1": {"name": 1, "replicas": 1},
+                            "flavor2": {"name": 2, "replicas": 2}
+                        }
+                    }
+                }]
+            }
+        }))
+        .expect("addon template");
+        let version = template.version("1.29.17").expect("version");
+
+        let err = build_cce_addon_values(
+            "autoscaler",
+            version,
+            None,
+            &CceAddonValueOverrides::default(),
+            "cluster-1",
+            "project-1",
+        )
+        .expect_err("nodeGroup is required");
         assert_eq!(
-            extract_cluster_kubeconfig(raw).as_deref(),
-            Some("apiVersion: v1\nclusters: []")
+            err,
+            "Add-on autoscaler 1.29.17 needs values for: custom.nodeGroup."
+        );
+
+        let wrong_type = CceAddonValueOverrides {
+            custom: json!({"coresTotal": "lots", "nodeGroup": "pool-1"})
+                .as_object()
+                .cloned(),
+            ..CceAddonValueOverrides::default()
+        };
+        assert_eq!(
+            build_cce_addon_values(
+                "autoscaler",
+                version,
+                None,
+                &wrong_type,
+                "cluster-1",
+                "project-1"
+            )
+            .err(),
+            Some("Add-on value custom.coresTotal must be a number.".to_string())
         );
+
+        let overrides = CceAddonValueOverrides {
+            custom: json!({"nodeGroup": "pool-1", "scaleDownEnabled": true})
+                .as_object()
+                .cloned(),
+            flavor: Some("flavor2".to_string()),
+            ..CceAddonValueOverrides::default()
+        };
+        let values = build_cce_addon_values(
+            "autoscaler",
+            version,
+            None,
+            &overrides,
+            "cluster-1",
+            "project-1",
+        )
+        .expect("values");
+        assert_eq!(values.basic["swr_addr"], "swr.example");
+        assert_eq!(values.custom["cluster_id"], "cluster-1");
+        assert_eq!(values.custom["tenant_id"], "project-1");
+        assert_eq!(values.custom["nodeGroup"], "pool-1");
+        assert_eq!(values.custom["scaleDownEnabled"], true);
+        assert_eq!(values.flavor, Some(json!({"name": 2, "replicas": 2})));
+
+        let unknown_flavor = CceAddonValueOverrides {
+            flavor: Some("flavor9".to_string()),
+            ..overrides
+        };
+        assert!(build_cce_addon_values(
+            "autoscaler",
+            version,
+            None,
+            &unknown_flavor,
+            "cluster-1",
+            "project-1"
+        )
+        .is_err());
     }
 
     #[test]
-    fn sanitize_cce_node_pool_ranges_follow_documented_limits() {
-        assert_eq!(sanitize_cce_node_pool_initial_count(None), 0);
-        assert_eq!(sanitize_cce_node_pool_initial_count(Some(0)), 0);
-        assert_eq!(sanitize_cce_node_pool_initial_count(Some(3)), 3);
+    fn build_cce_addon_values_keeps_running_settings_on_upgrade() {
+        let template: CceAddonTemplate = serde_json::from_value(json!({
+            "metadata": { "name": "autoscaler" },
+            "spec": {
+                "versions": [{
+                    "version": "1.29.17",
+                    "input": {
+                        "basic": { "swr_addr": "swr.example", "image_version": "1.29.17" },
+                        "parameters": {
+                            "custom": { "coresTotal": 32000, "scaleDownEnabled": false, "nodeGroup": null },
+                            "flavor2": { "name": 2, "replicas": 2 }
+                        }
+                    }
+                }]
+            }
+        }))
+        .expect("addon template");
+        let version = template.version("1.29.17").expect("version");
+        let current = CceAddonValues {
+            basic: json!({"swr_addr": "swr.example", "image_version": "1.28.4"})
+                .as_object()
+                .cloned()
+                .expect("basic"),
+            custom: json!({"cluster_id": "cluster-1", "coresTotal": 64, "nodeGroup": "pool-1"})
+                .as_object()
+                .cloned()
+                .expect("custom"),
+            flavor: Some(json!({"name": 2, "replicas": 2})),
+            ..CceAddonValues::default()
+        };
 
-        assert_eq!(sanitize_cce_node_pool_root_volume_size(None), 40);
-        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(1)), 40);
-        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(40)), 40);
-        assert_eq!(sanitize_cce_node_pool_root_volume_size(Some(2048)), 1024);
+        let values = build_cce_addon_values(
+            "autoscaler",
+            version,
+            Some(&current),
+            &CceAddonValueOverrides::default(),
+            "cluster-1",
+            "project-1",
+        )
+        .expect("values");
+        assert_eq!(values.basic["image_version"], "1.29.17");
+        assert_eq!(values.custom["coresTotal"], 64);
+        assert_eq!(values.custom["nodeGroup"], "pool-1");
+        assert_eq!(values.custom["scaleDownEnabled"], false);
+        assert_eq!(values.flavor, current.flavor);
+    }
 
-        assert_eq!(sanitize_cce_node_pool_data_volume_size(None), 100);
-        assert_eq!(sanitize_cce_node_pool_data_volume_size(Some(10)), 100);
-        assert_eq!(sanitize_cce_node_pool_data_volume_size(Some(100)), 100);
+    #[test]
+    fn build_cce_cluster_request_defaults_to_overlay_pay_per_use() {
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1"
+        }))
+        .expect("cluster params");
+        let body = build_cce_cluster_request(&params).expect("cluster request");
+        assert_eq!(body.spec.category, "CCE");
+        assert_eq!(body.spec.container_network.mode, "overlay_l2");
         assert_eq!(
-            sanitize_cce_node_pool_data_volume_size(Some(50_000)),
-            32_768
+            body.spec.container_network.cidr.as_deref(),
+            Some("172.16.0.0/16")
         );
+        assert!(body.spec.eni_network.is_none());
+        assert_eq!(body.spec.billing_mode, 0);
+        assert!(body.spec.masters.is_empty());
+        assert!(body.spec.extend_param.is_none());
+    }
 
-        assert_eq!(sanitize_cce_node_pool_max_pods(None), None);
-        assert_eq!(sanitize_cce_node_pool_max_pods(Some(1)), Some(16));
-        assert_eq!(sanitize_cce_node_pool_max_pods(Some(110)), Some(110));
-        assert_eq!(sanitize_cce_node_pool_max_pods(Some(999)), Some(256));
+    #[test]
+    fn build_cce_cluster_request_builds_turbo_multi_az_prepaid_clusters() {
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1",
+            "containerNetworkMode": "eni",
+            "containerNetworkCidr": "172.16.0.0/16",
+            "eniSubnetIds": [" subnet-2 ", ""],
+            "kubeProxyMode": "ipvs",
+            "ipv6Enable": true,
+            "masterAzs": ["az1", "az2", "az3"],
+            "eipAddress": "203.0.113.10",
+            "billingMode": "yearlyMonthly",
+            "periodType": "year",
+            "periodNum": 1,
+            "autoPay": true,
+            "tags": [{"key": "team", "value": "platform"}]
+        }))
+        .expect("cluster params");
+        let body = build_cce_cluster_request(&params).expect("turbo cluster request");
+        assert_eq!(body.spec.category, "Turbo");
+        assert!(body.spec.container_network.cidr.is_none());
+        let eni = body.spec.eni_network.as_ref().expect("eni network");
+        assert_eq!(eni.subnets[0].subnet_id, "subnet-2");
+        assert_eq!(body.spec.kube_proxy_mode.as_deref(), Some("ipvs"));
+        assert!(body.spec.ipv6_enable);
+        assert_eq!(body.spec.masters.len(), 3);
+        assert_eq!(body.spec.billing_mode, 1);
+        assert_eq!(body.spec.cluster_tags[0].key, "team");
+        let extend = body.spec.extend_param.as_ref().expect("extend param");
+        assert_eq!(extend.cluster_az.as_deref(), Some("multi_az"));
+        assert_eq!(extend.cluster_external_ip.as_deref(), Some("203.0.113.10"));
+        assert_eq!(extend.period_type.as_deref(), Some("year"));
+        assert_eq!(extend.period_num, Some(1));
+        assert_eq!(extend.is_auto_renew.as_deref(), Some("false"));
+        assert_eq!(extend.is_auto_pay.as_deref(), Some("true"));
+
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1",
+            "masterAzs": ["az1"]
+        }))
+        .expect("cluster params");
+        let single_az = build_cce_cluster_request(&params).expect("single az request");
+        assert_eq!(single_az.spec.masters.len(), 3);
+        assert_eq!(
+            single_az
+                .spec
+                .extend_param
+                .as_ref()
+                .and_then(|extend| extend.cluster_az.as_deref()),
+            Some("az1")
+        );
+
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1",
+            "containerNetworkMode": "eni"
+        }))
+        .expect("cluster params");
+        let turbo_default = build_cce_cluster_request(&params).expect("turbo default subnet");
+        assert_eq!(
+            turbo_default.spec.eni_network.expect("eni").subnets[0].subnet_id,
+            "subnet-1"
+        );
+    }
+
+    #[test]
+    fn build_cce_cluster_request_rejects_invalid_combinations() {
+        let valid = json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1"
+        });
+        for (overrides, expected) in [
+            (
+                json!({"containerNetworkMode": "flannel"}),
+                "container network mode",
+            ),
+            (json!({"eniSubnetIds": ["subnet-2"]}), "ENI subnets"),
+            (json!({"kubeProxyMode": "nftables"}), "kube-proxy mode"),
+            (json!({"masterAzs": ["az1", "az2"]}), "choose 1 or 3"),
+            (
+                json!({"flavor": "cce.s1.small", "masterAzs": ["az1", "az2", "az3"]}),
+                "single master",
+            ),
+            (json!({"eipAddress": "not-an-ip"}), "EIP address"),
+            (json!({"periodNum": 2}), "yearlyMonthly"),
+            (
+                json!({"billingMode": "yearlyMonthly", "periodNum": 12}),
+                "1-9 months",
+            ),
+            (json!({"billingMode": "spot"}), "billing mode"),
+            (
+                json!({"kubernetesSvcIpRange": "10.247.0.0"}),
+                "service CIDR",
+            ),
+            (
+                json!({"kubernetesSvcIpRange": "172.16.128.0/20"}),
+                "overlaps container CIDR",
+            ),
+        ] {
+            let mut params = valid.clone();
+            for (key, value) in overrides.as_object().expect("overrides") {
+                params[key] = value.clone();
+            }
+            let params: CceCreateClusterParams =
+                serde_json::from_value(params).expect("cluster params");
+            let err = build_cce_cluster_request(&params).expect_err("invalid cluster params");
+            assert!(err.contains(expected), "{overrides}: {err}");
+        }
+    }
+
+    #[test]
+    fn validate_cce_cluster_network_checks_vpc_ranges_and_maps_eni_subnets() {
+        let vpc: Vpc = serde_json::from_value(json!({
+            "id": "vpc-1",
+            "name": "main",
+            "cidr": "192.168.0.0/16"
+        }))
+        .expect("vpc");
+        let subnets: Vec<Subnet> = serde_json::from_value(json!([
+            {"id": "subnet-1", "name": "nodes", "cidr": "192.168.0.0/24", "neutron_subnet_id": "ipv4-1"},
+            {"id": "subnet-2", "name": "pods", "cidr": "192.168.8.0/22", "neutron_subnet_id": "ipv4-2"}
+        ]))
+        .expect("subnets");
+
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1",
+            "containerNetworkMode": "eni",
+            "eniSubnetIds": ["subnet-2"]
+        }))
+        .expect("cluster params");
+        let mut turbo = build_cce_cluster_request(&params).expect("turbo request");
+        validate_cce_cluster_network(&mut turbo.spec, &vpc, &subnets).expect("valid network");
+        assert_eq!(
+            turbo.spec.eni_network.as_ref().expect("eni").subnets[0].subnet_id,
+            "ipv4-2"
+        );
+
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-1",
+            "kubernetesSvcIpRange": "192.168.128.0/20"
+        }))
+        .expect("cluster params");
+        let mut overlapping = build_cce_cluster_request(&params).expect("overlapping request");
+        assert_eq!(
+            validate_cce_cluster_network(&mut overlapping.spec, &vpc, &subnets).err(),
+            Some("Service CIDR 192.168.128.0/20 overlaps VPC main (192.168.0.0/16).".to_string())
+        );
+
+        let params: CceCreateClusterParams = serde_json::from_value(json!({
+            "region": "sa-brazil-1",
+            "name": "dev-cluster",
+            "flavor": "cce.s2.small",
+            "version": "v1.30",
+            "vpcId": "vpc-1",
+            "subnetId": "subnet-9"
+        }))
+        .expect("cluster params");
+        let mut foreign = build_cce_cluster_request(&params).expect("foreign subnet request");
+        assert!(validate_cce_cluster_network(&mut foreign.spec, &vpc, &subnets).is_err());
     }
 }
@@ -0,0 +1,370 @@
+use crate::api::models::eip::PublicIp;
+use crate::api::models::nat::{NatGateway, SnatRule};
+use crate::api::models::tags::ResourceTag;
+use crate::app_dirs::AppDir;
+use crate::json_store::JsonFileStore;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+static NAT_BOOTSTRAP_RUNS: JsonFileStore<NatBootstrapRun> = JsonFileStore::new(
+    AppDir::Data,
+    "nat-bootstrap-runs.json",
+    "NAT bootstrap runs",
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NatBootstrapStep {
+    NatGateway,
+    Eip,
+    SnatRule,
+}
+
+impl NatBootstrapStep {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::NatGateway => "NAT gateway",
+            Self::Eip => "EIP",
+            Self::SnatRule => "SNAT rule",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NatBootstrapStatus {
+    InProgress,
+    Failed,
+    Completed,
+}
+
+/// One NAT + EIP + SNAT bootstrap, keyed by region and gateway name. Each step
+/// stores the ID it created or adopted, so a failed run resumes where it stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NatBootstrapRun {
+    pub region: String,
+    pub name: String,
+    pub vpc_id: String,
+    pub subnet_id: String,
+    pub spec: String,
+    pub description: Option<String>,
+    pub enterprise_project_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<ResourceTag>,
+    /// Existing EIPs bound to the SNAT rule; when empty the run allocates one.
+    #[serde(default)]
+    pub reused_eip_ids: Vec<String>,
+    pub nat_gateway_id: Option<String>,
+    /// Set when the gateway predates the run; rollback keeps adopted gateways.
+    #[serde(default)]
+    pub nat_gateway_adopted: bool,
+    /// EIP allocated or adopted by the run; rollback releases only this one.
+    pub eip_id: Option<String>,
+    pub eip_address: Option<String>,
+    pub snat_rule_id: Option<String>,
+    pub status: NatBootstrapStatus,
+    pub failed_step: Option<NatBootstrapStep>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl NatBootstrapRun {
+    pub fn new(
+        region: &str,
+        name: &str,
+        vpc_id: &str,
+        subnet_id: &str,
+        spec: &str,
+        description: Option<String>,
+        enterprise_project_id: Option<String>,
+    ) -> Self {
+        let now = Utc::now().to_rfc3339();
+        Self {
+            region: region.to_string(),
+            name: name.to_string(),
+            vpc_id: vpc_id.to_string(),
+            subnet_id: subnet_id.to_string(),
+            spec: spec.to_string(),
+            description,
+            enterprise_project_id,
+            tags: Vec::new(),
+            reused_eip_ids: Vec::new(),
+            nat_gateway_id: None,
+            nat_gateway_adopted: false,
+            eip_id: None,
+            eip_address: None,
+            snat_rule_id: None,
+            status: NatBootstrapStatus::InProgress,
+            failed_step: None,
+            last_error: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// The first step without a stored output, or `None` once every step is done.
+    pub fn next_step(&self) -> Option<NatBootstrapStep> {
+        if self.nat_gateway_id.is_none() {
+            return Some(NatBootstrapStep::NatGateway);
+        }
+        if self.reused_eip_ids.is_empty() && self.eip_id.is_none() {
+            return Some(NatBootstrapStep::Eip);
+        }
+        if self.snat_rule_id.is_none() {
+            return Some(NatBootstrapStep::SnatRule);
+        }
+        None
+    }
+
+    /// Comma-joined EIP IDs for the SNAT rule.
+    pub fn floating_ip_ids(&self) -> String {
+        if self.reused_eip_ids.is_empty() {
+            self.eip_id.clone().unwrap_or_default()
+        } else {
+            self.reused_eip_ids.join(",")
+        }
+    }
+
+    pub fn eip_name(&self) -> String {
+        format!("{}-eip", self.name)
+    }
+
+    /// Names of the requested settings that differ from the stored run. Unset
+    /// spec, tags and EIPs mean "as stored" and never conflict.
+    pub fn conflicting_settings(
+        &self,
+        spec: Option<&str>,
+        tags: &[ResourceTag],
+        reused_eip_ids: &[String],
+    ) -> Vec<&'static str> {
+        let mut conflicts = Vec::new();
+        if spec.is_some_and(|spec| spec != self.spec) {
+            conflicts.push("spec");
+        }
+        if !tags.is_empty() && tags != self.tags.as_slice() {
+            conflicts.push("tags");
+        }
+        if !reused_eip_ids.is_empty() && reused_eip_ids != self.reused_eip_ids.as_slice() {
+            conflicts.push("EIPs");
+        }
+        conflicts
+    }
+
+    pub fn matches(&self, region: &str, name: &str) -> bool {
+        self.region == region && self.name == name
+    }
+
+    pub fn mark_step_done(&mut self) {
+        self.failed_step = None;
+        self.last_error = None;
+        self.status = if self.next_step().is_some() {
+            NatBootstrapStatus::InProgress
+        } else {
+            NatBootstrapStatus::Completed
+        };
+        self.updated_at = Utc::now().to_rfc3339();
+    }
+
+    pub fn mark_failed(&mut self, step: NatBootstrapStep, error: String) {
+        self.status = NatBootstrapStatus::Failed;
+        self.failed_step = Some(step);
+        self.last_error = Some(error);
+        self.updated_at = Utc::now().to_rfc3339();
+    }
+}
+
+/// A gateway from an earlier run: same name on the same subnet, not being deleted.
+pub fn find_adoptable_nat_gateway<'a>(
+    gateways: &'a [NatGateway],
+    name: &str,
+    subnet_id: &str,
+) -> Option<&'a NatGateway> {
+    gateways.iter().find(|gateway| {
+        gateway
+            .id
+            .as_deref()
+            .is_some_and(|id| !id.trim().is_empty())
+            && gateway.name.as_deref() == Some(name)
+            && gateway.internal_network_id.as_deref() == Some(subnet_id)
+            && !gateway
+                .status
+                .as_deref()
+                .is_some_and(|status| status.eq_ignore_ascii_case("PENDING_DELETE"))
+    })
+}
+
+/// An unbound EIP whose bandwidth carries the bootstrap's EIP name.
+pub fn find_adoptable_eip<'a>(eips: &'a [PublicIp], eip_name: &str) -> Option<&'a PublicIp> {
+    eips.iter().find(|eip| {
+        eip.id.as_deref().is_some_and(|id| !id.trim().is_empty())
+            && eip
+                .bandwidth
+                .as_ref()
+                .and_then(|bandwidth| bandwidth.name.as_deref())
+                == Some(eip_name)
+            && eip
+                .associate_instance_id
+                .as_deref()
+                .is_none_or(|id| id.trim().is_empty())
+            && eip
+                .status
+                .as_deref()
+                .is_none_or(|status| status.eq_ignore_ascii_case("DOWN"))
+    })
+}
+
+/// The SNAT rule an earlier run created for the bootstrap subnet.
+pub fn find_adoptable_snat_rule<'a>(
+    rules: &'a [SnatRule],
+    subnet_id: &str,
+) -> Option<&'a SnatRule> {
+    rules.iter().find(|rule| {
+        rule.id.as_deref().is_some_and(|id| !id.trim().is_empty())
+            && rule.network_id.as_deref() == Some(subnet_id)
+    })
+}
+
+pub fn load_nat_bootstrap_runs() -> Result<Vec<NatBootstrapRun>, String> {
+    NAT_BOOTSTRAP_RUNS.load()
+}
+
+pub fn find_nat_bootstrap_run(region: &str, name: &str) -> Result<Option<NatBootstrapRun>, String> {
+    Ok(load_nat_bootstrap_runs()?
+        .into_iter()
+        .find(|run| run.matches(region, name)))
+}
+
+/// The run that built `nat_gateway_id`. Its `eip_id` is the EIP the bootstrap
+/// owns, even after the gateway has been renamed.
+pub fn find_nat_bootstrap_run_for_gateway(
+    region: &str,
+    nat_gateway_id: &str,
+) -> Result<Option<NatBootstrapRun>, String> {
+    Ok(load_nat_bootstrap_runs()?
+        .into_iter()
+        .find(|run| run.region == region && run.nat_gateway_id.as_deref() == Some(nat_gateway_id)))
+}
+
+/// Insert or replace the run with the same region and name.
+pub fn store_nat_bootstrap_run(run: &NatBootstrapRun) -> Result<(), String> {
+    NAT_BOOTSTRAP_RUNS.update(|runs| {
+        match runs
+            .iter_mut()
+            .find(|stored| stored.matches(&run.region, &run.name))
+        {
+            Some(stored) => *stored = run.clone(),
+            None => runs.push(run.clone()),
+        }
+    })
+}
+
+pub fn remove_nat_bootstrap_run(region: &str, name: &str) -> Result<(), String> {
+    NAT_BOOTSTRAP_RUNS.update(|runs| runs.retain(|run| !run.matches(region, name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        find_adoptable_eip, find_adoptable_nat_gateway, find_adoptable_snat_rule, NatBootstrapRun,
+        NatBootstrapStatus, NatBootstrapStep,
+    };
+    use crate::api::models::eip::PublicIp;
+    use crate::api::models::nat::{NatGateway, SnatRule};
+    use crate::api::models::tags::ResourceTag;
+
+    fn run() -> NatBootstrapRun {
+        NatBootstrapRun::new(
+            "sa-brazil-1",
+            "cce-nat",
+            "vpc-1",
+            "subnet-1",
+            "1",
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn next_step_follows_stored_outputs() {
+        let mut run = run();
+        assert_eq!(run.next_step(), Some(NatBootstrapStep::NatGateway));
+        run.nat_gateway_id = Some("nat-1".to_string());
+        assert_eq!(run.next_step(), Some(NatBootstrapStep::Eip));
+        run.eip_id = Some("eip-1".to_string());
+        run.mark_step_done();
+        assert_eq!(run.status, NatBootstrapStatus::InProgress);
+        assert_eq!(run.next_step(), Some(NatBootstrapStep::SnatRule));
+        run.snat_rule_id = Some("snat-1".to_string());
+        run.mark_step_done();
+        assert_eq!(run.next_step(), None);
+        assert_eq!(run.status, NatBootstrapStatus::Completed);
+    }
+
+    #[test]
+    fn reused_eips_skip_the_eip_step() {
+        let mut run = run();
+        run.nat_gateway_id = Some("nat-1".to_string());
+        run.reused_eip_ids = vec!["eip-a".to_string(), "eip-b".to_string()];
+        assert_eq!(run.next_step(), Some(NatBootstrapStep::SnatRule));
+        assert_eq!(run.floating_ip_ids(), "eip-a,eip-b");
+
+        run.mark_failed(NatBootstrapStep::SnatRule, "boom".to_string());
+        assert_eq!(run.status, NatBootstrapStatus::Failed);
+        assert_eq!(run.failed_step, Some(NatBootstrapStep::SnatRule));
+    }
+
+    #[test]
+    fn conflicting_settings_ignores_unset_values() {
+        let mut run = run();
+        run.reused_eip_ids = vec!["eip-a".to_string()];
+        assert!(run.conflicting_settings(None, &[], &[]).is_empty());
+        assert!(run
+            .conflicting_settings(Some("1"), &[], &["eip-a".to_string()])
+            .is_empty());
+
+        let tags = vec![ResourceTag {
+            key: "env".to_string(),
+            value: "dev".to_string(),
+        }];
+        assert_eq!(
+            run.conflicting_settings(Some("2"), &tags, &["eip-b".to_string()]),
+            vec!["spec", "tags", "EIPs"]
+        );
+    }
+
+    #[test]
+    fn adoption_matches_name_subnet_and_unbound_eips() {
+        let gateways: Vec<NatGateway> = serde_json::from_value(serde_json::json!([
+            {"id": "nat-old", "name": "cce-nat", "internal_network_id": "subnet-1", "status": "PENDING_DELETE"},
+            {"id": "nat-other", "name": "cce-nat", "internal_network_id": "subnet-2", "status": "ACTIVE"},
+            {"id": "nat-1", "name": "cce-nat", "internal_network_id": "subnet-1", "status": "ACTIVE"}
+        ]))
+        .expect("gateways");
+        assert_eq!(
+            find_adoptable_nat_gateway(&gateways, "cce-nat", "subnet-1")
+                .and_then(|gateway| gateway.id.as_deref()),
+            Some("nat-1")
+        );
+
+        let eips: Vec<PublicIp> = serde_json::from_value(serde_json::json!([
+            {"id": "eip-bound", "status": "ACTIVE", "associate_instance_id": "nat-9", "bandwidth": {"name": "cce-nat-eip"}},
+            {"id": "eip-1", "status": "DOWN", "bandwidth": {"name": "cce-nat-eip"}}
+        ]))
+        .expect("eips");
+        assert_eq!(
+            find_adoptable_eip(&eips, "cce-nat-eip").and_then(|eip| eip.id.as_deref()),
+            Some("eip-1")
+        );
+
+        let rules: Vec<SnatRule> = serde_json::from_value(serde_json::json!([
+            {"id": "snat-cidr", "cidr": "10.0.0.0/24"},
+            {"id": "snat-1", "network_id": "subnet-1", "floating_ip_id": "eip-1"}
+        ]))
+        .expect("rules");
+        assert_eq!(
+            find_adoptable_snat_rule(&rules, "subnet-1").and_then(|rule| rule.id.as_deref()),
+            Some("snat-1")
+        );
+    }
+}
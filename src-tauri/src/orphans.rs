@@ -0,0 +1,492 @@
+use crate::api::models::eip::PublicIp;
+use crate::api::models::evs::EvsVolume;
+use crate::api::models::nat::{DnatRuleListResponse, NatGateway, SnatRuleListResponse};
+use crate::api::models::obs::ObsBucket;
+use crate::pricing::{PriceTable, RegionPriceView, HOURS_PER_MONTH};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrphanKind {
+    Eip,
+    EvsVolume,
+    NatGateway,
+    ObsBucket,
+}
+
+impl OrphanKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            OrphanKind::Eip => "EIP",
+            OrphanKind::EvsVolume => "EVS disk",
+            OrphanKind::NatGateway => "NAT gateway",
+            OrphanKind::ObsBucket => "OBS bucket",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanedResource {
+    pub kind: OrphanKind,
+    pub id: String,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub created_at: Option<String>,
+    pub age_days: Option<i64>,
+    pub estimated_monthly_cost: f64,
+    /// Why the resource is considered orphaned.
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanCategory {
+    pub kind: OrphanKind,
+    pub count: usize,
+    pub estimated_monthly_cost: f64,
+    pub resources: Vec<OrphanedResource>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OrphanReport {
+    pub region: String,
+    pub generated_at: String,
+    pub currency: String,
+    pub total_estimated_monthly_cost: f64,
+    pub categories: Vec<OrphanCategory>,
+    /// Listings that failed or were truncated; the report may be incomplete.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanCleanupOutcome {
+    pub kind: OrphanKind,
+    pub id: String,
+    pub deleted: bool,
+    pub status_code: Option<u16>,
+    pub message: String,
+}
+
+pub struct OrphanInputs<'a> {
+    pub eips: &'a [PublicIp],
+    pub volumes: &'a [EvsVolume],
+    pub nat_gateways: &'a [NatGateway],
+    /// SNAT plus DNAT rule count per NAT gateway ID; gateways missing here are skipped.
+    pub nat_rule_counts: &'a HashMap<String, usize>,
+    /// Buckets already confirmed to hold zero objects.
+    pub empty_buckets: &'a [ObsBucket],
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+/// Parse the timestamp shapes used across services: RFC 3339 (EIP, OBS), naive ISO
+/// with fractional seconds (EVS) and space-separated naive (NAT). Naive values are UTC.
+pub fn parse_cloud_timestamp(input: &str) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(input) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .map(|naive| naive.and_utc())
+}
+
+fn age_days(created_at: Option<&str>, now: DateTime<Utc>) -> Option<i64> {
+    let created = parse_cloud_timestamp(non_empty(created_at)?)?;
+    Some((now - created).num_days().max(0))
+}
+
+/// Monthly costs from the active price table. Items the table does not price
+/// count as zero and are named once in `unpriced`.
+struct OrphanPricer<'a> {
+    prices: RegionPriceView<'a>,
+    unpriced: BTreeSet<String>,
+}
+
+impl OrphanPricer<'_> {
+    fn monthly(&mut self, hourly: Option<f64>, item: impl FnOnce() -> String) -> f64 {
+        match hourly {
+            Some(hourly) => hourly * HOURS_PER_MONTH,
+            None => {
+                self.unpriced.insert(item());
+                0.0
+            }
+        }
+    }
+
+    fn eip(&mut self, eip: &PublicIp) -> f64 {
+        let reserved = self.monthly(self.prices.eip_hourly(), || "EIP".to_string());
+        // Shared bandwidth is billed on the pool, and traffic billing is zero while idle.
+        let dedicated_size = eip
+            .bandwidth
+            .as_ref()
+            .filter(|bandwidth| {
+                bandwidth.share_type.as_deref() != Some("WHOLE")
+                    && bandwidth.charge_mode.as_deref() == Some("bandwidth")
+            })
+            .and_then(|bandwidth| bandwidth.size);
+        let bandwidth = match dedicated_size {
+            Some(size) => {
+                let hourly = self
+                    .prices
+                    .bandwidth_mbit_hourly()
+                    .map(|rate| rate * f64::from(size));
+                self.monthly(hourly, || "EIP bandwidth".to_string())
+            }
+            None => 0.0,
+        };
+        reserved + bandwidth
+    }
+
+    fn evs(&mut self, volume: &EvsVolume) -> f64 {
+        let volume_type = non_empty(volume.volume_type.as_deref()).unwrap_or("unknown");
+        let hourly = self
+            .prices
+            .disk_gib_hourly(volume_type)
+            .map(|rate| rate * f64::from(volume.size.unwrap_or(0)));
+        self.monthly(hourly, || format!("EVS disk type {}", volume_type))
+    }
+
+    fn nat(&mut self, gateway: &NatGateway) -> f64 {
+        let spec = non_empty(gateway.spec.as_deref()).unwrap_or("unknown");
+        let hourly = self.prices.nat_hourly(spec);
+        self.monthly(hourly, || format!("NAT gateway spec {}", spec))
+    }
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+pub fn is_unbound_eip(eip: &PublicIp) -> bool {
+    non_empty(eip.associate_instance_id.as_deref()).is_none()
+        && non_empty(eip.vnic.as_ref().and_then(|vnic| vnic.port_id.as_deref())).is_none()
+}
+
+pub fn is_detached_volume(volume: &EvsVolume) -> bool {
+    volume.attachments.is_empty()
+        && volume
+            .status
+            .as_deref()
+            .is_some_and(|status| status.trim().eq_ignore_ascii_case("available"))
+}
+
+/// SNAT plus DNAT rules on one NAT gateway. A gateway with only DNAT rules is
+/// still in use, so the count is unknown unless both listings succeed.
+pub fn nat_rule_count<E>(
+    snat: Result<SnatRuleListResponse, E>,
+    dnat: Result<DnatRuleListResponse, E>,
+) -> Result<usize, E> {
+    Ok(snat?.snat_rules.len() + dnat?.dnat_rules.len())
+}
+
+/// Cross-reference one region's listings and group the resources nothing uses,
+/// along with the items `prices` could not price.
+fn find_orphaned_resources(
+    inputs: &OrphanInputs<'_>,
+    prices: RegionPriceView<'_>,
+    now: DateTime<Utc>,
+) -> (Vec<OrphanCategory>, BTreeSet<String>) {
+    let mut pricer = OrphanPricer {
+        prices,
+        unpriced: BTreeSet::new(),
+    };
+    let mut eips = Vec::new();
+    for eip in inputs.eips.iter().filter(|eip| is_unbound_eip(eip)) {
+        let Some(id) = non_empty(eip.id.as_deref()) else {
+            continue;
+        };
+        eips.push(OrphanedResource {
+            kind: OrphanKind::Eip,
+            id: id.to_string(),
+            name: non_empty(eip.alias.as_deref())
+                .or(non_empty(eip.public_ip_address.as_deref()))
+                .map(str::to_string),
+            status: eip.status.clone(),
+            created_at: eip.created_at.clone(),
+            age_days: age_days(eip.created_at.as_deref(), now),
+            estimated_monthly_cost: round_cents(pricer.eip(eip)),
+            reason: "Not bound to any instance or port.".to_string(),
+        });
+    }
+
+    let mut volumes = Vec::new();
+    for volume in inputs
+        .volumes
+        .iter()
+        .filter(|volume| is_detached_volume(volume))
+    {
+        let Some(id) = non_empty(volume.id.as_deref()) else {
+            continue;
+        };
+        volumes.push(OrphanedResource {
+            kind: OrphanKind::EvsVolume,
+            id: id.to_string(),
+            name: volume.name.clone(),
+            status: volume.status.clone(),
+            created_at: volume.created_at.clone(),
+            age_days: age_days(volume.created_at.as_deref(), now),
+            estimated_monthly_cost: round_cents(pricer.evs(volume)),
+            reason: format!(
+                "Available and not attached ({} GiB {}).",
+                volume.size.unwrap_or(0),
+                volume.volume_type.as_deref().unwrap_or("disk")
+            ),
+        });
+    }
+
+    let mut gateways = Vec::new();
+    for gateway in inputs.nat_gateways {
+        let Some(id) = non_empty(gateway.id.as_deref()) else {
+            continue;
+        };
+        if inputs.nat_rule_counts.get(id) != Some(&0) {
+            continue;
+        }
+        gateways.push(OrphanedResource {
+            kind: OrphanKind::NatGateway,
+            id: id.to_string(),
+            name: gateway.name.clone(),
+            status: gateway.status.clone(),
+            created_at: gateway.created_at.clone(),
+            age_days: age_days(gateway.created_at.as_deref(), now),
+            estimated_monthly_cost: round_cents(pricer.nat(gateway)),
+            reason: "Has no SNAT or DNAT rules.".to_string(),
+        });
+    }
+
+    let buckets = inputs
+        .empty_buckets
+        .iter()
+        .map(|bucket| OrphanedResource {
+            kind: OrphanKind::ObsBucket,
+            id: bucket.name.clone(),
+            name: Some(bucket.name.clone()),
+            status: None,
+            created_at: bucket.creation_date.clone(),
+            age_days: age_days(bucket.creation_date.as_deref(), now),
+            estimated_monthly_cost: 0.0,
+            reason: "Holds no objects.".to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let categories = [
+        (OrphanKind::Eip, eips),
+        (OrphanKind::EvsVolume, volumes),
+        (OrphanKind::NatGateway, gateways),
+        (OrphanKind::ObsBucket, buckets),
+    ]
+    .into_iter()
+    .map(|(kind, mut resources)| {
+        // Most expensive, then oldest, first.
+        resources.sort_by(|left, right| {
+            right
+                .estimated_monthly_cost
+                .total_cmp(&left.estimated_monthly_cost)
+                .then(right.age_days.cmp(&left.age_days))
+        });
+        OrphanCategory {
+            kind,
+            count: resources.len(),
+            estimated_monthly_cost: round_cents(
+                resources
+                    .iter()
+                    .map(|resource| resource.estimated_monthly_cost)
+                    .sum(),
+            ),
+            resources,
+        }
+    })
+    .collect();
+    (categories, pricer.unpriced)
+}
+
+/// Costs come from `table`, the same one create estimates use.
+pub fn build_orphan_report(
+    region: &str,
+    inputs: &OrphanInputs<'_>,
+    table: &PriceTable,
+    now: DateTime<Utc>,
+) -> OrphanReport {
+    let (categories, unpriced) = find_orphaned_resources(inputs, table.for_region(region), now);
+    OrphanReport {
+        region: region.to_string(),
+        generated_at: now.to_rfc3339(),
+        currency: table.currency.clone(),
+        total_estimated_monthly_cost: round_cents(
+            categories
+                .iter()
+                .map(|category| category.estimated_monthly_cost)
+                .sum(),
+        ),
+        categories,
+        warnings: unpriced
+            .into_iter()
+            .map(|item| format!("No price for {}; it counts as zero.", item))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_orphan_report, nat_rule_count, parse_cloud_timestamp, OrphanInputs, OrphanKind,
+    };
+    use crate::api::models::eip::EipListResponse;
+    use crate::api::models::evs::EvsListResponse;
+    use crate::api::models::nat::{
+        DnatRuleListResponse, NatGatewayListResponse, SnatRuleListResponse,
+    };
+    use crate::api::models::obs::ObsBucket;
+    use crate::pricing::PriceTable;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    #[test]
+    fn parse_cloud_timestamp_accepts_service_formats() {
+        let expected = Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap();
+        for raw in [
+            "2026-01-02T03:04:05Z",
+            "2026-01-02T03:04:05.000000",
+            "2026-01-02 03:04:05.000000",
+            "2026-01-02T03:04:05.000Z",
+        ] {
+            assert_eq!(parse_cloud_timestamp(raw), Some(expected), "{raw}");
+        }
+        assert_eq!(parse_cloud_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn nat_rule_count_adds_dnat_rules_and_needs_both_listings() {
+        let snat: SnatRuleListResponse =
+            serde_json::from_str(r#"{"snat_rules":[{"id":"snat-1"}]}"#).expect("snat");
+        let dnat: DnatRuleListResponse =
+            serde_json::from_str(r#"{"dnat_rules":[{"id":"dnat-1"},{"id":"dnat-2"}]}"#)
+                .expect("dnat");
+        assert_eq!(
+            nat_rule_count::<String>(Ok(snat.clone()), Ok(dnat.clone())),
+            Ok(3)
+        );
+        assert_eq!(
+            nat_rule_count::<String>(Ok(SnatRuleListResponse::default()), Ok(dnat)),
+            Ok(2)
+        );
+        assert_eq!(
+            nat_rule_count::<String>(
+                Ok(SnatRuleListResponse::default()),
+                Ok(DnatRuleListResponse::default())
+            ),
+            Ok(0)
+        );
+        assert_eq!(
+            nat_rule_count(Ok(snat), Err("DNAT listing failed".to_string())),
+            Err("DNAT listing failed".to_string())
+        );
+    }
+
+    #[test]
+    fn build_orphan_report_flags_unused_resources_only() {
+        let eips: EipListResponse = serde_json::from_str(
+            r#"{"publicips":[
+              {"id":"eip-free","public_ip_address":"203.0.113.1","created_at":"2026-01-01T00:00:00Z",
+               "bandwidth":{"size":10,"share_type":"PER","charge_mode":"bandwidth"}},
+              {"id":"eip-bound","associate_instance_id":"port-1","associate_instance_type":"PORT"},
+              {"id":"eip-vnic","vnic":{"port_id":"port-2"}}
+            ]}"#,
+        )
+        .expect("eips");
+        let volumes: EvsListResponse = serde_json::from_str(
+            r#"{"volumes":[
+              {"id":"vol-free","status":"available","size":100,"volume_type":"SSD","created_at":"2026-01-21T00:00:00.000000"},
+              {"id":"vol-used","status":"in-use","size":40,"attachments":[{"server_id":"ecs-1"}]},
+              {"id":"vol-busy","status":"creating","size":40}
+            ]}"#,
+        )
+        .expect("volumes");
+        let gateways: NatGatewayListResponse = serde_json::from_str(
+            r#"{"nat_gateways":[
+              {"id":"nat-idle","spec":"1","created_at":"2026-01-31 00:00:00.000000"},
+              {"id":"nat-used","spec":"2"},
+              {"id":"nat-unknown","spec":"3"}
+            ]}"#,
+        )
+        .expect("nat gateways");
+        // nat-used only carries a DNAT rule.
+        let nat_rule_counts =
+            HashMap::from([("nat-idle".to_string(), 0), ("nat-used".to_string(), 1)]);
+        let buckets = vec![ObsBucket {
+            name: "empty-bucket".to_string(),
+            creation_date: None,
+            location: Some("sa-brazil-1".to_string()),
+            bucket_type: None,
+        }];
+
+        // No SSD price, so the volume is reported unpriced.
+        let table = PriceTable::parse(
+            r#"
+version = "test"
+currency = "USD"
+
+[default]
+eip_hourly = 0.01
+bandwidth_mbit_hourly = 0.001
+
+[default.nat]
+"1" = 0.05
+"#,
+        )
+        .expect("price table");
+
+        let now = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+        let report = build_orphan_report(
+            "sa-brazil-1",
+            &OrphanInputs {
+                eips: &eips.publicips,
+                volumes: &volumes.volumes,
+                nat_gateways: &gateways.nat_gateways,
+                nat_rule_counts: &nat_rule_counts,
+                empty_buckets: &buckets,
+            },
+            &table,
+            now,
+        );
+
+        let ids = |kind: OrphanKind| {
+            report
+                .categories
+                .iter()
+                .find(|category| category.kind == kind)
+                .map(|category| {
+                    category
+                        .resources
+                        .iter()
+                        .map(|resource| resource.id.as_str())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        assert_eq!(ids(OrphanKind::Eip), vec!["eip-free"]);
+        assert_eq!(ids(OrphanKind::EvsVolume), vec!["vol-free"]);
+        assert_eq!(ids(OrphanKind::NatGateway), vec!["nat-idle"]);
+        assert_eq!(ids(OrphanKind::ObsBucket), vec!["empty-bucket"]);
+
+        let eip = &report.categories[0].resources[0];
+        assert_eq!(eip.age_days, Some(31));
+        assert_eq!(eip.estimated_monthly_cost, 14.6);
+        let volume = &report.categories[1].resources[0];
+        assert_eq!(volume.age_days, Some(11));
+        assert_eq!(volume.estimated_monthly_cost, 0.0);
+        let gateway = &report.categories[2].resources[0];
+        assert_eq!(gateway.age_days, Some(1));
+        assert_eq!(gateway.estimated_monthly_cost, 36.5);
+        assert!((report.total_estimated_monthly_cost - 51.1).abs() < 1e-9);
+        assert_eq!(
+            report.warnings,
+            vec!["No price for EVS disk type SSD; it counts as zero.".to_string()]
+        );
+    }
+}
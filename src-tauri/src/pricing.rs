@@ -0,0 +1,721 @@
+use crate::api::models::cce::{CceCreateClusterRequest, CceCreateNodePoolRequest};
+use crate::api::models::ecs::{Bandwidth, CreateEcsRequest};
+use crate::app_dirs::{app_path, AppDir};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const HOURS_PER_MONTH: f64 = 730.0;
+const BUNDLED_PRICE_TABLE: &str = include_str!("../pricing/prices.toml");
+const IMPORTED_PRICE_TABLE_FILE: &str = "prices.toml";
+
+/// Prices for one scope. Every field is optional so regions only list overrides.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RegionPrices {
+    pub vcpu_hourly: Option<f64>,
+    pub ram_gib_hourly: Option<f64>,
+    pub eip_hourly: Option<f64>,
+    pub bandwidth_mbit_hourly: Option<f64>,
+    pub traffic_gb: Option<f64>,
+    #[serde(default)]
+    pub flavors: BTreeMap<String, f64>,
+    /// Per GiB per hour, keyed by EVS volume type.
+    #[serde(default)]
+    pub disks: BTreeMap<String, f64>,
+    /// Keyed by NAT gateway spec ("1" to "4").
+    #[serde(default)]
+    pub nat: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub cce_clusters: BTreeMap<String, f64>,
+}
+
+impl RegionPrices {
+    fn all_prices(&self) -> impl Iterator<Item = f64> + '_ {
+        [
+            self.vcpu_hourly,
+            self.ram_gib_hourly,
+            self.eip_hourly,
+            self.bandwidth_mbit_hourly,
+            self.traffic_gb,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.flavors.values().copied())
+        .chain(self.disks.values().copied())
+        .chain(self.nat.values().copied())
+        .chain(self.cce_clusters.values().copied())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceTable {
+    pub version: String,
+    pub currency: String,
+    #[serde(default)]
+    pub default: RegionPrices,
+    #[serde(default)]
+    pub regions: BTreeMap<String, RegionPrices>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceTableSource {
+    Bundled,
+    Imported,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceTableInfo {
+    pub version: String,
+    pub currency: String,
+    pub source: PriceTableSource,
+    pub regions: Vec<String>,
+    /// Where imported tables are stored, even when none has been imported yet.
+    pub imported_path: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceLine {
+    pub item: String,
+    pub quantity: u32,
+    pub unit_hourly: Option<f64>,
+    pub hourly: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceEstimate {
+    pub region: String,
+    pub currency: String,
+    pub table_version: String,
+    pub table_source: PriceTableSource,
+    pub lines: Vec<PriceLine>,
+    /// Sum of the priced lines; unpriced lines are listed in `warnings`.
+    pub hourly: f64,
+    pub monthly: f64,
+    pub warnings: Vec<String>,
+}
+
+/// The active table plus where it came from.
+#[derive(Debug, Clone)]
+pub struct LoadedPriceTable {
+    pub table: PriceTable,
+    pub source: PriceTableSource,
+    pub warnings: Vec<String>,
+}
+
+impl PriceTable {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let table: PriceTable =
+            toml::from_str(raw).map_err(|err| format!("Invalid price table: {}", err))?;
+        if table.version.trim().is_empty() {
+            return Err("Price table version is required.".to_string());
+        }
+        if table.currency.trim().is_empty() {
+            return Err("Price table currency is required.".to_string());
+        }
+        let scopes = std::iter::once(("default", &table.default)).chain(
+            table
+                .regions
+                .iter()
+                .map(|(region, prices)| (region.as_str(), prices)),
+        );
+        for (scope, prices) in scopes {
+            if prices
+                .all_prices()
+                .any(|price| !price.is_finite() || price < 0.0)
+            {
+                return Err(format!(
+                    "Price table has a negative or invalid price in [{}].",
+                    scope
+                ));
+            }
+        }
+        Ok(table)
+    }
+
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_PRICE_TABLE).expect("bundled price table is valid")
+    }
+
+    pub fn for_region(&self, region: &str) -> RegionPriceView<'_> {
+        RegionPriceView {
+            region: self.regions.get(region.trim()),
+            default: &self.default,
+        }
+    }
+
+    pub fn info(&self, source: PriceTableSource, warnings: Vec<String>) -> PriceTableInfo {
+        PriceTableInfo {
+            version: self.version.clone(),
+            currency: self.currency.clone(),
+            source,
+            regions: self.regions.keys().cloned().collect(),
+            imported_path: imported_price_table_path().map(|path| path.display().to_string()),
+            warnings,
+        }
+    }
+}
+
+/// Region overrides first, then the table defaults.
+pub struct RegionPriceView<'a> {
+    region: Option<&'a RegionPrices>,
+    default: &'a RegionPrices,
+}
+
+fn lookup_key(map: &BTreeMap<String, f64>, key: &str) -> Option<f64> {
+    let key = key.trim();
+    map.get(key).copied().or_else(|| {
+        map.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, price)| *price)
+    })
+}
+
+impl RegionPriceView<'_> {
+    fn scalar(&self, pick: impl Fn(&RegionPrices) -> Option<f64>) -> Option<f64> {
+        self.region.and_then(&pick).or_else(|| pick(self.default))
+    }
+
+    fn keyed(
+        &self,
+        pick: impl Fn(&RegionPrices) -> &BTreeMap<String, f64>,
+        key: &str,
+    ) -> Option<f64> {
+        self.region
+            .and_then(|prices| lookup_key(pick(prices), key))
+            .or_else(|| lookup_key(pick(self.default), key))
+    }
+
+    pub fn flavor_hourly(&self, flavor: &str) -> Option<f64> {
+        self.keyed(|prices| &prices.flavors, flavor).or_else(|| {
+            let (vcpus, ram_gib) = flavor_shape(flavor)?;
+            let vcpu = self.scalar(|prices| prices.vcpu_hourly)?;
+            let ram = self.scalar(|prices| prices.ram_gib_hourly)?;
+            Some(f64::from(vcpus) * vcpu + f64::from(ram_gib) * ram)
+        })
+    }
+
+    pub fn disk_gib_hourly(&self, volume_type: &str) -> Option<f64> {
+        self.keyed(|prices| &prices.disks, volume_type)
+    }
+
+    pub fn eip_hourly(&self) -> Option<f64> {
+        self.scalar(|prices| prices.eip_hourly)
+    }
+
+    pub fn bandwidth_mbit_hourly(&self) -> Option<f64> {
+        self.scalar(|prices| prices.bandwidth_mbit_hourly)
+    }
+
+    pub fn traffic_gb(&self) -> Option<f64> {
+        self.scalar(|prices| prices.traffic_gb)
+    }
+
+    pub fn nat_hourly(&self, spec: &str) -> Option<f64> {
+        self.keyed(|prices| &prices.nat, spec)
+    }
+
+    pub fn cce_cluster_hourly(&self, flavor: &str) -> Option<f64> {
+        self.keyed(|prices| &prices.cce_clusters, flavor)
+    }
+}
+
+/// vCPUs and RAM (GiB) from a `<family>.<size>.<ram ratio>` flavor name,
+/// e.g. `s6.xlarge.2` is 4 vCPUs and 8 GiB.
+pub fn flavor_shape(flavor: &str) -> Option<(u32, u32)> {
+    let mut parts = flavor.trim().split('.');
+    let _family = parts.next().filter(|family| !family.is_empty())?;
+    let size = parts.next()?;
+    let ratio = parts.next()?.parse::<u32>().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let vcpus = match size {
+        "small" | "medium" => 1,
+        "large" => 2,
+        "xlarge" => 4,
+        other => other
+            .strip_suffix("xlarge")?
+            .parse::<u32>()
+            .ok()?
+            .checked_mul(4)?,
+    };
+    Some((vcpus, vcpus.checked_mul(ratio)?))
+}
+
+pub fn imported_price_table_path() -> Option<PathBuf> {
+    app_path(
+        AppDir::Data,
+        Path::new("pricing").join(IMPORTED_PRICE_TABLE_FILE),
+    )
+}
+
+/// The imported table when present and valid, otherwise the bundled one.
+pub fn load_price_table() -> LoadedPriceTable {
+    let bundled = |warnings| LoadedPriceTable {
+        table: PriceTable::bundled(),
+        source: PriceTableSource::Bundled,
+        warnings,
+    };
+    let Some(path) = imported_price_table_path().filter(|path| path.is_file()) else {
+        return bundled(Vec::new());
+    };
+    let loaded = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|raw| PriceTable::parse(&raw));
+    match loaded {
+        Ok(table) => LoadedPriceTable {
+            table,
+            source: PriceTableSource::Imported,
+            warnings: Vec::new(),
+        },
+        Err(err) => bundled(vec![format!(
+            "Ignoring imported price table at {}: {}",
+            path.display(),
+            err
+        )]),
+    }
+}
+
+/// Validate `raw` and store it as the imported table. Older versions are rejected.
+pub fn import_price_table(raw: &str, current: &PriceTable) -> Result<PriceTable, String> {
+    let table = PriceTable::parse(raw)?;
+    if table.version.trim() < current.version.trim() {
+        return Err(format!(
+            "Price table {} is older than the active table {}.",
+            table.version, current.version
+        ));
+    }
+    let path = imported_price_table_path()
+        .ok_or_else(|| "Could not resolve the app data directory.".to_string())?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    std::fs::write(&path, raw)
+        .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+    Ok(table)
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+struct EstimateBuilder<'a> {
+    table: &'a PriceTable,
+    prices: RegionPriceView<'a>,
+    region: String,
+    lines: Vec<PriceLine>,
+    warnings: Vec<String>,
+}
+
+impl<'a> EstimateBuilder<'a> {
+    fn new(table: &'a PriceTable, region: &str) -> Self {
+        Self {
+            table,
+            prices: table.for_region(region),
+            region: region.trim().to_string(),
+            lines: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn line(&mut self, item: String, quantity: u32, unit_hourly: Option<f64>) {
+        if unit_hourly.is_none() {
+            self.warnings
+                .push(format!("No price for {}; it is not in the total.", item));
+        }
+        self.lines.push(PriceLine {
+            item,
+            quantity,
+            unit_hourly: unit_hourly.map(|price| round_to(price, 6)),
+            hourly: unit_hourly.map(|price| round_to(price * f64::from(quantity), 6)),
+        });
+    }
+
+    fn disk(&mut self, label: &str, volume_type: &str, size: u32, quantity: u32) {
+        let unit = self
+            .prices
+            .disk_gib_hourly(volume_type)
+            .map(|price| price * f64::from(size));
+        self.line(
+            format!("{} {} {} GiB", label, volume_type, size),
+            quantity,
+            unit,
+        );
+    }
+
+    fn public_bandwidth(&mut self, bandwidth: &Bandwidth) {
+        self.line("EIP".to_string(), 1, self.prices.eip_hourly());
+        if bandwidth.share_type.eq_ignore_ascii_case("WHOLE") {
+            self.warnings
+                .push("Shared bandwidth is billed on the shared pool.".to_string());
+        } else if bandwidth.charge_mode.eq_ignore_ascii_case("bandwidth") {
+            let unit = self
+                .prices
+                .bandwidth_mbit_hourly()
+                .map(|price| price * f64::from(bandwidth.size));
+            self.line(format!("Bandwidth {} Mbit/s", bandwidth.size), 1, unit);
+        } else {
+            self.traffic_warning(bandwidth.size);
+        }
+    }
+
+    fn traffic_warning(&mut self, size: u32) {
+        let rate = self
+            .prices
+            .traffic_gb()
+            .map(|price| format!("{} {}/GB", price, self.table.currency))
+            .unwrap_or_else(|| "an unknown rate".to_string());
+        self.warnings.push(format!(
+            "Bandwidth ({} Mbit/s) is billed by traffic at {} and is not in the total.",
+            size, rate
+        ));
+    }
+
+    fn finish(self, source: PriceTableSource) -> PriceEstimate {
+        let hourly = self
+            .lines
+            .iter()
+            .filter_map(|line| line.hourly)
+            .sum::<f64>();
+        PriceEstimate {
+            region: self.region,
+            currency: self.table.currency.clone(),
+            table_version: self.table.version.clone(),
+            table_source: source,
+            lines: self.lines,
+            hourly: round_to(hourly, 4),
+            monthly: round_to(hourly * HOURS_PER_MONTH, 2),
+            warnings: self.warnings,
+        }
+    }
+}
+
+pub fn estimate_ecs(
+    loaded: &LoadedPriceTable,
+    region: &str,
+    request: &CreateEcsRequest,
+) -> PriceEstimate {
+    let server = &request.server;
+    let mut builder = EstimateBuilder::new(&loaded.table, region);
+    builder.warnings.extend(loaded.warnings.iter().cloned());
+    builder.line(
+        format!("ECS {}", server.flavor_ref),
+        1,
+        builder.prices.flavor_hourly(&server.flavor_ref),
+    );
+    builder.disk(
+        "System disk",
+        &server.root_volume.volumetype,
+        server.root_volume.size,
+        1,
+    );
+    for volume in &server.data_volumes {
+        builder.disk(
+            "Data disk",
+            &volume.volumetype,
+            volume.size,
+            volume.count.unwrap_or(1),
+        );
+    }
+    if let Some(publicip) = &server.publicip {
+        builder.public_bandwidth(&publicip.eip.bandwidth);
+    }
+    builder.finish(loaded.source)
+}
+
+pub fn estimate_cce_cluster(
+    loaded: &LoadedPriceTable,
+    region: &str,
+    request: &CceCreateClusterRequest,
+) -> PriceEstimate {
+    let mut builder = EstimateBuilder::new(&loaded.table, region);
+    builder.warnings.extend(loaded.warnings.iter().cloned());
+    builder.line(
+        format!("CCE cluster {}", request.spec.flavor),
+        1,
+        builder.prices.cce_cluster_hourly(&request.spec.flavor),
+    );
+    builder
+        .warnings
+        .push("Worker nodes are billed separately through node pools.".to_string());
+    builder.finish(loaded.source)
+}
+
+pub fn estimate_cce_node_pool(
+    loaded: &LoadedPriceTable,
+    region: &str,
+    request: &CceCreateNodePoolRequest,
+) -> PriceEstimate {
+    let template = &request.spec.node_template;
+    let nodes = request.spec.initial_node_count;
+    let mut builder = EstimateBuilder::new(&loaded.table, region);
+    builder.warnings.extend(loaded.warnings.iter().cloned());
+    builder.line(
+        format!("Node {}", template.flavor),
+        nodes,
+        builder.prices.flavor_hourly(&template.flavor),
+    );
+    builder.disk(
+        "Node system disk",
+        &template.root_volume.volumetype,
+        template.root_volume.size,
+        nodes,
+    );
+    for volume in &template.data_volumes {
+        builder.disk("Node data disk", &volume.volumetype, volume.size, nodes);
+    }
+    if nodes == 0 {
+        let per_node = builder
+            .lines
+            .iter()
+            .map(|line| line.unit_hourly)
+            .sum::<Option<f64>>();
+        if let Some(per_node) = per_node {
+            let per_node = round_to(per_node, 4);
+            builder.warnings.push(format!(
+                "The pool starts empty; each node adds {} {}/hour.",
+                per_node, loaded.table.currency
+            ));
+        }
+    }
+    builder.finish(loaded.source)
+}
+
+/// NAT gateway plus the traffic-billed EIP that the bootstrap creates for SNAT.
+pub fn estimate_nat_bootstrap(
+    loaded: &LoadedPriceTable,
+    region: &str,
+    spec: &str,
+    eip_bandwidth_size: u32,
+) -> PriceEstimate {
+    let mut builder = EstimateBuilder::new(&loaded.table, region);
+    builder.warnings.extend(loaded.warnings.iter().cloned());
+    builder.line(
+        format!("NAT gateway spec {}", spec),
+        1,
+        builder.prices.nat_hourly(spec),
+    );
+    builder.line("EIP".to_string(), 1, builder.prices.eip_hourly());
+    builder.traffic_warning(eip_bandwidth_size);
+    builder.finish(loaded.source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        estimate_cce_node_pool, estimate_ecs, estimate_nat_bootstrap, flavor_shape,
+        LoadedPriceTable, PriceTable, PriceTableSource,
+    };
+    use crate::api::models::cce::{
+        CceCreateNodePoolRequest, CceNodePoolCreateMetadata, CceNodePoolCreateSpec,
+        CceNodePoolTemplateSpec, CceNodePoolVolume,
+    };
+    use crate::api::models::ecs::{
+        Bandwidth, CreateEcsRequest, DataVolume, Eip, Nic, PublicIp, RootVolume, Server,
+    };
+
+    const TABLE: &str = r#"
+        version = "2026-01-01"
+        currency = "USD"
+
+        [default]
+        vcpu_hourly = 0.02
+        ram_gib_hourly = 0.005
+        eip_hourly = 0.005
+        bandwidth_mbit_hourly = 0.004
+        traffic_gb = 0.09
+
+        [default.flavors]
+        "s6.large.2" = 0.05
+
+        [default.disks]
+        GPSSD = 0.0001
+        SSD = 0.0002
+
+        [default.nat]
+        "1" = 0.05
+
+        [regions."sa-brazil-1"]
+        vcpu_hourly = 0.03
+
+        [regions."sa-brazil-1".flavors]
+        "s6.large.2" = 0.06
+    "#;
+
+    fn loaded() -> LoadedPriceTable {
+        LoadedPriceTable {
+            table: PriceTable::parse(TABLE).expect("table"),
+            source: PriceTableSource::Bundled,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn ecs_request(flavor: &str, charge_mode: &str) -> CreateEcsRequest {
+        CreateEcsRequest {
+            server: Server {
+                name: "ecs-test".to_string(),
+                image_ref: "image".to_string(),
+                flavor_ref: flavor.to_string(),
+                vpcid: "vpc".to_string(),
+                nics: vec![Nic {
+                    subnet_id: "subnet".to_string(),
+                }],
+                root_volume: RootVolume {
+                    volumetype: "GPSSD".to_string(),
+                    size: 40,
+                },
+                data_volumes: vec![DataVolume {
+                    volumetype: "SSD".to_string(),
+                    size: 100,
+                    count: Some(2),
+                    multiattach: None,
+                    hw_passthrough: None,
+                }],
+                publicip: Some(PublicIp {
+                    eip: Eip {
+                        ip_type: "5_bgp".to_string(),
+                        bandwidth: Bandwidth {
+                            size: 10,
+                            share_type: "PER".to_string(),
+                            charge_mode: charge_mode.to_string(),
+                        },
+                    },
+                }),
+                admin_pass: None,
+                security_groups: Vec::new(),
+                server_tags: Vec::new(),
+                extendparam: None,
+            },
+        }
+    }
+
+    #[test]
+    fn bundled_table_parses() {
+        let table = PriceTable::bundled();
+        assert!(!table.version.is_empty());
+        assert!(table.for_region("sa-brazil-1").nat_hourly("4").is_some());
+    }
+
+    #[test]
+    fn parse_rejects_negative_prices_and_missing_version() {
+        let negative = TABLE.replace("GPSSD = 0.0001", "GPSSD = -0.0001");
+        assert!(PriceTable::parse(&negative).is_err());
+        let unversioned = TABLE.replace("version = \"2026-01-01\"", "version = \"\"");
+        assert!(PriceTable::parse(&unversioned).is_err());
+    }
+
+    #[test]
+    fn flavor_shape_reads_size_and_ratio() {
+        assert_eq!(flavor_shape("s6.small.1"), Some((1, 1)));
+        assert_eq!(flavor_shape("c7.xlarge.2"), Some((4, 8)));
+        assert_eq!(flavor_shape("m7.4xlarge.8"), Some((16, 128)));
+        assert_eq!(flavor_shape("custom-flavor"), None);
+    }
+
+    #[test]
+    fn region_overrides_fall_back_to_defaults() {
+        let table = PriceTable::parse(TABLE).expect("table");
+        let brazil = table.for_region("sa-brazil-1");
+        assert_eq!(brazil.flavor_hourly("s6.large.2"), Some(0.06));
+        assert_eq!(brazil.disk_gib_hourly("gpssd"), Some(0.0001));
+        // 4 vCPUs at the regional rate plus 8 GiB at the default rate.
+        let shaped = brazil.flavor_hourly("c7.xlarge.2").expect("shape price");
+        assert!((shaped - 0.16).abs() < 1e-9);
+        assert_eq!(
+            table.for_region("eu-west-101").flavor_hourly("s6.large.2"),
+            Some(0.05)
+        );
+    }
+
+    #[test]
+    fn estimate_ecs_prices_flavor_disks_and_bandwidth() {
+        let estimate = estimate_ecs(
+            &loaded(),
+            "ap-southeast-1",
+            &ecs_request("s6.large.2", "bandwidth"),
+        );
+        // 0.05 + 40 * 0.0001 + 2 * 100 * 0.0002 + 0.005 + 10 * 0.004
+        assert!((estimate.hourly - 0.139).abs() < 1e-9);
+        assert!((estimate.monthly - 101.47).abs() < 1e-9);
+        assert_eq!(estimate.lines.len(), 5);
+        assert!(estimate.warnings.is_empty());
+
+        let traffic = estimate_ecs(
+            &loaded(),
+            "ap-southeast-1",
+            &ecs_request("s6.large.2", "traffic"),
+        );
+        assert!((traffic.hourly - 0.099).abs() < 1e-9);
+        assert_eq!(traffic.warnings.len(), 1);
+    }
+
+    #[test]
+    fn estimate_ecs_warns_on_unpriced_items() {
+        let estimate = estimate_ecs(
+            &loaded(),
+            "ap-southeast-1",
+            &ecs_request("bms-x", "traffic"),
+        );
+        assert!(estimate.lines[0].hourly.is_none());
+        assert!(estimate
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("ECS bms-x")));
+    }
+
+    #[test]
+    fn estimate_cce_node_pool_scales_with_node_count() {
+        let request = |nodes| CceCreateNodePoolRequest {
+            kind: "NodePool".to_string(),
+            api_version: "v3".to_string(),
+            metadata: CceNodePoolCreateMetadata {
+                name: "pool".to_string(),
+            },
+            spec: CceNodePoolCreateSpec {
+                node_pool_type: "vm".to_string(),
+                initial_node_count: nodes,
+                node_template: CceNodePoolTemplateSpec {
+                    flavor: "s6.large.2".to_string(),
+                    az: "sa-brazil-1a".to_string(),
+                    os: None,
+                    login: None,
+                    root_volume: CceNodePoolVolume {
+                        volumetype: "GPSSD".to_string(),
+                        size: 50,
+                        extend_param: None,
+                    },
+                    data_volumes: vec![CceNodePoolVolume {
+                        volumetype: "GPSSD".to_string(),
+                        size: 100,
+                        extend_param: None,
+                    }],
+                    node_nic_spec: None,
+                    billing_mode: 0,
+                    user_tags: Vec::new(),
+                    extend_param: None,
+                },
+            },
+        };
+        let three = estimate_cce_node_pool(&loaded(), "sa-brazil-1", &request(3));
+        // 3 * (0.06 + 50 * 0.0001 + 100 * 0.0001)
+        assert!((three.hourly - 0.225).abs() < 1e-9);
+
+        let empty = estimate_cce_node_pool(&loaded(), "sa-brazil-1", &request(0));
+        assert_eq!(empty.hourly, 0.0);
+        assert!(empty
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("0.075")));
+    }
+
+    #[test]
+    fn estimate_nat_bootstrap_adds_eip_reservation() {
+        let estimate = estimate_nat_bootstrap(&loaded(), "sa-brazil-1", "1", 100);
+        assert!((estimate.hourly - 0.055).abs() < 1e-9);
+        assert_eq!(estimate.warnings.len(), 1);
+    }
+}
@@ -0,0 +1,389 @@
+use crate::api::models::ecs::CreateEcsRequest;
+use crate::api::models::quotas::{
+    CceQuotaResponse, EcsLimitsResponse, EvsQuotaSetResponse, VpcQuotaResponse,
+};
+use crate::pricing::flavor_shape;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaService {
+    Ecs,
+    Evs,
+    Vpc,
+    Cce,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaResource {
+    EcsInstances,
+    EcsCores,
+    EcsRamMib,
+    EvsVolumes,
+    EvsGigabytes,
+    VpcEips,
+    VpcSecurityGroups,
+    VpcSubnets,
+    CceClusters,
+}
+
+impl QuotaResource {
+    pub fn service(self) -> QuotaService {
+        match self {
+            Self::EcsInstances | Self::EcsCores | Self::EcsRamMib => QuotaService::Ecs,
+            Self::EvsVolumes | Self::EvsGigabytes => QuotaService::Evs,
+            Self::VpcEips | Self::VpcSecurityGroups | Self::VpcSubnets => QuotaService::Vpc,
+            Self::CceClusters => QuotaService::Cce,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::EcsInstances => "ECS instances",
+            Self::EcsCores => "ECS vCPUs",
+            Self::EcsRamMib => "ECS RAM (MiB)",
+            Self::EvsVolumes => "EVS disks",
+            Self::EvsGigabytes => "EVS capacity (GiB)",
+            Self::VpcEips => "EIPs",
+            Self::VpcSecurityGroups => "Security groups",
+            Self::VpcSubnets => "Subnets",
+            Self::CceClusters => "CCE clusters",
+        }
+    }
+}
+
+/// One quota with its usage; `limit: None` means the service reports no limit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuotaUsage {
+    pub resource: QuotaResource,
+    pub label: &'static str,
+    pub used: u64,
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+}
+
+impl QuotaUsage {
+    fn new(resource: QuotaResource, used: i64, limit: i64) -> Self {
+        let used = used.max(0) as u64;
+        // Huawei Cloud reports unlimited quotas as -1.
+        let limit = (limit >= 0).then_some(limit as u64);
+        Self {
+            resource,
+            label: resource.label(),
+            used,
+            limit,
+            remaining: limit.map(|limit| limit.saturating_sub(used)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaReport {
+    pub region: String,
+    pub quotas: Vec<QuotaUsage>,
+    /// Services whose quota lookup failed; their rows are missing from `quotas`.
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuotaShortfall {
+    pub resource: QuotaResource,
+    pub label: &'static str,
+    pub requested: u64,
+    pub used: u64,
+    pub limit: u64,
+    pub remaining: u64,
+}
+
+/// Error body returned by create commands when the pre-flight check fails.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaCheckError {
+    pub error: &'static str,
+    pub message: String,
+    pub shortfalls: Vec<QuotaShortfall>,
+}
+
+pub fn ecs_quota_usages(response: &EcsLimitsResponse) -> Vec<QuotaUsage> {
+    let limits = &response.absolute;
+    [
+        (
+            QuotaResource::EcsInstances,
+            limits.total_instances_used,
+            limits.max_total_instances,
+        ),
+        (
+            QuotaResource::EcsCores,
+            limits.total_cores_used,
+            limits.max_total_cores,
+        ),
+        (
+            QuotaResource::EcsRamMib,
+            limits.total_ram_used,
+            limits.max_total_ram_size,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(resource, used, limit)| Some(QuotaUsage::new(resource, used?, limit?)))
+    .collect()
+}
+
+pub fn evs_quota_usages(response: &EvsQuotaSetResponse) -> Vec<QuotaUsage> {
+    let quota_set = &response.quota_set;
+    [
+        (QuotaResource::EvsVolumes, quota_set.volumes.as_ref()),
+        (QuotaResource::EvsGigabytes, quota_set.gigabytes.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(resource, usage)| {
+        let usage = usage?;
+        Some(QuotaUsage::new(
+            resource,
+            usage.in_use + usage.reserved,
+            usage.limit,
+        ))
+    })
+    .collect()
+}
+
+pub fn vpc_quota_usages(response: &VpcQuotaResponse) -> Vec<QuotaUsage> {
+    response
+        .quotas
+        .resources
+        .iter()
+        .filter_map(|entry| {
+            let resource = match entry.resource_type.as_str() {
+                "publicIp" => QuotaResource::VpcEips,
+                "securityGroup" => QuotaResource::VpcSecurityGroups,
+                "subnet" => QuotaResource::VpcSubnets,
+                _ => return None,
+            };
+            Some(QuotaUsage::new(resource, entry.used, entry.quota))
+        })
+        .collect()
+}
+
+pub fn cce_quota_usages(response: &CceQuotaResponse) -> Vec<QuotaUsage> {
+    response
+        .quotas
+        .iter()
+        .filter(|entry| entry.quota_key == "cluster")
+        .map(|entry| QuotaUsage::new(QuotaResource::CceClusters, entry.used, entry.quota_limit))
+        .collect()
+}
+
+/// Quota demands of one ECS create: the server, its disks and an optional EIP.
+/// vCPU and RAM demands are skipped when the flavor name does not encode its shape.
+pub fn ecs_quota_demands(request: &CreateEcsRequest) -> Vec<(QuotaResource, u64)> {
+    let server = &request.server;
+    let mut demands = vec![(QuotaResource::EcsInstances, 1)];
+    if let Some((vcpus, ram_gib)) = flavor_shape(&server.flavor_ref) {
+        demands.push((QuotaResource::EcsCores, u64::from(vcpus)));
+        demands.push((QuotaResource::EcsRamMib, u64::from(ram_gib) * 1024));
+    }
+    let mut volumes = 1;
+    let mut gigabytes = u64::from(server.root_volume.size);
+    for volume in &server.data_volumes {
+        let count = u64::from(volume.count.unwrap_or(1));
+        volumes += count;
+        gigabytes += u64::from(volume.size) * count;
+    }
+    demands.push((QuotaResource::EvsVolumes, volumes));
+    demands.push((QuotaResource::EvsGigabytes, gigabytes));
+    if server.publicip.is_some() {
+        demands.push((QuotaResource::VpcEips, 1));
+    }
+    demands
+}
+
+/// Quota demands of `nodes` new CCE nodes, each one ECS server with one disk per
+/// entry of `volume_sizes`. vCPU and RAM demands are skipped when the flavor name
+/// does not encode its shape.
+pub fn cce_node_quota_demands(
+    flavor: &str,
+    volume_sizes: &[u32],
+    nodes: u32,
+) -> Vec<(QuotaResource, u64)> {
+    if nodes == 0 {
+        return Vec::new();
+    }
+    let nodes = u64::from(nodes);
+    let mut demands = vec![(QuotaResource::EcsInstances, nodes)];
+    if let Some((vcpus, ram_gib)) = flavor_shape(flavor) {
+        demands.push((QuotaResource::EcsCores, u64::from(vcpus) * nodes));
+        demands.push((QuotaResource::EcsRamMib, u64::from(ram_gib) * 1024 * nodes));
+    }
+    let gigabytes = volume_sizes
+        .iter()
+        .map(|size| u64::from(*size))
+        .sum::<u64>();
+    demands.push((QuotaResource::EvsVolumes, volume_sizes.len() as u64 * nodes));
+    demands.push((QuotaResource::EvsGigabytes, gigabytes * nodes));
+    demands
+}
+
+/// Services that must be queried to check `demands`, without duplicates.
+pub fn quota_services(demands: &[(QuotaResource, u64)]) -> Vec<QuotaService> {
+    let mut services = Vec::new();
+    for (resource, _) in demands {
+        let service = resource.service();
+        if !services.contains(&service) {
+            services.push(service);
+        }
+    }
+    services
+}
+
+/// Compare requested amounts with remaining quota. Resources with no reported
+/// usage or no limit are treated as available.
+pub fn quota_shortfalls(
+    usages: &[QuotaUsage],
+    demands: &[(QuotaResource, u64)],
+) -> Vec<QuotaShortfall> {
+    let mut shortfalls = Vec::new();
+    for usage in usages {
+        let requested = demands
+            .iter()
+            .filter(|(resource, _)| *resource == usage.resource)
+            .map(|(_, amount)| amount)
+            .sum::<u64>();
+        let (Some(limit), Some(remaining)) = (usage.limit, usage.remaining) else {
+            continue;
+        };
+        if requested <= remaining {
+            continue;
+        }
+        shortfalls.push(QuotaShortfall {
+            resource: usage.resource,
+            label: usage.label,
+            requested,
+            used: usage.used,
+            limit,
+            remaining,
+        });
+    }
+    shortfalls
+}
+
+pub fn quota_check_error(shortfalls: Vec<QuotaShortfall>) -> QuotaCheckError {
+    let details = shortfalls
+        .iter()
+        .map(|item| {
+            format!(
+                "{} needs {} but only {} of {} remain",
+                item.label, item.requested, item.remaining, item.limit
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    QuotaCheckError {
+        error: "quota_exceeded",
+        message: format!("Quota pre-flight check failed: {}.", details),
+        shortfalls,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cce_node_quota_demands, ecs_quota_demands, evs_quota_usages, quota_services,
+        quota_shortfalls, QuotaResource, QuotaService, QuotaUsage,
+    };
+    use crate::api::models::ecs::{CreateEcsRequest, DataVolume, Nic, RootVolume, Server};
+    use crate::api::models::quotas::{EvsQuotaSet, EvsQuotaSetResponse, EvsQuotaUsage};
+
+    #[test]
+    fn evs_usage_counts_reserved_and_maps_unlimited() {
+        let response = EvsQuotaSetResponse {
+            quota_set: EvsQuotaSet {
+                volumes: Some(EvsQuotaUsage {
+                    in_use: 3,
+                    limit: -1,
+                    reserved: 0,
+                }),
+                gigabytes: Some(EvsQuotaUsage {
+                    in_use: 90,
+                    limit: 100,
+                    reserved: 5,
+                }),
+            },
+        };
+        let usages = evs_quota_usages(&response);
+        assert_eq!(usages[0].limit, None);
+        assert_eq!(usages[0].remaining, None);
+        assert_eq!(usages[1].used, 95);
+        assert_eq!(usages[1].remaining, Some(5));
+    }
+
+    #[test]
+    fn ecs_demands_cover_flavor_disks_and_eip() {
+        let request = CreateEcsRequest {
+            server: Server {
+                name: "web".to_string(),
+                image_ref: "img".to_string(),
+                flavor_ref: "s6.xlarge.2".to_string(),
+                vpcid: "vpc".to_string(),
+                nics: vec![Nic {
+                    subnet_id: "subnet".to_string(),
+                }],
+                root_volume: RootVolume {
+                    volumetype: "GPSSD".to_string(),
+                    size: 40,
+                },
+                data_volumes: vec![DataVolume {
+                    volumetype: "SSD".to_string(),
+                    size: 100,
+                    count: Some(2),
+                    multiattach: None,
+                    hw_passthrough: None,
+                }],
+                publicip: None,
+                admin_pass: None,
+                security_groups: Vec::new(),
+                server_tags: Vec::new(),
+                extendparam: None,
+            },
+        };
+        let demands = ecs_quota_demands(&request);
+        assert!(demands.contains(&(QuotaResource::EcsCores, 4)));
+        assert!(demands.contains(&(QuotaResource::EcsRamMib, 8192)));
+        assert!(demands.contains(&(QuotaResource::EvsVolumes, 3)));
+        assert!(demands.contains(&(QuotaResource::EvsGigabytes, 240)));
+        assert!(!demands
+            .iter()
+            .any(|(resource, _)| *resource == QuotaResource::VpcEips));
+        assert_eq!(
+            quota_services(&demands),
+            vec![QuotaService::Ecs, QuotaService::Evs]
+        );
+    }
+
+    #[test]
+    fn cce_node_demands_scale_with_the_node_count() {
+        let demands = cce_node_quota_demands("c7.large.2", &[50, 100], 3);
+        assert!(demands.contains(&(QuotaResource::EcsInstances, 3)));
+        assert!(demands.contains(&(QuotaResource::EcsCores, 6)));
+        assert!(demands.contains(&(QuotaResource::EcsRamMib, 12288)));
+        assert!(demands.contains(&(QuotaResource::EvsVolumes, 6)));
+        assert!(demands.contains(&(QuotaResource::EvsGigabytes, 450)));
+        assert!(cce_node_quota_demands("c7.large.2", &[50, 100], 0).is_empty());
+    }
+
+    #[test]
+    fn shortfalls_sum_demands_and_skip_unlimited() {
+        let usages = vec![
+            QuotaUsage::new(QuotaResource::VpcEips, 9, 10),
+            QuotaUsage::new(QuotaResource::EcsInstances, 50, -1),
+        ];
+        let demands = vec![
+            (QuotaResource::VpcEips, 1),
+            (QuotaResource::VpcEips, 1),
+            (QuotaResource::EcsInstances, 1),
+        ];
+        let shortfalls = quota_shortfalls(&usages, &demands);
+        assert_eq!(shortfalls.len(), 1);
+        assert_eq!(shortfalls[0].resource, QuotaResource::VpcEips);
+        assert_eq!(shortfalls[0].requested, 2);
+        assert_eq!(shortfalls[0].remaining, 1);
+    }
+}
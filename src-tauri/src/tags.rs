@@ -0,0 +1,385 @@
+use crate::api::models::tags::{ResourceTag, TagFilter};
+use crate::app_dirs::{app_path, AppDir};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+/// ECS allows the fewest tags per resource; the other services accept at least this many.
+pub const MAX_RESOURCE_TAGS: usize = 10;
+const TAG_KEY_MAX: usize = 128;
+const TAG_VALUE_MAX: usize = 255;
+const SYSTEM_TAG_PREFIX: &str = "_sys_";
+const TAG_POLICY_FILE: &str = "tag-policy.json";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagInput {
+    pub key: String,
+    /// Empty or missing means an empty value on create, and any value in filters.
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaggableResource {
+    Ecs,
+    Evs,
+    Eip,
+    NatGateway,
+    CceCluster,
+    ObsBucket,
+}
+
+impl TaggableResource {
+    pub fn label(self) -> &'static str {
+        match self {
+            TaggableResource::Ecs => "ECS",
+            TaggableResource::Evs => "EVS disk",
+            TaggableResource::Eip => "EIP",
+            TaggableResource::NatGateway => "NAT gateway",
+            TaggableResource::CceCluster => "CCE cluster",
+            TaggableResource::ObsBucket => "OBS bucket",
+        }
+    }
+}
+
+/// What a tag set is checked for against the tag policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagPolicyCheck {
+    /// The full tag set of a new resource: required keys and allowed values.
+    Create,
+    /// Tags added to an existing resource, which keeps its other tags: allowed values only.
+    Add,
+}
+
+/// Rules every create and tag update must satisfy before it is sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagPolicy {
+    #[serde(default)]
+    pub required_keys: Vec<String>,
+    /// Keys listed here only accept the given values (case-sensitive).
+    #[serde(default)]
+    pub allowed_values: BTreeMap<String, Vec<String>>,
+}
+
+fn normalize_tag_key(key: &str) -> Result<String, String> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("Tag key is required.".to_string());
+    }
+    if key.chars().count() > TAG_KEY_MAX {
+        return Err(format!(
+            "Tag key '{}' is longer than {} characters.",
+            key, TAG_KEY_MAX
+        ));
+    }
+    if key.starts_with(SYSTEM_TAG_PREFIX) {
+        return Err(format!(
+            "Tag key '{}' uses the reserved '{}' prefix.",
+            key, SYSTEM_TAG_PREFIX
+        ));
+    }
+    if key.chars().any(|ch| ch.is_control() || ch == '=') {
+        return Err(format!(
+            "Tag key '{}' contains '=' or a control character.",
+            key
+        ));
+    }
+    Ok(key.to_string())
+}
+
+fn normalize_tag_value(key: &str, value: Option<&str>) -> Result<String, String> {
+    let value = value.map(str::trim).unwrap_or("");
+    if value.chars().count() > TAG_VALUE_MAX {
+        return Err(format!(
+            "Tag '{}' value is longer than {} characters.",
+            key, TAG_VALUE_MAX
+        ));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(format!("Tag '{}' value contains a control character.", key));
+    }
+    Ok(value.to_string())
+}
+
+/// Trim and validate create-time tags. Duplicate keys are rejected.
+pub fn normalize_tags(input: Option<Vec<TagInput>>) -> Result<Vec<ResourceTag>, String> {
+    let input = input.unwrap_or_default();
+    if input.len() > MAX_RESOURCE_TAGS {
+        return Err(format!(
+            "At most {} tags are allowed per resource.",
+            MAX_RESOURCE_TAGS
+        ));
+    }
+    let mut seen = HashSet::new();
+    input
+        .into_iter()
+        .map(|tag| {
+            let key = normalize_tag_key(&tag.key)?;
+            if !seen.insert(key.clone()) {
+                return Err(format!("Tag key '{}' is repeated.", key));
+            }
+            let value = normalize_tag_value(&key, tag.value.as_deref())?;
+            Ok(ResourceTag { key, value })
+        })
+        .collect()
+}
+
+/// Trim, validate and de-duplicate tag keys for removal.
+pub fn normalize_tag_keys(input: Vec<String>) -> Result<Vec<String>, String> {
+    let mut seen = HashSet::new();
+    let mut keys = Vec::new();
+    for key in input {
+        let key = normalize_tag_key(&key)?;
+        if seen.insert(key.clone()) {
+            keys.push(key);
+        }
+    }
+    Ok(keys)
+}
+
+/// Build list filters; repeated keys widen the accepted values for that key.
+pub fn normalize_tag_filters(input: Option<Vec<TagInput>>) -> Result<Vec<TagFilter>, String> {
+    let mut filters: Vec<TagFilter> = Vec::new();
+    for tag in input.unwrap_or_default() {
+        let key = normalize_tag_key(&tag.key)?;
+        let value = normalize_tag_value(&key, tag.value.as_deref())?;
+        let index = match filters.iter().position(|filter| filter.key == key) {
+            Some(index) => index,
+            None => {
+                filters.push(TagFilter {
+                    key,
+                    values: Vec::new(),
+                });
+                filters.len() - 1
+            }
+        };
+        if !value.is_empty() && !filters[index].values.contains(&value) {
+            filters[index].values.push(value);
+        }
+    }
+    Ok(filters)
+}
+
+/// Every filter key must be present with one of its values (any value when none are given).
+pub fn matches_tag_filters(tags: &[ResourceTag], filters: &[TagFilter]) -> bool {
+    filters.iter().all(|filter| {
+        tags.iter().any(|tag| {
+            tag.key == filter.key
+                && (filter.values.is_empty() || filter.values.contains(&tag.value))
+        })
+    })
+}
+
+/// Overlay `updates` on `existing`, replacing values of keys that already exist.
+pub fn merge_tags(mut existing: Vec<ResourceTag>, updates: &[ResourceTag]) -> Vec<ResourceTag> {
+    for update in updates {
+        match existing.iter_mut().find(|tag| tag.key == update.key) {
+            Some(tag) => tag.value = update.value.clone(),
+            None => existing.push(update.clone()),
+        }
+    }
+    existing
+}
+
+impl TagPolicy {
+    /// Trim and validate keys and values so stored policies stay canonical.
+    pub fn normalized(self) -> Result<Self, String> {
+        let required_keys = normalize_tag_keys(self.required_keys)?;
+        let mut allowed_values = BTreeMap::new();
+        for (key, values) in self.allowed_values {
+            let key = normalize_tag_key(&key)?;
+            let mut normalized = Vec::new();
+            for value in values {
+                let value = normalize_tag_value(&key, Some(&value))?;
+                if !normalized.contains(&value) {
+                    normalized.push(value);
+                }
+            }
+            if normalized.is_empty() {
+                return Err(format!("Tag policy for '{}' allows no values.", key));
+            }
+            allowed_values.insert(key, normalized);
+        }
+        Ok(Self {
+            required_keys,
+            allowed_values,
+        })
+    }
+
+    pub fn validate(&self, tags: &[ResourceTag], check: TagPolicyCheck) -> Result<(), String> {
+        let missing = self
+            .required_keys
+            .iter()
+            .filter(|key| !tags.iter().any(|tag| &tag.key == *key))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if check == TagPolicyCheck::Create && !missing.is_empty() {
+            return Err(format!("Tag policy requires tags: {}.", missing.join(", ")));
+        }
+        for tag in tags {
+            if let Some(allowed) = self.allowed_values.get(&tag.key) {
+                if !allowed.contains(&tag.value) {
+                    return Err(format!(
+                        "Tag '{}' must be one of: {}.",
+                        tag.key,
+                        allowed.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse to strip tags the policy requires.
+    pub fn check_removal(&self, keys: &[String]) -> Result<(), String> {
+        match keys.iter().find(|key| self.required_keys.contains(key)) {
+            Some(key) => Err(format!("Tag '{}' is required by the tag policy.", key)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn tag_policy_path() -> Option<PathBuf> {
+    app_path(AppDir::Config, TAG_POLICY_FILE)
+}
+
+/// The stored policy, or an empty one when none has been saved.
+pub fn load_tag_policy() -> Result<TagPolicy, String> {
+    let Some(path) = tag_policy_path().filter(|path| path.is_file()) else {
+        return Ok(TagPolicy::default());
+    };
+    let raw = std::fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+    serde_json::from_str(&raw)
+        .map_err(|err| format!("Invalid tag policy at {}: {}", path.display(), err))
+}
+
+pub fn save_tag_policy(policy: &TagPolicy) -> Result<(), String> {
+    let path = tag_policy_path()
+        .ok_or_else(|| "Could not resolve the app config directory.".to_string())?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    let raw = serde_json::to_string_pretty(policy).map_err(|err| err.to_string())?;
+    std::fs::write(&path, raw).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        matches_tag_filters, merge_tags, normalize_tag_filters, normalize_tag_keys, normalize_tags,
+        TagInput, TagPolicy, TagPolicyCheck,
+    };
+    use crate::api::models::tags::ResourceTag;
+    use std::collections::BTreeMap;
+
+    fn input(key: &str, value: Option<&str>) -> TagInput {
+        TagInput {
+            key: key.to_string(),
+            value: value.map(str::to_string),
+        }
+    }
+
+    fn tag(key: &str, value: &str) -> ResourceTag {
+        ResourceTag {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn normalize_tags_trims_and_rejects_bad_input() {
+        let tags = normalize_tags(Some(vec![
+            input(" team ", Some(" core ")),
+            input("env", None),
+        ]))
+        .expect("valid tags");
+        assert_eq!(tags, vec![tag("team", "core"), tag("env", "")]);
+
+        assert!(normalize_tags(Some(vec![input("team", None), input("team", None)])).is_err());
+        assert!(normalize_tags(Some(vec![input("  ", None)])).is_err());
+        assert!(normalize_tags(Some(vec![input("a=b", None)])).is_err());
+        assert!(normalize_tags(Some(vec![input("_sys_owner", None)])).is_err());
+        let too_many = (0..11)
+            .map(|index| input(&format!("k{index}"), None))
+            .collect();
+        assert!(normalize_tags(Some(too_many)).is_err());
+        assert_eq!(normalize_tags(None), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn tag_filters_group_values_by_key() {
+        let filters = normalize_tag_filters(Some(vec![
+            input("env", Some("dev")),
+            input("env", Some("qa")),
+            input("team", None),
+        ]))
+        .expect("filters");
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters[0].values, vec!["dev", "qa"]);
+        assert!(filters[1].values.is_empty());
+
+        assert!(matches_tag_filters(
+            &[tag("env", "qa"), tag("team", "core")],
+            &filters
+        ));
+        assert!(!matches_tag_filters(
+            &[tag("env", "prod"), tag("team", "core")],
+            &filters
+        ));
+        assert!(!matches_tag_filters(&[tag("env", "dev")], &filters));
+        assert!(matches_tag_filters(&[], &[]));
+    }
+
+    #[test]
+    fn tag_policy_checks_required_keys_and_allowed_values() {
+        let policy = TagPolicy {
+            required_keys: vec![" owner ".to_string()],
+            allowed_values: BTreeMap::from([(
+                "env".to_string(),
+                vec!["dev".to_string(), "prod".to_string()],
+            )]),
+        }
+        .normalized()
+        .expect("policy");
+        assert_eq!(policy.required_keys, vec!["owner"]);
+
+        let create = TagPolicyCheck::Create;
+        assert!(policy.validate(&[tag("owner", "ana")], create).is_ok());
+        assert!(policy
+            .validate(&[tag("owner", "ana"), tag("env", "prod")], create)
+            .is_ok());
+        let missing = policy.validate(&[tag("env", "dev")], create).unwrap_err();
+        assert!(missing.contains("owner"));
+        assert!(policy
+            .validate(&[tag("owner", "ana"), tag("env", "test")], create)
+            .is_err());
+
+        let add = TagPolicyCheck::Add;
+        assert!(policy.validate(&[tag("env", "dev")], add).is_ok());
+        assert!(policy.validate(&[tag("env", "test")], add).is_err());
+
+        assert!(policy.check_removal(&["env".to_string()]).is_ok());
+        assert!(policy.check_removal(&["owner".to_string()]).is_err());
+        assert!(TagPolicy::default().normalized().is_ok());
+    }
+
+    #[test]
+    fn merge_tags_overwrites_existing_keys() {
+        let merged = merge_tags(
+            vec![tag("env", "dev"), tag("team", "core")],
+            &[tag("env", "prod"), tag("owner", "ana")],
+        );
+        assert_eq!(
+            merged,
+            vec![tag("env", "prod"), tag("team", "core"), tag("owner", "ana")]
+        );
+        assert_eq!(
+            normalize_tag_keys(vec!["env".to_string(), " env ".to_string()]),
+            Ok(vec!["env".to_string()])
+        );
+    }
+}
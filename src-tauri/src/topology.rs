@@ -0,0 +1,427 @@
+use crate::api::models::cce::CceCluster;
+use crate::api::models::ecs::EcsServer;
+use crate::api::models::eip::PublicIp;
+use crate::api::models::nat::NatGateway;
+use crate::api::models::vpc::{Subnet, Vpc};
+use crate::validators::{ipv4_cidr_contains, parse_ipv4_cidr};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopologyNodeKind {
+    Vpc,
+    Subnet,
+    Ecs,
+    Eip,
+    NatGateway,
+    CceCluster,
+}
+
+impl TopologyNodeKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            TopologyNodeKind::Vpc => "vpc",
+            TopologyNodeKind::Subnet => "subnet",
+            TopologyNodeKind::Ecs => "ecs",
+            TopologyNodeKind::Eip => "eip",
+            TopologyNodeKind::NatGateway => "nat",
+            TopologyNodeKind::CceCluster => "cce",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TopologyNode {
+    /// Graph-unique ID: "<kind prefix>:<cloud resource id>".
+    pub id: String,
+    pub kind: TopologyNodeKind,
+    pub resource_id: String,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub details: Value,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TopologyEdge {
+    pub from: String,
+    pub to: String,
+    /// "contains" (VPC -> subnet), "nic" (subnet -> ECS), "bound" (EIP -> ECS/NAT),
+    /// "gateway" (subnet/VPC -> NAT) or "hosts" (subnet/VPC -> CCE cluster).
+    pub relation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct NetworkTopology {
+    pub nodes: Vec<TopologyNode>,
+    pub edges: Vec<TopologyEdge>,
+    /// References that could not be resolved, or listings that failed to load.
+    pub warnings: Vec<String>,
+}
+
+pub struct TopologyInputs<'a> {
+    pub vpcs: &'a [Vpc],
+    pub subnets: &'a [Subnet],
+    pub servers: &'a [EcsServer],
+    pub eips: &'a [PublicIp],
+    pub nat_gateways: &'a [NatGateway],
+    pub clusters: &'a [CceCluster],
+}
+
+fn node_id(kind: TopologyNodeKind, resource_id: &str) -> String {
+    format!("{}:{}", kind.prefix(), resource_id)
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+struct GraphBuilder {
+    topology: NetworkTopology,
+    node_ids: HashSet<String>,
+}
+
+impl GraphBuilder {
+    fn node(
+        &mut self,
+        kind: TopologyNodeKind,
+        resource_id: &str,
+        name: Option<&str>,
+        status: Option<&str>,
+        details: Value,
+    ) {
+        let id = node_id(kind, resource_id);
+        if !self.node_ids.insert(id.clone()) {
+            return;
+        }
+        self.topology.nodes.push(TopologyNode {
+            id,
+            kind,
+            resource_id: resource_id.to_string(),
+            name: non_empty(name).map(str::to_string),
+            status: non_empty(status).map(str::to_string),
+            details,
+        });
+    }
+
+    fn edge(&mut self, from: String, to: String, relation: &str) {
+        let edge = TopologyEdge {
+            from,
+            to,
+            relation: relation.to_string(),
+        };
+        if !self.topology.edges.contains(&edge) {
+            self.topology.edges.push(edge);
+        }
+    }
+
+    fn has(&self, kind: TopologyNodeKind, resource_id: &str) -> bool {
+        self.node_ids.contains(&node_id(kind, resource_id))
+    }
+
+    fn warn(&mut self, message: String) {
+        self.topology.warnings.push(message);
+    }
+}
+
+/// Join the network-related listings of one region into a single graph.
+pub fn build_network_topology(inputs: &TopologyInputs<'_>) -> NetworkTopology {
+    let mut graph = GraphBuilder {
+        topology: NetworkTopology::default(),
+        node_ids: HashSet::new(),
+    };
+
+    for vpc in inputs.vpcs {
+        graph.node(
+            TopologyNodeKind::Vpc,
+            &vpc.id,
+            Some(&vpc.name),
+            vpc.status.as_deref(),
+            json!({
+                "cidr": vpc.cidr,
+                "enterprise_project_id": vpc.enterprise_project_id,
+            }),
+        );
+    }
+
+    // NAT gateways and CCE clusters reference subnets by their neutron network ID,
+    // which matches the VPC v1 subnet ID in Huawei Cloud but is tracked separately here.
+    let mut subnet_by_network_id: HashMap<&str, &Subnet> = HashMap::new();
+    for subnet in inputs.subnets {
+        subnet_by_network_id.insert(subnet.id.as_str(), subnet);
+        if let Some(network_id) = non_empty(subnet.neutron_network_id.as_deref()) {
+            subnet_by_network_id.insert(network_id, subnet);
+        }
+        graph.node(
+            TopologyNodeKind::Subnet,
+            &subnet.id,
+            Some(&subnet.name),
+            subnet.status.as_deref(),
+            json!({
+                "cidr": subnet.cidr,
+                "gateway_ip": subnet.gateway_ip,
+                "availability_zone": subnet.availability_zone,
+                "primary_dns": subnet.primary_dns,
+                "secondary_dns": subnet.secondary_dns,
+            }),
+        );
+        match non_empty(subnet.vpc_id.as_deref()) {
+            Some(vpc_id) if graph.has(TopologyNodeKind::Vpc, vpc_id) => graph.edge(
+                node_id(TopologyNodeKind::Vpc, vpc_id),
+                node_id(TopologyNodeKind::Subnet, &subnet.id),
+                "contains",
+            ),
+            Some(vpc_id) => graph.warn(format!(
+                "Subnet {} references unknown VPC {}.",
+                subnet.id, vpc_id
+            )),
+            None => {}
+        }
+    }
+
+    let mut server_by_port_id: HashMap<&str, &str> = HashMap::new();
+    for server in inputs.servers {
+        let Some(server_id) = non_empty(server.id.as_deref()) else {
+            continue;
+        };
+        graph.node(
+            TopologyNodeKind::Ecs,
+            server_id,
+            server.name.as_deref(),
+            server.status.as_deref(),
+            json!({
+                "availability_zone": server.availability_zone,
+                "flavor": server.flavor.as_ref().and_then(|flavor| flavor.id.clone()),
+            }),
+        );
+
+        for (vpc_id, addresses) in &server.addresses {
+            for address in addresses {
+                if let Some(port_id) = non_empty(address.port_id.as_deref()) {
+                    server_by_port_id.insert(port_id, server_id);
+                }
+                if non_empty(address.ip_type.as_deref()) == Some("floating") {
+                    continue;
+                }
+                let Some(ip) = non_empty(address.addr.as_deref())
+                    .and_then(|addr| addr.parse::<std::net::Ipv4Addr>().ok())
+                else {
+                    continue;
+                };
+                let subnet = inputs.subnets.iter().find(|subnet| {
+                    subnet.vpc_id.as_deref() == Some(vpc_id.as_str())
+                        && parse_ipv4_cidr(&subnet.cidr)
+                            .map(|cidr| ipv4_cidr_contains(cidr, ip))
+                            .unwrap_or(false)
+                });
+                match subnet {
+                    Some(subnet) => graph.edge(
+                        node_id(TopologyNodeKind::Subnet, &subnet.id),
+                        node_id(TopologyNodeKind::Ecs, server_id),
+                        "nic",
+                    ),
+                    None => graph.warn(format!(
+                        "ECS {} has NIC {} in VPC {} outside every known subnet.",
+                        server_id, ip, vpc_id
+                    )),
+                }
+            }
+        }
+    }
+
+    for nat in inputs.nat_gateways {
+        let Some(nat_id) = non_empty(nat.id.as_deref()) else {
+            continue;
+        };
+        graph.node(
+            TopologyNodeKind::NatGateway,
+            nat_id,
+            nat.name.as_deref(),
+            nat.status.as_deref(),
+            json!({ "spec": nat.spec }),
+        );
+        let subnet = non_empty(nat.internal_network_id.as_deref())
+            .and_then(|network_id| subnet_by_network_id.get(network_id));
+        match (subnet, non_empty(nat.router_id.as_deref())) {
+            (Some(subnet), _) => graph.edge(
+                node_id(TopologyNodeKind::Subnet, &subnet.id),
+                node_id(TopologyNodeKind::NatGateway, nat_id),
+                "gateway",
+            ),
+            (None, Some(vpc_id)) if graph.has(TopologyNodeKind::Vpc, vpc_id) => graph.edge(
+                node_id(TopologyNodeKind::Vpc, vpc_id),
+                node_id(TopologyNodeKind::NatGateway, nat_id),
+                "gateway",
+            ),
+            _ => graph.warn(format!(
+                "NAT gateway {} is not attached to a known VPC or subnet.",
+                nat_id
+            )),
+        }
+    }
+
+    for cluster in inputs.clusters {
+        let Some(cluster_id) = non_empty(cluster.metadata.uid.as_deref()) else {
+            continue;
+        };
+        let host_network = cluster.spec.host_network.as_ref();
+        let host_vpc = host_network.and_then(|network| non_empty(network.vpc.as_deref()));
+        let host_subnet = host_network.and_then(|network| non_empty(network.subnet.as_deref()));
+        graph.node(
+            TopologyNodeKind::CceCluster,
+            cluster_id,
+            cluster.metadata.name.as_deref(),
+            cluster.status.phase.as_deref(),
+            json!({
+                "version": cluster.spec.version,
+                "flavor": cluster.spec.flavor,
+                "container_network": cluster.spec.container_network,
+            }),
+        );
+        let subnet = host_subnet.and_then(|network_id| subnet_by_network_id.get(network_id));
+        match (subnet, host_vpc) {
+            (Some(subnet), _) => graph.edge(
+                node_id(TopologyNodeKind::Subnet, &subnet.id),
+                node_id(TopologyNodeKind::CceCluster, cluster_id),
+                "hosts",
+            ),
+            (None, Some(vpc_id)) if graph.has(TopologyNodeKind::Vpc, vpc_id) => graph.edge(
+                node_id(TopologyNodeKind::Vpc, vpc_id),
+                node_id(TopologyNodeKind::CceCluster, cluster_id),
+                "hosts",
+            ),
+            _ => graph.warn(format!(
+                "CCE cluster {} is not attached to a known VPC or subnet.",
+                cluster_id
+            )),
+        }
+    }
+
+    for eip in inputs.eips {
+        let Some(eip_id) = non_empty(eip.id.as_deref()) else {
+            continue;
+        };
+        graph.node(
+            TopologyNodeKind::Eip,
+            eip_id,
+            eip.public_ip_address.as_deref().or(eip.alias.as_deref()),
+            eip.status.as_deref(),
+            json!({
+                "public_ip_address": eip.public_ip_address,
+                "bandwidth_size": eip.bandwidth.as_ref().and_then(|bandwidth| bandwidth.size),
+                "associate_instance_type": eip.associate_instance_type,
+            }),
+        );
+
+        let vnic = eip.vnic.as_ref();
+        let candidates = [
+            non_empty(eip.associate_instance_id.as_deref()),
+            vnic.and_then(|vnic| non_empty(vnic.instance_id.as_deref())),
+            vnic.and_then(|vnic| non_empty(vnic.device_id.as_deref())),
+        ];
+        let target = candidates
+            .into_iter()
+            .flatten()
+            .find_map(|id| {
+                if graph.has(TopologyNodeKind::Ecs, id) {
+                    Some(node_id(TopologyNodeKind::Ecs, id))
+                } else if graph.has(TopologyNodeKind::NatGateway, id) {
+                    Some(node_id(TopologyNodeKind::NatGateway, id))
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                vnic.and_then(|vnic| non_empty(vnic.port_id.as_deref()))
+                    .and_then(|port_id| server_by_port_id.get(port_id))
+                    .map(|server_id| node_id(TopologyNodeKind::Ecs, server_id))
+            });
+        if let Some(target) = target {
+            graph.edge(node_id(TopologyNodeKind::Eip, eip_id), target, "bound");
+        }
+    }
+
+    graph.topology
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_network_topology, TopologyInputs, TopologyNodeKind};
+    use crate::api::models::cce::CceClusterListResponse;
+    use crate::api::models::ecs::EcsListResponse;
+    use crate::api::models::eip::EipListResponse;
+    use crate::api::models::nat::NatGatewayListResponse;
+    use crate::api::models::vpc::{SubnetListResponse, VpcListResponse};
+
+    #[test]
+    fn build_network_topology_links_all_resource_kinds() {
+        let vpcs: VpcListResponse = serde_json::from_str(
+            r#"{"vpcs":[{"id":"vpc-1","name":"prod","cidr":"192.168.0.0/16","status":"OK"}]}"#,
+        )
+        .expect("vpcs");
+        let subnets: SubnetListResponse = serde_json::from_str(
+            r#"{"subnets":[
+              {"id":"subnet-1","name":"apps","cidr":"192.168.1.0/24","vpc_id":"vpc-1","neutron_network_id":"subnet-1"},
+              {"id":"subnet-2","name":"orphan","cidr":"10.0.0.0/24","vpc_id":"vpc-missing"}
+            ]}"#,
+        )
+        .expect("subnets");
+        let servers: EcsListResponse = serde_json::from_str(
+            r#"{"servers":[{"id":"ecs-1","name":"web","status":"ACTIVE","addresses":{"vpc-1":[
+              {"addr":"192.168.1.10","OS-EXT-IPS:type":"fixed","OS-EXT-IPS:port_id":"port-1"},
+              {"addr":"203.0.113.7","OS-EXT-IPS:type":"floating"}
+            ]}}]}"#,
+        )
+        .expect("servers");
+        let eips: EipListResponse = serde_json::from_str(
+            r#"{"publicips":[
+              {"id":"eip-1","public_ip_address":"203.0.113.7","vnic":{"port_id":"port-1"}},
+              {"id":"eip-2","public_ip_address":"203.0.113.8","associate_instance_type":"NATGW","associate_instance_id":"nat-1"},
+              {"id":"eip-3","public_ip_address":"203.0.113.9"}
+            ]}"#,
+        )
+        .expect("eips");
+        let nats: NatGatewayListResponse = serde_json::from_str(
+            r#"{"nat_gateways":[{"id":"nat-1","name":"egress","router_id":"vpc-1","internal_network_id":"subnet-1"}]}"#,
+        )
+        .expect("nats");
+        let clusters: CceClusterListResponse = serde_json::from_str(
+            r#"{"items":[{"metadata":{"uid":"cce-1","name":"dev"},"spec":{"hostNetwork":{"vpc":"vpc-1","subnet":"subnet-1"}},"status":{"phase":"Available"}}]}"#,
+        )
+        .expect("clusters");
+
+        let topology = build_network_topology(&TopologyInputs {
+            vpcs: &vpcs.vpcs,
+            subnets: &subnets.subnets,
+            servers: &servers.servers,
+            eips: &eips.publicips,
+            nat_gateways: &nats.nat_gateways,
+            clusters: &clusters.items,
+        });
+
+        let has_edge = |from: &str, to: &str, relation: &str| {
+            topology
+                .edges
+                .iter()
+                .any(|edge| edge.from == from && edge.to == to && edge.relation == relation)
+        };
+        assert!(has_edge("vpc:vpc-1", "subnet:subnet-1", "contains"));
+        assert!(has_edge("subnet:subnet-1", "ecs:ecs-1", "nic"));
+        assert!(has_edge("eip:eip-1", "ecs:ecs-1", "bound"));
+        assert!(has_edge("eip:eip-2", "nat:nat-1", "bound"));
+        assert!(has_edge("subnet:subnet-1", "nat:nat-1", "gateway"));
+        assert!(has_edge("subnet:subnet-1", "cce:cce-1", "hosts"));
+        assert!(!topology.edges.iter().any(|edge| edge.from == "eip:eip-3"));
+
+        let cluster = topology
+            .nodes
+            .iter()
+            .find(|node| node.kind == TopologyNodeKind::CceCluster)
+            .expect("cluster node");
+        assert_eq!(cluster.status.as_deref(), Some("Available"));
+        assert_eq!(topology.nodes.len(), 9);
+        assert_eq!(topology.warnings.len(), 1);
+        assert!(topology.warnings[0].contains("vpc-missing"));
+    }
+}
@@ -63,11 +63,324 @@ pub fn control_char_from_input(input: &str) -> Result<u8, String> {
     }
 }
 
+pub fn parse_ipv4_cidr(input: &str) -> Result<(std::net::Ipv4Addr, u8), String> {
+    let trimmed = input.trim();
+    let (address, prefix) = trimmed
+        .split_once('/')
+        .ok_or_else(|| format!("CIDR '{}' must use the a.b.c.d/n format.", trimmed))?;
+    let address = address
+        .parse::<std::net::Ipv4Addr>()
+        .map_err(|_| format!("CIDR '{}' has an invalid IPv4 address.", trimmed))?;
+    let prefix = prefix
+        .parse::<u8>()
+        .ok()
+        .filter(|value| *value <= 32)
+        .ok_or_else(|| format!("CIDR '{}' has an invalid prefix length.", trimmed))?;
+    Ok((address, prefix))
+}
+
+pub fn ipv4_cidr_contains(cidr: (std::net::Ipv4Addr, u8), ip: std::net::Ipv4Addr) -> bool {
+    let (network, prefix) = cidr;
+    let mask = if prefix == 0 {
+        0
+    } else {
+        u32::MAX << (32 - u32::from(prefix))
+    };
+    u32::from(network) & mask == u32::from(ip) & mask
+}
+
+pub fn ipv4_cidr_overlaps(left: (std::net::Ipv4Addr, u8), right: (std::net::Ipv4Addr, u8)) -> bool {
+    // Two CIDRs overlap exactly when the wider one contains the narrower one's network.
+    if left.1 <= right.1 {
+        ipv4_cidr_contains(left, right.0)
+    } else {
+        ipv4_cidr_contains(right, left.0)
+    }
+}
+
+pub fn normalize_ipv4_network_cidr(input: &str) -> Result<String, String> {
+    let (address, prefix) = parse_ipv4_cidr(input)?;
+    if u32::from(address) & host_mask(prefix) != 0 {
+        return Err(format!(
+            "CIDR '{}' has host bits set; use the network address instead.",
+            input.trim()
+        ));
+    }
+    Ok(format!("{address}/{prefix}"))
+}
+
+fn host_mask(prefix: u8) -> u32 {
+    if prefix >= 32 {
+        0
+    } else {
+        u32::MAX >> prefix
+    }
+}
+
+pub fn normalize_remote_ip_prefix(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("Remote IP prefix is required.".to_string());
+    }
+    if let Ok(address) = trimmed.parse::<std::net::Ipv4Addr>() {
+        return Ok(format!("{address}/32"));
+    }
+    let (address, prefix) = parse_ipv4_cidr(trimmed)?;
+    Ok(format!("{address}/{prefix}"))
+}
+
+pub fn normalize_security_group_ports(input: &str, max_entries: usize) -> Result<String, String> {
+    let entries = input
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        return Err("At least one port or port range is required.".to_string());
+    }
+    if entries.len() > max_entries {
+        return Err(format!(
+            "A security group rule accepts at most {} ports or port ranges.",
+            max_entries
+        ));
+    }
+
+    let parse_port = |value: &str| {
+        value
+            .trim()
+            .parse::<u16>()
+            .ok()
+            .filter(|port| *port > 0)
+            .ok_or_else(|| format!("Port '{}' must be between 1 and 65535.", value.trim()))
+    };
+    let mut normalized = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match entry.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_port(start)?;
+                let end = parse_port(end)?;
+                if start > end {
+                    return Err(format!(
+                        "Port range '{}' must start at or below its end.",
+                        entry
+                    ));
+                }
+                normalized.push(format!("{start}-{end}"));
+            }
+            None => normalized.push(parse_port(entry)?.to_string()),
+        }
+    }
+    Ok(normalized.join(","))
+}
+
+pub fn normalize_security_group_direction(input: Option<&str>) -> Result<String, String> {
+    let direction = input
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or("ingress")
+        .to_ascii_lowercase();
+    match direction.as_str() {
+        "ingress" | "egress" => Ok(direction),
+        _ => Err(format!(
+            "Security group rule direction must be 'ingress' or 'egress', got '{}'.",
+            direction
+        )),
+    }
+}
+
+pub fn normalize_security_group_protocol(input: Option<&str>) -> Result<Option<String>, String> {
+    let Some(protocol) = input
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_ascii_lowercase)
+    else {
+        return Ok(None);
+    };
+    match protocol.as_str() {
+        "any" => Ok(None),
+        "tcp" | "udp" | "icmp" => Ok(Some(protocol)),
+        _ => Err(format!(
+            "Security group rule protocol must be tcp, udp, icmp or any, got '{}'.",
+            protocol
+        )),
+    }
+}
+
+pub fn normalize_security_group_action(input: Option<&str>) -> Result<Option<String>, String> {
+    let Some(action) = input
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_ascii_lowercase)
+    else {
+        return Ok(None);
+    };
+    match action.as_str() {
+        "allow" | "deny" => Ok(Some(action)),
+        _ => Err(format!(
+            "Security group rule action must be 'allow' or 'deny', got '{}'.",
+            action
+        )),
+    }
+}
+
+const EVS_VOLUME_TYPES: &[&str] = &["SATA", "SAS", "SSD", "GPSSD", "ESSD", "GPSSD2", "ESSD2"];
+
+pub fn normalize_evs_volume_type(input: &str) -> Result<String, String> {
+    let volume_type = input.trim().to_ascii_uppercase();
+    if EVS_VOLUME_TYPES.contains(&volume_type.as_str()) {
+        return Ok(volume_type);
+    }
+    Err(format!(
+        "EVS disk type must be one of {}, got '{}'.",
+        EVS_VOLUME_TYPES.join(", "),
+        input.trim()
+    ))
+}
+
+/// Split a Linux block device path like "/dev/vdb" into its bus prefix and slot letter.
+fn split_evs_device(device: &str) -> Option<(&str, char)> {
+    let name = device.trim().strip_prefix("/dev/")?;
+    let prefix = ["xvd", "sd", "vd"]
+        .into_iter()
+        .find(|prefix| name.starts_with(prefix))?;
+    let mut slot = name[prefix.len()..].chars();
+    match (slot.next(), slot.next()) {
+        (Some(letter @ 'a'..='z'), None) => Some((prefix, letter)),
+        _ => None,
+    }
+}
+
+/// The first slot ("/dev/vda", "/dev/sda", "/dev/xvda") always holds the system disk.
+pub fn is_evs_system_device(device: &str) -> bool {
+    split_evs_device(device).is_some_and(|(_, letter)| letter == 'a')
+}
+
+pub fn normalize_evs_device_name(input: Option<&str>) -> Result<Option<String>, String> {
+    let Some(device) = input.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    if split_evs_device(device).is_none() {
+        return Err(format!(
+            "Device name must look like /dev/vdb or /dev/sdb, got '{}'.",
+            device
+        ));
+    }
+    if is_evs_system_device(device) {
+        return Err(format!(
+            "Device {} is reserved for the system disk.",
+            device
+        ));
+    }
+    Ok(Some(device.to_string()))
+}
+
+/// IMS image names: 1-128 letters, digits, spaces, `_`, `-` or `.`, no edge spaces.
+pub fn normalize_ims_image_name(input: &str) -> Result<String, String> {
+    let name = input.trim();
+    if name.is_empty() || name.chars().count() > 128 {
+        return Err("Image name must be 1-128 characters.".to_string());
+    }
+    if let Some(ch) = name
+        .chars()
+        .find(|ch| !(ch.is_alphanumeric() || matches!(ch, ' ' | '_' | '-' | '.')))
+    {
+        return Err(format!("Image name cannot contain '{}'.", ch));
+    }
+    Ok(name.to_string())
+}
+
+/// Huawei Cloud project IDs are 32 lowercase hex characters.
+pub fn normalize_project_id(input: &str) -> Result<String, String> {
+    let project_id = input.trim().to_ascii_lowercase();
+    if project_id.len() != 32 || !project_id.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return Err(format!(
+            "Project ID must be 32 hex characters, got '{}'.",
+            input.trim()
+        ));
+    }
+    Ok(project_id)
+}
+
+/// Image file formats IMS can register, keyed by file extension.
+const IMS_IMPORT_FORMATS: &[&str] = &[
+    "qcow2", "vhd", "vhdx", "vmdk", "zvhd", "zvhd2", "raw", "qcow", "vdi", "img", "iso",
+];
+
+/// Formats IMS can export a private image to.
+const IMS_EXPORT_FORMATS: &[&str] = &["qcow2", "vhd", "zvhd", "zvhd2", "vmdk"];
+
+/// IMS infers the import format from the object key extension, so the key must end in one.
+pub fn ims_import_file_format(object_key: &str) -> Result<String, String> {
+    let format = object_key
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    if !IMS_IMPORT_FORMATS.contains(&format.as_str()) {
+        return Err(format!(
+            "Image file '{}' must end in one of: {}.",
+            object_key,
+            IMS_IMPORT_FORMATS.join(", ")
+        ));
+    }
+    Ok(format)
+}
+
+pub fn normalize_ims_export_format(input: &str) -> Result<String, String> {
+    let format = input.trim().to_ascii_lowercase();
+    if !IMS_EXPORT_FORMATS.contains(&format.as_str()) {
+        return Err(format!(
+            "Image export format must be one of: {}.",
+            IMS_EXPORT_FORMATS.join(", ")
+        ));
+    }
+    Ok(format)
+}
+
+/// Enterprise project ID used to list resources across every granted project.
+pub const ALL_ENTERPRISE_PROJECTS: &str = "all_granted_eps";
+
+/// Accepts "0" (the default project), a project UUID, and, for list filters only,
+/// `all_granted_eps`.
+pub fn normalize_enterprise_project_id(
+    input: Option<&str>,
+    allow_all: bool,
+) -> Result<Option<String>, String> {
+    let Some(value) = input.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    if value == ALL_ENTERPRISE_PROJECTS {
+        if allow_all {
+            return Ok(Some(value.to_string()));
+        }
+        return Err(format!(
+            "'{}' is only valid as a list filter.",
+            ALL_ENTERPRISE_PROJECTS
+        ));
+    }
+    let is_uuid = value.len() == 36
+        && value.char_indices().all(|(index, ch)| match index {
+            8 | 13 | 18 | 23 => ch == '-',
+            _ => ch.is_ascii_hexdigit(),
+        });
+    if value != "0" && !is_uuid {
+        return Err(format!(
+            "Enterprise project ID must be 0 or a UUID, got '{}'.",
+            value
+        ));
+    }
+    Ok(Some(value.to_ascii_lowercase()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        control_char_from_input, normalize_obs_bucket_name, normalize_obs_object_key,
-        normalize_ssh_session_id,
+        control_char_from_input, ims_import_file_format, ipv4_cidr_contains, ipv4_cidr_overlaps,
+        is_evs_system_device, normalize_enterprise_project_id, normalize_evs_device_name,
+        normalize_evs_volume_type, normalize_ims_export_format, normalize_ims_image_name,
+        normalize_ipv4_network_cidr, normalize_obs_bucket_name, normalize_obs_object_key,
+        normalize_project_id, normalize_remote_ip_prefix, normalize_security_group_action,
+        normalize_security_group_direction, normalize_security_group_ports,
+        normalize_security_group_protocol, normalize_ssh_session_id, parse_ipv4_cidr,
     };
 
     const OBS_BUCKET_MIN: usize = 3;
@@ -147,4 +460,180 @@ mod tests {
     fn control_char_from_input_rejects_unknown_values() {
         assert!(control_char_from_input("ctrl+z").is_err());
     }
+
+    #[test]
+    fn parse_ipv4_cidr_requires_prefix_and_bounds() {
+        assert_eq!(
+            parse_ipv4_cidr(" 192.168.0.0/16 ").expect("cidr"),
+            ("192.168.0.0".parse().expect("ip"), 16)
+        );
+        assert!(parse_ipv4_cidr("192.168.0.0").is_err());
+        assert!(parse_ipv4_cidr("192.168.0.0/33").is_err());
+        assert!(parse_ipv4_cidr("192.168.0/24").is_err());
+    }
+
+    #[test]
+    fn ipv4_cidr_contains_checks_masked_network() {
+        let cidr = parse_ipv4_cidr("10.0.0.0/8").expect("cidr");
+        assert!(ipv4_cidr_contains(cidr, "10.20.30.40".parse().expect("ip")));
+        assert!(!ipv4_cidr_contains(cidr, "11.0.0.1".parse().expect("ip")));
+        let any = parse_ipv4_cidr("0.0.0.0/0").expect("cidr");
+        assert!(ipv4_cidr_contains(any, "203.0.113.7".parse().expect("ip")));
+    }
+
+    #[test]
+    fn normalize_remote_ip_prefix_expands_bare_addresses() {
+        assert_eq!(
+            normalize_remote_ip_prefix(" 203.0.113.7 ").expect("prefix"),
+            "203.0.113.7/32"
+        );
+        assert_eq!(
+            normalize_remote_ip_prefix("0.0.0.0/0").expect("prefix"),
+            "0.0.0.0/0"
+        );
+        assert!(normalize_remote_ip_prefix("").is_err());
+        assert!(normalize_remote_ip_prefix("not-an-ip").is_err());
+    }
+
+    #[test]
+    fn normalize_security_group_ports_accepts_ports_and_ranges() {
+        assert_eq!(
+            normalize_security_group_ports(" 22, 8000 - 8080 ,", 20).expect("ports"),
+            "22,8000-8080"
+        );
+        assert!(normalize_security_group_ports("", 20).is_err());
+        assert!(normalize_security_group_ports("0", 20).is_err());
+        assert!(normalize_security_group_ports("70000", 20).is_err());
+        assert!(normalize_security_group_ports("90-80", 20).is_err());
+        assert!(normalize_security_group_ports("1,2,3", 2).is_err());
+    }
+
+    #[test]
+    fn security_group_rule_fields_normalize_case_and_defaults() {
+        assert_eq!(
+            normalize_security_group_direction(None).expect("direction"),
+            "ingress"
+        );
+        assert_eq!(
+            normalize_security_group_direction(Some(" Egress ")).expect("direction"),
+            "egress"
+        );
+        assert!(normalize_security_group_direction(Some("inbound")).is_err());
+        assert_eq!(
+            normalize_security_group_protocol(Some("TCP")).expect("protocol"),
+            Some("tcp".to_string())
+        );
+        assert_eq!(
+            normalize_security_group_protocol(Some("any")).expect("protocol"),
+            None
+        );
+        assert!(normalize_security_group_protocol(Some("gre")).is_err());
+        assert_eq!(
+            normalize_security_group_action(Some("Deny")).expect("action"),
+            Some("deny".to_string())
+        );
+        assert!(normalize_security_group_action(Some("drop")).is_err());
+    }
+
+    #[test]
+    fn ipv4_cidr_overlaps_detects_nested_and_disjoint_ranges() {
+        let vpc = parse_ipv4_cidr("172.16.0.0/12").expect("cidr");
+        let cce = parse_ipv4_cidr("172.16.0.0/16").expect("cidr");
+        let other = parse_ipv4_cidr("192.168.0.0/16").expect("cidr");
+        assert!(ipv4_cidr_overlaps(vpc, cce));
+        assert!(ipv4_cidr_overlaps(cce, vpc));
+        assert!(!ipv4_cidr_overlaps(cce, other));
+    }
+
+    #[test]
+    fn normalize_ipv4_network_cidr_rejects_host_bits() {
+        assert_eq!(
+            normalize_ipv4_network_cidr(" 192.168.1.0/24 ").expect("cidr"),
+            "192.168.1.0/24"
+        );
+        assert!(normalize_ipv4_network_cidr("192.168.1.5/24").is_err());
+        assert!(normalize_ipv4_network_cidr("192.168.1.5").is_err());
+    }
+
+    #[test]
+    fn normalize_evs_volume_type_uppercases_known_types() {
+        assert_eq!(normalize_evs_volume_type(" gpssd ").expect("type"), "GPSSD");
+        assert_eq!(normalize_evs_volume_type("ESSD2").expect("type"), "ESSD2");
+        assert!(normalize_evs_volume_type("nvme").is_err());
+    }
+
+    #[test]
+    fn normalize_evs_device_name_rejects_system_slot() {
+        assert_eq!(normalize_evs_device_name(None).expect("device"), None);
+        assert_eq!(normalize_evs_device_name(Some("  ")).expect("device"), None);
+        assert_eq!(
+            normalize_evs_device_name(Some("/dev/vdb")).expect("device"),
+            Some("/dev/vdb".to_string())
+        );
+        assert!(normalize_evs_device_name(Some("/dev/vda")).is_err());
+        assert!(normalize_evs_device_name(Some("/dev/xvda")).is_err());
+        assert!(normalize_evs_device_name(Some("vdb")).is_err());
+        assert!(normalize_evs_device_name(Some("/dev/vdb1")).is_err());
+    }
+
+    #[test]
+    fn is_evs_system_device_matches_first_slot_only() {
+        assert!(is_evs_system_device("/dev/vda"));
+        assert!(is_evs_system_device("/dev/sda"));
+        assert!(is_evs_system_device("/dev/xvda"));
+        assert!(!is_evs_system_device("/dev/vdb"));
+        assert!(!is_evs_system_device("/dev/xvdb"));
+        assert!(!is_evs_system_device(""));
+    }
+
+    #[test]
+    fn normalize_enterprise_project_id_accepts_default_and_uuids() {
+        assert_eq!(normalize_enterprise_project_id(Some("  "), false), Ok(None));
+        assert_eq!(
+            normalize_enterprise_project_id(Some("0"), false),
+            Ok(Some("0".to_string()))
+        );
+        assert_eq!(
+            normalize_enterprise_project_id(Some("2C9A3A5E-0D4B-4C1E-9F3A-6B8D1E2F3A4B"), false),
+            Ok(Some("2c9a3a5e-0d4b-4c1e-9f3a-6b8d1e2f3a4b".to_string()))
+        );
+        assert!(normalize_enterprise_project_id(Some("team-a"), false).is_err());
+        assert!(normalize_enterprise_project_id(Some("all_granted_eps"), false).is_err());
+        assert_eq!(
+            normalize_enterprise_project_id(Some("all_granted_eps"), true),
+            Ok(Some("all_granted_eps".to_string()))
+        );
+    }
+
+    #[test]
+    fn normalize_ims_image_name_rejects_symbols_and_length() {
+        assert_eq!(
+            normalize_ims_image_name("  golden-web_v1.2 ").expect("name"),
+            "golden-web_v1.2"
+        );
+        assert!(normalize_ims_image_name("").is_err());
+        assert!(normalize_ims_image_name("golden/web").is_err());
+        assert!(normalize_ims_image_name(&"a".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn ims_formats_come_from_extension_and_allow_list() {
+        assert_eq!(
+            ims_import_file_format("builds/Golden.QCOW2"),
+            Ok("qcow2".to_string())
+        );
+        assert!(ims_import_file_format("builds/golden").is_err());
+        assert!(ims_import_file_format("golden.tar.gz").is_err());
+        assert_eq!(normalize_ims_export_format(" VHD "), Ok("vhd".to_string()));
+        assert!(normalize_ims_export_format("iso").is_err());
+    }
+
+    #[test]
+    fn normalize_project_id_requires_32_hex_chars() {
+        assert_eq!(
+            normalize_project_id(" 0A1B2C3D4E5F60718293A4B5C6D7E8F9 ").expect("project"),
+            "0a1b2c3d4e5f60718293a4b5c6d7e8f9"
+        );
+        assert!(normalize_project_id("proj-1").is_err());
+    }
 }
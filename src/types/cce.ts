@@ -28,6 +28,80 @@ export type CceNodePoolListResponse = {
   items?: CceNodePool[];
 };
 
+export type CceTaint = {
+  key: string;
+  value?: string | null;
+  effect: "NoSchedule" | "PreferNoSchedule" | "NoExecute";
+};
+
+export type CceScaleNodePoolParams = {
+  region: string;
+  clusterId: string;
+  nodePoolId: string;
+  nodeCount: number;
+};
+
+export type CceUpdateNodePoolParams = {
+  region: string;
+  clusterId: string;
+  nodePoolId: string;
+  nodeCount?: number | null;
+  autoscalingEnabled?: boolean | null;
+  minNodeCount?: number | null;
+  maxNodeCount?: number | null;
+  scaleDownCooldownTime?: number | null;
+  priority?: number | null;
+  labels?: Record<string, string> | null;
+  taints?: CceTaint[] | null;
+  tags?: { key: string; value?: string | null }[] | null;
+};
+
+export type CceNode = {
+  kind?: string | null;
+  apiVersion?: string | null;
+  metadata?: {
+    name?: string | null;
+    uid?: string | null;
+    labels?: Record<string, string>;
+    annotations?: Record<string, string>;
+    creationTimestamp?: string | null;
+  } | null;
+  spec?: {
+    flavor?: string | null;
+    az?: string | null;
+    os?: string | null;
+    billingMode?: number | null;
+  } | null;
+  status?: {
+    phase?: string | null;
+    serverId?: string | null;
+    privateIP?: string | null;
+    publicIP?: string | null;
+    jobID?: string | null;
+  } | null;
+};
+
+export type CceNodeListResponse = {
+  kind?: string | null;
+  apiVersion?: string | null;
+  items?: CceNode[];
+};
+
+export type CceResetNodesParams = {
+  region: string;
+  clusterId: string;
+  nodeIds: string[];
+  os: string;
+  sshKey: string;
+};
+
+export type CceRemoveNodesParams = {
+  region: string;
+  clusterId: string;
+  nodeIds: string[];
+  sshKey: string;
+};
+
 export type CceNatGateway = {
   id?: string | null;
   name?: string | null;
@@ -44,6 +118,297 @@ export type CceNatGatewayListResponse = {
   nat_gateways?: CceNatGateway[];
 };
 
+export type CceSnatRule = {
+  id?: string | null;
+  nat_gateway_id?: string | null;
+  network_id?: string | null;
+  cidr?: string | null;
+  source_type?: number | null;
+  floating_ip_id?: string | null;
+  floating_ip_address?: string | null;
+  description?: string | null;
+  status?: string | null;
+  created_at?: string | null;
+};
+
+export type CceSnatRuleListResponse = {
+  snat_rules?: CceSnatRule[];
+};
+
+export type CceCreateSnatRuleParams = {
+  region: string;
+  natGatewayId: string;
+  subnetId?: string | null;
+  cidr?: string | null;
+  eipIds: string[];
+  description?: string | null;
+};
+
+export type CceUpdateNatGatewayParams = {
+  region: string;
+  natGatewayId: string;
+  name?: string | null;
+  description?: string | null;
+  spec?: "1" | "2" | "3" | "4" | null;
+};
+
+export type CceDnatRule = {
+  id?: string | null;
+  nat_gateway_id?: string | null;
+  port_id?: string | null;
+  private_ip?: string | null;
+  protocol?: string | null;
+  internal_service_port?: number | null;
+  external_service_port?: number | null;
+  floating_ip_id?: string | null;
+  floating_ip_address?: string | null;
+  description?: string | null;
+  status?: string | null;
+  created_at?: string | null;
+};
+
+export type CceDnatRuleListResponse = {
+  dnat_rules?: CceDnatRule[];
+};
+
+export type CceCreateDnatRuleParams = {
+  region: string;
+  natGatewayId: string;
+  protocol: "tcp" | "udp" | "any";
+  internalPort?: number | null;
+  externalPort?: number | null;
+  portId?: string | null;
+  privateIp?: string | null;
+  eipId: string;
+  description?: string | null;
+};
+
+export type NatBootstrapStep = "nat_gateway" | "eip" | "snat_rule";
+
+export type NatBootstrapRun = {
+  region: string;
+  name: string;
+  vpc_id: string;
+  subnet_id: string;
+  spec: string;
+  description: string | null;
+  enterprise_project_id: string | null;
+  tags: { key: string; value: string }[];
+  reused_eip_ids: string[];
+  nat_gateway_id: string | null;
+  nat_gateway_adopted: boolean;
+  eip_id: string | null;
+  eip_address: string | null;
+  snat_rule_id: string | null;
+  status: "in_progress" | "failed" | "completed";
+  failed_step: NatBootstrapStep | null;
+  last_error: string | null;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CceUpgradeInfo = {
+  kind?: string | null;
+  apiVersion?: string | null;
+  spec?: {
+    versionInfo?: {
+      release?: string | null;
+      patch?: string | null;
+      suggestPatch?: string | null;
+      targetVersions?: string[] | null;
+    } | null;
+  } & CcePlainObject;
+  status?: CcePlainObject | null;
+};
+
+export type CceClusterTask = {
+  metadata?: { uid?: string | null } & CcePlainObject;
+  spec?: CcePlainObject;
+  status?: {
+    phase?: string | null;
+    progress?: string | null;
+    message?: string | null;
+    jobID?: string | null;
+  } & CcePlainObject;
+};
+
+export type CceJob = {
+  kind?: string | null;
+  metadata?: { uid?: string | null } & CcePlainObject;
+  spec?: { type?: string | null; subJobs?: CceJob[] | null } & CcePlainObject;
+  status?: { phase?: string | null; reason?: string | null } & CcePlainObject;
+};
+
+export type CceClusterUpgradeParams = {
+  region: string;
+  clusterId: string;
+  targetVersion: string;
+};
+
+export type CceUpgradeStartResult = {
+  status: string;
+  status_code: number;
+  body: string;
+  job_id?: string | null;
+};
+
+export type CceUpgradeProgressEvent = {
+  clusterId: string;
+  stage: "precheck" | "upgrade";
+  step: string;
+  phase: string;
+  message?: string | null;
+  at: string;
+};
+
+export type CceClusterPowerParams = {
+  region: string;
+  clusterId: string;
+};
+
+export type CceSetHibernateScheduleParams = {
+  region: string;
+  clusterId: string;
+  clusterName?: string | null;
+  // Local time as HH:MM.
+  hibernateAt: string;
+  enabled?: boolean;
+};
+
+export type CceHibernateSchedule = {
+  region: string;
+  cluster_id: string;
+  cluster_name?: string | null;
+  hibernate_at: string;
+  enabled: boolean;
+  last_run_on?: string | null;
+  last_error?: string | null;
+  created_at: string;
+  updated_at: string;
+};
+
+export type CceHibernateScheduleEvent = {
+  region: string;
+  clusterId: string;
+  phase?: string | null;
+  error?: string | null;
+  at: string;
+};
+
+export type CceAddonMetadata = {
+  uid?: string | null;
+  name?: string | null;
+  alias?: string | null;
+  creationTimestamp?: string | null;
+  updateTimestamp?: string | null;
+} & CcePlainObject;
+
+export type CceAddonValues = {
+  basic?: CcePlainObject;
+  custom?: CcePlainObject;
+  flavor?: CcePlainObject | null;
+} & CcePlainObject;
+
+export type CceAddonTemplateVersion = {
+  version: string;
+  input?: {
+    basic?: CcePlainObject;
+    // Holds `custom` plus the flavor1, flavor2, ... choices.
+    parameters?: { custom?: CcePlainObject } & CcePlainObject;
+  } & CcePlainObject;
+  stable?: boolean | null;
+  supportVersions?: {
+    clusterType?: string | null;
+    clusterVersion?: string[] | null;
+  }[] | null;
+} & CcePlainObject;
+
+export type CceAddonTemplate = {
+  kind?: string | null;
+  apiVersion?: string | null;
+  metadata?: CceAddonMetadata;
+  spec?: {
+    type?: string | null;
+    require?: boolean | null;
+    labels?: string[] | null;
+    description?: string | null;
+    versions?: CceAddonTemplateVersion[];
+  } & CcePlainObject;
+};
+
+export type CceAddonInstance = {
+  kind?: string | null;
+  apiVersion?: string | null;
+  metadata?: CceAddonMetadata;
+  spec?: {
+    clusterID?: string | null;
+    version?: string | null;
+    addonTemplateName?: string | null;
+    addonTemplateType?: string | null;
+    description?: string | null;
+    values?: CceAddonValues;
+  } & CcePlainObject;
+  status?: {
+    status?: string | null;
+    Reason?: string | null;
+    message?: string | null;
+    currentVersion?: CceAddonTemplateVersion | null;
+    isRollbackable?: boolean | null;
+  } & CcePlainObject;
+};
+
+export type CceAddonValueOverrides = {
+  basic?: CcePlainObject | null;
+  custom?: CcePlainObject | null;
+  // Template flavor key such as "flavor2".
+  flavor?: string | null;
+};
+
+export type CceInstallAddonParams = {
+  region: string;
+  clusterId: string;
+  templateName: string;
+  version: string;
+} & CceAddonValueOverrides;
+
+export type CceUpgradeAddonParams = {
+  region: string;
+  clusterId: string;
+  addonId: string;
+  version: string;
+} & CceAddonValueOverrides;
+
+export type CceCreateClusterParams = {
+  region: string;
+  name: string;
+  flavor: string;
+  version: string;
+  vpcId: string;
+  subnetId: string;
+  description?: string | null;
+  clusterType?: string | null;
+  // "eni" creates a CCE Turbo cluster.
+  containerNetworkMode?: "overlay_l2" | "underlay_ipvlan" | "vpc-router" | "eni" | null;
+  containerNetworkCidr?: string | null;
+  // VPC subnet IDs for ENI pods; defaults to subnetId.
+  eniSubnetIds?: string[] | null;
+  kubernetesSvcIpRange?: string | null;
+  ipv6Enable?: boolean | null;
+  kubeProxyMode?: "iptables" | "ipvs" | null;
+  // One AZ for all masters, or one per master.
+  masterAzs?: string[] | null;
+  eipAddress?: string | null;
+  authenticationMode?: string | null;
+  billingMode?: "payPerUse" | "yearlyMonthly" | null;
+  periodType?: "month" | "year" | null;
+  periodNum?: number | null;
+  autoRenew?: boolean | null;
+  autoPay?: boolean | null;
+  clusterTagEnv?: string | null;
+  tags?: { key: string; value?: string | null }[] | null;
+  enterpriseProjectId?: string | null;
+};
+
 export type CceOperationResult = {
   status: string;
   status_code: number;
@@ -1,15 +1,158 @@
-export type VpcOption = { id: string; name: string };
+export type VpcOption = {
+  id: string;
+  name: string;
+  cidr?: string | null;
+  status?: string | null;
+  description?: string | null;
+  enterprise_project_id?: string | null;
+  created_at?: string | null;
+  updated_at?: string | null;
+};
 export type SubnetOption = {
   id: string;
   name: string;
   cidr: string;
   availability_zone?: string | null;
+  vpc_id?: string | null;
+  status?: string | null;
+  description?: string | null;
+  gateway_ip?: string | null;
+  dhcp_enable?: boolean | null;
+  primary_dns?: string | null;
+  secondary_dns?: string | null;
+  dnsList?: string[];
+  neutron_network_id?: string | null;
+  neutron_subnet_id?: string | null;
+};
+export type TopologyNodeKind = "vpc" | "subnet" | "ecs" | "eip" | "nat_gateway" | "cce_cluster";
+export type TopologyNode = {
+  id: string;
+  kind: TopologyNodeKind;
+  resource_id: string;
+  name?: string | null;
+  status?: string | null;
+  details: Record<string, unknown>;
+};
+export type TopologyEdge = {
+  from: string;
+  to: string;
+  relation: "contains" | "nic" | "bound" | "gateway" | "hosts";
+};
+export type NetworkTopology = {
+  nodes: TopologyNode[];
+  edges: TopologyEdge[];
+  warnings: string[];
+};
+export type OrphanKind = "eip" | "evs_volume" | "nat_gateway" | "obs_bucket";
+export type OrphanedResource = {
+  kind: OrphanKind;
+  id: string;
+  name: string | null;
+  status: string | null;
+  created_at: string | null;
+  age_days: number | null;
+  estimated_monthly_cost: number;
+  reason: string;
+};
+export type OrphanCategory = {
+  kind: OrphanKind;
+  count: number;
+  estimated_monthly_cost: number;
+  resources: OrphanedResource[];
+};
+export type OrphanReport = {
+  region: string;
+  generated_at: string;
+  currency: string;
+  total_estimated_monthly_cost: number;
+  categories: OrphanCategory[];
+  warnings: string[];
+};
+export type OrphanCleanupOutcome = {
+  kind: OrphanKind;
+  id: string;
+  deleted: boolean;
+  status_code: number | null;
+  message: string;
+};
+export type PriceTableSource = "bundled" | "imported";
+export type PriceLine = {
+  item: string;
+  quantity: number;
+  unit_hourly: number | null;
+  hourly: number | null;
+};
+export type PriceEstimate = {
+  region: string;
+  currency: string;
+  table_version: string;
+  table_source: PriceTableSource;
+  lines: PriceLine[];
+  hourly: number;
+  monthly: number;
+  warnings: string[];
+};
+export type PriceTableInfo = {
+  version: string;
+  currency: string;
+  source: PriceTableSource;
+  regions: string[];
+  imported_path: string | null;
+  warnings: string[];
 };
 export type ImageOption = {
   id: string;
   name: string;
   min_disk?: number | null;
   min_ram?: number | null;
+  status?: string;
+  owner?: string | null;
+  __imagetype?: string | null;
+  __image_source_type?: string | null;
+  __whole_image?: boolean | null;
+  __support_kvm?: string | null;
+  __support_xen?: string | null;
+  __support_largememory?: string | null;
+  __support_diskintensive?: string | null;
+  __support_highperformance?: string | null;
+  __support_kvm_gpu_type?: string | null;
+  __support_kvm_infiniband?: string | null;
+  __support_arm?: string | null;
+  __support_amd?: string | null;
+};
+export type ImsJobResult = {
+  status: string;
+  status_code: number;
+  body: string;
+  job_id: string | null;
+};
+export type ImageImportParams = {
+  region: string;
+  bucketName: string;
+  objectKey: string;
+  name: string;
+  osVersion: string;
+  minDisk: number;
+  description?: string | null;
+  enterpriseProjectId?: string | null;
+};
+export type ImageFileImportParams = ImageImportParams & { filePath: string };
+export type ImageExportFormat = "qcow2" | "vhd" | "zvhd" | "zvhd2" | "vmdk";
+export type ImageExportParams = {
+  region: string;
+  imageId: string;
+  bucketName: string;
+  objectKey: string;
+  fileFormat: ImageExportFormat;
+  quickExport?: boolean | null;
+};
+export type ImageImportProgressEventPayload = {
+  bucketName: string;
+  objectKey: string;
+  stage: "uploading" | "uploaded" | "registering" | "submitted" | "failed";
+  uploadedBytes: number;
+  totalBytes: number;
+  at: string;
 };
 export type FlavorOption = {
   id: string;
@@ -26,13 +169,26 @@ export type EipVnic = {
   port_id?: string | null;
   instance_id?: string | null;
 };
+export type BandwidthPublicIpInfo = {
+  publicip_id?: string | null;
+  publicip_address?: string | null;
+  publicip_type?: string | null;
+  ip_version?: number | null;
+};
 export type EipBandwidth = {
+  id?: string | null;
+  name?: string | null;
   size?: number | null;
   share_type?: string | null;
   charge_mode?: string | null;
+  bandwidth_type?: string | null;
+  status?: string | null;
+  publicip_info?: BandwidthPublicIpInfo[];
 };
 export type EipRecord = {
   id?: string | null;
+  alias?: string | null;
+  description?: string | null;
   public_ip_address?: string | null;
   status?: string | null;
   associate_instance_id?: string | null;
@@ -68,6 +224,20 @@ export type EvsListResponse = {
   volumes?: EvsVolume[];
   count?: number | null;
 };
+export type EvsSnapshot = {
+  id?: string | null;
+  name?: string | null;
+  description?: string | null;
+  status?: string | null;
+  size?: number | null;
+  volume_id?: string | null;
+  created_at?: string | null;
+  updated_at?: string | null;
+};
+export type EvsSnapshotListResponse = {
+  snapshots?: EvsSnapshot[];
+  count?: number | null;
+};
 export type EcsFlavorInfo = {
   name?: string | null;
   id?: string | null;
@@ -87,7 +257,24 @@ export type EcsListResponse = {
 };
 export type CreateEcsResult = { status: string; status_code: number; body: string };
 export type ServiceModule = "ecs" | "obs" | "cce";
-export type CredentialsPayload = { accessKey: string; secretKey: string };
+export type CredentialsPayload = {
+  accessKey: string;
+  secretKey: string;
+  enterpriseProjectId?: string | null;
+};
+export type EnterpriseProject = {
+  id: string | null;
+  name: string | null;
+  description: string | null;
+  status: number | null;
+  type: string | null;
+  created_at: string | null;
+  updated_at: string | null;
+};
+export type EnterpriseProjectListResponse = {
+  enterprise_projects: EnterpriseProject[];
+  total_count: number | null;
+};
 export type DeleteOperationResult = {
   status: string;
   status_code?: number | null;
@@ -100,6 +287,82 @@ export type DeleteEcsResult = {
 export type StopEcsResult = {
   ecs: DeleteOperationResult;
 };
+export type EcsJobResult = {
+  status: string;
+  status_code: number;
+  body: string;
+  job_id?: string | null;
+};
+export type SecurityGroupRule = {
+  id?: string | null;
+  security_group_id?: string | null;
+  description?: string | null;
+  direction?: string | null;
+  ethertype?: string | null;
+  protocol?: string | null;
+  multiport?: string | null;
+  remote_ip_prefix?: string | null;
+  remote_group_id?: string | null;
+  action?: string | null;
+  priority?: number | null;
+};
+export type SecurityGroup = {
+  id?: string | null;
+  name?: string | null;
+  description?: string | null;
+  enterprise_project_id?: string | null;
+  security_group_rules?: SecurityGroupRule[];
+};
+export type SecurityGroupRuleListResponse = {
+  security_group_rules?: SecurityGroupRule[];
+};
+export type VpcOperationResult = { status: string; status_code: number; body: string };
+export type EvsOperationResult = { status: string; status_code: number; body: string };
+export type QuotaResource =
+  | "ecs_instances"
+  | "ecs_cores"
+  | "ecs_ram_mib"
+  | "evs_volumes"
+  | "evs_gigabytes"
+  | "vpc_eips"
+  | "vpc_security_groups"
+  | "vpc_subnets"
+  | "cce_clusters";
+export type QuotaUsage = {
+  resource: QuotaResource;
+  label: string;
+  used: number;
+  limit: number | null;
+  remaining: number | null;
+};
+export type QuotaReport = { region: string; quotas: QuotaUsage[]; errors: string[] };
+export type QuotaShortfall = {
+  resource: QuotaResource;
+  label: string;
+  requested: number;
+  used: number;
+  limit: number;
+  remaining: number;
+};
+export type QuotaCheckError = {
+  error: "quota_exceeded";
+  message: string;
+  shortfalls: QuotaShortfall[];
+};
+export type TagOperationResult = { status: string; status_code: number; body: string };
+export type TagInput = { key: string; value?: string | null };
+export type ListParamsInput = {
+  marker?: string | null;
+  limit?: number | null;
+  offset?: number | null;
+  tags?: TagInput[] | null;
+  enterpriseProjectId?: string | null;
+};
+export type TaggableResource = "ecs" | "evs" | "eip" | "nat_gateway" | "cce_cluster" | "obs_bucket";
+export type TagPolicy = {
+  requiredKeys: string[];
+  allowedValues: Record<string, string[]>;
+};
 export type SshConnectResult = {
   sessionId: string;
   host: string;
@@ -220,6 +483,9 @@ export type StartupTaskConfig = {
   region: string;
   autoUpdate: boolean;
   setupGuiRdp: boolean;
+  snapshotBeforeTask: boolean;
+  // Looks up the public IP via api.ipify.org to open SSH/RDP in the security group.
+  openIngressForCurrentIp: boolean;
   rdpUsername: string | null;
   lastStatus: "pending" | "done" | "failed";
   createdAt: string;